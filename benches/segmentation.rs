@@ -0,0 +1,104 @@
+//! Simple throughput benchmarks for EGC segmentation.
+//! 
+//! Run with `cargo bench`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use egc::lookup::{lookup_egc_props, EgcProps};
+use egc::Egc;
+
+/// Allocator which counts heap allocations.
+struct CountingAlloc;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		ALLOCS.fetch_add(1, Ordering::Relaxed);
+		unsafe { System.alloc(layout) }
+	}
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		unsafe { System.dealloc(ptr, layout) }
+	}
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Runs `f` repeatedly for about a second, and prints the throughput over `len` bytes,
+/// and the number of heap allocations.
+fn bench(name: &str, len: usize, mut f: impl FnMut() -> usize) {
+	let mut iters = 0u32;
+	let allocs = ALLOCS.load(Ordering::Relaxed);
+	let start = Instant::now();
+	while start.elapsed() < Duration::from_secs(1) {
+		black_box(f());
+		iters += 1;
+	}
+	let per_iter = start.elapsed() / iters;
+	let allocs_per_iter = (ALLOCS.load(Ordering::Relaxed) - allocs) / iters as usize;
+	let throughput = len as f64 / per_iter.as_secs_f64() / 1e6;
+	println!("{:<32} {:>10.2?}/iter {:>10.1} MB/s {:>8} allocs/iter", name, per_iter, throughput, allocs_per_iter);
+}
+
+fn corpora() -> Vec<(&'static str, String)> {
+	let code = include_str!("../src/logic.rs").repeat(16);
+	let latin = "Le cœur déçu mais l'âme plutôt naïve, Louÿs rêva de crapaüter en canoë au delà des îles. ".repeat(512);
+	let devanagari = "अनुच्छेद १. सभी मनुष्यों को गौरव और अधिकारों के मामले में जन्मजात स्वतन्त्रता प्राप्त है। ".repeat(256);
+	let emoji = "👨‍👩‍👧‍👦🇫🇷🇯🇵👋🏽❤️ ok 🇪🇺🇫🇷 ".repeat(512);
+	vec![("code", code), ("latin", latin), ("devanagari", devanagari), ("emoji", emoji)]
+}
+
+fn main() {
+	// Property lookups in random order, which defeat branch prediction
+	let mut x = 1u32;
+	let chars: Vec<char> = (0..1 << 16)
+		.map(|_| {
+			x ^= x << 13;
+			x ^= x >> 17;
+			x ^= x << 5;
+			x % 0x20000
+		})
+		.filter_map(char::from_u32)
+		.collect();
+	bench("random lookups", chars.len() * 4, || chars.iter().filter(|&&c| lookup_egc_props(c) == EgcProps::XX).count());
+	#[cfg(feature = "rayon")]
+	{
+		// Parallel segmentation only pays off on long strings
+		for size in [1 << 10, 1 << 17, 1 << 20] {
+			let mixed: String = corpora().iter().flat_map(|(_, text)| text.chars().take(1024)).collect();
+			let text: String = mixed.chars().cycle().take(size).collect();
+			bench(&format!("{} chars count", size), text.len(), || text.egc_count());
+			bench(&format!("{} chars parallel count", size), text.len(), || egc::par::par_egc_count(&text));
+		}
+	}
+	for (name, text) in corpora() {
+		bench(&format!("{} forward", name), text.len(), || text.egc_indices().fold(0, |n, _| n + 1));
+		// Per-item overhead of producing slices, which matters most on short EGCs
+		bench(&format!("{} slices", name), text.len(), || text.egcs().fold(0, |n, egc| n + (egc.len() == 1) as usize));
+		bench(&format!("{} slices (loop)", name), text.len(), || {
+			let mut n = 0;
+			for egc in text.egcs() {
+				n += egc.len();
+			}
+			n
+		});
+		bench(&format!("{} count", name), text.len(), || text.egc_count());
+		#[cfg(feature = "rayon")]
+		bench(&format!("{} parallel count", name), text.len(), || egc::par::par_egc_count(&text));
+		let mut positions = Vec::new();
+		bench(&format!("{} break positions", name), text.len(), || {
+			positions.clear();
+			text.egc_break_positions_into(&mut positions);
+			positions.len()
+		});
+		bench(&format!("{} backward", name), text.len(), || text.egc_indices().rev().count());
+		// Starting many short backward iterations measures the setup cost of the iterator
+		bench(&format!("{} last EGC of words", name), text.len(), || {
+			text.split(' ').filter_map(|word| word.egc_indices().rev().next()).count()
+		});
+	}
+}
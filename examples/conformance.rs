@@ -0,0 +1,7 @@
+//! Prints a conformance report of the crate, as JSON.
+//! 
+//! Run with `cargo run --example conformance --features conformance`.
+
+fn main() {
+	println!("{}", egc::conformance::conformance_report().to_json());
+}
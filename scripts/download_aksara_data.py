@@ -0,0 +1,37 @@
+from download_ucd import download_ucd_data
+from url import DATA_DIR
+
+OUTPUT_PATH = f"{DATA_DIR}/aksara_data.rs"
+
+def get_aksara_enum(attrib: dict[str, str], start: int, end: int) -> str:
+	# Same values as in the derivation of Indic_Conjunct_Break, but for all scripts
+	if attrib["InSC"] in ("Virama", "Invisible_Stacker"):
+		return "Linker"
+	if attrib["InSC"] == "Consonant":
+		return "Consonant"
+	return None
+
+
+ranges = download_ucd_data(get_aksara_enum)
+
+# split ranges which do not fit in the u16 count
+split_ranges = []
+for r in ranges:
+	while r.end - r.start + 1 > 0xffff:
+		split_ranges.append((r.start, 0xffff, r.val))
+		r.start += 0xffff
+	split_ranges.append((r.start, r.end - r.start + 1, r.val))
+
+print(f"Writing {OUTPUT_PATH}")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+use crate::lookup::IndicConjunctBreak as I;
+use crate::tailoring::{{AksaraRange, ran}};
+pub const AKSARA_RANGES: [AksaraRange; {len(split_ranges)}] = [
+{
+	"".join(f"\tran(0x{start:05x}, {count: >5}, I::{val}),\n" for start, count, val in split_ranges)
+}];
+""")
+f.flush()
+
+print("Done.")
@@ -1,6 +1,7 @@
 from download_ucd import download_ucd_data
+from url import DATA_DIR
 
-OUTPUT_PATH = "src/data.rs"
+OUTPUT_PATH = f"{DATA_DIR}/data.rs"
 
 GCB_DEPR = ["EB","EBG","EM","GAZ"]
 ENUM_MAP = {
@@ -13,29 +14,49 @@ ENUM_MAP = {
 for gcb in ["XX", "LF","CR","CN", "L","V","T","LV","LVT", "SM","PP","EX","RI"]:
 	ENUM_MAP[(gcb, "None", "N")] = gcb
 
-def get_egc_enum(attrib: dict[str, str], start: int, end: int) -> str:
-	gcb, incb, ext_pict = attrib["GCB"], attrib["InCB"], attrib["ExtPict"]
+# Variants of EgcProps, in the order of their codes
+CODE_ORDER = ["XX", "LF","CR","CN", "L","V","T","LV","LVT", "SM","PP", "IN_CO","ZWJ","IN_EX","IN_LI","EX", "EP", "RI"]
+# Names of the GraphemeClusterBreak variants
+GCB_NAMES = {"XX": "Other", "CN": "Control", "EX": "Extend", "RI": "RegionalIndicator", "PP": "Prepend", "SM": "SpacingMark"}
+
+def get_egc_enum(attrib: dict[str, str], start: int, end: int) -> str | tuple:
+	# (InCB only exists since Unicode 15.1)
+	gcb, incb, ext_pict = attrib["GCB"], attrib.get("InCB", "None"), attrib["ExtPict"]
 	if gcb in GCB_DEPR: gcb = "XX"
 	if (gcb, incb, ext_pict) not in ENUM_MAP:
-		print(f"Unexpected combination: U+{start:06x} to U+{end:06x} has GCB={gcb}, InCB={incb}, ExtPict={ext_pict}")
-		exit(1)
+		# No EgcProps variant: the tables store packed properties, so this only needs
+		# a CharRange with the closest variant for the compatibility view
+		print(f"Note: U+{start:06x} to U+{end:06x} has GCB={gcb}, InCB={incb}, ExtPict={ext_pict}")
+		return (gcb, incb, ext_pict == "Y")
 	val = ENUM_MAP[(gcb, incb, ext_pict)]
 	if val == "XX": return None
 	return val
 
+def range_entry(r) -> str:
+	count = r.end - r.start + 1
+	if isinstance(r.val, str):
+		return f"\tran(0x{r.start:05x}, {count: >4}, P::{r.val}),\n"
+	gcb, incb, ext_pict = r.val
+	# first variant with the same GCB (in the order of the codes), as returned by PackedProps::egc_props
+	view = next(v for v in CODE_ORDER if any(g == gcb and val == v for (g, _, _), val in ENUM_MAP.items()))
+	return (f"\tCharRange {{ start: 0x{r.start:05x}, count: {count}, kind: P::{view}, "
+		f"packed: PackedProps::new(G::{GCB_NAMES.get(gcb, gcb)}, I::{incb}, {str(ext_pict).lower()}) }},\n")
+
 
 ranges = download_ucd_data(get_egc_enum)
 
 # remove precomposed hangul: handled in software to save space
 ranges = [r for r in ranges if r.end < 0xac00 or r.start > 0xd7af]
+packed = any(not isinstance(r.val, str) for r in ranges)
 
 print(f"Writing {OUTPUT_PATH}")
 f = open(OUTPUT_PATH, "w")
 f.write(f"""\
 use crate::lookup::{{EgcProps as P, CharRange, ran}};
+{"use crate::lookup::{GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};" + chr(10) if packed else ""}\
 pub const RANGES: [CharRange; {len(ranges)}] = [
 {
-	"".join(f"\tran(0x{r.start:05x}, {r.end-r.start+1: >4}, P::{r.val}),\n" for r in ranges)
+	"".join(range_entry(r) for r in ranges)
 }];
 """)
 f.flush()
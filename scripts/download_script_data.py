@@ -0,0 +1,91 @@
+import os
+import urllib.request
+
+from download_ucd import UcdRange, download_ucd_data
+from url import DATA_DIR, get_ucd_url
+
+OUTPUT_PATH = f"{DATA_DIR}/script_data.rs"
+
+aliases_url = get_ucd_url() + "/ucd/PropertyValueAliases.txt"
+print(f"Fetching {aliases_url}")
+aliases_path, _ = urllib.request.urlretrieve(aliases_url)
+
+# (short name, long name) of the values of the Script property
+names = []
+with open(aliases_path) as aliases_file:
+	for line in aliases_file:
+		fields = [f.strip() for f in line.split("#")[0].split(";")]
+		if fields[0] == "sc":
+			names.append((fields[1], fields[2]))
+os.remove(aliases_path)
+variants = {short: long.replace("_", "") for short, long in names}
+
+def get_script(attrib: dict[str, str], start: int, end: int) -> str:
+	# Characters not listed have Script=Unknown
+	return None if attrib["sc"] == "Zzzz" else variants[attrib["sc"]]
+
+def get_extensions(attrib: dict[str, str], start: int, end: int) -> tuple[str]:
+	sc, scx = attrib["sc"], attrib["scx"].split()
+	if scx == [sc]:
+		return None
+	# The Script of the character comes first, so that a run of such characters takes it
+	return tuple(variants[s] for s in sorted(scx, key=lambda s: s != sc))
+
+def get_range_values(attrib: dict[str, str], start: int, end: int) -> tuple:
+	return (get_script(attrib, start, end), get_extensions(attrib, start, end))
+
+def table_ranges(ranges: list[UcdRange], idx: int) -> list:
+	"""Merges the ranges with the same value of one of the tables, and splits the ones
+	which do not fit in the u16 count."""
+	merged = []
+	for r in ranges:
+		if r.val[idx] is None: continue
+		if len(merged) > 0 and (prev := merged[-1]).end == r.start-1 and prev.val == r.val[idx]:
+			prev.end = r.end
+		else:
+			merged.append(UcdRange(r.start, r.end, r.val[idx]))
+	split = []
+	for r in merged:
+		while r.end - r.start + 1 > 0xffff:
+			split.append((r.start, 0xffff, r.val))
+			r.start += 0xffff
+		split.append((r.start, r.end - r.start + 1, r.val))
+	return split
+
+ranges = download_ucd_data(get_range_values)
+scripts = table_ranges(ranges, 0)
+extensions = table_ranges(ranges, 1)
+
+print(f"Writing {OUTPUT_PATH}")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+use crate::script::{{Script as S, ScriptRange, ScriptExtRange, ran, ext}};
+
+/// Value of the Script property of a character (UAX #24).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Script {{
+{
+	"".join(f"\t{variants[short]},\n" for short, _ in names)
+}}}
+
+/// Short and long names of the scripts, in the order of [Script]
+pub(crate) const SCRIPT_NAMES: [(&str, &str); {len(names)}] = [
+{
+	"".join(f"\t(\"{short}\", \"{long}\"),\n" for short, long in names)
+}];
+
+pub const SCRIPT_RANGES: [ScriptRange; {len(scripts)}] = [
+{
+	"".join(f"\tran(0x{start:05x}, {count: >5}, S::{val}),\n" for start, count, val in scripts)
+}];
+
+pub const SCRIPT_EXTENSIONS: [ScriptExtRange; {len(extensions)}] = [
+{
+	"".join(f"\text(0x{start:05x}, {count: >5}, &[{', '.join(f'S::{v}' for v in val)}]),\n" for start, count, val in extensions)
+}];
+""")
+f.flush()
+
+print("Done.")
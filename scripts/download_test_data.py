@@ -1,9 +1,9 @@
 import os
 import urllib.request
 
-from url import get_ucd_url
+from url import DATA_DIR, get_ucd_url
 
-OUTPUT_PATH = "src/test_data.rs"
+OUTPUT_PATH = f"{DATA_DIR}/test_data.rs"
 
 test_url = get_ucd_url() + "/ucd/auxiliary/GraphemeBreakTest.txt"
 print(f"Fetching {test_url}")
@@ -43,8 +43,8 @@ def escape_str(s):
 
 f = open(OUTPUT_PATH, "w")
 f.write(f"""\
-use crate::tests::TestCase;
-pub const TEST_CASES: [TestCase; {len(test_cases)}] = [
+use crate::TestCase;
+pub static TEST_CASES: [TestCase; {len(test_cases)}] = [
 """)
 for line_no, test_str, breaks in test_cases:
 	f.write(
@@ -0,0 +1,38 @@
+from download_ucd import download_ucd_data
+from url import DATA_DIR
+
+OUTPUT_PATH = f"{DATA_DIR}/width_data.rs"
+
+def get_width_enum(attrib: dict[str, str], start: int, end: int) -> str:
+	if start == 0xad:
+		# soft hyphen: default ignorable, but usually rendered by terminals
+		return None
+	if attrib["gc"] in ("Mn", "Me", "Cf") or attrib["DI"] == "Y" or 0x1160 <= start <= 0x11ff:
+		return "Zero"
+	if attrib["ea"] in ("W", "F"):
+		return "Wide"
+	return None
+
+
+ranges = download_ucd_data(get_width_enum)
+
+# split ranges which do not fit in the u16 count
+split_ranges = []
+for r in ranges:
+	while r.end - r.start + 1 > 0xffff:
+		split_ranges.append((r.start, 0xffff, r.val))
+		r.start += 0xffff
+	split_ranges.append((r.start, r.end - r.start + 1, r.val))
+
+print(f"Writing {OUTPUT_PATH}")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+use crate::width::{{CharWidth as W, WidthRange, ran}};
+pub const WIDTH_RANGES: [WidthRange; {len(split_ranges)}] = [
+{
+	"".join(f"\tran(0x{start:05x}, {count: >5}, W::{val}),\n" for start, count, val in split_ranges)
+}];
+""")
+f.flush()
+
+print("Done.")
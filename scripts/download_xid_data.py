@@ -0,0 +1,35 @@
+from download_ucd import download_ucd_data
+from url import DATA_DIR
+
+OUTPUT_PATH = f"{DATA_DIR}/xid_data.rs"
+
+def get_xid_enum(attrib: dict[str, str], start: int, end: int) -> str:
+	if attrib["XIDS"] == "Y":
+		return "Start"
+	if attrib["XIDC"] == "Y":
+		return "Continue"
+	return None
+
+
+ranges = download_ucd_data(get_xid_enum)
+
+# split ranges which do not fit in the u16 count
+split_ranges = []
+for r in ranges:
+	while r.end - r.start + 1 > 0xffff:
+		split_ranges.append((r.start, 0xffff, r.val))
+		r.start += 0xffff
+	split_ranges.append((r.start, r.end - r.start + 1, r.val))
+
+print(f"Writing {OUTPUT_PATH}")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+use crate::ident::{{XidClass as X, XidRange, ran}};
+pub const XID_RANGES: [XidRange; {len(split_ranges)}] = [
+{
+	"".join(f"\tran(0x{start:05x}, {count: >5}, X::{val}),\n" for start, count, val in split_ranges)
+}];
+""")
+f.flush()
+
+print("Done.")
@@ -0,0 +1,201 @@
+//! Regenerates `src/data.rs` and `src/test_data.rs` from local copies of the UCD files
+//! (the `gen` feature enables this binary):
+//!
+//! ```text
+//! cargo run --features gen --bin gen-tables -- <ucd-dir> [<output-dir>]
+//! ```
+//!
+//! where `<ucd-dir>` contains `auxiliary/GraphemeBreakProperty.txt`, `auxiliary/GraphemeBreakTest.txt`,
+//! `DerivedCoreProperties.txt` and `emoji/emoji-data.txt`, as in the UCD (files directly in
+//! `<ucd-dir>` are also accepted). The output only depends on the input files.
+//!
+//! The tables of the latest version of Unicode are in `src`, and the ones of older versions,
+//! selected by the `unicode-*` features, in `src/unicode_*` (eg. `src/unicode_15_1`), which can
+//! be given as `<output-dir>`. The version is read from the headers of the files, which must agree,
+//! and checked against `UNICODE_VERSION` (ie. the version selected by the features).
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+use egc::lookup::{GraphemeClusterBreak, IndicConjunctBreak, PackedProps};
+
+/// Precomposed Hangul syllables are handled in software to save space, see `search_packed_props`
+const HANGUL_SYLLABLES: (u32, u32) = (0xac00, 0xd7af);
+
+fn fail(msg: &str) -> ! {
+	eprintln!("error: {}", msg);
+	exit(1)
+}
+
+fn read_ucd_file(dir: &Path, subdir: &str, name: &str) -> String {
+	let path: PathBuf = [dir.join(subdir).join(name), dir.join(name)].into_iter()
+		.find(|p| p.is_file())
+		.unwrap_or_else(|| fail(&format!("{} not found in {}", name, dir.display())));
+	eprintln!("Reading {}", path.display());
+	std::fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path.display(), e)))
+}
+
+/// Reads the Unicode version from the header of a UCD file (eg. `# GraphemeBreakProperty-15.1.0.txt`)
+fn ucd_version(text: &str, name: &str) -> Option<(u8, u8, u8)> {
+	let stem = name.strip_suffix(".txt")?;
+	let header = text.lines().next()?.strip_prefix("# ")?.strip_prefix(stem)?.strip_prefix('-')?;
+	let mut parts = header.strip_suffix(".txt")?.split('.').map(|p| p.parse().ok());
+	let version = (parts.next()??, parts.next()??, parts.next()??);
+	parts.next().is_none().then_some(version)
+}
+
+/// Checks that the versions of the UCD files agree, and returns it if they are known
+fn check_versions(files: &[(&str, &str)]) -> Option<(u8, u8, u8)> {
+	let mut found: Option<(&str, (u8, u8, u8))> = None;
+	for &(name, text) in files {
+		let Some(version) = ucd_version(text, name) else {
+			eprintln!("warning: no version in the header of {}", name);
+			continue;
+		};
+		match found {
+			Some((first, v)) if v != version => fail(&format!("{} and {} are from different versions of Unicode", first, name)),
+			_ => found = Some((name, version)),
+		}
+	}
+	found.map(|(_, v)| v)
+}
+
+/// Parses the lines of a UCD property file, as `(first, last, fields)`, without the comments
+fn parse_lines(text: &str) -> impl Iterator<Item = (u32, u32, Vec<&str>)> {
+	text.lines().filter_map(|line| {
+		let line = line.split('#').next().unwrap().trim();
+		if line.is_empty() {
+			return None;
+		}
+		let mut fields = line.split(';').map(str::trim);
+		let cps = fields.next().unwrap();
+		let (first, last) = cps.split_once("..").unwrap_or((cps, cps));
+		let parse = |s| u32::from_str_radix(s, 16).unwrap_or_else(|_| fail(&format!("invalid code point in {:?}", line)));
+		Some((parse(first), parse(last), fields.collect()))
+	})
+}
+
+fn generate_data(gcb_text: &str, incb_text: &str, emoji_text: &str) -> String {
+	let mut gcb = vec![GraphemeClusterBreak::Other; 0x110000];
+	let mut incb = vec![IndicConjunctBreak::None; 0x110000];
+	let mut ext_pict = vec![false; 0x110000];
+	for (first, last, fields) in parse_lines(gcb_text) {
+		let value = fields[0].parse().unwrap_or_else(|_| fail(&format!("unknown GCB value {}", fields[0])));
+		gcb[first as usize..=last as usize].fill(value);
+	}
+	for (first, last, fields) in parse_lines(incb_text) {
+		if fields[0] == "InCB" {
+			let value = fields[1].parse().unwrap_or_else(|_| fail(&format!("unknown InCB value {}", fields[1])));
+			incb[first as usize..=last as usize].fill(value);
+		}
+	}
+	for (first, last, fields) in parse_lines(emoji_text) {
+		if fields[0] == "Extended_Pictographic" {
+			ext_pict[first as usize..=last as usize].fill(true);
+		}
+	}
+
+	// (start, count, properties) for characters other than XX, merged when adjacent
+	let mut ranges: Vec<(u32, u32, PackedProps)> = Vec::new();
+	for cp in (0..0x110000).filter(|&cp| char::from_u32(cp).is_some()) {
+		let packed = PackedProps::new(gcb[cp as usize], incb[cp as usize], ext_pict[cp as usize]);
+		if packed.bits() == 0 {
+			continue;
+		}
+		match ranges.last_mut() {
+			Some((start, count, last)) if *start + *count == cp && *last == packed => *count += 1,
+			_ => ranges.push((cp, 1, packed)),
+		}
+	}
+	ranges.retain(|&(start, count, _)| start + count - 1 < HANGUL_SYLLABLES.0 || start > HANGUL_SYLLABLES.1);
+
+	let mut out = String::new();
+	let mut entries = String::new();
+	let mut new_combinations = false;
+	for &(start, count, packed) in &ranges {
+		if count > u16::MAX as u32 {
+			fail(&format!("range at U+{:06X} is too long", start));
+		}
+		match packed.to_egc_props() {
+			Some(props) => writeln!(entries, "\tran(0x{:05x}, {: >4}, P::{}),", start, count, props.name()).unwrap(),
+			None => {
+				// The rules and the derived tables read the packed properties, so this needs no new
+				// EgcProps variant: the closest one is only used by the compatibility view
+				eprintln!("note: U+{:06X} has GCB={}, InCB={}, ExtPict={}, which no EgcProps variant represents",
+					start, packed.gcb(), packed.incb(), packed.is_ext_pict());
+				new_combinations = true;
+				writeln!(entries, "\tCharRange {{ start: 0x{:05x}, count: {}, kind: P::{}, packed: PackedProps::new(G::{:?}, I::{:?}, {}) }},",
+					start, count, packed.egc_props().name(), packed.gcb(), packed.incb(), packed.is_ext_pict()).unwrap();
+			},
+		}
+	}
+	out.push_str("use crate::lookup::{EgcProps as P, CharRange, ran};\n");
+	if new_combinations {
+		out.push_str("use crate::lookup::{GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};\n");
+	}
+	writeln!(out, "pub const RANGES: [CharRange; {}] = [", ranges.len()).unwrap();
+	out.push_str(&entries);
+	out.push_str("];\n");
+	out
+}
+
+fn generate_test_data(text: &str) -> String {
+	let mut cases = Vec::new();
+	for (line_idx, line) in text.lines().enumerate() {
+		let line = line.split('#').next().unwrap().trim();
+		if line.is_empty() {
+			continue;
+		}
+		let Some(line) = line.strip_prefix("÷ ").and_then(|l| l.strip_suffix(" ÷")) else {
+			fail(&format!("malformed test on line {}", line_idx + 1));
+		};
+		let mut test_str = String::new();
+		let mut breaks = Vec::new();
+		for part in line.split_whitespace() {
+			match part {
+				"×" => (),
+				"÷" => breaks.push(test_str.len().to_string()),
+				_ => test_str.push(u32::from_str_radix(part, 16).ok().and_then(char::from_u32)
+					.unwrap_or_else(|| fail(&format!("invalid character on line {}", line_idx + 1)))),
+			}
+		}
+		let escaped: String = test_str.chars().map(|c| {
+			if c as u32 <= 0x7f { format!("\\x{:02x}", c as u32) } else { format!("\\u{{{:04x}}}", c as u32) }
+		}).collect();
+		cases.push(format!("\tTestCase::new({}, \"{}\", &[{}]),\n", line_idx + 1, escaped, breaks.join(",")));
+	}
+
+	let mut out = String::new();
+	out.push_str("use crate::TestCase;\n");
+	writeln!(out, "pub static TEST_CASES: [TestCase; {}] = [", cases.len()).unwrap();
+	out.extend(cases);
+	out.push_str("];\n");
+	out
+}
+
+fn main() {
+	let args: Vec<String> = std::env::args().collect();
+	let (dir, out) = match &args[..] {
+		[_, dir] => (Path::new(dir), Path::new(env!("CARGO_MANIFEST_DIR")).join("src")),
+		[_, dir, out] => (Path::new(dir), PathBuf::from(out)),
+		_ => fail("usage: gen-tables <ucd-dir> [<output-dir>]"),
+	};
+	let gcb = read_ucd_file(dir, "auxiliary", "GraphemeBreakProperty.txt");
+	let incb = read_ucd_file(dir, "", "DerivedCoreProperties.txt");
+	let emoji = read_ucd_file(dir, "emoji", "emoji-data.txt");
+	let test = read_ucd_file(dir, "auxiliary", "GraphemeBreakTest.txt");
+	let files = [("GraphemeBreakProperty.txt", &*gcb), ("DerivedCoreProperties.txt", &*incb), ("GraphemeBreakTest.txt", &*test)];
+	match check_versions(&files) {
+		Some(version) if version != egc::UNICODE_VERSION => eprintln!(
+			"warning: the files are from Unicode {:?}, update UNICODE_VERSION in src/lib.rs and the README, \
+			or enable the feature of that version", version),
+		Some(version) => eprintln!("Unicode version: {:?}", version),
+		None => (),
+	}
+	for (name, contents) in [("data.rs", generate_data(&gcb, &incb, &emoji)), ("test_data.rs", generate_test_data(&test))] {
+		let path = out.join(name);
+		eprintln!("Writing {}", path.display());
+		std::fs::write(&path, contents).unwrap_or_else(|e| fail(&format!("{}: {}", path.display(), e)));
+	}
+	eprintln!("Done. Run the generate_*_data.py scripts (with the same output directory) to update the derived tables.");
+}
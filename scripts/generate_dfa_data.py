@@ -0,0 +1,50 @@
+from read_egc_data import DATA_DIR, INPUT_PATH, read_egc_props, table
+
+OUTPUT_PATH = f"{DATA_DIR}/dfa_data.rs"
+
+LEAF = 0x8000
+MAX_CP = 0x140000 # codepoints reachable from 4-byte UTF-8 lead bytes
+
+props = read_egc_props(MAX_CP)
+
+# Trie over UTF-8 continuation bytes: each node has 64 entries, which are either
+# the index of a child node, or LEAF | props if all the codepoints below have the same properties
+nodes = []
+node_ids = {}
+def entry(base: int, size: int) -> int:
+	block = props[base:base+size]
+	if block.count(block[0]) == size:
+		return LEAF | block[0]
+	child_size = size // 64
+	node = tuple(entry(base + i * child_size, child_size) for i in range(64))
+	if node not in node_ids:
+		node_ids[node] = len(nodes)
+		nodes.append(node)
+	return node_ids[node]
+
+lead = []
+for b in range(256):
+	if b < 0x80:
+		lead.append(LEAF | props[b])
+	elif 0xc2 <= b <= 0xdf:
+		lead.append(entry((b & 0x1f) << 6, 64))
+	elif 0xe0 <= b <= 0xef:
+		lead.append(entry((b & 0x0f) << 12, 64 * 64))
+	elif 0xf0 <= b <= 0xf4:
+		lead.append(entry((b & 0x07) << 18, 64 * 64 * 64))
+	else:
+		# never appear in valid UTF-8
+		lead.append(LEAF)
+
+print(f"Writing {OUTPUT_PATH} ({len(nodes)} nodes)")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+// Generated by scripts/generate_dfa_data.py from {INPUT_PATH}
+pub static LEAD: [u16; 256] = [
+{table(lead, 4)}];
+pub static NODES: [u16; {len(nodes) * 64}] = [
+{table([e for node in nodes for e in node], 4)}];
+""")
+f.flush()
+
+print("Done.")
@@ -0,0 +1,34 @@
+from read_egc_data import DATA_DIR, INPUT_PATH, read_egc_props, table
+
+OUTPUT_PATH = f"{DATA_DIR}/lookup_data.rs"
+
+PAGE_SIZE = 256
+
+props = read_egc_props()
+
+# Two-stage table: the first stage maps each page of codepoints to a deduplicated page
+# of packed properties in the second stage
+pages = []
+page_ids = {}
+stage1 = []
+for base in range(0, len(props), PAGE_SIZE):
+	page = tuple(props[base:base+PAGE_SIZE])
+	if page not in page_ids:
+		page_ids[page] = len(pages)
+		pages.append(page)
+	stage1.append(page_ids[page])
+assert len(pages) <= 256
+
+print(f"Writing {OUTPUT_PATH} ({len(pages)} pages)")
+f = open(OUTPUT_PATH, "w")
+f.write(f"""\
+// Generated by scripts/generate_lookup_data.py from {INPUT_PATH}
+pub const PAGE_SIZE: usize = {PAGE_SIZE};
+pub static STAGE1: [u8; {len(stage1)}] = [
+{table(stage1, 2)}];
+pub static STAGE2: [u8; {len(pages) * PAGE_SIZE}] = [
+{table([p for page in pages for p in page], 2)}];
+""")
+f.flush()
+
+print("Done.")
@@ -0,0 +1,59 @@
+import re
+import sys
+
+# Directory of the tables, eg. src/unicode_15_1 for the tables of an older version
+DATA_DIR = sys.argv[1] if len(sys.argv) > 1 else "src"
+INPUT_PATH = f"{DATA_DIR}/data.rs"
+
+# Values of the properties, in the order of GraphemeClusterBreak::ALL and IndicConjunctBreak::ALL
+GCB = ["Other", "CR", "LF", "Control", "Extend", "ZWJ", "RegionalIndicator", "Prepend", "SpacingMark", "L", "V", "T", "LV", "LVT"]
+INCB = ["None", "Linker", "Consonant", "Extend"]
+
+def packed(gcb: str, incb: str, ext_pict: bool) -> int:
+	"""Returns the PackedProps::bits() of the given property values."""
+	return GCB.index(gcb) | INCB.index(incb) << 4 | ext_pict << 6
+
+# PackedProps of the EgcProps variants
+PROPS = {
+	"XX": packed("Other", "None", False),
+	"LF": packed("LF", "None", False),
+	"CR": packed("CR", "None", False),
+	"CN": packed("Control", "None", False),
+	"L": packed("L", "None", False),
+	"V": packed("V", "None", False),
+	"T": packed("T", "None", False),
+	"LV": packed("LV", "None", False),
+	"LVT": packed("LVT", "None", False),
+	"SM": packed("SpacingMark", "None", False),
+	"PP": packed("Prepend", "None", False),
+	"IN_CO": packed("Other", "Consonant", False),
+	"ZWJ": packed("ZWJ", "Extend", False),
+	"IN_EX": packed("Extend", "Extend", False),
+	"IN_LI": packed("Extend", "Linker", False),
+	"EX": packed("Extend", "None", False),
+	"EP": packed("Other", "None", True),
+	"RI": packed("RegionalIndicator", "None", False),
+}
+
+def read_egc_props(size: int = 0x110000) -> list[int]:
+	"""Rebuilds the packed properties of the first `size` codepoints from the main data table,
+	and the special cases of lookup_packed_props."""
+	props = [0] * size
+	for cp in range(0xac00, 0xd7a4):
+		props[cp] = PROPS["LV" if (cp - 0xac00) % 28 == 0 else "LVT"]
+	text = open(INPUT_PATH).read()
+	for m in re.finditer(r"ran\(0x([0-9a-f]+),\s*(\d+), P::(\w+)\)", text):
+		start, count, val = int(m[1], 16), int(m[2]), PROPS[m[3]]
+		props[start:start+count] = [val] * count
+	# Combinations of properties without an EgcProps variant
+	for m in re.finditer(r"start: 0x([0-9a-f]+), count: (\d+), .*PackedProps::new\(G::(\w+), I::(\w+), (\w+)\)", text):
+		start, count, val = int(m[1], 16), int(m[2]), packed(m[3], m[4], m[5] == "true")
+		props[start:start+count] = [val] * count
+	return props
+
+def table(values: list[int], digits: int) -> str:
+	"""Formats values as the lines of a Rust array literal."""
+	return "".join(
+		"\t" + " ".join(f"0x{v:0{digits}x}," for v in values[i:i+16]) + "\n"
+		for i in range(0, len(values), 16)
+	)
@@ -1,8 +1,8 @@
-UNICODE_VERSION = "latest"
+import sys
+
+# Version of the UCD to download, and directory of the tables to write: the download scripts
+# take them as arguments for the tables of an older version (eg. "15.1.0 src/unicode_15_1")
+UNICODE_VERSION, DATA_DIR = (sys.argv[1], sys.argv[2]) if len(sys.argv) > 2 else ("16.0.0", "src")
 
 def get_ucd_url():
-	if UNICODE_VERSION == "latest":
-		subdir = "UCD/latest"
-	else:
-		subdir = UNICODE_VERSION
-	return "https://www.unicode.org/Public/" + subdir
+	return "https://www.unicode.org/Public/" + UNICODE_VERSION
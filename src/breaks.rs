@@ -0,0 +1,211 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::Egc;
+
+/// The EGC boundaries of a string, stored as a bitset over its byte offsets.
+/// 
+/// This takes one bit per byte of the string (plus one), and answers
+/// [is_boundary](EgcBreaks::is_boundary) queries in constant time, making it
+/// suitable for caching the boundaries of many lines of text.
+/// 
+/// The offsets 0 and the length of the string are always boundaries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EgcBreaks {
+	bits: Vec<u64>,
+	len: usize,
+}
+
+impl EgcBreaks {
+	/// Computes the EGC boundaries of `s`.
+	pub fn new(s: &str) -> Self {
+		let mut bits = vec![0; s.len() / 64 + 1];
+		bits[0] = 1;
+		for i in s.egc_indices() {
+			bits[i / 64] |= 1 << (i % 64);
+		}
+		EgcBreaks { bits, len: s.len() }
+	}
+
+	/// Returns the length of the string, in bytes.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns true if the string is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Is `index` an EGC boundary of the string.
+	/// 
+	/// Returns false if `index` is greater than the length of the string.
+	pub fn is_boundary(&self, index: usize) -> bool {
+		index <= self.len && self.bits[index / 64] & (1 << (index % 64)) != 0
+	}
+
+	/// Returns the first EGC boundary strictly after `index`,
+	/// or None if `index` is at or past the end of the string.
+	pub fn next_after(&self, index: usize) -> Option<usize> {
+		if index >= self.len {
+			return None;
+		}
+		let start = index + 1;
+		let mut word = start / 64;
+		let mut bits = self.bits[word] & (!0 << (start % 64));
+		while bits == 0 {
+			word += 1;
+			bits = self.bits[word];
+		}
+		Some(word * 64 + bits.trailing_zeros() as usize)
+	}
+
+	/// Returns the last EGC boundary strictly before `index`,
+	/// or None if `index` is 0.
+	/// 
+	/// If `index` is past the end of the string, this returns the length of the string.
+	pub fn prev_before(&self, index: usize) -> Option<usize> {
+		if index == 0 {
+			return None;
+		}
+		if index > self.len {
+			return Some(self.len);
+		}
+		let end = index - 1;
+		let mut word = end / 64;
+		let mut bits = self.bits[word] & (!0 >> (63 - end % 64));
+		while bits == 0 {
+			word -= 1;
+			bits = self.bits[word];
+		}
+		Some(word * 64 + 63 - bits.leading_zeros() as usize)
+	}
+
+	/// Updates the boundaries after the bytes `range` of the string were replaced
+	/// with `new_len` bytes, `s` being the string after the replacement.
+	/// 
+	/// Boundaries only depend on the text before them, so only the text from the start
+	/// of the EGC containing the replacement is segmented again, until a boundary
+	/// which already existed before the replacement is found after it. The boundaries
+	/// after that are shifted.
+	/// 
+	/// Panics if `range` is out of bounds, or if the length of `s` does not match.
+	pub fn splice(&mut self, s: &str, range: Range<usize>, new_len: usize) {
+		assert!(range.start <= range.end && range.end <= self.len, "range out of bounds");
+		assert_eq!(s.len(), self.len - range.len() + new_len, "length of the new string does not match");
+		let start = self.prev_before(range.start).unwrap_or(0);
+		let new_end = range.start + new_len;
+		// Offset in the old string corresponding to an offset after the replacement
+		let old_offset = |i: usize| i - new_end + range.end;
+
+		let mut bits = vec![0; s.len() / 64 + 1];
+		bits[..=start / 64].copy_from_slice(&self.bits[..=start / 64]);
+		bits[start / 64] &= !0 >> (63 - start % 64);
+		let mut resume = s.len();
+		for i in s[start..].egc_indices() {
+			let i = start + i;
+			bits[i / 64] |= 1 << (i % 64);
+			if i >= new_end && self.is_boundary(old_offset(i)) {
+				resume = i;
+				break;
+			}
+		}
+		// Segmentation after a boundary does not depend on the text before it,
+		// so the following boundaries are the same as before
+		bits[resume / 64] |= read_bits(&self.bits, old_offset(resume)) << (resume % 64);
+		for (word, bits) in bits.iter_mut().enumerate().skip(resume / 64 + 1) {
+			*bits = read_bits(&self.bits, old_offset(word * 64));
+		}
+		self.bits = bits;
+		self.len = s.len();
+	}
+}
+
+/// Reads the 64 bits starting at bit `pos` of `bits`, with bits past the end being 0.
+fn read_bits(bits: &[u64], pos: usize) -> u64 {
+	let (word, shift) = (pos / 64, pos % 64);
+	let lo = bits.get(word).map_or(0, |w| w >> shift);
+	let hi = if shift == 0 { 0 } else { bits.get(word + 1).map_or(0, |w| w << (64 - shift)) };
+	lo | hi
+}
+
+/// An index of the EGCs of a string, for converting between byte offsets and EGC indices.
+/// 
+/// This stores the [EgcBreaks] of the string, along with the number of boundaries
+/// before each 64-byte block, so that the EGC containing a byte offset can be found
+/// in constant time (rank), and the offset of an EGC in logarithmic time (select).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EgcIndex {
+	breaks: EgcBreaks,
+	/// Number of boundaries in the blocks before each block
+	ranks: Vec<usize>,
+}
+
+impl EgcIndex {
+	/// Builds the index of the EGCs of `s`.
+	pub fn new(s: &str) -> Self {
+		let mut index = EgcIndex { breaks: EgcBreaks::new(s), ranks: Vec::new() };
+		index.update_ranks();
+		index
+	}
+
+	/// Recomputes the number of boundaries before each block.
+	fn update_ranks(&mut self) {
+		self.ranks.clear();
+		let mut rank = 0;
+		for word in &self.breaks.bits {
+			self.ranks.push(rank);
+			rank += word.count_ones() as usize;
+		}
+	}
+
+	/// Returns the EGC boundaries of the string.
+	pub fn breaks(&self) -> &EgcBreaks {
+		&self.breaks
+	}
+
+	/// Returns the number of EGCs in the string.
+	pub fn egc_count(&self) -> usize {
+		let last = self.ranks.len() - 1;
+		self.ranks[last] + self.breaks.bits[last].count_ones() as usize - 1
+	}
+
+	/// Returns the index of the EGC containing the byte at offset `index`,
+	/// or None if `index` is not less than the length of the string.
+	pub fn egc_at(&self, index: usize) -> Option<usize> {
+		if index >= self.breaks.len {
+			return None;
+		}
+		// Number of boundaries up to and including `index`
+		let rank = self.ranks[index / 64] + (self.breaks.bits[index / 64] << (63 - index % 64)).count_ones() as usize;
+		Some(rank - 1)
+	}
+
+	/// Returns the byte offset at which the EGC of index `n` starts, or the length of
+	/// the string if `n` is the number of EGCs. Returns None if `n` is greater.
+	pub fn egc_offset(&self, n: usize) -> Option<usize> {
+		if n > self.egc_count() {
+			return None;
+		}
+		// The block containing the boundary is the last one with fewer boundaries before it
+		let block = self.ranks.partition_point(|&rank| rank <= n) - 1;
+		let mut bits = self.breaks.bits[block];
+		for _ in 0..n - self.ranks[block] {
+			bits &= bits - 1;
+		}
+		Some(block * 64 + bits.trailing_zeros() as usize)
+	}
+
+	/// Updates the index after the bytes `range` of the string were replaced with `new_len`
+	/// bytes, `s` being the string after the replacement. See [EgcBreaks::splice].
+	pub fn splice(&mut self, s: &str, range: Range<usize>, new_len: usize) {
+		self.breaks.splice(s, range, new_len);
+		self.update_ranks();
+	}
+
+	/// Returns the byte range of the EGC of index `n`, or None if there are not enough EGCs.
+	pub fn egc_range(&self, n: usize) -> Option<Range<usize>> {
+		Some(self.egc_offset(n)?..self.egc_offset(n + 1)?)
+	}
+}
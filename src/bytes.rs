@@ -0,0 +1,118 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::Utf8Chunks;
+
+use crate::logic::{first_boundary, Segmenter};
+use crate::lookup::{EgcProps, PackedProps};
+
+/// An iterator over the EGCs of bytes which may not be valid UTF-8, returned as byte ranges.
+/// 
+/// Obtained with [egc_ranges_lossy] or [egc_ranges_os].
+#[derive(Debug, Clone)]
+pub struct LossyEgcRanges<'a> {
+	bytes: &'a [u8],
+	chunks: Utf8Chunks<'a>,
+	// Valid part of the current chunk which has not been segmented yet
+	valid: &'a str,
+	// Invalid part of the current chunk, if not segmented yet
+	invalid: Range<usize>,
+	offset: usize,
+	// Whether surrogates encoded as in WTF-8 are segmented as single characters
+	wtf8: bool,
+}
+
+impl Iterator for LossyEgcRanges<'_> {
+	type Item = Range<usize>;
+	fn next(&mut self) -> Option<Range<usize>> {
+		let start = self.offset;
+		if start == self.bytes.len() {
+			return None;
+		}
+		let mut seg = Segmenter::new();
+		loop {
+			if !self.valid.is_empty() {
+				// Fast path for EGCs entirely within the valid part
+				if seg.offset() == 0 {
+					let end = first_boundary(self.valid);
+					if end < self.valid.len() {
+						self.valid = &self.valid[end..];
+						self.offset += end;
+						return Some(start..self.offset);
+					}
+				}
+				for (i, c) in self.valid.char_indices() {
+					if let Some(end) = seg.push_char(c) {
+						self.valid = &self.valid[i..];
+						self.offset += end;
+						return Some(start..self.offset);
+					}
+				}
+				self.valid = "";
+			}
+			if !self.invalid.is_empty() {
+				// Invalid UTF-8 is segmented like the U+FFFD which would replace it,
+				// and surrogates like the control characters they are
+				let (props, len) = if self.wtf8 && is_wtf8_surrogate(&self.bytes[self.invalid.start..]) {
+					(PackedProps::from_egc_props(EgcProps::CN), 3)
+				} else {
+					(PackedProps::from_egc_props(EgcProps::XX), self.invalid.len())
+				};
+				if let Some(end) = seg.push_props('\u{fffd}', props, len) {
+					self.offset += end;
+					return Some(start..self.offset);
+				}
+				if len != self.invalid.len() {
+					self.chunks = self.bytes[self.invalid.start + len..].utf8_chunks();
+				}
+				self.invalid = 0..0;
+			}
+			let Some(chunk) = self.chunks.next() else {
+				self.offset = self.bytes.len();
+				return Some(start..self.offset);
+			};
+			self.valid = chunk.valid();
+			let invalid_start = start + seg.offset() + chunk.valid().len();
+			self.invalid = invalid_start..invalid_start + chunk.invalid().len();
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.bytes.len() - self.offset;
+		(remaining.min(1), Some(remaining))
+	}
+}
+impl FusedIterator for LossyEgcRanges<'_> {}
+
+/// Returns an iterator over the EGCs of `bytes`, returned as byte ranges, where the bytes
+/// are expected to be UTF-8 but may not be (eg. raw file contents or network buffers).
+/// 
+/// Each maximal invalid subsequence is segmented as if it had been replaced by a U+FFFD,
+/// following the replacement policy of the WHATWG Encoding Standard (and of
+/// `String::from_utf8_lossy`), so the EGCs are the same as the ones of the replaced text.
+/// In particular, combining marks following invalid UTF-8 are part of its EGC.
+pub fn egc_ranges_lossy(bytes: &[u8]) -> LossyEgcRanges<'_> {
+	LossyEgcRanges {
+		bytes,
+		chunks: bytes.utf8_chunks(),
+		valid: "",
+		invalid: 0..0,
+		offset: 0,
+		wtf8: false,
+	}
+}
+
+/// Does `bytes` start with a surrogate code point, encoded as in WTF-8
+fn is_wtf8_surrogate(bytes: &[u8]) -> bool {
+	matches!(bytes, [0xed, 0xa0..=0xbf, 0x80..=0xbf, ..])
+}
+
+/// Returns an iterator over the EGCs of an `OsStr` (eg. a file name), returned as
+/// byte ranges in [OsStr::as_encoded_bytes](std::ffi::OsStr::as_encoded_bytes).
+/// 
+/// Like [egc_ranges_lossy], but unpaired surrogates (which can appear in file names
+/// on Windows) are segmented as single characters rather than invalid UTF-8, so each
+/// is a cluster of its own, as surrogates are control characters. Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn egc_ranges_os(s: &std::ffi::OsStr) -> LossyEgcRanges<'_> {
+	LossyEgcRanges { wtf8: true, ..egc_ranges_lossy(s.as_encoded_bytes()) }
+}
@@ -0,0 +1,4 @@
+/// Facade mirroring the grapheme cluster segmenter of the `icu_segmenter` crate.
+pub mod icu_segmenter;
+/// Facade mirroring the grapheme iterators of the `unicode-segmentation` crate.
+pub mod unicode_segmentation;
@@ -0,0 +1,39 @@
+use crate::iter::EgcIndices;
+use crate::Egc;
+
+/// Grapheme cluster segmenter with the same method names and shapes as
+/// `icu_segmenter::GraphemeClusterSegmenter`, so that code written against
+/// one can switch to the other (eg. behind a trait) during an evaluation or a migration.
+/// 
+/// Unlike the ICU4X segmenter, this has no data to load, so it is free to construct.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphemeClusterSegmenter;
+
+impl GraphemeClusterSegmenter {
+	/// Creates a segmenter.
+	pub const fn new() -> Self {
+		GraphemeClusterSegmenter
+	}
+
+	/// Returns an iterator over the grapheme cluster boundaries of `input`,
+	/// including 0 and the length of the string, like its ICU4X counterpart
+	/// (only 0 is returned for an empty string).
+	pub fn segment_str<'s>(&self, input: &'s str) -> GraphemeClusterBreakIterator<'s> {
+		GraphemeClusterBreakIterator { start: Some(0), inner: input.egc_indices() }
+	}
+}
+
+/// An iterator over the grapheme cluster boundaries of a string, returned by
+/// [GraphemeClusterSegmenter::segment_str].
+#[derive(Debug, Clone)]
+pub struct GraphemeClusterBreakIterator<'s> {
+	start: Option<usize>,
+	inner: EgcIndices<'s>,
+}
+
+impl Iterator for GraphemeClusterBreakIterator<'_> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		self.start.take().or_else(|| self.inner.next())
+	}
+}
@@ -0,0 +1,130 @@
+use crate::logic::{first_boundary, prev_boundary};
+use crate::lookup::lookup_packed_props;
+use crate::tailoring;
+
+/// Extension trait with the same grapheme methods as `unicode_segmentation::UnicodeSegmentation`,
+/// so that existing code can switch crates by changing its imports.
+/// 
+/// As in `unicode-segmentation`, passing `is_extended = false` returns legacy grapheme clusters,
+/// which are not extended by spacing marks (rule GB9a) or prepended characters (rule GB9b).
+pub trait UnicodeSegmentation {
+	/// Returns an iterator over the grapheme clusters of the string, in both directions.
+	fn graphemes(&self, is_extended: bool) -> Graphemes<'_>;
+
+	/// Returns an iterator over the grapheme clusters of the string along with their
+	/// _start_ offsets, like `char_indices` (unlike [egc_indices](crate::Egc::egc_indices)).
+	fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_>;
+}
+
+impl UnicodeSegmentation for str {
+	fn graphemes(&self, is_extended: bool) -> Graphemes<'_> {
+		Graphemes { str: self, front: 0, back: self.len(), is_extended }
+	}
+	fn grapheme_indices(&self, is_extended: bool) -> GraphemeIndices<'_> {
+		GraphemeIndices { inner: self.graphemes(is_extended) }
+	}
+}
+
+/// Is there a legacy grapheme cluster boundary between `c1` and `c2` where there is
+/// no extended one (ie. one prevented by rules GB9a or GB9b)
+fn is_legacy_only_boundary(c1: char, c2: char) -> bool {
+	tailoring::is_legacy_only_boundary(lookup_packed_props(c1), lookup_packed_props(c2))
+}
+
+/// Iterator over the grapheme clusters of a string, returned by [UnicodeSegmentation::graphemes].
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+	str: &'a str,
+	front: usize,
+	back: usize,
+	is_extended: bool,
+}
+
+impl<'a> Graphemes<'a> {
+	/// Returns the part of the string which has not been iterated over yet.
+	pub fn as_str(&self) -> &'a str {
+		&self.str[self.front..self.back]
+	}
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+	type Item = &'a str;
+	fn next(&mut self) -> Option<&'a str> {
+		let rest = self.as_str();
+		if rest.is_empty() {
+			return None;
+		}
+		let mut len = first_boundary(rest);
+		if !self.is_extended {
+			let mut chars = rest[..len].char_indices().peekable();
+			while let (Some((_, c1)), Some(&(i, c2))) = (chars.next(), chars.peek()) {
+				if is_legacy_only_boundary(c1, c2) {
+					len = i;
+					break;
+				}
+			}
+		}
+		self.front += len;
+		Some(&rest[..len])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len.min(1), Some(len))
+	}
+}
+
+impl<'a> DoubleEndedIterator for Graphemes<'a> {
+	fn next_back(&mut self) -> Option<&'a str> {
+		if self.front == self.back {
+			return None;
+		}
+		// Legacy boundaries are a superset of the extended ones, and the front offset
+		// is one, so the last legacy boundary found is not before it
+		let mut start = prev_boundary(self.str, self.back);
+		if !self.is_extended {
+			let mut chars = self.str[start..self.back].char_indices().rev().peekable();
+			while let (Some((i, c2)), Some(&(_, c1))) = (chars.next(), chars.peek()) {
+				if is_legacy_only_boundary(c1, c2) {
+					start += i;
+					break;
+				}
+			}
+		}
+		let egc = &self.str[start..self.back];
+		self.back = start;
+		Some(egc)
+	}
+}
+
+/// Iterator over the grapheme clusters of a string and their start offsets,
+/// returned by [UnicodeSegmentation::grapheme_indices].
+#[derive(Debug, Clone)]
+pub struct GraphemeIndices<'a> {
+	inner: Graphemes<'a>,
+}
+
+impl<'a> GraphemeIndices<'a> {
+	/// Returns the part of the string which has not been iterated over yet.
+	pub fn as_str(&self) -> &'a str {
+		self.inner.as_str()
+	}
+}
+
+impl<'a> Iterator for GraphemeIndices<'a> {
+	type Item = (usize, &'a str);
+	fn next(&mut self) -> Option<(usize, &'a str)> {
+		let start = self.inner.front;
+		self.inner.next().map(|egc| (start, egc))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl DoubleEndedIterator for GraphemeIndices<'_> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(|egc| (self.inner.back, egc))
+	}
+}
@@ -0,0 +1,169 @@
+use std::fmt::Write;
+use std::time::Instant;
+
+use crate::logic::next_boundary;
+use crate::test_data::TEST_CASES;
+use crate::{Egc, UNICODE_VERSION};
+
+/// Result of one check of a [ConformanceReport].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+	/// Name of the check
+	pub name: &'static str,
+	/// Number of test cases
+	pub cases: usize,
+	/// Number of test cases which passed
+	pub passed: usize,
+	/// Descriptions of the test cases which failed (the line in `GraphemeBreakTest.txt`,
+	/// or the name of the adversarial input)
+	pub failures: Vec<String>,
+	/// Time taken by the check, in microseconds
+	pub micros: u128,
+}
+
+/// Memory used by backward iteration over one of the adversarial inputs of a [ConformanceReport].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverseMemory {
+	/// Name of the input
+	pub name: &'static str,
+	/// Length of the input, in bytes
+	pub len: usize,
+	/// Maximum number of boundaries buffered by [rev](crate::iter::EgcIndices::rev)
+	pub max_buffered: usize,
+}
+
+/// Report of the conformance and performance of the crate, returned by [conformance_report].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConformanceReport {
+	/// Unicode version of the data tables
+	pub unicode_version: (u8, u8, u8),
+	/// Results of the checks
+	pub checks: Vec<CheckResult>,
+	/// Memory used by backward iteration
+	pub reverse_memory: Vec<ReverseMemory>,
+}
+
+impl ConformanceReport {
+	/// Formats the report as a JSON object.
+	pub fn to_json(&self) -> String {
+		let (major, minor, patch) = self.unicode_version;
+		let mut json = format!("{{\"unicode_version\":\"{}.{}.{}\",\"checks\":[", major, minor, patch);
+		for (i, check) in self.checks.iter().enumerate() {
+			let sep = if i > 0 { "," } else { "" };
+			let failures: Vec<String> = check.failures.iter().map(|f| format!("\"{}\"", f)).collect();
+			write!(json, "{}{{\"name\":\"{}\",\"cases\":{},\"passed\":{},\"failures\":[{}],\"micros\":{}}}",
+				sep, check.name, check.cases, check.passed, failures.join(","), check.micros).unwrap();
+		}
+		json.push_str("],\"reverse_memory\":[");
+		for (i, mem) in self.reverse_memory.iter().enumerate() {
+			let sep = if i > 0 { "," } else { "" };
+			write!(json, "{}{{\"name\":\"{}\",\"len\":{},\"max_buffered\":{}}}",
+				sep, mem.name, mem.len, mem.max_buffered).unwrap();
+		}
+		json.push_str("]}");
+		json
+	}
+}
+
+/// Inputs which exercise the worst cases of the segmentation rules.
+fn adversarial_inputs() -> Vec<(&'static str, String)> {
+	vec![
+		("regional indicators", "\u{1f1eb}".repeat(10_001)),
+		("flags after a mark", format!("a\u{301}{}", "\u{1f1eb}\u{1f1f7}".repeat(5_000))),
+		("zwj sequence", "\u{1f468}\u{200d}".repeat(5_000)),
+		("combining marks", format!("e{}", "\u{301}".repeat(10_000))),
+		("indic conjuncts", "\u{915}\u{94d}".repeat(5_000)),
+		("hangul jamo", "\u{1100}\u{1161}\u{11a8}".repeat(5_000)),
+		("crlf", "\r\n\r".repeat(5_000)),
+	]
+}
+
+/// A test case which can be described in a report.
+trait Case {
+	fn describe(&self) -> String;
+}
+impl Case for crate::TestCase {
+	fn describe(&self) -> String {
+		format!("line {}", self.line)
+	}
+}
+impl Case for (&'static str, String) {
+	fn describe(&self) -> String {
+		self.0.to_string()
+	}
+}
+
+/// Runs `check` over each case, and times it.
+fn run<T: Case>(name: &'static str, cases: &[T], mut check: impl FnMut(&T) -> bool) -> CheckResult {
+	let start = Instant::now();
+	let failures: Vec<String> = cases.iter().filter(|case| !check(case)).map(T::describe).collect();
+	let micros = start.elapsed().as_micros();
+	CheckResult { name, cases: cases.len(), passed: cases.len() - failures.len(), failures, micros }
+}
+
+/// Forward boundaries of `s`, excluding its start.
+fn forward(s: &str) -> Vec<usize> {
+	s.egc_indices().collect()
+}
+
+/// Runs the crate over the test cases of `GraphemeBreakTest.txt` and over adversarial
+/// inputs, and reports the results, along with timings and memory usage.
+/// 
+/// Each test case is checked with every way of segmenting text: forward and backward
+/// iteration, batch boundary computation, and boundary queries. Adversarial inputs
+/// are checked for consistency between these.
+pub fn conformance_report() -> ConformanceReport {
+	let ucd_expected = |case: &crate::TestCase| {
+		let mut breaks = case.breaks.to_vec();
+		breaks.push(case.str.len());
+		breaks
+	};
+	let mut checks = vec![
+		run("ucd forward", &TEST_CASES, |case| forward(case.str) == ucd_expected(case)),
+		run("ucd break positions", &TEST_CASES, |case| case.str.egc_break_positions() == ucd_expected(case)),
+		run("ucd backward", &TEST_CASES, |case| {
+			let mut breaks: Vec<usize> = case.str.egc_indices().rev().collect();
+			breaks.reverse();
+			breaks[1..] == case.breaks[..]
+		}),
+		run("ucd backward without allocation", &TEST_CASES, |case| {
+			let mut breaks: Vec<usize> = case.str.egc_indices().rev_no_alloc().collect();
+			breaks.reverse();
+			breaks[1..] == case.breaks[..]
+		}),
+		run("ucd boundary queries", &TEST_CASES, |case| {
+			case.str.char_indices().all(|(i, _)| {
+				let next = ucd_expected(case).into_iter().find(|&b| b > i);
+				next == Some(next_boundary(case.str, i))
+			})
+		}),
+	];
+
+	let inputs = adversarial_inputs();
+	checks.push(run("adversarial backward", &inputs, |(_, s)| {
+		let mut breaks: Vec<usize> = s.egc_indices().rev().collect();
+		breaks.reverse();
+		breaks.push(s.len());
+		breaks[1..] == forward(s)
+	}));
+	checks.push(run("adversarial backward without allocation", &inputs, |(_, s)| {
+		let mut breaks: Vec<usize> = s.egc_indices().rev_no_alloc().collect();
+		breaks.reverse();
+		breaks.push(s.len());
+		breaks[1..] == forward(s)
+	}));
+	checks.push(run("adversarial count", &inputs, |(_, s)| s.egc_count() == forward(s).len()));
+
+	let reverse_memory = inputs.iter()
+		.map(|(name, s)| {
+			let mut it = s.egc_indices().rev();
+			let mut max_buffered = 0;
+			while it.next().is_some() {
+				max_buffered = max_buffered.max(it.buffered());
+			}
+			ReverseMemory { name, len: s.len(), max_buffered }
+		})
+		.collect();
+
+	ConformanceReport { unicode_version: UNICODE_VERSION, checks, reverse_memory }
+}
@@ -0,0 +1,351 @@
+use crate::logic::{first_boundary, is_boundary_packed, is_local_boundary_packed, last_local_boundary, ContextScan, Ctx};
+use crate::lookup::{lookup_packed_props, PackedProps};
+use crate::Egc;
+
+/// Returned by [ChunkCursor] when it needs text outside of the chunk it was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NeedContext {
+	/// The operation should be retried with the chunk preceding the one given.
+	PrevChunk,
+	/// The operation should be retried with the chunk following the one given.
+	NextChunk,
+	/// The chunk ending at the given offset should be passed to
+	/// [provide_context](ChunkCursor::provide_context), then the operation retried.
+	PreContext(usize),
+}
+
+/// What is known of the context at the cursor offset.
+#[derive(Debug, Clone, Copy)]
+enum State {
+	Unknown,
+	/// The offset is known to be an EGC boundary.
+	Boundary,
+	/// The context up to and including the character before the offset,
+	/// and the properties of that character.
+	Known(Ctx, PackedProps),
+}
+
+/// Progress of an operation which was interrupted to ask for more text.
+#[derive(Debug, Clone, Copy)]
+enum Pending {
+	None,
+	/// Determining the context at the cursor offset: the text after `scan_pos` was scanned.
+	Context { scan: ContextScan, scan_pos: usize },
+	/// Searching for the next boundary: `pos` was reached with context `ctx`,
+	/// `p1` being the properties of the character before `pos`.
+	Forward { pos: usize, ctx: Ctx, p1: Option<PackedProps> },
+	/// Searching for the previous boundary: `pos` was reached,
+	/// `p2` being the properties of the character after `pos`.
+	Backward { pos: usize, p2: Option<PackedProps> },
+	/// Searching for the previous boundary: determining the context at `pos`,
+	/// to decide whether there is a boundary between the character starting at
+	/// `char_start` and the following one, with properties `p2`.
+	/// The text between `scan_pos` and `pos` was scanned.
+	BackwardContext { pos: usize, char_start: usize, p2: PackedProps, scan: ContextScan, scan_pos: usize },
+}
+
+/// A cursor which finds EGC boundaries in text stored as a sequence of
+/// non-contiguous chunks, such as a rope.
+/// 
+/// The cursor is not tied to any text: each operation is given the chunk
+/// containing the cursor offset, along with the offset of that chunk in the
+/// whole text. When the operation needs text outside of that chunk, it
+/// returns a [NeedContext] explaining what it needs, and should be retried
+/// once the needed text has been supplied. Progress is kept between retries.
+/// 
+/// Chunks should not split `char`s. Offsets are relative to the whole text.
+#[derive(Debug, Clone)]
+pub struct ChunkCursor {
+	offset: usize,
+	len: usize,
+	state: State,
+	pending: Pending,
+}
+
+impl ChunkCursor {
+	/// Creates a cursor at byte offset `offset` in a text of length `len`.
+	/// 
+	/// `offset` does not need to be an EGC boundary.
+	pub fn new(offset: usize, len: usize) -> Self {
+		assert!(offset <= len, "offset out of bounds");
+		let state = if offset == 0 || offset == len { State::Boundary } else { State::Unknown };
+		ChunkCursor { offset, len, state, pending: Pending::None }
+	}
+
+	/// Returns the current offset of the cursor.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Moves the cursor to `offset`, which does not need to be an EGC boundary.
+	pub fn set_offset(&mut self, offset: usize) {
+		if offset != self.offset {
+			*self = ChunkCursor::new(offset, self.len);
+		}
+	}
+
+	/// Supplies the text before the cursor, when an operation returned
+	/// [NeedContext::PreContext]. `chunk` should end at the offset given there.
+	pub fn provide_context(&mut self, chunk: &str, chunk_start: usize) {
+		let (scan, scan_pos) = match &mut self.pending {
+			Pending::Context { scan, scan_pos } => (scan, scan_pos),
+			Pending::BackwardContext { scan, scan_pos, .. } => (scan, scan_pos),
+			_ => panic!("no context was requested"),
+		};
+		assert_eq!(chunk_start + chunk.len(), *scan_pos, "context chunk does not end at the requested offset");
+		if scan.feed(chunk).is_none() && chunk_start == 0 {
+			scan.finish();
+		}
+		*scan_pos = chunk_start;
+	}
+
+	/// Moves the cursor to the next EGC boundary, and returns its offset.
+	/// 
+	/// `chunk` should be the chunk containing the cursor offset, starting at
+	/// offset `chunk_start` in the text. Returns `Ok(None)` if the cursor is
+	/// at the end of the text.
+	pub fn next_boundary(&mut self, chunk: &str, chunk_start: usize) -> Result<Option<usize>, NeedContext> {
+		if self.offset == self.len {
+			return Ok(None);
+		}
+		let (mut pos, mut ctx, mut p1) = match self.pending {
+			Pending::Forward { pos, ctx, p1 } => (pos, ctx, p1),
+			_ => match self.context(chunk, chunk_start)? {
+				State::Known(ctx, p1) => (self.offset, ctx, Some(p1)),
+				_ => (self.offset, Ctx::Start, None),
+			},
+		};
+		for c in chunk[pos - chunk_start..].chars() {
+			let p2 = lookup_packed_props(c);
+			if let Some(p1) = p1.filter(|_| pos > self.offset) {
+				if is_boundary_packed(ctx, p1, p2) {
+					self.offset = pos;
+					self.state = State::Known(ctx, p1);
+					self.pending = Pending::None;
+					return Ok(Some(pos));
+				}
+			}
+			ctx = ctx.step_packed(p2);
+			p1 = Some(p2);
+			pos += c.len_utf8();
+		}
+		if pos == self.len {
+			self.offset = pos;
+			self.state = State::Boundary;
+			self.pending = Pending::None;
+			return Ok(Some(pos));
+		}
+		self.pending = Pending::Forward { pos, ctx, p1 };
+		Err(NeedContext::NextChunk)
+	}
+
+	/// Moves the cursor to the previous EGC boundary, and returns its offset.
+	/// 
+	/// `chunk` should be the chunk containing the text right before the cursor
+	/// offset, starting at offset `chunk_start` in the text. Returns `Ok(None)`
+	/// if the cursor is at the start of the text.
+	pub fn prev_boundary(&mut self, chunk: &str, chunk_start: usize) -> Result<Option<usize>, NeedContext> {
+		if self.offset == 0 {
+			return Ok(None);
+		}
+		let (mut pos, mut p2) = match self.pending {
+			Pending::Backward { pos, p2 } => (pos, p2),
+			Pending::BackwardContext { pos, char_start, p2, scan, scan_pos } => {
+				let Some(ctx) = scan.result() else {
+					return Err(NeedContext::PreContext(scan_pos));
+				};
+				let p1 = scan.last().unwrap();
+				if is_boundary_packed(ctx, p1, p2) {
+					return Ok(Some(self.found_prev(pos)));
+				}
+				(char_start, Some(p1))
+			},
+			_ => (self.offset, None),
+		};
+		if pos == chunk_start && pos > 0 {
+			self.pending = Pending::Backward { pos, p2 };
+			return Err(NeedContext::PrevChunk);
+		}
+		let before = &chunk[..pos - chunk_start];
+		for (i, c) in before.char_indices().rev() {
+			let p1 = lookup_packed_props(c);
+			if let Some(p2) = p2 {
+				match is_local_boundary_packed(p1, p2) {
+					Some(true) => return Ok(Some(self.found_prev(pos))),
+					Some(false) => (),
+					None => {
+						let mut scan = ContextScan::new();
+						scan.feed(&before[..i + c.len_utf8()]);
+						if chunk_start == 0 {
+							scan.finish();
+						}
+						match scan.result() {
+							Some(ctx) => if is_boundary_packed(ctx, p1, p2) {
+								return Ok(Some(self.found_prev(pos)));
+							},
+							None => {
+								self.pending = Pending::BackwardContext {
+									pos, char_start: chunk_start + i, p2, scan, scan_pos: chunk_start,
+								};
+								return Err(NeedContext::PreContext(chunk_start));
+							},
+						}
+					},
+				}
+			}
+			p2 = Some(p1);
+			pos = chunk_start + i;
+		}
+		if pos == 0 {
+			return Ok(Some(self.found_prev(0)));
+		}
+		self.pending = Pending::Backward { pos, p2 };
+		Err(NeedContext::PrevChunk)
+	}
+
+	fn found_prev(&mut self, pos: usize) -> usize {
+		self.offset = pos;
+		self.state = State::Boundary;
+		self.pending = Pending::None;
+		pos
+	}
+
+	/// Determines the context at the cursor offset.
+	fn context(&mut self, chunk: &str, chunk_start: usize) -> Result<State, NeedContext> {
+		if let State::Unknown = self.state {
+			let (scan, scan_pos) = match self.pending {
+				Pending::Context { scan, scan_pos } => (scan, scan_pos),
+				_ => {
+					let mut scan = ContextScan::new();
+					scan.feed(&chunk[..self.offset - chunk_start]);
+					if chunk_start == 0 {
+						scan.finish();
+					}
+					(scan, chunk_start)
+				},
+			};
+			let Some(ctx) = scan.result() else {
+				self.pending = Pending::Context { scan, scan_pos };
+				return Err(NeedContext::PreContext(scan_pos));
+			};
+			self.state = State::Known(ctx, scan.last().unwrap());
+			self.pending = Pending::None;
+		}
+		Ok(self.state)
+	}
+}
+
+/// Number of boundaries remembered by [EgcCursor].
+const CURSOR_CACHE_SIZE: usize = 16;
+
+/// A cursor over the EGC boundaries of a string, which can move forward
+/// and backward in any order (eg. for arrow keys in a text editor).
+/// 
+/// Finding the previous boundary may require scanning forward from an earlier
+/// local boundary (see [EgcRevIndicesNoAlloc](crate::iter::EgcRevIndicesNoAlloc)): the boundaries
+/// found by such a scan are remembered, along with those found while moving
+/// around them, so that moving back and forth over them does not scan again.
+/// The cursor never allocates.
+#[derive(Debug, Clone)]
+pub struct EgcCursor<'a> {
+	str: &'a str,
+	offset: usize,
+	// Sorted boundaries, with no other boundary between the first and the last
+	cache: [usize; CURSOR_CACHE_SIZE],
+	cache_len: usize,
+}
+
+impl<'a> EgcCursor<'a> {
+	/// Creates a cursor in `s`, at the start of the EGC containing `offset`
+	/// (or at the end of the string if `offset` is past it).
+	pub fn new(s: &'a str, offset: usize) -> Self {
+		let offset = s.floor_egc_boundary(offset);
+		EgcCursor { str: s, offset, cache: [0; CURSOR_CACHE_SIZE], cache_len: 0 }
+	}
+
+	/// Returns the string the cursor moves in.
+	pub fn as_str(&self) -> &'a str {
+		self.str
+	}
+
+	/// Returns the current offset of the cursor, which is always an EGC boundary.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Moves the cursor to the start of the EGC containing `offset`.
+	pub fn set_offset(&mut self, offset: usize) {
+		self.offset = self.str.floor_egc_boundary(offset);
+	}
+
+	/// Returns the index of the cursor offset in the cache, if it is there.
+	fn cache_index(&self) -> Option<usize> {
+		self.cache[..self.cache_len].binary_search(&self.offset).ok()
+	}
+
+	/// Moves the cursor to the next EGC boundary and returns it,
+	/// or returns None if the cursor is at the end of the string.
+	pub fn next_boundary(&mut self) -> Option<usize> {
+		if self.offset == self.str.len() {
+			return None;
+		}
+		let index = self.cache_index();
+		let next = match index {
+			Some(i) if i + 1 < self.cache_len => self.cache[i + 1],
+			_ => self.offset + first_boundary(&self.str[self.offset..]),
+		};
+		if index.is_some_and(|i| i + 1 == self.cache_len) {
+			// Extend the cache, dropping its first boundary if full
+			if self.cache_len == CURSOR_CACHE_SIZE {
+				self.cache.copy_within(1.., 0);
+				self.cache_len -= 1;
+			}
+			self.cache[self.cache_len] = next;
+			self.cache_len += 1;
+		}
+		self.offset = next;
+		Some(next)
+	}
+
+	/// Moves the cursor to the previous EGC boundary and returns it,
+	/// or returns None if the cursor is at the start of the string.
+	pub fn prev_boundary(&mut self) -> Option<usize> {
+		if self.offset == 0 {
+			return None;
+		}
+		let index = self.cache_index();
+		if let Some(i) = index.filter(|&i| i > 0) {
+			self.offset = self.cache[i - 1];
+			return Some(self.offset);
+		}
+		let rest = &self.str[..self.offset];
+		let (mut start, maybe_skipped) = last_local_boundary(rest);
+		if maybe_skipped {
+			// Remember the last boundaries found by the forward scan, up to the cursor
+			let mut len = 0;
+			while start < self.offset {
+				self.cache[len % CURSOR_CACHE_SIZE] = start;
+				len += 1;
+				start += first_boundary(&rest[start..]);
+			}
+			self.cache_len = len.min(CURSOR_CACHE_SIZE - 1);
+			if len > self.cache_len {
+				self.cache.rotate_left(len % CURSOR_CACHE_SIZE);
+				self.cache.copy_within(1.., 0);
+			}
+			self.cache[self.cache_len] = self.offset;
+			self.cache_len += 1;
+			self.offset = self.cache[self.cache_len - 2];
+			return Some(self.offset);
+		}
+		if index == Some(0) {
+			// Extend the cache, dropping its last boundary if full
+			self.cache.copy_within(..CURSOR_CACHE_SIZE - 1, 1);
+			self.cache_len = (self.cache_len + 1).min(CURSOR_CACHE_SIZE);
+			self.cache[0] = start;
+		}
+		self.offset = start;
+		Some(start)
+	}
+}
@@ -1,5 +1,5 @@
 use crate::lookup::{EgcProps as P, CharRange, ran};
-pub const RANGES: [CharRange; 733] = [
+pub const RANGES: [CharRange; 683] = [
 	ran(0x00000,   10, P::CN),
 	ran(0x0000a,    1, P::LF),
 	ran(0x0000b,    2, P::CN),
@@ -9,11 +9,8 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x000a9,    1, P::EP),
 	ran(0x000ad,    1, P::CN),
 	ran(0x000ae,    1, P::EP),
-	ran(0x00300,   79, P::IN_EX),
-	ran(0x0034f,    1, P::EX),
-	ran(0x00350,   32, P::IN_EX),
-	ran(0x00483,    5, P::IN_EX),
-	ran(0x00488,    2, P::EX),
+	ran(0x00300,  112, P::IN_EX),
+	ran(0x00483,    7, P::IN_EX),
 	ran(0x00591,   45, P::IN_EX),
 	ran(0x005bf,    1, P::IN_EX),
 	ran(0x005c1,    2, P::IN_EX),
@@ -32,7 +29,7 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x0070f,    1, P::PP),
 	ran(0x00711,    1, P::IN_EX),
 	ran(0x00730,   27, P::IN_EX),
-	ran(0x007a6,   11, P::EX),
+	ran(0x007a6,   11, P::IN_EX),
 	ran(0x007eb,    9, P::IN_EX),
 	ran(0x007fd,    1, P::IN_EX),
 	ran(0x00816,    4, P::IN_EX),
@@ -41,56 +38,54 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x00829,    5, P::IN_EX),
 	ran(0x00859,    3, P::IN_EX),
 	ran(0x00890,    2, P::PP),
-	ran(0x00898,    8, P::IN_EX),
+	ran(0x00897,    9, P::IN_EX),
 	ran(0x008ca,   24, P::IN_EX),
 	ran(0x008e2,    1, P::PP),
-	ran(0x008e3,   29, P::IN_EX),
-	ran(0x00900,    3, P::EX),
+	ran(0x008e3,   32, P::IN_EX),
 	ran(0x00903,    1, P::SM),
 	ran(0x00915,   37, P::IN_CO),
-	ran(0x0093a,    1, P::EX),
+	ran(0x0093a,    1, P::IN_EX),
 	ran(0x0093b,    1, P::SM),
 	ran(0x0093c,    1, P::IN_EX),
 	ran(0x0093e,    3, P::SM),
-	ran(0x00941,    8, P::EX),
+	ran(0x00941,    8, P::IN_EX),
 	ran(0x00949,    4, P::SM),
 	ran(0x0094d,    1, P::IN_LI),
 	ran(0x0094e,    2, P::SM),
-	ran(0x00951,    4, P::IN_EX),
-	ran(0x00955,    3, P::EX),
+	ran(0x00951,    7, P::IN_EX),
 	ran(0x00958,    8, P::IN_CO),
-	ran(0x00962,    2, P::EX),
+	ran(0x00962,    2, P::IN_EX),
 	ran(0x00978,    8, P::IN_CO),
-	ran(0x00981,    1, P::EX),
+	ran(0x00981,    1, P::IN_EX),
 	ran(0x00982,    2, P::SM),
 	ran(0x00995,   20, P::IN_CO),
 	ran(0x009aa,    7, P::IN_CO),
 	ran(0x009b2,    1, P::IN_CO),
 	ran(0x009b6,    4, P::IN_CO),
 	ran(0x009bc,    1, P::IN_EX),
-	ran(0x009be,    1, P::EX),
+	ran(0x009be,    1, P::IN_EX),
 	ran(0x009bf,    2, P::SM),
-	ran(0x009c1,    4, P::EX),
+	ran(0x009c1,    4, P::IN_EX),
 	ran(0x009c7,    2, P::SM),
 	ran(0x009cb,    2, P::SM),
 	ran(0x009cd,    1, P::IN_LI),
-	ran(0x009d7,    1, P::EX),
+	ran(0x009d7,    1, P::IN_EX),
 	ran(0x009dc,    2, P::IN_CO),
 	ran(0x009df,    1, P::IN_CO),
-	ran(0x009e2,    2, P::EX),
+	ran(0x009e2,    2, P::IN_EX),
 	ran(0x009f0,    2, P::IN_CO),
 	ran(0x009fe,    1, P::IN_EX),
-	ran(0x00a01,    2, P::EX),
+	ran(0x00a01,    2, P::IN_EX),
 	ran(0x00a03,    1, P::SM),
 	ran(0x00a3c,    1, P::IN_EX),
 	ran(0x00a3e,    3, P::SM),
-	ran(0x00a41,    2, P::EX),
-	ran(0x00a47,    2, P::EX),
-	ran(0x00a4b,    3, P::EX),
-	ran(0x00a51,    1, P::EX),
-	ran(0x00a70,    2, P::EX),
-	ran(0x00a75,    1, P::EX),
-	ran(0x00a81,    2, P::EX),
+	ran(0x00a41,    2, P::IN_EX),
+	ran(0x00a47,    2, P::IN_EX),
+	ran(0x00a4b,    3, P::IN_EX),
+	ran(0x00a51,    1, P::IN_EX),
+	ran(0x00a70,    2, P::IN_EX),
+	ran(0x00a75,    1, P::IN_EX),
+	ran(0x00a81,    2, P::IN_EX),
 	ran(0x00a83,    1, P::SM),
 	ran(0x00a95,   20, P::IN_CO),
 	ran(0x00aaa,    7, P::IN_CO),
@@ -98,225 +93,192 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x00ab5,    5, P::IN_CO),
 	ran(0x00abc,    1, P::IN_EX),
 	ran(0x00abe,    3, P::SM),
-	ran(0x00ac1,    5, P::EX),
-	ran(0x00ac7,    2, P::EX),
+	ran(0x00ac1,    5, P::IN_EX),
+	ran(0x00ac7,    2, P::IN_EX),
 	ran(0x00ac9,    1, P::SM),
 	ran(0x00acb,    2, P::SM),
 	ran(0x00acd,    1, P::IN_LI),
-	ran(0x00ae2,    2, P::EX),
+	ran(0x00ae2,    2, P::IN_EX),
 	ran(0x00af9,    1, P::IN_CO),
-	ran(0x00afa,    6, P::EX),
-	ran(0x00b01,    1, P::EX),
+	ran(0x00afa,    6, P::IN_EX),
+	ran(0x00b01,    1, P::IN_EX),
 	ran(0x00b02,    2, P::SM),
 	ran(0x00b15,   20, P::IN_CO),
 	ran(0x00b2a,    7, P::IN_CO),
 	ran(0x00b32,    2, P::IN_CO),
 	ran(0x00b35,    5, P::IN_CO),
 	ran(0x00b3c,    1, P::IN_EX),
-	ran(0x00b3e,    2, P::EX),
+	ran(0x00b3e,    2, P::IN_EX),
 	ran(0x00b40,    1, P::SM),
-	ran(0x00b41,    4, P::EX),
+	ran(0x00b41,    4, P::IN_EX),
 	ran(0x00b47,    2, P::SM),
 	ran(0x00b4b,    2, P::SM),
 	ran(0x00b4d,    1, P::IN_LI),
-	ran(0x00b55,    3, P::EX),
+	ran(0x00b55,    3, P::IN_EX),
 	ran(0x00b5c,    2, P::IN_CO),
 	ran(0x00b5f,    1, P::IN_CO),
-	ran(0x00b62,    2, P::EX),
+	ran(0x00b62,    2, P::IN_EX),
 	ran(0x00b71,    1, P::IN_CO),
-	ran(0x00b82,    1, P::EX),
-	ran(0x00bbe,    1, P::EX),
+	ran(0x00b82,    1, P::IN_EX),
+	ran(0x00bbe,    1, P::IN_EX),
 	ran(0x00bbf,    1, P::SM),
-	ran(0x00bc0,    1, P::EX),
+	ran(0x00bc0,    1, P::IN_EX),
 	ran(0x00bc1,    2, P::SM),
 	ran(0x00bc6,    3, P::SM),
 	ran(0x00bca,    3, P::SM),
-	ran(0x00bcd,    1, P::EX),
-	ran(0x00bd7,    1, P::EX),
-	ran(0x00c00,    1, P::EX),
+	ran(0x00bcd,    1, P::IN_EX),
+	ran(0x00bd7,    1, P::IN_EX),
+	ran(0x00c00,    1, P::IN_EX),
 	ran(0x00c01,    3, P::SM),
-	ran(0x00c04,    1, P::EX),
+	ran(0x00c04,    1, P::IN_EX),
 	ran(0x00c15,   20, P::IN_CO),
 	ran(0x00c2a,   16, P::IN_CO),
 	ran(0x00c3c,    1, P::IN_EX),
-	ran(0x00c3e,    3, P::EX),
+	ran(0x00c3e,    3, P::IN_EX),
 	ran(0x00c41,    4, P::SM),
-	ran(0x00c46,    3, P::EX),
-	ran(0x00c4a,    3, P::EX),
+	ran(0x00c46,    3, P::IN_EX),
+	ran(0x00c4a,    3, P::IN_EX),
 	ran(0x00c4d,    1, P::IN_LI),
 	ran(0x00c55,    2, P::IN_EX),
 	ran(0x00c58,    3, P::IN_CO),
-	ran(0x00c62,    2, P::EX),
-	ran(0x00c81,    1, P::EX),
+	ran(0x00c62,    2, P::IN_EX),
+	ran(0x00c81,    1, P::IN_EX),
 	ran(0x00c82,    2, P::SM),
 	ran(0x00cbc,    1, P::IN_EX),
 	ran(0x00cbe,    1, P::SM),
-	ran(0x00cbf,    1, P::EX),
-	ran(0x00cc0,    2, P::SM),
-	ran(0x00cc2,    1, P::EX),
+	ran(0x00cbf,    2, P::IN_EX),
+	ran(0x00cc1,    1, P::SM),
+	ran(0x00cc2,    1, P::IN_EX),
 	ran(0x00cc3,    2, P::SM),
-	ran(0x00cc6,    1, P::EX),
-	ran(0x00cc7,    2, P::SM),
-	ran(0x00cca,    2, P::SM),
-	ran(0x00ccc,    2, P::EX),
-	ran(0x00cd5,    2, P::EX),
-	ran(0x00ce2,    2, P::EX),
+	ran(0x00cc6,    3, P::IN_EX),
+	ran(0x00cca,    4, P::IN_EX),
+	ran(0x00cd5,    2, P::IN_EX),
+	ran(0x00ce2,    2, P::IN_EX),
 	ran(0x00cf3,    1, P::SM),
-	ran(0x00d00,    2, P::EX),
+	ran(0x00d00,    2, P::IN_EX),
 	ran(0x00d02,    2, P::SM),
 	ran(0x00d15,   38, P::IN_CO),
 	ran(0x00d3b,    2, P::IN_EX),
-	ran(0x00d3e,    1, P::EX),
+	ran(0x00d3e,    1, P::IN_EX),
 	ran(0x00d3f,    2, P::SM),
-	ran(0x00d41,    4, P::EX),
+	ran(0x00d41,    4, P::IN_EX),
 	ran(0x00d46,    3, P::SM),
 	ran(0x00d4a,    3, P::SM),
 	ran(0x00d4d,    1, P::IN_LI),
 	ran(0x00d4e,    1, P::PP),
-	ran(0x00d57,    1, P::EX),
-	ran(0x00d62,    2, P::EX),
-	ran(0x00d81,    1, P::EX),
+	ran(0x00d57,    1, P::IN_EX),
+	ran(0x00d62,    2, P::IN_EX),
+	ran(0x00d81,    1, P::IN_EX),
 	ran(0x00d82,    2, P::SM),
-	ran(0x00dca,    1, P::EX),
-	ran(0x00dcf,    1, P::EX),
+	ran(0x00dca,    1, P::IN_EX),
+	ran(0x00dcf,    1, P::IN_EX),
 	ran(0x00dd0,    2, P::SM),
-	ran(0x00dd2,    3, P::EX),
-	ran(0x00dd6,    1, P::EX),
+	ran(0x00dd2,    3, P::IN_EX),
+	ran(0x00dd6,    1, P::IN_EX),
 	ran(0x00dd8,    7, P::SM),
-	ran(0x00ddf,    1, P::EX),
+	ran(0x00ddf,    1, P::IN_EX),
 	ran(0x00df2,    2, P::SM),
-	ran(0x00e31,    1, P::EX),
+	ran(0x00e31,    1, P::IN_EX),
 	ran(0x00e33,    1, P::SM),
-	ran(0x00e34,    4, P::EX),
-	ran(0x00e38,    3, P::IN_EX),
-	ran(0x00e47,    1, P::EX),
-	ran(0x00e48,    4, P::IN_EX),
-	ran(0x00e4c,    3, P::EX),
-	ran(0x00eb1,    1, P::EX),
+	ran(0x00e34,    7, P::IN_EX),
+	ran(0x00e47,    8, P::IN_EX),
+	ran(0x00eb1,    1, P::IN_EX),
 	ran(0x00eb3,    1, P::SM),
-	ran(0x00eb4,    4, P::EX),
-	ran(0x00eb8,    3, P::IN_EX),
-	ran(0x00ebb,    2, P::EX),
-	ran(0x00ec8,    4, P::IN_EX),
-	ran(0x00ecc,    3, P::EX),
+	ran(0x00eb4,    9, P::IN_EX),
+	ran(0x00ec8,    7, P::IN_EX),
 	ran(0x00f18,    2, P::IN_EX),
 	ran(0x00f35,    1, P::IN_EX),
 	ran(0x00f37,    1, P::IN_EX),
 	ran(0x00f39,    1, P::IN_EX),
 	ran(0x00f3e,    2, P::SM),
-	ran(0x00f71,    2, P::IN_EX),
-	ran(0x00f73,    1, P::EX),
-	ran(0x00f74,    1, P::IN_EX),
-	ran(0x00f75,    5, P::EX),
-	ran(0x00f7a,    4, P::IN_EX),
-	ran(0x00f7e,    1, P::EX),
+	ran(0x00f71,   14, P::IN_EX),
 	ran(0x00f7f,    1, P::SM),
-	ran(0x00f80,    1, P::IN_EX),
-	ran(0x00f81,    1, P::EX),
-	ran(0x00f82,    3, P::IN_EX),
+	ran(0x00f80,    5, P::IN_EX),
 	ran(0x00f86,    2, P::IN_EX),
-	ran(0x00f8d,   11, P::EX),
-	ran(0x00f99,   36, P::EX),
+	ran(0x00f8d,   11, P::IN_EX),
+	ran(0x00f99,   36, P::IN_EX),
 	ran(0x00fc6,    1, P::IN_EX),
-	ran(0x0102d,    4, P::EX),
+	ran(0x0102d,    4, P::IN_EX),
 	ran(0x01031,    1, P::SM),
-	ran(0x01032,    5, P::EX),
-	ran(0x01037,    1, P::IN_EX),
+	ran(0x01032,    6, P::IN_EX),
 	ran(0x01039,    2, P::IN_EX),
 	ran(0x0103b,    2, P::SM),
-	ran(0x0103d,    2, P::EX),
+	ran(0x0103d,    2, P::IN_EX),
 	ran(0x01056,    2, P::SM),
-	ran(0x01058,    2, P::EX),
-	ran(0x0105e,    3, P::EX),
-	ran(0x01071,    4, P::EX),
-	ran(0x01082,    1, P::EX),
+	ran(0x01058,    2, P::IN_EX),
+	ran(0x0105e,    3, P::IN_EX),
+	ran(0x01071,    4, P::IN_EX),
+	ran(0x01082,    1, P::IN_EX),
 	ran(0x01084,    1, P::SM),
-	ran(0x01085,    2, P::EX),
+	ran(0x01085,    2, P::IN_EX),
 	ran(0x0108d,    1, P::IN_EX),
-	ran(0x0109d,    1, P::EX),
+	ran(0x0109d,    1, P::IN_EX),
 	ran(0x01100,   96, P::L),
 	ran(0x01160,   72, P::V),
 	ran(0x011a8,   88, P::T),
 	ran(0x0135d,    3, P::IN_EX),
-	ran(0x01712,    2, P::EX),
-	ran(0x01714,    1, P::IN_EX),
-	ran(0x01715,    1, P::SM),
-	ran(0x01732,    2, P::EX),
-	ran(0x01734,    1, P::SM),
-	ran(0x01752,    2, P::EX),
-	ran(0x01772,    2, P::EX),
-	ran(0x017b4,    2, P::EX),
+	ran(0x01712,    4, P::IN_EX),
+	ran(0x01732,    3, P::IN_EX),
+	ran(0x01752,    2, P::IN_EX),
+	ran(0x01772,    2, P::IN_EX),
+	ran(0x017b4,    2, P::IN_EX),
 	ran(0x017b6,    1, P::SM),
-	ran(0x017b7,    7, P::EX),
+	ran(0x017b7,    7, P::IN_EX),
 	ran(0x017be,    8, P::SM),
-	ran(0x017c6,    1, P::EX),
+	ran(0x017c6,    1, P::IN_EX),
 	ran(0x017c7,    2, P::SM),
-	ran(0x017c9,    9, P::EX),
-	ran(0x017d2,    1, P::IN_EX),
-	ran(0x017d3,    1, P::EX),
+	ran(0x017c9,   11, P::IN_EX),
 	ran(0x017dd,    1, P::IN_EX),
-	ran(0x0180b,    3, P::EX),
+	ran(0x0180b,    3, P::IN_EX),
 	ran(0x0180e,    1, P::CN),
-	ran(0x0180f,    1, P::EX),
-	ran(0x01885,    2, P::EX),
+	ran(0x0180f,    1, P::IN_EX),
+	ran(0x01885,    2, P::IN_EX),
 	ran(0x018a9,    1, P::IN_EX),
-	ran(0x01920,    3, P::EX),
+	ran(0x01920,    3, P::IN_EX),
 	ran(0x01923,    4, P::SM),
-	ran(0x01927,    2, P::EX),
+	ran(0x01927,    2, P::IN_EX),
 	ran(0x01929,    3, P::SM),
 	ran(0x01930,    2, P::SM),
-	ran(0x01932,    1, P::EX),
+	ran(0x01932,    1, P::IN_EX),
 	ran(0x01933,    6, P::SM),
 	ran(0x01939,    3, P::IN_EX),
 	ran(0x01a17,    2, P::IN_EX),
 	ran(0x01a19,    2, P::SM),
-	ran(0x01a1b,    1, P::EX),
+	ran(0x01a1b,    1, P::IN_EX),
 	ran(0x01a55,    1, P::SM),
-	ran(0x01a56,    1, P::EX),
+	ran(0x01a56,    1, P::IN_EX),
 	ran(0x01a57,    1, P::SM),
-	ran(0x01a58,    7, P::EX),
+	ran(0x01a58,    7, P::IN_EX),
 	ran(0x01a60,    1, P::IN_EX),
-	ran(0x01a62,    1, P::EX),
-	ran(0x01a65,    8, P::EX),
+	ran(0x01a62,    1, P::IN_EX),
+	ran(0x01a65,    8, P::IN_EX),
 	ran(0x01a6d,    6, P::SM),
-	ran(0x01a73,    2, P::EX),
-	ran(0x01a75,    8, P::IN_EX),
+	ran(0x01a73,   10, P::IN_EX),
 	ran(0x01a7f,    1, P::IN_EX),
-	ran(0x01ab0,   14, P::IN_EX),
-	ran(0x01abe,    1, P::EX),
-	ran(0x01abf,   16, P::IN_EX),
-	ran(0x01b00,    4, P::EX),
+	ran(0x01ab0,   31, P::IN_EX),
+	ran(0x01b00,    4, P::IN_EX),
 	ran(0x01b04,    1, P::SM),
-	ran(0x01b34,    1, P::IN_EX),
-	ran(0x01b35,    6, P::EX),
-	ran(0x01b3b,    1, P::SM),
-	ran(0x01b3c,    1, P::EX),
-	ran(0x01b3d,    5, P::SM),
-	ran(0x01b42,    1, P::EX),
-	ran(0x01b43,    2, P::SM),
+	ran(0x01b34,   10, P::IN_EX),
+	ran(0x01b3e,    4, P::SM),
+	ran(0x01b42,    3, P::IN_EX),
 	ran(0x01b6b,    9, P::IN_EX),
-	ran(0x01b80,    2, P::EX),
+	ran(0x01b80,    2, P::IN_EX),
 	ran(0x01b82,    1, P::SM),
 	ran(0x01ba1,    1, P::SM),
-	ran(0x01ba2,    4, P::EX),
+	ran(0x01ba2,    4, P::IN_EX),
 	ran(0x01ba6,    2, P::SM),
-	ran(0x01ba8,    2, P::EX),
-	ran(0x01baa,    1, P::SM),
-	ran(0x01bab,    1, P::IN_EX),
-	ran(0x01bac,    2, P::EX),
+	ran(0x01ba8,    6, P::IN_EX),
 	ran(0x01be6,    1, P::IN_EX),
 	ran(0x01be7,    1, P::SM),
-	ran(0x01be8,    2, P::EX),
+	ran(0x01be8,    2, P::IN_EX),
 	ran(0x01bea,    3, P::SM),
-	ran(0x01bed,    1, P::EX),
+	ran(0x01bed,    1, P::IN_EX),
 	ran(0x01bee,    1, P::SM),
-	ran(0x01bef,    3, P::EX),
-	ran(0x01bf2,    2, P::SM),
+	ran(0x01bef,    5, P::IN_EX),
 	ran(0x01c24,    8, P::SM),
-	ran(0x01c2c,    8, P::EX),
+	ran(0x01c2c,    8, P::IN_EX),
 	ran(0x01c34,    2, P::SM),
-	ran(0x01c36,    1, P::EX),
-	ran(0x01c37,    1, P::IN_EX),
+	ran(0x01c36,    2, P::IN_EX),
 	ran(0x01cd0,    3, P::IN_EX),
 	ran(0x01cd4,   13, P::IN_EX),
 	ran(0x01ce1,    1, P::SM),
@@ -334,11 +296,7 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x0203c,    1, P::EP),
 	ran(0x02049,    1, P::EP),
 	ran(0x02060,   16, P::CN),
-	ran(0x020d0,   13, P::IN_EX),
-	ran(0x020dd,    4, P::EX),
-	ran(0x020e1,    1, P::IN_EX),
-	ran(0x020e2,    3, P::EX),
-	ran(0x020e5,   12, P::IN_EX),
+	ran(0x020d0,   33, P::IN_EX),
 	ran(0x02122,    1, P::EP),
 	ran(0x02139,    1, P::EP),
 	ran(0x02194,    6, P::EP),
@@ -390,302 +348,293 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x03099,    2, P::IN_EX),
 	ran(0x03297,    1, P::EP),
 	ran(0x03299,    1, P::EP),
-	ran(0x0a66f,    1, P::IN_EX),
-	ran(0x0a670,    3, P::EX),
+	ran(0x0a66f,    4, P::IN_EX),
 	ran(0x0a674,   10, P::IN_EX),
 	ran(0x0a69e,    2, P::IN_EX),
 	ran(0x0a6f0,    2, P::IN_EX),
-	ran(0x0a802,    1, P::EX),
-	ran(0x0a806,    1, P::EX),
-	ran(0x0a80b,    1, P::EX),
+	ran(0x0a802,    1, P::IN_EX),
+	ran(0x0a806,    1, P::IN_EX),
+	ran(0x0a80b,    1, P::IN_EX),
 	ran(0x0a823,    2, P::SM),
-	ran(0x0a825,    2, P::EX),
+	ran(0x0a825,    2, P::IN_EX),
 	ran(0x0a827,    1, P::SM),
 	ran(0x0a82c,    1, P::IN_EX),
 	ran(0x0a880,    2, P::SM),
 	ran(0x0a8b4,   16, P::SM),
-	ran(0x0a8c4,    2, P::EX),
+	ran(0x0a8c4,    2, P::IN_EX),
 	ran(0x0a8e0,   18, P::IN_EX),
-	ran(0x0a8ff,    1, P::EX),
-	ran(0x0a926,    5, P::EX),
-	ran(0x0a92b,    3, P::IN_EX),
-	ran(0x0a947,   11, P::EX),
-	ran(0x0a952,    2, P::SM),
+	ran(0x0a8ff,    1, P::IN_EX),
+	ran(0x0a926,    8, P::IN_EX),
+	ran(0x0a947,   11, P::IN_EX),
+	ran(0x0a952,    1, P::SM),
+	ran(0x0a953,    1, P::IN_EX),
 	ran(0x0a960,   29, P::L),
-	ran(0x0a980,    3, P::EX),
+	ran(0x0a980,    3, P::IN_EX),
 	ran(0x0a983,    1, P::SM),
 	ran(0x0a9b3,    1, P::IN_EX),
 	ran(0x0a9b4,    2, P::SM),
-	ran(0x0a9b6,    4, P::EX),
+	ran(0x0a9b6,    4, P::IN_EX),
 	ran(0x0a9ba,    2, P::SM),
-	ran(0x0a9bc,    2, P::EX),
-	ran(0x0a9be,    3, P::SM),
-	ran(0x0a9e5,    1, P::EX),
-	ran(0x0aa29,    6, P::EX),
+	ran(0x0a9bc,    2, P::IN_EX),
+	ran(0x0a9be,    2, P::SM),
+	ran(0x0a9c0,    1, P::IN_EX),
+	ran(0x0a9e5,    1, P::IN_EX),
+	ran(0x0aa29,    6, P::IN_EX),
 	ran(0x0aa2f,    2, P::SM),
-	ran(0x0aa31,    2, P::EX),
+	ran(0x0aa31,    2, P::IN_EX),
 	ran(0x0aa33,    2, P::SM),
-	ran(0x0aa35,    2, P::EX),
-	ran(0x0aa43,    1, P::EX),
-	ran(0x0aa4c,    1, P::EX),
+	ran(0x0aa35,    2, P::IN_EX),
+	ran(0x0aa43,    1, P::IN_EX),
+	ran(0x0aa4c,    1, P::IN_EX),
 	ran(0x0aa4d,    1, P::SM),
-	ran(0x0aa7c,    1, P::EX),
+	ran(0x0aa7c,    1, P::IN_EX),
 	ran(0x0aab0,    1, P::IN_EX),
 	ran(0x0aab2,    3, P::IN_EX),
 	ran(0x0aab7,    2, P::IN_EX),
 	ran(0x0aabe,    2, P::IN_EX),
 	ran(0x0aac1,    1, P::IN_EX),
 	ran(0x0aaeb,    1, P::SM),
-	ran(0x0aaec,    2, P::EX),
+	ran(0x0aaec,    2, P::IN_EX),
 	ran(0x0aaee,    2, P::SM),
 	ran(0x0aaf5,    1, P::SM),
 	ran(0x0aaf6,    1, P::IN_EX),
 	ran(0x0abe3,    2, P::SM),
-	ran(0x0abe5,    1, P::EX),
+	ran(0x0abe5,    1, P::IN_EX),
 	ran(0x0abe6,    2, P::SM),
-	ran(0x0abe8,    1, P::EX),
+	ran(0x0abe8,    1, P::IN_EX),
 	ran(0x0abe9,    2, P::SM),
 	ran(0x0abec,    1, P::SM),
 	ran(0x0abed,    1, P::IN_EX),
 	ran(0x0d7b0,   23, P::V),
 	ran(0x0d7cb,   49, P::T),
 	ran(0x0fb1e,    1, P::IN_EX),
-	ran(0x0fe00,   16, P::EX),
+	ran(0x0fe00,   16, P::IN_EX),
 	ran(0x0fe20,   16, P::IN_EX),
 	ran(0x0feff,    1, P::CN),
-	ran(0x0ff9e,    2, P::EX),
+	ran(0x0ff9e,    2, P::IN_EX),
 	ran(0x0fff0,   12, P::CN),
 	ran(0x101fd,    1, P::IN_EX),
 	ran(0x102e0,    1, P::IN_EX),
 	ran(0x10376,    5, P::IN_EX),
-	ran(0x10a01,    3, P::EX),
-	ran(0x10a05,    2, P::EX),
-	ran(0x10a0c,    1, P::EX),
-	ran(0x10a0d,    1, P::IN_EX),
-	ran(0x10a0e,    1, P::EX),
-	ran(0x10a0f,    1, P::IN_EX),
+	ran(0x10a01,    3, P::IN_EX),
+	ran(0x10a05,    2, P::IN_EX),
+	ran(0x10a0c,    4, P::IN_EX),
 	ran(0x10a38,    3, P::IN_EX),
 	ran(0x10a3f,    1, P::IN_EX),
 	ran(0x10ae5,    2, P::IN_EX),
 	ran(0x10d24,    4, P::IN_EX),
+	ran(0x10d69,    5, P::IN_EX),
 	ran(0x10eab,    2, P::IN_EX),
-	ran(0x10efd,    3, P::IN_EX),
+	ran(0x10efc,    4, P::IN_EX),
 	ran(0x10f46,   11, P::IN_EX),
 	ran(0x10f82,    4, P::IN_EX),
 	ran(0x11000,    1, P::SM),
-	ran(0x11001,    1, P::EX),
+	ran(0x11001,    1, P::IN_EX),
 	ran(0x11002,    1, P::SM),
-	ran(0x11038,   15, P::EX),
+	ran(0x11038,   15, P::IN_EX),
 	ran(0x11070,    1, P::IN_EX),
-	ran(0x11073,    2, P::EX),
-	ran(0x1107f,    1, P::IN_EX),
-	ran(0x11080,    2, P::EX),
+	ran(0x11073,    2, P::IN_EX),
+	ran(0x1107f,    3, P::IN_EX),
 	ran(0x11082,    1, P::SM),
 	ran(0x110b0,    3, P::SM),
-	ran(0x110b3,    4, P::EX),
+	ran(0x110b3,    4, P::IN_EX),
 	ran(0x110b7,    2, P::SM),
-	ran(0x110b9,    1, P::EX),
-	ran(0x110ba,    1, P::IN_EX),
+	ran(0x110b9,    2, P::IN_EX),
 	ran(0x110bd,    1, P::PP),
-	ran(0x110c2,    1, P::EX),
+	ran(0x110c2,    1, P::IN_EX),
 	ran(0x110cd,    1, P::PP),
 	ran(0x11100,    3, P::IN_EX),
-	ran(0x11127,    5, P::EX),
+	ran(0x11127,    5, P::IN_EX),
 	ran(0x1112c,    1, P::SM),
-	ran(0x1112d,    6, P::EX),
-	ran(0x11133,    2, P::IN_EX),
+	ran(0x1112d,    8, P::IN_EX),
 	ran(0x11145,    2, P::SM),
 	ran(0x11173,    1, P::IN_EX),
-	ran(0x11180,    2, P::EX),
+	ran(0x11180,    2, P::IN_EX),
 	ran(0x11182,    1, P::SM),
 	ran(0x111b3,    3, P::SM),
-	ran(0x111b6,    9, P::EX),
-	ran(0x111bf,    2, P::SM),
+	ran(0x111b6,    9, P::IN_EX),
+	ran(0x111bf,    1, P::SM),
+	ran(0x111c0,    1, P::IN_EX),
 	ran(0x111c2,    2, P::PP),
-	ran(0x111c9,    1, P::EX),
-	ran(0x111ca,    1, P::IN_EX),
-	ran(0x111cb,    2, P::EX),
+	ran(0x111c9,    4, P::IN_EX),
 	ran(0x111ce,    1, P::SM),
-	ran(0x111cf,    1, P::EX),
+	ran(0x111cf,    1, P::IN_EX),
 	ran(0x1122c,    3, P::SM),
-	ran(0x1122f,    3, P::EX),
+	ran(0x1122f,    3, P::IN_EX),
 	ran(0x11232,    2, P::SM),
-	ran(0x11234,    1, P::EX),
-	ran(0x11235,    1, P::SM),
-	ran(0x11236,    1, P::IN_EX),
-	ran(0x11237,    1, P::EX),
-	ran(0x1123e,    1, P::EX),
-	ran(0x11241,    1, P::EX),
-	ran(0x112df,    1, P::EX),
+	ran(0x11234,    4, P::IN_EX),
+	ran(0x1123e,    1, P::IN_EX),
+	ran(0x11241,    1, P::IN_EX),
+	ran(0x112df,    1, P::IN_EX),
 	ran(0x112e0,    3, P::SM),
-	ran(0x112e3,    6, P::EX),
-	ran(0x112e9,    2, P::IN_EX),
-	ran(0x11300,    2, P::EX),
+	ran(0x112e3,    8, P::IN_EX),
+	ran(0x11300,    2, P::IN_EX),
 	ran(0x11302,    2, P::SM),
 	ran(0x1133b,    2, P::IN_EX),
-	ran(0x1133e,    1, P::EX),
+	ran(0x1133e,    1, P::IN_EX),
 	ran(0x1133f,    1, P::SM),
-	ran(0x11340,    1, P::EX),
+	ran(0x11340,    1, P::IN_EX),
 	ran(0x11341,    4, P::SM),
 	ran(0x11347,    2, P::SM),
-	ran(0x1134b,    3, P::SM),
-	ran(0x11357,    1, P::EX),
+	ran(0x1134b,    2, P::SM),
+	ran(0x1134d,    1, P::IN_EX),
+	ran(0x11357,    1, P::IN_EX),
 	ran(0x11362,    2, P::SM),
 	ran(0x11366,    7, P::IN_EX),
 	ran(0x11370,    5, P::IN_EX),
+	ran(0x113b8,    1, P::IN_EX),
+	ran(0x113b9,    2, P::SM),
+	ran(0x113bb,    6, P::IN_EX),
+	ran(0x113c2,    1, P::IN_EX),
+	ran(0x113c5,    1, P::IN_EX),
+	ran(0x113c7,    3, P::IN_EX),
+	ran(0x113ca,    1, P::SM),
+	ran(0x113cc,    2, P::SM),
+	ran(0x113ce,    3, P::IN_EX),
+	ran(0x113d1,    1, P::PP),
+	ran(0x113d2,    1, P::IN_EX),
+	ran(0x113e1,    2, P::IN_EX),
 	ran(0x11435,    3, P::SM),
-	ran(0x11438,    8, P::EX),
+	ran(0x11438,    8, P::IN_EX),
 	ran(0x11440,    2, P::SM),
-	ran(0x11442,    3, P::EX),
+	ran(0x11442,    3, P::IN_EX),
 	ran(0x11445,    1, P::SM),
 	ran(0x11446,    1, P::IN_EX),
 	ran(0x1145e,    1, P::IN_EX),
-	ran(0x114b0,    1, P::EX),
+	ran(0x114b0,    1, P::IN_EX),
 	ran(0x114b1,    2, P::SM),
-	ran(0x114b3,    6, P::EX),
+	ran(0x114b3,    6, P::IN_EX),
 	ran(0x114b9,    1, P::SM),
-	ran(0x114ba,    1, P::EX),
+	ran(0x114ba,    1, P::IN_EX),
 	ran(0x114bb,    2, P::SM),
-	ran(0x114bd,    1, P::EX),
+	ran(0x114bd,    1, P::IN_EX),
 	ran(0x114be,    1, P::SM),
-	ran(0x114bf,    2, P::EX),
+	ran(0x114bf,    2, P::IN_EX),
 	ran(0x114c1,    1, P::SM),
-	ran(0x114c2,    1, P::EX),
-	ran(0x114c3,    1, P::IN_EX),
-	ran(0x115af,    1, P::EX),
+	ran(0x114c2,    2, P::IN_EX),
+	ran(0x115af,    1, P::IN_EX),
 	ran(0x115b0,    2, P::SM),
-	ran(0x115b2,    4, P::EX),
+	ran(0x115b2,    4, P::IN_EX),
 	ran(0x115b8,    4, P::SM),
-	ran(0x115bc,    2, P::EX),
+	ran(0x115bc,    2, P::IN_EX),
 	ran(0x115be,    1, P::SM),
-	ran(0x115bf,    1, P::EX),
-	ran(0x115c0,    1, P::IN_EX),
-	ran(0x115dc,    2, P::EX),
+	ran(0x115bf,    2, P::IN_EX),
+	ran(0x115dc,    2, P::IN_EX),
 	ran(0x11630,    3, P::SM),
-	ran(0x11633,    8, P::EX),
+	ran(0x11633,    8, P::IN_EX),
 	ran(0x1163b,    2, P::SM),
-	ran(0x1163d,    1, P::EX),
+	ran(0x1163d,    1, P::IN_EX),
 	ran(0x1163e,    1, P::SM),
-	ran(0x1163f,    2, P::EX),
-	ran(0x116ab,    1, P::EX),
+	ran(0x1163f,    2, P::IN_EX),
+	ran(0x116ab,    1, P::IN_EX),
 	ran(0x116ac,    1, P::SM),
-	ran(0x116ad,    1, P::EX),
+	ran(0x116ad,    1, P::IN_EX),
 	ran(0x116ae,    2, P::SM),
-	ran(0x116b0,    6, P::EX),
-	ran(0x116b6,    1, P::SM),
-	ran(0x116b7,    1, P::IN_EX),
-	ran(0x1171d,    3, P::EX),
-	ran(0x11722,    4, P::EX),
+	ran(0x116b0,    8, P::IN_EX),
+	ran(0x1171d,    1, P::IN_EX),
+	ran(0x1171e,    1, P::SM),
+	ran(0x1171f,    1, P::IN_EX),
+	ran(0x11722,    4, P::IN_EX),
 	ran(0x11726,    1, P::SM),
-	ran(0x11727,    4, P::EX),
-	ran(0x1172b,    1, P::IN_EX),
+	ran(0x11727,    5, P::IN_EX),
 	ran(0x1182c,    3, P::SM),
-	ran(0x1182f,    9, P::EX),
+	ran(0x1182f,    9, P::IN_EX),
 	ran(0x11838,    1, P::SM),
-	ran(0x11839,    1, P::EX),
-	ran(0x1183a,    1, P::IN_EX),
-	ran(0x11930,    1, P::EX),
+	ran(0x11839,    2, P::IN_EX),
+	ran(0x11930,    1, P::IN_EX),
 	ran(0x11931,    5, P::SM),
 	ran(0x11937,    2, P::SM),
-	ran(0x1193b,    2, P::EX),
-	ran(0x1193d,    1, P::SM),
-	ran(0x1193e,    1, P::IN_EX),
+	ran(0x1193b,    4, P::IN_EX),
 	ran(0x1193f,    1, P::PP),
 	ran(0x11940,    1, P::SM),
 	ran(0x11941,    1, P::PP),
 	ran(0x11942,    1, P::SM),
 	ran(0x11943,    1, P::IN_EX),
 	ran(0x119d1,    3, P::SM),
-	ran(0x119d4,    4, P::EX),
-	ran(0x119da,    2, P::EX),
+	ran(0x119d4,    4, P::IN_EX),
+	ran(0x119da,    2, P::IN_EX),
 	ran(0x119dc,    4, P::SM),
-	ran(0x119e0,    1, P::EX),
+	ran(0x119e0,    1, P::IN_EX),
 	ran(0x119e4,    1, P::SM),
-	ran(0x11a01,   10, P::EX),
-	ran(0x11a33,    1, P::EX),
-	ran(0x11a34,    1, P::IN_EX),
-	ran(0x11a35,    4, P::EX),
+	ran(0x11a01,   10, P::IN_EX),
+	ran(0x11a33,    6, P::IN_EX),
 	ran(0x11a39,    1, P::SM),
 	ran(0x11a3a,    1, P::PP),
-	ran(0x11a3b,    4, P::EX),
+	ran(0x11a3b,    4, P::IN_EX),
 	ran(0x11a47,    1, P::IN_EX),
-	ran(0x11a51,    6, P::EX),
+	ran(0x11a51,    6, P::IN_EX),
 	ran(0x11a57,    2, P::SM),
-	ran(0x11a59,    3, P::EX),
+	ran(0x11a59,    3, P::IN_EX),
 	ran(0x11a84,    6, P::PP),
-	ran(0x11a8a,   13, P::EX),
+	ran(0x11a8a,   13, P::IN_EX),
 	ran(0x11a97,    1, P::SM),
-	ran(0x11a98,    1, P::EX),
-	ran(0x11a99,    1, P::IN_EX),
+	ran(0x11a98,    2, P::IN_EX),
 	ran(0x11c2f,    1, P::SM),
-	ran(0x11c30,    7, P::EX),
-	ran(0x11c38,    6, P::EX),
+	ran(0x11c30,    7, P::IN_EX),
+	ran(0x11c38,    6, P::IN_EX),
 	ran(0x11c3e,    1, P::SM),
-	ran(0x11c3f,    1, P::EX),
-	ran(0x11c92,   22, P::EX),
+	ran(0x11c3f,    1, P::IN_EX),
+	ran(0x11c92,   22, P::IN_EX),
 	ran(0x11ca9,    1, P::SM),
-	ran(0x11caa,    7, P::EX),
+	ran(0x11caa,    7, P::IN_EX),
 	ran(0x11cb1,    1, P::SM),
-	ran(0x11cb2,    2, P::EX),
+	ran(0x11cb2,    2, P::IN_EX),
 	ran(0x11cb4,    1, P::SM),
-	ran(0x11cb5,    2, P::EX),
-	ran(0x11d31,    6, P::EX),
-	ran(0x11d3a,    1, P::EX),
-	ran(0x11d3c,    2, P::EX),
-	ran(0x11d3f,    3, P::EX),
-	ran(0x11d42,    1, P::IN_EX),
-	ran(0x11d43,    1, P::EX),
-	ran(0x11d44,    2, P::IN_EX),
+	ran(0x11cb5,    2, P::IN_EX),
+	ran(0x11d31,    6, P::IN_EX),
+	ran(0x11d3a,    1, P::IN_EX),
+	ran(0x11d3c,    2, P::IN_EX),
+	ran(0x11d3f,    7, P::IN_EX),
 	ran(0x11d46,    1, P::PP),
-	ran(0x11d47,    1, P::EX),
+	ran(0x11d47,    1, P::IN_EX),
 	ran(0x11d8a,    5, P::SM),
-	ran(0x11d90,    2, P::EX),
+	ran(0x11d90,    2, P::IN_EX),
 	ran(0x11d93,    2, P::SM),
-	ran(0x11d95,    1, P::EX),
+	ran(0x11d95,    1, P::IN_EX),
 	ran(0x11d96,    1, P::SM),
 	ran(0x11d97,    1, P::IN_EX),
-	ran(0x11ef3,    2, P::EX),
+	ran(0x11ef3,    2, P::IN_EX),
 	ran(0x11ef5,    2, P::SM),
-	ran(0x11f00,    2, P::EX),
+	ran(0x11f00,    2, P::IN_EX),
 	ran(0x11f02,    1, P::PP),
 	ran(0x11f03,    1, P::SM),
 	ran(0x11f34,    2, P::SM),
-	ran(0x11f36,    5, P::EX),
+	ran(0x11f36,    5, P::IN_EX),
 	ran(0x11f3e,    2, P::SM),
-	ran(0x11f40,    1, P::EX),
-	ran(0x11f41,    1, P::SM),
-	ran(0x11f42,    1, P::IN_EX),
+	ran(0x11f40,    3, P::IN_EX),
+	ran(0x11f5a,    1, P::IN_EX),
 	ran(0x13430,   16, P::CN),
-	ran(0x13440,    1, P::EX),
-	ran(0x13447,   15, P::EX),
+	ran(0x13440,    1, P::IN_EX),
+	ran(0x13447,   15, P::IN_EX),
+	ran(0x1611e,   12, P::IN_EX),
+	ran(0x1612a,    3, P::SM),
+	ran(0x1612d,    3, P::IN_EX),
 	ran(0x16af0,    5, P::IN_EX),
 	ran(0x16b30,    7, P::IN_EX),
-	ran(0x16f4f,    1, P::EX),
+	ran(0x16d63,    1, P::V),
+	ran(0x16d67,    4, P::V),
+	ran(0x16f4f,    1, P::IN_EX),
 	ran(0x16f51,   55, P::SM),
-	ran(0x16f8f,    4, P::EX),
-	ran(0x16fe4,    1, P::EX),
-	ran(0x16ff0,    2, P::SM),
-	ran(0x1bc9d,    1, P::EX),
-	ran(0x1bc9e,    1, P::IN_EX),
+	ran(0x16f8f,    4, P::IN_EX),
+	ran(0x16fe4,    1, P::IN_EX),
+	ran(0x16ff0,    2, P::IN_EX),
+	ran(0x1bc9d,    2, P::IN_EX),
 	ran(0x1bca0,    4, P::CN),
-	ran(0x1cf00,   46, P::EX),
-	ran(0x1cf30,   23, P::EX),
-	ran(0x1d165,    1, P::IN_EX),
-	ran(0x1d166,    1, P::SM),
-	ran(0x1d167,    3, P::IN_EX),
-	ran(0x1d16d,    1, P::SM),
-	ran(0x1d16e,    5, P::IN_EX),
+	ran(0x1cf00,   46, P::IN_EX),
+	ran(0x1cf30,   23, P::IN_EX),
+	ran(0x1d165,    5, P::IN_EX),
+	ran(0x1d16d,    6, P::IN_EX),
 	ran(0x1d173,    8, P::CN),
 	ran(0x1d17b,    8, P::IN_EX),
 	ran(0x1d185,    7, P::IN_EX),
 	ran(0x1d1aa,    4, P::IN_EX),
 	ran(0x1d242,    3, P::IN_EX),
-	ran(0x1da00,   55, P::EX),
-	ran(0x1da3b,   50, P::EX),
-	ran(0x1da75,    1, P::EX),
-	ran(0x1da84,    1, P::EX),
-	ran(0x1da9b,    5, P::EX),
-	ran(0x1daa1,   15, P::EX),
+	ran(0x1da00,   55, P::IN_EX),
+	ran(0x1da3b,   50, P::IN_EX),
+	ran(0x1da75,    1, P::IN_EX),
+	ran(0x1da84,    1, P::IN_EX),
+	ran(0x1da9b,    5, P::IN_EX),
+	ran(0x1daa1,   15, P::IN_EX),
 	ran(0x1e000,    7, P::IN_EX),
 	ran(0x1e008,   17, P::IN_EX),
 	ran(0x1e01b,    7, P::IN_EX),
@@ -696,6 +645,7 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x1e2ae,    1, P::IN_EX),
 	ran(0x1e2ec,    4, P::IN_EX),
 	ran(0x1e4ec,    4, P::IN_EX),
+	ran(0x1e5ee,    2, P::IN_EX),
 	ran(0x1e8d0,    7, P::IN_EX),
 	ran(0x1e944,    7, P::IN_EX),
 	ran(0x1f000,  256, P::EP),
@@ -713,7 +663,7 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x1f232,    9, P::EP),
 	ran(0x1f23c,    4, P::EP),
 	ran(0x1f249,  434, P::EP),
-	ran(0x1f3fb,    5, P::EX),
+	ran(0x1f3fb,    5, P::IN_EX),
 	ran(0x1f400,  318, P::EP),
 	ran(0x1f546,  266, P::EP),
 	ran(0x1f680,  128, P::EP),
@@ -729,8 +679,8 @@ pub const RANGES: [CharRange; 733] = [
 	ran(0x1f947,  441, P::EP),
 	ran(0x1fc00, 1022, P::EP),
 	ran(0xe0000,   32, P::CN),
-	ran(0xe0020,   96, P::EX),
+	ran(0xe0020,   96, P::IN_EX),
 	ran(0xe0080,  128, P::CN),
-	ran(0xe0100,  240, P::EX),
+	ran(0xe0100,  240, P::IN_EX),
 	ran(0xe01f0, 3600, P::CN),
 ];
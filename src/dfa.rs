@@ -0,0 +1,119 @@
+use crate::dfa_data::{LEAD, NODES};
+use crate::lookup::{GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};
+use crate::logic::{is_boundary_packed, Ctx};
+
+/// Number of possible [PackedProps::bits], which index the transitions.
+const BITS: usize = 128;
+/// Marks entries of the byte trie which are packed properties rather than node indices.
+const LEAF: u16 = 0x8000;
+
+/// All segmentation contexts, in the order of their codes.
+const CTXS: [Ctx; 6] = Ctx::ALL;
+/// Number of DFA states: a state is a context along with the Grapheme_Cluster_Break property
+/// of the last character (the context already tells the rules all they need about its other properties).
+const STATES: usize = CTXS.len() * G::ALL.len();
+/// Marks transitions which cross an EGC boundary.
+const BOUNDARY: u8 = 0x80;
+
+/// State after a character with properties `p`, in the context `ctx` computed up to it.
+const fn state(ctx: Ctx, p: PackedProps) -> u8 {
+	(ctx.code() as usize * G::ALL.len() + p.gcb() as usize) as u8
+}
+
+/// State after the first character of an EGC, with properties `p`.
+const fn initial_state(p: PackedProps) -> u8 {
+	state(Ctx::Start.step_packed(p), p)
+}
+
+/// Compiles the segmentation rules into a transition table, indexed by state and
+/// by the packed properties of the next character.
+const fn build_transitions() -> [[u8; BITS]; STATES] {
+	let mut table = [[0; BITS]; STATES];
+	let mut ci = 0;
+	while ci < CTXS.len() {
+		let ctx = CTXS[ci];
+		// Only a linker or an extender can be followed by a consonant in this context
+		let incb = if matches!(ctx, Ctx::Indic(true)) { I::Linker } else { I::None };
+		let mut g = 0;
+		while g < G::ALL.len() {
+			let p1 = PackedProps::new(G::ALL[g], incb, false);
+			let mut bits = 0;
+			while bits < BITS {
+				// Invalid bits never come out of the tables
+				if let Some(p2) = PackedProps::from_bits(bits as u8) {
+					table[ci * G::ALL.len() + g][bits] = if is_boundary_packed(ctx, p1, p2) {
+						BOUNDARY | initial_state(p2)
+					} else {
+						state(ctx.step_packed(p2), p2)
+					};
+				}
+				bits += 1;
+			}
+			g += 1;
+		}
+		ci += 1;
+	}
+	table
+}
+
+static TRANSITIONS: [[u8; BITS]; STATES] = build_transitions();
+
+/// Looks up the properties of the character starting at byte `i`, by walking the
+/// byte trie, and returns them along with the length of the character.
+#[inline]
+pub(crate) fn props_at(bytes: &[u8], i: usize) -> (PackedProps, usize) {
+	let b = bytes[i];
+	let mut entry = LEAD[b as usize];
+	let mut j = i + 1;
+	while entry & LEAF == 0 {
+		entry = NODES[entry as usize * 64 + (bytes[j] & 0x3f) as usize];
+		j += 1;
+	}
+	(PackedProps(entry as u8), utf8_len(b))
+}
+
+/// Length of a UTF-8 sequence, given its first byte.
+#[inline]
+fn utf8_len(b: u8) -> usize {
+	match b {
+		0x00..=0x7f => 1,
+		0x80..=0xdf => 2,
+		0xe0..=0xef => 3,
+		_ => 4,
+	}
+}
+
+/// Like [first_boundary], but takes the properties of the first character if already
+/// known, and returns those of the character following the boundary.
+pub(crate) fn first_boundary_with(s: &str, first: Option<PackedProps>) -> (usize, Option<PackedProps>) {
+	let bytes = s.as_bytes();
+	if bytes.is_empty() {
+		return (0, None);
+	}
+	let (p, mut i) = match first {
+		Some(p) => (p, utf8_len(bytes[0])),
+		None => props_at(bytes, 0),
+	};
+	let mut state = initial_state(p);
+	while i < bytes.len() {
+		let (p2, len) = props_at(bytes, i);
+		let next = TRANSITIONS[state as usize][p2.bits() as usize];
+		if next & BOUNDARY != 0 {
+			return (i, Some(p2));
+		}
+		state = next;
+		i += len;
+	}
+	(bytes.len(), None)
+}
+
+/// Returns the offset of the first EGC boundary in the string, like
+/// [logic::first_boundary](crate::logic::first_boundary).
+/// 
+/// Instead of decoding characters and looking up their properties with a binary search,
+/// this walks a trie indexed by the UTF-8 bytes of the string, and applies the segmentation
+/// rules with a transition table computed at compile time. When the `dfa` feature is enabled,
+/// this is also the engine used by forward iterators such as [EgcIndices](crate::iter::EgcIndices).
+pub fn first_boundary(s: &str) -> usize {
+	first_boundary_with(s, None).0
+}
@@ -0,0 +1,120 @@
+use alloc::string::String;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::Egc;
+
+/// Converts a range of EGC indices in `s` to a range of byte offsets.
+/// 
+/// Returns None if the range is decreasing, or ends past the number of EGCs in `s`.
+fn egc_byte_range(s: &str, egcs: Range<usize>) -> Option<Range<usize>> {
+	if egcs.start > egcs.end {
+		return None;
+	}
+	let mut start = if egcs.start == 0 { Some(0) } else { None };
+	let mut end = if egcs.end == 0 { Some(0) } else { None };
+	for (i, offset) in s.egc_indices().enumerate().take(egcs.end) {
+		if i + 1 == egcs.start {
+			start = Some(offset);
+		}
+		if i + 1 == egcs.end {
+			end = Some(offset);
+		}
+	}
+	Some(start?..end?)
+}
+
+/// Removes the EGCs of index `egcs` from `s`, and returns them.
+/// 
+/// Like [String::drain], but indices are counted in EGCs rather than bytes,
+/// so EGCs cannot be split.
+/// 
+/// Panics if the start of the range is greater than its end, or if its end
+/// is greater than the number of EGCs in the string.
+pub fn drain_egcs(s: &mut String, egcs: Range<usize>) -> String {
+	let Some(range) = egc_byte_range(s, egcs.clone()) else {
+		panic!("EGC range {:?} out of bounds", egcs);
+	};
+	s.drain(range).collect()
+}
+
+/// Moves the EGCs of index `egcs` within `s`, so that they start at EGC index `to`
+/// once moved, and returns their new byte range.
+/// 
+/// For instance, moving the EGCs `1..3` of "abcd" to 0 gives "bcad", and to 2 gives "adbc".
+/// Note that the moved EGCs may merge with their new neighbors (eg. regional indicators
+/// forming a flag), in which case the EGC indices of the result differ.
+/// 
+/// Panics if `egcs` is out of bounds, or if `to` is greater than the number of EGCs
+/// which are not moved.
+pub fn move_egcs(s: &mut String, egcs: Range<usize>, to: usize) -> Range<usize> {
+	let Some(range) = egc_byte_range(s, egcs.clone()) else {
+		panic!("EGC range {:?} out of bounds", egcs);
+	};
+	// Position of the destination in `s` before the move
+	let dest = if to <= egcs.start {
+		egc_byte_range(s, to..to).map(|r| r.start)
+	} else {
+		let end = to + egcs.len();
+		egc_byte_range(s, end..end).map(|r| r.start - range.len())
+	};
+	let Some(dest) = dest else {
+		panic!("destination {} out of bounds", to);
+	};
+	let moved: String = s.drain(range).collect();
+	s.insert_str(dest, &moved);
+	dest..dest + moved.len()
+}
+
+/// Extension methods editing owned strings EGC by EGC.
+pub trait EgcString {
+	/// Shortens the string to its first `n` EGCs, like [String::truncate] with a length
+	/// counted in EGCs. Has no effect if the string has `n` EGCs or fewer.
+	fn egc_truncate(&mut self, n: usize);
+
+	/// Returns an iterator taking ownership of the string, and returning its EGCs
+	/// as owned strings (eg. to send them to another thread).
+	fn into_egcs(self) -> IntoEgcs;
+}
+
+impl EgcString for String {
+	fn egc_truncate(&mut self, n: usize) {
+		let len = self.egc_take(n).len();
+		self.truncate(len);
+	}
+	fn into_egcs(self) -> IntoEgcs {
+		IntoEgcs { str: self, offset: 0 }
+	}
+}
+
+/// An iterator over the EGCs of an owned string, returned as owned strings.
+/// 
+/// Obtained with [into_egcs](EgcString::into_egcs).
+#[derive(Debug, Clone)]
+pub struct IntoEgcs {
+	str: String,
+	offset: usize,
+}
+
+impl IntoEgcs {
+	/// Returns the part of the string which has not been segmented yet.
+	pub fn as_str(&self) -> &str {
+		&self.str[self.offset..]
+	}
+}
+
+impl Iterator for IntoEgcs {
+	type Item = String;
+	fn next(&mut self) -> Option<String> {
+		let egc = String::from(self.as_str().egc_first()?);
+		self.offset += egc.len();
+		Some(egc)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.str.len() - self.offset;
+		(remaining.min(1), Some(remaining))
+	}
+}
+
+impl FusedIterator for IntoEgcs {}
@@ -0,0 +1,128 @@
+use core::ops::Range;
+
+use crate::logic::{first_boundary, is_boundary_packed, Ctx};
+use crate::lookup::lookup_packed_props;
+
+/// A position in a sequence of string fragments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FragmentPos {
+	/// Index of the fragment
+	pub fragment: usize,
+	/// Byte offset in the fragment
+	pub offset: usize,
+}
+
+/// A forward iterator over the EGCs in the concatenation of several string fragments,
+/// without concatenating them.
+/// 
+/// Each EGC is returned as a range of [FragmentPos], and may span several fragments.
+/// The start of an EGC always points inside a fragment (never at its end), and the
+/// end of an EGC always points to the end of its last character (never to the start
+/// of the next fragment). Empty fragments are skipped over.
+#[derive(Debug, Clone)]
+pub struct FragmentEgcs<'a, 'b> {
+	fragments: &'a [&'b str],
+	pos: FragmentPos,
+}
+
+impl<'a, 'b> FragmentEgcs<'a, 'b> {
+	/// Creates an iterator over the EGCs in the concatenation of `fragments`.
+	pub fn new(fragments: &'a [&'b str]) -> Self {
+		FragmentEgcs { fragments, pos: FragmentPos { fragment: 0, offset: 0 } }
+	}
+
+	/// Returns the pieces of the fragments covered by `range`, which can
+	/// be a range returned by this iterator.
+	pub fn pieces(&self, range: Range<FragmentPos>) -> impl Iterator<Item = &'b str> + 'a {
+		let fragments = self.fragments;
+		(range.start.fragment..=range.end.fragment).map(move |i| {
+			let frag = fragments[i];
+			let start = if i == range.start.fragment { range.start.offset } else { 0 };
+			let end = if i == range.end.fragment { range.end.offset } else { frag.len() };
+			&frag[start..end]
+		})
+	}
+
+	/// Skips to the next character, if any.
+	fn skip_empty(&mut self) -> bool {
+		while let Some(frag) = self.fragments.get(self.pos.fragment) {
+			if self.pos.offset < frag.len() {
+				return true;
+			}
+			self.pos = FragmentPos { fragment: self.pos.fragment + 1, offset: 0 };
+		}
+		false
+	}
+}
+
+impl Iterator for FragmentEgcs<'_, '_> {
+	type Item = Range<FragmentPos>;
+	fn next(&mut self) -> Option<Range<FragmentPos>> {
+		if !self.skip_empty() {
+			return None;
+		}
+		let start = self.pos;
+		let mut end = start;
+		let mut ctx = Ctx::Start;
+		let mut p1 = None;
+		while self.skip_empty() {
+			let frag = self.fragments[self.pos.fragment];
+			for c in frag[self.pos.offset..].chars() {
+				let p2 = lookup_packed_props(c);
+				if let Some(p1) = p1 {
+					if is_boundary_packed(ctx, p1, p2) {
+						return Some(start..end);
+					}
+				}
+				ctx = ctx.step_packed(p2);
+				p1 = Some(p2);
+				self.pos.offset += c.len_utf8();
+				end = self.pos;
+			}
+		}
+		Some(start..end)
+	}
+}
+
+/// A forward iterator over the EGCs in each record of a buffer of delimiter-separated
+/// records (eg. NUL-terminated strings packed in one allocation).
+/// 
+/// Each record is segmented independently, as if it were a separate string: EGCs
+/// never span a delimiter, even when the delimiter would not break an EGC by itself
+/// (which can only happen with a non-control delimiter). Delimiters are not returned.
+/// 
+/// Each EGC is returned as the index of its record, and its byte range in the whole buffer.
+#[derive(Debug, Clone)]
+pub struct RecordEgcs<'a> {
+	str: &'a str,
+	delimiter: char,
+	record: usize,
+	pos: usize,
+	/// End of the current record
+	record_end: usize,
+}
+
+impl<'a> RecordEgcs<'a> {
+	/// Creates an iterator over the EGCs of the records in `str`, separated by `delimiter`.
+	pub fn new(str: &'a str, delimiter: char) -> Self {
+		let record_end = str.find(delimiter).unwrap_or(str.len());
+		RecordEgcs { str, delimiter, record: 0, pos: 0, record_end }
+	}
+}
+
+impl Iterator for RecordEgcs<'_> {
+	type Item = (usize, Range<usize>);
+	fn next(&mut self) -> Option<(usize, Range<usize>)> {
+		while self.pos == self.record_end {
+			if self.record_end == self.str.len() {
+				return None;
+			}
+			self.pos = self.record_end + self.delimiter.len_utf8();
+			self.record += 1;
+			self.record_end = self.str[self.pos..].find(self.delimiter).map_or(self.str.len(), |i| self.pos + i);
+		}
+		let start = self.pos;
+		self.pos += first_boundary(&self.str[start..self.record_end]);
+		Some((self.record, start..self.pos))
+	}
+}
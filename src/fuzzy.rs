@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::Egc;
+
+/// An EGC of a candidate matched by [fuzzy_match].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchedEgc {
+	/// Index of the EGC in the candidate, counted in EGCs
+	pub index: usize,
+	/// Byte range of the EGC in the candidate
+	pub range: Range<usize>,
+}
+
+/// Result of [fuzzy_match].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+	/// Score of the match: higher is better
+	pub score: i32,
+	/// EGCs of the candidate matched with the EGCs of the query, in order
+	pub matched: Vec<MatchedEgc>,
+}
+
+impl FuzzyMatch {
+	/// Returns the byte ranges to highlight in the candidate, with the matched
+	/// EGCs which are next to each other merged into a single span.
+	pub fn highlight_spans(&self) -> Vec<Range<usize>> {
+		merge_spans(self.matched.iter().map(|m| m.range.clone()).collect())
+	}
+}
+
+/// Score for each matched EGC
+const SCORE_MATCH: i32 = 16;
+/// Bonus for a match immediately following the previous one
+const BONUS_CONSECUTIVE: i32 = 8;
+/// Bonus for a match at the start of the candidate or of a word
+const BONUS_WORD_START: i32 = 8;
+/// Penalty for each EGC skipped between the first and last matches
+const PENALTY_GAP: i32 = 1;
+
+/// Are the EGCs equal, ignoring case
+fn egc_eq_ignore_case(a: &str, b: &str) -> bool {
+	a == b || a.chars().flat_map(char::to_lowercase).eq(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Does the EGC separate words
+fn is_separator(egc: &str) -> bool {
+	matches!(egc, " " | "\t" | "_" | "-" | "/" | "\\" | "." | ":")
+}
+
+/// Matches `query` against `candidate` as a subsequence of EGCs, ignoring case,
+/// as in command palettes and file pickers.
+/// 
+/// Returns None if the EGCs of the query do not all appear in the candidate, in order.
+/// Otherwise, returns the matched EGCs of the candidate, and a score rewarding matches
+/// which are consecutive or at the start of words, and penalizing gaps between matches.
+/// EGCs are only matched as a whole: "e" does not match an "e" followed by a combining
+/// accent, and the matched ranges never split an emoji or a flag.
+/// 
+/// The match is found greedily, from left to right, so it is not necessarily the best
+/// scoring one. An empty query matches any candidate with a score of 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+	let mut matched: Vec<MatchedEgc> = Vec::new();
+	let mut score = 0;
+	let mut query_egcs = query.egcs().peekable();
+	let mut start = 0;
+	let mut prev: Option<&str> = None;
+	for (index, egc) in candidate.egcs().enumerate() {
+		let range = start..start + egc.len();
+		start = range.end;
+		let Some(&wanted) = query_egcs.peek() else {
+			break;
+		};
+		if egc_eq_ignore_case(wanted, egc) {
+			query_egcs.next();
+			score += SCORE_MATCH;
+			if let Some(last) = matched.last() {
+				if last.index + 1 == index {
+					score += BONUS_CONSECUTIVE;
+				} else {
+					score -= PENALTY_GAP * (index - last.index - 1) as i32;
+				}
+			}
+			if prev.map_or(true, is_separator) {
+				score += BONUS_WORD_START;
+			}
+			matched.push(MatchedEgc { index, range });
+		}
+		prev = Some(egc);
+	}
+	if query_egcs.peek().is_some() {
+		return None;
+	}
+	Some(FuzzyMatch { score, matched })
+}
+
+/// Sorts byte ranges and merges the ones which overlap or touch, dropping empty ones.
+fn merge_spans(mut spans: Vec<Range<usize>>) -> Vec<Range<usize>> {
+	spans.retain(|r| r.start < r.end);
+	spans.sort_unstable_by_key(|r| r.start);
+	let mut merged: Vec<Range<usize>> = Vec::with_capacity(spans.len());
+	for span in spans {
+		match merged.last_mut() {
+			Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+			_ => merged.push(span),
+		}
+	}
+	merged
+}
+
+/// Converts byte ranges of `s` to highlight (eg. search results) into a minimal list
+/// of sorted, non-overlapping spans, which never split an EGC.
+/// 
+/// Each range is extended to the EGC boundaries around it, then ranges which overlap
+/// or are adjacent are merged. Empty ranges are ignored. The ranges can be given in
+/// any order, and do not need to be on `char` boundaries.
+pub fn highlight_spans(s: &str, ranges: impl IntoIterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+	merge_spans(ranges.into_iter()
+		.filter(|r| r.start < r.end)
+		.map(|r| s.floor_egc_boundary(r.start)..s.ceil_egc_boundary(r.end))
+		.collect())
+}
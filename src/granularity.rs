@@ -0,0 +1,71 @@
+use crate::iter::EgcSlices;
+use crate::tailoring::{tailored_egc_indices, BreakTailoring, Legacy, TailoredEgcIndices};
+use crate::Egc;
+
+/// Unit in which [segments] splits a string, eg. to implement a user setting
+/// such as "count characters as: codepoints / characters".
+/// 
+/// Clusters with other tailorings are given by [segments_tailored].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Granularity {
+	/// Unicode scalar values (Rust `char`s)
+	Codepoint,
+	/// Legacy grapheme clusters, as returned by [Legacy] tailoring
+	Legacy,
+	/// Extended grapheme clusters, as returned by [egcs](Egc::egcs)
+	#[default]
+	Extended,
+}
+
+/// An iterator over the segments of a string at a given [Granularity], or with
+/// a given tailoring (`T`, which is [Legacy] when returned by [segments]),
+/// returned as sub-slices.
+#[derive(Debug, Clone)]
+pub struct Segments<'a, T = Legacy> {
+	inner: Inner<'a, T>,
+}
+
+#[derive(Debug, Clone)]
+enum Inner<'a, T> {
+	Codepoints(&'a str),
+	Tailored(&'a str, TailoredEgcIndices<'a, T>),
+	Egcs(EgcSlices<'a>),
+}
+
+impl<'a, T: BreakTailoring> Iterator for Segments<'a, T> {
+	type Item = &'a str;
+	fn next(&mut self) -> Option<&'a str> {
+		match &mut self.inner {
+			Inner::Codepoints(rest) => {
+				let len = rest.chars().next()?.len_utf8();
+				let (segment, tail) = rest.split_at(len);
+				*rest = tail;
+				Some(segment)
+			},
+			Inner::Tailored(s, indices) => {
+				let start = indices.offset();
+				let end = indices.next()?;
+				Some(&s[start..end])
+			},
+			Inner::Egcs(egcs) => egcs.next(),
+		}
+	}
+}
+
+/// Returns an iterator over the segments of `s` at the given granularity, so that
+/// the unit can be chosen at runtime without changing the call site.
+pub fn segments(s: &str, granularity: Granularity) -> Segments<'_> {
+	let inner = match granularity {
+		Granularity::Codepoint => Inner::Codepoints(s),
+		Granularity::Legacy => Inner::Tailored(s, tailored_egc_indices(s, Legacy)),
+		Granularity::Extended => Inner::Egcs(s.egcs()),
+	};
+	Segments { inner }
+}
+
+/// Returns an iterator over the clusters of `s` determined with the given tailoring,
+/// like [segments] with a custom granularity.
+pub fn segments_tailored<T: BreakTailoring>(s: &str, tailoring: T) -> Segments<'_, T> {
+	Segments { inner: Inner::Tailored(s, tailored_egc_indices(s, tailoring)) }
+}
@@ -0,0 +1,100 @@
+use core::fmt;
+
+use crate::lookup::{lookup_egc_props, EgcProps as EP};
+use crate::search::egc_prefix;
+
+/// Options for [group_key_first_egc].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GroupOptions {
+	/// Strip the combining marks (and other extending characters) from the EGC,
+	/// so that eg. "É" written with a combining accent is grouped under "E"
+	pub strip_marks: bool,
+	/// Uppercase the base character of the EGC, so that "a" is grouped under "A"
+	pub uppercase: bool,
+	/// Group all EGCs starting with an emoji (including flags and keycaps) under [GroupKey::Emoji]
+	pub emoji_bucket: bool,
+	/// Group all EGCs starting with a numeric character under [GroupKey::Digit]
+	pub digit_bucket: bool,
+}
+
+impl GroupOptions {
+	/// Options typical of contact lists: all options enabled.
+	pub const LIST: GroupOptions = GroupOptions { strip_marks: true, uppercase: true, emoji_bucket: true, digit_bucket: true };
+}
+
+/// Section key of a string in an alphabetical list, returned by [group_key_first_egc].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GroupKey<'a> {
+	/// The string is empty.
+	Empty,
+	/// The first EGC of the string, or its base if marks were stripped.
+	Egc(&'a str),
+	/// The base character of the first EGC after uppercasing, followed by the rest
+	/// of the EGC (which is empty if marks were stripped).
+	Upper(char, &'a str),
+	/// The string starts with a numeric character.
+	Digit,
+	/// The string starts with an emoji.
+	Emoji,
+}
+
+/// Displays the key as text: digits are shown as "#", and emojis as "☺".
+impl fmt::Display for GroupKey<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			GroupKey::Empty => Ok(()),
+			GroupKey::Egc(egc) => f.write_str(egc),
+			GroupKey::Upper(c, rest) => write!(f, "{}{}", c, rest),
+			GroupKey::Digit => f.write_str("#"),
+			GroupKey::Emoji => f.write_str("\u{263a}"),
+		}
+	}
+}
+
+/// Does the EGC start with an emoji (pictographic character, flag, or keycap)
+fn is_emoji_egc(egc: &str) -> bool {
+	let first = egc.chars().next();
+	first.is_some_and(|c| matches!(lookup_egc_props(c), EP::EP | EP::RI)) || egc.ends_with('\u{20e3}')
+}
+
+/// Returns the key used to group `s` into sections of an alphabetical list,
+/// based on its first EGC.
+/// 
+/// With the default options, this is simply the first EGC. See [GroupOptions]
+/// for the normalizations available.
+/// 
+/// Uppercasing is only applied when the base character has a single-character
+/// uppercase mapping (so "ß" is left as is): this keeps the function allocation-free.
+/// Note that no Unicode normalization is performed, so a precomposed "é" is not
+/// turned into "e" by stripping marks.
+pub fn group_key_first_egc<'a>(s: &'a str, options: &GroupOptions) -> GroupKey<'a> {
+	let mut egc = egc_prefix(s, 1);
+	let mut chars = egc.char_indices();
+	let Some((_, first)) = chars.next() else {
+		return GroupKey::Empty;
+	};
+	if options.emoji_bucket && is_emoji_egc(egc) {
+		return GroupKey::Emoji;
+	}
+	if options.digit_bucket && first.is_numeric() {
+		return GroupKey::Digit;
+	}
+	if options.strip_marks {
+		let is_mark = |c| {
+			let p = lookup_egc_props(c);
+			p.is_gcb_ex() || matches!(p, EP::ZWJ | EP::SM)
+		};
+		let base_len = chars.find(|&(_, c)| is_mark(c)).map_or(egc.len(), |(i, _)| i);
+		egc = &egc[..base_len];
+	}
+	if options.uppercase {
+		let mut upper = first.to_uppercase();
+		if let (Some(c), None) = (upper.next(), upper.next()) {
+			if c != first {
+				return GroupKey::Upper(c, &egc[first.len_utf8()..]);
+			}
+		}
+	}
+	GroupKey::Egc(egc)
+}
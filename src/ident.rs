@@ -0,0 +1,74 @@
+use crate::lookup::{is_default_ignorable, lookup_egc_props, EgcProps};
+
+/// Identifier class of a character, from the XID_Start and XID_Continue properties
+/// of [UAX #31](https://www.unicode.org/reports/tr31/).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum XidClass {
+	/// Can start an identifier (XID_Start, which implies XID_Continue)
+	Start,
+	/// Can only continue an identifier (XID_Continue but not XID_Start)
+	Continue,
+}
+
+/// A range of Unicode codepoints, and the associated identifier class.
+#[derive(Clone, Copy)]
+pub struct XidRange {
+	pub start: u32,
+	pub count: u16,
+	pub kind: XidClass,
+}
+pub(crate) const fn ran(start: u32, count: u16, kind: XidClass) -> XidRange {
+	XidRange { start, count, kind }
+}
+
+/// The identifier class table. Characters not listed cannot appear in identifiers.
+pub use crate::xid_data::XID_RANGES;
+
+/// Looks up the identifier class of `c`, or None if it cannot appear in identifiers.
+pub fn lookup_xid_class(c: char) -> Option<XidClass> {
+	let cp = c as u32;
+	let mut start = 0usize;
+	let mut end = XID_RANGES.len();
+	while end > start {
+		let pivot_idx = (start + end) / 2;
+		let pivot = XID_RANGES[pivot_idx];
+		if cp < pivot.start {
+			end = pivot_idx;
+		} else if cp >= pivot.start + pivot.count as u32 {
+			start = pivot_idx + 1;
+		} else {
+			return Some(pivot.kind);
+		}
+	}
+	None
+}
+
+/// Can the character appear in an emoji sequence: pictographs, regional indicators,
+/// joiners, emoji presentation selectors, skin tone modifiers, and tags.
+fn is_emoji_component(c: char) -> bool {
+	matches!(lookup_egc_props(c), EgcProps::EP | EgcProps::RI | EgcProps::ZWJ)
+		|| matches!(c, '\u{fe0f}' | '\u{1f3fb}'..='\u{1f3ff}' | '\u{e0020}'..='\u{e007f}')
+}
+
+/// Checks whether an EGC is safe to use in identifiers such as user names or handles.
+/// 
+/// An EGC is identifier-safe if it is either:
+/// - made of characters which can appear in identifiers (XID_Continue, see [lookup_xid_class]),
+///   none of them being invisible (default-ignorable, eg. joiners or variation selectors);
+/// - or an emoji (including flags and ZWJ sequences), made only of emoji components, and
+///   not ending with a joiner. Invisible characters are only allowed there as part of
+///   the sequence.
+/// 
+/// Whether the EGC can start an identifier, or the mix of scripts in an identifier, are
+/// left to the caller's policy. The result is unspecified if `egc` is not a single EGC.
+pub fn is_identifier_safe_cluster(egc: &str) -> bool {
+	let Some(first) = egc.chars().next() else {
+		return false;
+	};
+	if matches!(lookup_egc_props(first), EgcProps::EP | EgcProps::RI) {
+		return egc.chars().all(is_emoji_component) && !egc.ends_with('\u{200d}');
+	}
+	egc.chars().all(|c| lookup_xid_class(c).is_some() && !is_default_ignorable(c))
+}
@@ -0,0 +1,260 @@
+use std::io::{self, Read, Write};
+use std::ops::Range;
+
+use crate::logic::{first_boundary, prev_boundary};
+
+pub(crate) const CHUNK_SIZE: usize = 8192;
+
+/// Buffer of UTF-8 text received in chunks, which holds back incomplete EGCs.
+#[derive(Default)]
+pub(crate) struct EgcBuffer {
+	/// Decoded text, the part before `pos` having already been returned
+	text: String,
+	pos: usize,
+	/// Trailing bytes of an incomplete UTF-8 sequence
+	partial: Vec<u8>,
+	eof: bool,
+}
+
+impl EgcBuffer {
+	/// Returns the range of the next complete EGC in the buffer.
+	/// 
+	/// Returns None if more text is needed, or if the end of the text was reached
+	/// (which can be checked with [is_done](EgcBuffer::is_done)).
+	pub fn next_range(&self) -> Option<Range<usize>> {
+		let rest = &self.text[self.pos..];
+		if rest.is_empty() {
+			return None;
+		}
+		let len = first_boundary(rest);
+		// The end of the buffer may not be the end of the EGC
+		if len < rest.len() || self.eof {
+			Some(self.pos..self.pos + len)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the next complete EGC in the buffer, and consumes it.
+	pub fn next_egc(&mut self) -> Option<&str> {
+		let range = self.next_range()?;
+		self.pos = range.end;
+		Some(&self.text[range])
+	}
+
+	/// Has all the text been returned
+	pub fn is_done(&self) -> bool {
+		self.eof && self.pos == self.text.len()
+	}
+
+	/// Appends a chunk of bytes to the text.
+	pub fn push_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+		self.text.drain(..self.pos);
+		self.pos = 0;
+
+		self.partial.extend_from_slice(bytes);
+		let valid_len = match std::str::from_utf8(&self.partial) {
+			Ok(_) => self.partial.len(),
+			Err(err) if err.error_len().is_none() => err.valid_up_to(),
+			Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+		};
+		self.text.push_str(std::str::from_utf8(&self.partial[..valid_len]).unwrap());
+		self.partial.drain(..valid_len);
+		Ok(())
+	}
+
+	/// Signals the end of the text.
+	pub fn push_eof(&mut self) -> io::Result<()> {
+		self.eof = true;
+		if !self.partial.is_empty() {
+			self.partial.clear();
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "incomplete UTF-8 sequence at end of stream"));
+		}
+		Ok(())
+	}
+}
+
+/// Reads UTF-8 text from a [Read] implementation and returns it EGC by EGC,
+/// without requiring the whole text to be in memory.
+/// 
+/// EGCs which straddle the boundary between two reads are buffered until
+/// they are complete. Invalid UTF-8 results in an error of kind
+/// [InvalidData](io::ErrorKind::InvalidData).
+/// 
+/// EGCs can be obtained as owned [String]s by using the reader as an [Iterator],
+/// or borrowed without allocation with [for_each_egc](EgcReader::for_each_egc).
+pub struct EgcReader<R> {
+	reader: R,
+	buf: EgcBuffer,
+}
+
+impl<R: Read> EgcReader<R> {
+	/// Creates a new reader of EGCs.
+	pub fn new(reader: R) -> Self {
+		EgcReader { reader, buf: EgcBuffer::default() }
+	}
+
+	/// Returns the underlying reader.
+	/// 
+	/// Any text which was read from it but not returned yet is lost.
+	pub fn into_inner(self) -> R {
+		self.reader
+	}
+
+	/// Reads the next EGC, returning None at the end of the text.
+	pub fn read_egc(&mut self) -> io::Result<Option<&str>> {
+		while self.buf.next_range().is_none() {
+			if self.buf.is_done() {
+				return Ok(None);
+			}
+			self.fill()?;
+		}
+		Ok(self.buf.next_egc())
+	}
+
+	/// Calls `f` with every remaining EGC in the text.
+	pub fn for_each_egc(&mut self, mut f: impl FnMut(&str)) -> io::Result<()> {
+		while let Some(egc) = self.read_egc()? {
+			f(egc);
+		}
+		Ok(())
+	}
+
+	/// Reads a chunk of text into the buffer.
+	fn fill(&mut self) -> io::Result<()> {
+		let mut chunk = [0u8; CHUNK_SIZE];
+		loop {
+			match self.reader.read(&mut chunk) {
+				Ok(0) => return self.buf.push_eof(),
+				Ok(n) => return self.buf.push_bytes(&chunk[..n]),
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			}
+		}
+	}
+}
+
+impl<R: Read> Iterator for EgcReader<R> {
+	type Item = io::Result<String>;
+	fn next(&mut self) -> Option<io::Result<String>> {
+		self.read_egc().map(|egc| egc.map(String::from)).transpose()
+	}
+}
+
+/// A writer which only passes complete EGCs to an underlying [Write] implementation.
+/// 
+/// The trailing EGC of the text written so far is held back, since later writes may
+/// still extend it (with a combining mark, a ZWJ, the second half of a flag, etc.),
+/// as well as any incomplete UTF-8 sequence. This avoids displaying torn EGCs when
+/// streaming text to a terminal. Invalid UTF-8 is passed through as is.
+/// 
+/// [flush](Write::flush) only flushes the complete EGCs: use [flush_all](EgcWriter::flush_all)
+/// once the text is known to be complete. The held back text is also written when the
+/// writer is dropped, or when calling [into_inner](EgcWriter::into_inner).
+pub struct EgcWriter<W: Write> {
+	// Only None while dropping or in `into_inner`
+	writer: Option<W>,
+	/// Bytes held back
+	pending: Vec<u8>,
+}
+
+impl<W: Write> EgcWriter<W> {
+	/// Creates a new EGC-buffering writer.
+	pub fn new(writer: W) -> Self {
+		EgcWriter { writer: Some(writer), pending: vec![] }
+	}
+
+	/// Returns a reference to the underlying writer.
+	pub fn get_ref(&self) -> &W {
+		self.writer.as_ref().unwrap()
+	}
+
+	/// Returns the bytes which are being held back.
+	pub fn pending(&self) -> &[u8] {
+		&self.pending
+	}
+
+	/// Writes all held back bytes, even if they do not form a complete EGC,
+	/// and flushes the underlying writer.
+	pub fn flush_all(&mut self) -> io::Result<()> {
+		self.write_pending(self.pending.len())?;
+		self.writer.as_mut().unwrap().flush()
+	}
+
+	/// Writes all held back bytes, and returns the underlying writer.
+	/// 
+	/// On error, the underlying writer is dropped along with the bytes which
+	/// could not be written, which are not written again.
+	pub fn into_inner(mut self) -> io::Result<W> {
+		let res = self.flush_all();
+		let writer = self.writer.take().unwrap();
+		res.map(|()| writer)
+	}
+
+	/// Writes the first `len` held back bytes, and removes the ones
+	/// accepted by the underlying writer, even on error.
+	fn write_pending(&mut self, len: usize) -> io::Result<()> {
+		let writer = self.writer.as_mut().unwrap();
+		let mut written = 0;
+		let res = loop {
+			if written == len {
+				break Ok(());
+			}
+			match writer.write(&self.pending[written..len]) {
+				Ok(0) => break Err(io::ErrorKind::WriteZero.into()),
+				Ok(n) => written += n,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => break Err(err),
+			}
+		};
+		self.pending.drain(..written);
+		res
+	}
+
+	/// Returns the length of the prefix of `bytes` which can be written
+	/// without risking to split an EGC.
+	fn complete_len(bytes: &[u8]) -> usize {
+		let mut start = 0;
+		loop {
+			let (valid_len, error_len) = match std::str::from_utf8(&bytes[start..]) {
+				Ok(text) => (text.len(), None),
+				Err(err) => (err.valid_up_to(), err.error_len()),
+			};
+			match error_len {
+				// Pass invalid UTF-8 through, as it cannot be part of an EGC
+				Some(error_len) => start += valid_len + error_len,
+				None => {
+					let text = std::str::from_utf8(&bytes[start..start + valid_len]).unwrap();
+					return start + prev_boundary(text, text.len());
+				},
+			}
+		}
+	}
+}
+
+impl<W: Write> Write for EgcWriter<W> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.pending.extend_from_slice(buf);
+		let len = Self::complete_len(&self.pending);
+		if let Err(err) = self.write_pending(len) {
+			// Only report the error if none of `buf` was written, so that it can be written again
+			if self.pending.len() >= buf.len() {
+				self.pending.truncate(self.pending.len() - buf.len());
+				return Err(err);
+			}
+		}
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.writer.as_mut().unwrap().flush()
+	}
+}
+
+impl<W: Write> Drop for EgcWriter<W> {
+	fn drop(&mut self) {
+		if self.writer.is_some() {
+			let _ = self.flush_all();
+		}
+	}
+}
@@ -1,33 +1,308 @@
-use crate::logic::{first_boundary, last_local_boundary};
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::logic::{first_boundary, first_boundary_with, last_local_boundary, prev_boundary};
+use crate::lookup::{is_default_ignorable_str, lookup_egc_props, EgcProps, PackedProps, PropsLookup};
+use crate::tailoring::{tailored_egc_indices, BreakTailoring, TailoredEgcIndices};
+
+#[cfg(feature = "alloc")]
+use smallvec::SmallVec;
 
 /// A forward iterator over the end indices of EGCs in a string.
-#[derive(Clone, Copy)]
+/// 
+/// Each character is only looked up once: the properties of the character
+/// following a boundary are carried over to the next call.
+#[derive(Debug, Clone, Copy)]
 pub struct EgcIndices<'a> {
 	str: &'a str,
 	offset: usize,
+	// Properties of the character at `offset`, if already looked up
+	next: Option<PackedProps>,
+	lookup: PropsLookup,
+	// End of the run of ASCII characters (except CR) starting before `offset`:
+	// all offsets strictly between `offset` and `ascii_end` are boundaries.
+	ascii_end: usize,
+}
+impl<'a> EgcIndices<'a> {
+	fn new(str: &'a str, offset: usize) -> Self {
+		EgcIndices { str, offset, next: None, lookup: PropsLookup::new(), ascii_end: 0 }
+	}
+
+	/// Like [step](EgcIndices::step), but kept out of line, so that callers with a hot ASCII
+	/// fast path (such as [EgcSlices]) keep it small.
+	#[inline(never)]
+	fn step_outlined(&mut self) -> Option<usize> {
+		self.step()
+	}
+
+	/// Moves to the end of the EGC starting at `offset`, when not inside a run of ASCII.
+	#[inline]
+	fn step(&mut self) -> Option<usize> {
+		if self.offset == self.str.len() {
+			return None;
+		}
+		let (len, next) = first_boundary_with(&self.str[self.offset..], self.next, &mut self.lookup);
+		self.offset += len;
+		self.next = next;
+		if len == 1 && self.offset < self.str.len() {
+			// We may be entering a run of ASCII
+			self.ascii_end = self.offset + ascii_run_len(&self.str.as_bytes()[self.offset..]);
+		}
+		Some(self.offset)
+	}
+
+	/// Skips `n` boundaries, stepping over ASCII runs at once,
+	/// and returns the number of boundaries which could not be skipped.
+	fn advance(&mut self, mut n: usize) -> usize {
+		while n > 0 {
+			if self.offset + 1 < self.ascii_end {
+				let skip = n.min(self.ascii_end - 1 - self.offset);
+				self.offset += skip;
+				self.next = None;
+				n -= skip;
+			} else if self.next().is_some() {
+				n -= 1;
+			} else {
+				break;
+			}
+		}
+		n
+	}
 }
 impl<'a> Iterator for EgcIndices<'a> {
 	type Item = usize;
+	#[inline]
 	fn next(&mut self) -> Option<usize> {
-		if self.offset == self.str.len() {
+		if self.offset + 1 < self.ascii_end {
+			self.offset += 1;
+			self.next = None;
+			return Some(self.offset);
+		}
+		self.step()
+	}
+
+	fn count(self) -> usize {
+		let mut count = 0;
+		for_each_boundary(self, |range| count += range.len());
+		count
+	}
+
+	fn nth(&mut self, n: usize) -> Option<usize> {
+		if self.advance(n) > 0 {
 			return None;
 		}
-		self.offset += first_boundary(&self.str[self.offset..]);
-		Some(self.offset)
+		self.next()
+	}
+
+	fn last(self) -> Option<usize> {
+		// The last EGC always ends at the end of the string
+		if self.offset == self.str.len() { None } else { Some(self.str.len()) }
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		bytes_hint(self.str.len() - self.offset)
+	}
+}
+impl FusedIterator for EgcIndices<'_> {}
+
+/// Size hint of an iterator over the EGCs in `remaining` bytes of text:
+/// there is at least one EGC unless the text is empty, and at most one per byte.
+fn bytes_hint(remaining: usize) -> (usize, Option<usize>) {
+	(remaining.min(1), Some(remaining))
+}
+
+/// Returns the length of the run of ASCII characters other than CR at the start of `bytes`.
+/// 
+/// There is a boundary between any two such characters (CR being the only ASCII character
+/// which can start a multi-character EGC), so no lookups are needed inside the run.
+#[inline]
+fn ascii_run_len(bytes: &[u8]) -> usize {
+	const LO: u64 = u64::from_ne_bytes([0x01; 8]);
+	const HI: u64 = u64::from_ne_bytes([0x80; 8]);
+	const CR: u64 = u64::from_ne_bytes([b'\r'; 8]);
+	let mut i = 0;
+	// Check 8 bytes at a time: a byte is flagged by its high bit if it is non-ASCII or CR
+	while let Some(chunk) = bytes.get(i..i + 8) {
+		let word = u64::from_ne_bytes(chunk.try_into().unwrap());
+		let cr = word ^ CR;
+		let flagged = (word | (cr.wrapping_sub(LO) & !cr)) & HI;
+		if flagged != 0 {
+			break;
+		}
+		i += 8;
 	}
+	while i < bytes.len() && bytes[i] < 0x80 && bytes[i] != b'\r' {
+		i += 1;
+	}
+	i
 }
 
 /// A forward iterator over EGCs in a string returned as sub-slices.
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct EgcSlices<'a> {
 	inner: EgcIndices<'a>,
 }
 impl<'a> Iterator for EgcSlices<'a> {
 	type Item = &'a str;
+	#[inline]
 	fn next(&mut self) -> Option<&'a str> {
+		let it = &mut self.inner;
+		let start = it.offset;
+		let end = if start + 1 < it.ascii_end {
+			it.offset = start + 1;
+			it.next = None;
+			it.offset
+		} else {
+			it.step_outlined()?
+		};
+		Some(&it.str[start..end])
+	}
+
+	fn count(self) -> usize {
+		self.inner.count()
+	}
+
+	fn nth(&mut self, n: usize) -> Option<&'a str> {
+		if self.inner.advance(n) > 0 {
+			return None;
+		}
+		self.next()
+	}
+
+	fn last(self) -> Option<&'a str> {
+		let it = self.inner;
+		if it.offset == it.str.len() {
+			return None;
+		}
+		// The current offset is a boundary, so the last EGC starts at or after it
+		Some(&it.str[prev_boundary(it.str, it.str.len())..])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcSlices<'_> {}
+
+/// A forward iterator over the start indices of EGCs in a string, like `char_indices`
+/// (and like the backward iterator [EgcRevIndicesNoAlloc]).
+/// 
+/// Obtained with [egc_start_indices](Egc::egc_start_indices).
+#[derive(Debug, Clone, Copy)]
+pub struct EgcStartIndices<'a> {
+	inner: EgcIndices<'a>,
+}
+impl Iterator for EgcStartIndices<'_> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
 		let start = self.inner.offset;
-		self.inner.next().map(|i| &self.inner.str[start..i])
+		self.inner.next()?;
+		Some(start)
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcStartIndices<'_> {}
+
+/// A forward iterator over the byte ranges of the EGCs in a string.
+/// 
+/// Obtained with [egc_ranges](Egc::egc_ranges).
+#[derive(Debug, Clone, Copy)]
+pub struct EgcRanges<'a> {
+	inner: EgcIndices<'a>,
+}
+impl Iterator for EgcRanges<'_> {
+	type Item = Range<usize>;
+	fn next(&mut self) -> Option<Range<usize>> {
+		let start = self.inner.offset;
+		let end = self.inner.next()?;
+		Some(start..end)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcRanges<'_> {}
+
+/// A forward iterator over the EGCs in a string, returned along with their index
+/// (counted in EGCs) and their byte range.
+/// 
+/// Obtained with [egc_enumerate](Egc::egc_enumerate).
+#[derive(Debug, Clone, Copy)]
+pub struct EgcEnumerate<'a> {
+	inner: EgcIndices<'a>,
+	index: usize,
+}
+impl<'a> Iterator for EgcEnumerate<'a> {
+	type Item = (usize, Range<usize>, &'a str);
+	fn next(&mut self) -> Option<(usize, Range<usize>, &'a str)> {
+		let start = self.inner.offset;
+		let end = self.inner.next()?;
+		let index = self.index;
+		self.index += 1;
+		Some((index, start..end, &self.inner.str[start..end]))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcEnumerate<'_> {}
+
+/// A forward iterator over the end indices of EGCs in a string, counted in UTF-16 code units.
+/// 
+/// Obtained with [egc_indices_utf16](Egc::egc_indices_utf16).
+#[derive(Debug, Clone, Copy)]
+pub struct EgcUtf16Indices<'a> {
+	inner: EgcIndices<'a>,
+	offset: usize,
+}
+impl Iterator for EgcUtf16Indices<'_> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		let start = self.inner.offset;
+		let end = self.inner.next()?;
+		self.offset += utf16_len(&self.inner.str.as_bytes()[start..end]);
+		Some(self.offset)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcUtf16Indices<'_> {}
+
+/// A forward iterator over the end indices of EGCs in a string, counted in `char`s
+/// (unlike `char_indices`, which returns byte offsets).
+/// 
+/// Obtained with [egc_indices_chars](Egc::egc_indices_chars).
+#[derive(Debug, Clone, Copy)]
+pub struct EgcCharIndices<'a> {
+	inner: EgcIndices<'a>,
+	offset: usize,
+}
+impl Iterator for EgcCharIndices<'_> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		let start = self.inner.offset;
+		let end = self.inner.next()?;
+		self.offset += self.inner.str.as_bytes()[start..end].iter().filter(|&&b| b & 0xc0 != 0x80).count();
+		Some(self.offset)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcCharIndices<'_> {}
+
+/// Returns the length of valid UTF-8 text once encoded in UTF-16
+fn utf16_len(bytes: &[u8]) -> usize {
+	// One code unit per character, plus one for characters encoded in 4 bytes
+	bytes.iter().map(|&b| (b & 0xc0 != 0x80) as usize + (b >= 0xf0) as usize).sum()
 }
 
 /// A backward iterator over the start indices of EGCs in a string.
@@ -35,25 +310,40 @@ impl<'a> Iterator for EgcSlices<'a> {
 /// Note that this is not as straightforward as forward iteration:
 /// 
 /// - An initial backwards pass is made, only looking for local EGC boundaries
-/// (ie. those which can be determined without prior context).
+///   (ie. those which can be determined without prior context).
 /// - Then, if we skipped over any possible non-local boundaries that required
-/// more context to determine, a forwards pass is made to identify them.
+///   more context to determine, a forwards pass is made to identify them.
 /// - If any boundaries were skipped, we store them in the iterator for later
-/// retrieval. This avoids backtracking multiple times, at the cost of memory.
+///   retrieval. This avoids backtracking multiple times, at the cost of memory.
+///   The first few are stored inline, so that short runs (eg. a couple of flags)
+///   do not allocate.
 /// 
 /// For example, a very long string full of flag emojis will require
 /// backtracking all the way to the start to determine the flag boundaries,
 /// even for the very last flag. All the flag boundaries will end up being
 /// computed in the first call to [next](Iterator::next), but will not be
 /// recomputed in later calls.
-#[derive(Clone)]
+/// 
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
 pub struct EgcRevIndices<'a> {
 	str: &'a str,
+	// Offset of `str` in the string the forward iterator was created from
+	base: usize,
 	offset: usize,
 	// If we backtracked too much and skipped over some non-local boundaries,
 	// we store them in a stack to output later.
-	stack: Vec<usize>,
+	stack: SmallVec<[usize; 8]>,
+}
+#[cfg(feature = "conformance")]
+impl EgcRevIndices<'_> {
+	/// Returns the number of boundaries currently stored to be returned later.
+	pub(crate) fn buffered(&self) -> usize {
+		self.stack.len()
+	}
 }
+#[cfg(feature = "alloc")]
 impl<'a> Iterator for EgcRevIndices<'a> {
 	type Item = usize;
 	fn next(&mut self) -> Option<usize> {
@@ -63,21 +353,21 @@ impl<'a> Iterator for EgcRevIndices<'a> {
 
 		if let Some(i) = self.stack.pop() {
 			self.offset = i;
-			return Some(i);
+			return Some(self.base + i);
 		}
 
 		let rest = &self.str[..self.offset];
 		let (mut i, maybe_skipped) = last_local_boundary(rest);
 		if !maybe_skipped {
 			self.offset = i;
-			return Some(i);
+			return Some(self.base + i);
 		}
 
-		let mut it = EgcIndices { str: rest, offset: i };
-		while let Some(end) = it.next() {
+		let it = EgcIndices::new(rest, i);
+		for end in it {
 			if end == self.offset {
 				self.offset = i;
-				return Some(i);
+				return Some(self.base + i);
 			} else {
 				self.stack.push(i);
 			}
@@ -85,24 +375,245 @@ impl<'a> Iterator for EgcRevIndices<'a> {
 		}
 		unreachable!()
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		bytes_hint(self.offset)
+	}
 }
+#[cfg(feature = "alloc")]
+impl FusedIterator for EgcRevIndices<'_> {}
 
 /// A backward iterator over EGCs in a string returned as sub-slices.
 /// 
 /// Same caveats as [EgcRevIndices].
-#[derive(Clone)]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
 pub struct EgcRevSlices<'a> {
 	inner: EgcRevIndices<'a>,
 }
+#[cfg(feature = "alloc")]
 impl<'a> Iterator for EgcRevSlices<'a> {
 	type Item = &'a str;
 	fn next(&mut self) -> Option<&'a str> {
 		let end = self.inner.offset;
-		self.inner.next().map(|i| &self.inner.str[i..end])
+		self.inner.next()?;
+		Some(&self.inner.str[self.inner.offset..end])
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
 	}
 }
+#[cfg(feature = "alloc")]
+impl FusedIterator for EgcRevSlices<'_> {}
+
+/// An iterator over EGCs in a string returned as sub-slices, along with whether each
+/// EGC may still be extended by text appended to the string.
+/// 
+/// Obtained with [EgcSlices::streaming].
+#[derive(Debug, Clone)]
+pub struct EgcStreamingSlices<'a> {
+	inner: EgcSlices<'a>,
+}
+impl<'a> Iterator for EgcStreamingSlices<'a> {
+	type Item = (&'a str, bool);
+	fn next(&mut self) -> Option<(&'a str, bool)> {
+		let egc = self.inner.next()?;
+		let indices = &self.inner.inner;
+		let is_last = indices.offset == indices.str.len();
+		// Only a control character other than CR is always followed by a boundary (GB4)
+		let extendable = is_last && egc.chars().next_back().is_some_and(|c| {
+			let p = lookup_egc_props(c);
+			!p.is_control() || p == EgcProps::CR
+		});
+		Some((egc, extendable))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcStreamingSlices<'_> {}
+
+/// An iterator over the EGCs of text produced as a sequence of `char`s (eg. by a decoder),
+/// returned as owned strings.
+/// 
+/// Obtained with [egcs_from_chars]. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CharEgcs<I> {
+	chars: I,
+	seg: crate::logic::Segmenter,
+	// First character of the next EGC, if already pulled from `chars`
+	pending: Option<char>,
+}
+#[cfg(feature = "alloc")]
+impl<I: Iterator<Item = char>> Iterator for CharEgcs<I> {
+	type Item = alloc::string::String;
+	fn next(&mut self) -> Option<alloc::string::String> {
+		let mut egc = alloc::string::String::new();
+		egc.extend(self.pending.take());
+		for c in self.chars.by_ref() {
+			if self.seg.push_char(c).is_some() {
+				self.pending = Some(c);
+				return Some(egc);
+			}
+			egc.push(c);
+		}
+		if egc.is_empty() { None } else { Some(egc) }
+	}
+}
+#[cfg(feature = "alloc")]
+impl<I: FusedIterator<Item = char>> FusedIterator for CharEgcs<I> {}
+
+/// Returns an iterator over the EGCs of the text made of `chars`, returned as owned strings.
+/// 
+/// This allows segmenting text which does not exist as a `&str`, such as the output of
+/// a charset decoder. Each EGC is returned as soon as the character following it is read.
+#[cfg(feature = "alloc")]
+pub fn egcs_from_chars<I: IntoIterator<Item = char>>(chars: I) -> CharEgcs<I::IntoIter> {
+	CharEgcs { chars: chars.into_iter(), seg: crate::logic::Segmenter::new(), pending: None }
+}
+
+/// Number of skipped boundaries remembered by [EgcRevIndicesNoAlloc].
+const REV_CACHE_SIZE: usize = 16;
+
+/// A backward iterator over the start indices of EGCs in a string, which never allocates.
+/// 
+/// This works like [EgcRevIndices], except that only the last few boundaries skipped over
+/// in the backwards pass are remembered, in a fixed-size buffer. Once they have been returned,
+/// the string is scanned again to find the earlier ones.
+/// 
+/// This bounds memory usage, at the cost of time: in the worst case (eg. a very long
+/// string full of flag emojis), iterating over the whole string takes quadratic time.
+#[derive(Debug, Clone)]
+pub struct EgcRevIndicesNoAlloc<'a> {
+	str: &'a str,
+	// Offset of `str` in the string the forward iterator was created from
+	base: usize,
+	offset: usize,
+	// The last boundaries skipped over, the latest one last.
+	cache: [usize; REV_CACHE_SIZE],
+	cache_len: usize,
+}
+impl<'a> Iterator for EgcRevIndicesNoAlloc<'a> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		if self.offset == 0 {
+			return None;
+		}
+
+		if self.cache_len > 0 {
+			self.cache_len -= 1;
+			self.offset = self.cache[self.cache_len];
+			return Some(self.base + self.offset);
+		}
+
+		let rest = &self.str[..self.offset];
+		let (mut i, maybe_skipped) = last_local_boundary(rest);
+		if !maybe_skipped {
+			self.offset = i;
+			return Some(self.base + i);
+		}
+
+		// Remember the boundaries skipped over in a circular buffer,
+		// only keeping the latest ones.
+		let it = EgcIndices::new(rest, i);
+		for (skipped, end) in it.enumerate() {
+			if end == self.offset {
+				self.cache_len = skipped.min(REV_CACHE_SIZE);
+				if skipped > REV_CACHE_SIZE {
+					self.cache.rotate_left(skipped % REV_CACHE_SIZE);
+				}
+				self.offset = i;
+				return Some(self.base + i);
+			}
+			self.cache[skipped % REV_CACHE_SIZE] = i;
+			i = end;
+		}
+		unreachable!()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		bytes_hint(self.offset)
+	}
+}
+impl FusedIterator for EgcRevIndicesNoAlloc<'_> {}
+
+/// How [egcs_with_ignorables](Egc::egcs_with_ignorables) handles EGCs which only
+/// contain default-ignorable characters (eg. a lone zero-width space or bidi control).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IgnorablePolicy {
+	/// Yield them like any other EGC, without flagging them.
+	Yield,
+	/// Yield them, and flag them as ignorable.
+	Flag,
+	/// Merge them into the preceding EGC, or the following one at the start of the string.
+	/// 
+	/// A merged EGC is only flagged as ignorable if the whole string is made of ignorables.
+	Merge,
+}
+
+/// A forward iterator over EGCs in a string returned as sub-slices, along with
+/// whether they only contain default-ignorable characters.
+/// 
+/// See [IgnorablePolicy].
+#[derive(Debug, Clone, Copy)]
+pub struct EgcIgnorableSlices<'a> {
+	inner: EgcIndices<'a>,
+	policy: IgnorablePolicy,
+}
+impl<'a> Iterator for EgcIgnorableSlices<'a> {
+	type Item = (&'a str, bool);
+	fn next(&mut self) -> Option<(&'a str, bool)> {
+		let str = self.inner.str;
+		let start = self.inner.offset;
+		let mut end = self.inner.next()?;
+		let mut ignorable = self.policy != IgnorablePolicy::Yield && is_default_ignorable_str(&str[start..end]);
+		if self.policy == IgnorablePolicy::Merge {
+			if ignorable {
+				// Merge leading ignorables into the following EGC
+				for next in self.inner.by_ref() {
+					ignorable = is_default_ignorable_str(&str[end..next]);
+					end = next;
+					if !ignorable {
+						break;
+					}
+				}
+			}
+			// Merge following ignorables into this EGC
+			let mut peek = self.inner;
+			while let Some(next) = peek.next() {
+				if !is_default_ignorable_str(&str[end..next]) {
+					break;
+				}
+				end = next;
+				self.inner = peek;
+			}
+		}
+		Some((&str[start..end], ignorable))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+impl FusedIterator for EgcIgnorableSlices<'_> {}
 
 impl<'a> EgcIndices<'a> {
+	/// Returns the current offset of the iterator in the string, ie. the end
+	/// of the last EGC returned, or 0 if none was.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the part of the string which has not been segmented yet,
+	/// like [Chars::as_str](core::str::Chars::as_str).
+	pub fn as_str(&self) -> &'a str {
+		&self.str[self.offset..]
+	}
+
 	/// Returns a backwards iterator over the indices.
 	/// 
 	/// Note that this is a different iterator type, and thus
@@ -110,27 +621,80 @@ impl<'a> EgcIndices<'a> {
 	/// 
 	/// Moreover, the backward iterator returns the _start_
 	/// indices of the EGCs, rather than the _end_ indices.
+	/// 
+	/// If some indices were already consumed, the backward iterator only walks
+	/// the rest of the string, from its end down to the current offset. The indices
+	/// it returns are still offsets in the whole string.
+	#[cfg(feature = "alloc")]
 	pub fn rev(self) -> EgcRevIndices<'a> {
 		let rest = &self.str[self.offset..];
 		EgcRevIndices {
 			str: rest,
+			base: self.offset,
+			offset: rest.len(),
+			stack: SmallVec::new(),
+		}
+	}
+
+	/// Returns a backwards iterator over the indices which never allocates.
+	/// 
+	/// Like [rev](EgcIndices::rev), it returns the _start_ indices of the EGCs
+	/// in the rest of the string, as offsets in the whole string.
+	/// See [EgcRevIndicesNoAlloc] for the tradeoffs involved.
+	pub fn rev_no_alloc(self) -> EgcRevIndicesNoAlloc<'a> {
+		let rest = &self.str[self.offset..];
+		EgcRevIndicesNoAlloc {
+			str: rest,
+			base: self.offset,
 			offset: rest.len(),
-			stack: vec![],
+			cache: [0; REV_CACHE_SIZE],
+			cache_len: 0,
 		}
 	}
 }
 
 impl<'a> EgcSlices<'a> {
+	/// Returns the current offset of the iterator in the string, ie. the end
+	/// of the last EGC returned, or 0 if none was.
+	pub fn offset(&self) -> usize {
+		self.inner.offset
+	}
+
+	/// Returns the part of the string which has not been segmented yet,
+	/// like [Chars::as_str](core::str::Chars::as_str).
+	pub fn as_str(&self) -> &'a str {
+		self.inner.as_str()
+	}
+
 	/// Returns a backwards iterator over the EGC slices.
 	/// 
 	/// Note that this is a different iterator type, and thus
 	/// cannot be an implementation of [DoubleEndedIterator].
+	/// 
+	/// If some EGCs were already consumed, only the rest of the string is walked.
+	#[cfg(feature = "alloc")]
 	pub fn rev(self) -> EgcRevSlices<'a> {
 		EgcRevSlices { inner: self.inner.rev() }
 	}
+
+	/// Returns an iterator which also flags the EGC which may be incomplete
+	/// when the string is the text received so far from a stream.
+	/// 
+	/// The last EGC of the string is flagged unless it ends with a control
+	/// character other than CR, since otherwise more text could extend it
+	/// (eg. a lone regional indicator awaiting its pair, or a letter followed
+	/// by a combining mark). This lets typing previews render it differently.
+	pub fn streaming(self) -> EgcStreamingSlices<'a> {
+		EgcStreamingSlices { inner: self }
+	}
 }
 
 /// An extension trait which adds EGC-related methods to [str].
+/// 
+/// With the `alloc` feature, it is also implemented for the owned and shared string types
+/// (`String`, `Cow<str>`, `Box<str>`, `Rc<str>` and `Arc<str>`), so that their methods can be
+/// called without reborrowing as `&str`. Other types implementing `AsRef<str>` can be
+/// segmented with `egcs_of`.
 pub trait Egc {
 	/// Returns an iterator over the indices of the
 	/// extended grapheme clusters (EGCs) in the string.
@@ -140,7 +704,7 @@ pub trait Egc {
 	/// A backwards iterator can be obtained with [rev](EgcIndices::rev),
 	/// but because it is a different iterator type, [EgcIndices] does
 	/// not implement [DoubleEndedIterator].
-	fn egc_indices(&self) -> EgcIndices;
+	fn egc_indices(&self) -> EgcIndices<'_>;
 
 	/// Returns an iterator over the extended grapheme clusters
 	/// (EGC) in the string, returned as sub-slices.
@@ -148,14 +712,338 @@ pub trait Egc {
 	/// A backwards iterator can be obtained with [rev](EgcSlices::rev),
 	/// but because it is a different type, [EgcSlices] does
 	/// not implement [DoubleEndedIterator].
-	fn egcs(&self) -> EgcSlices;
+	fn egcs(&self) -> EgcSlices<'_>;
+
+	/// Returns the whole string, as segmented by [egc_indices](Egc::egc_indices).
+	fn egc_str(&self) -> &str {
+		self.egc_indices().str
+	}
+
+	/// Finds the closest EGC boundary not exceeding `index`.
+	/// 
+	/// If `index` is greater than the length of the string, this returns the length.
+	/// Like [str::floor_char_boundary], `index` does not need to be on a `char` boundary.
+	fn floor_egc_boundary(&self, index: usize) -> usize {
+		floor_egc_boundary(self.egc_str(), index)
+	}
+
+	/// Finds the closest EGC boundary not below `index`.
+	/// 
+	/// If `index` is greater than the length of the string, this returns the length.
+	/// Like [str::ceil_char_boundary], `index` does not need to be on a `char` boundary.
+	fn ceil_egc_boundary(&self, index: usize) -> usize {
+		ceil_egc_boundary(self.egc_str(), index)
+	}
+
+	/// Returns an iterator over the extended grapheme clusters (EGC) in the string,
+	/// returned as sub-slices, handling EGCs made only of default-ignorable
+	/// characters according to `policy`.
+	/// 
+	/// The iterator also returns whether each EGC only contains default-ignorable characters.
+	fn egcs_with_ignorables(&self, policy: IgnorablePolicy) -> EgcIgnorableSlices<'_> {
+		EgcIgnorableSlices { inner: self.egc_indices(), policy }
+	}
+
+	/// Returns the number of EGCs in the string, ie. of user-perceived characters.
+	/// 
+	/// This is faster than counting the EGCs returned by [egcs](Egc::egcs).
+	fn egc_count(&self) -> usize {
+		self.egc_indices().count()
+	}
+
+	/// Returns the EGC of index `n` in the string, or None if there are not enough EGCs.
+	fn egc_get(&self, n: usize) -> Option<&str> {
+		let mut it = self.egc_indices();
+		if it.advance(n) > 0 {
+			return None;
+		}
+		let start = it.offset;
+		let end = it.next()?;
+		Some(&it.str[start..end])
+	}
+
+	/// Returns an iterator over the end indices of the EGCs in the string, like
+	/// [egc_indices](Egc::egc_indices), but starting from the EGC containing `offset`
+	/// rather than from the start of the string.
+	/// 
+	/// `offset` is snapped to the start of its EGC, as with [floor_egc_boundary](Egc::floor_egc_boundary),
+	/// which only looks at the text around it. The indices are still offsets in the whole string.
+	fn egc_indices_from(&self, offset: usize) -> EgcIndices<'_> {
+		EgcIndices::new(self.egc_str(), self.floor_egc_boundary(offset))
+	}
+
+	/// Returns an iterator over the offsets at the _start_ of each EGC in the string,
+	/// unlike [egc_indices](Egc::egc_indices), which returns the offsets at their end.
+	fn egc_start_indices(&self) -> EgcStartIndices<'_> {
+		EgcStartIndices { inner: self.egc_indices() }
+	}
+
+	/// Returns an iterator over the byte ranges of the EGCs in the string.
+	fn egc_ranges(&self) -> EgcRanges<'_> {
+		EgcRanges { inner: self.egc_indices() }
+	}
+
+	/// Returns an iterator over the EGCs in the string, along with their index
+	/// (counted in EGCs) and their byte range.
+	fn egc_enumerate(&self) -> EgcEnumerate<'_> {
+		EgcEnumerate { inner: self.egc_indices(), index: 0 }
+	}
+
+	/// Like [egc_indices](Egc::egc_indices), but the offsets are counted in UTF-16 code units,
+	/// as expected by JavaScript, the DOM or LSP clients.
+	fn egc_indices_utf16(&self) -> EgcUtf16Indices<'_> {
+		EgcUtf16Indices { inner: self.egc_indices(), offset: 0 }
+	}
+
+	/// Like [egc_indices](Egc::egc_indices), but the offsets are counted in `char`s, for APIs
+	/// addressing strings by scalar value index.
+	fn egc_indices_chars(&self) -> EgcCharIndices<'_> {
+		EgcCharIndices { inner: self.egc_indices(), offset: 0 }
+	}
+
+	/// Like [egc_indices](Egc::egc_indices), but the boundaries are determined with the given
+	/// tailoring of the rules (eg. [Legacy](crate::tailoring::Legacy) grapheme clusters).
+	fn egc_indices_tailored<T: BreakTailoring>(&self, tailoring: T) -> TailoredEgcIndices<'_, T> {
+		tailored_egc_indices(self.egc_str(), tailoring)
+	}
+
+	/// Returns the first EGC of the string, or None if it is empty.
+	fn egc_first(&self) -> Option<&str> {
+		let end = self.egc_indices().next()?;
+		Some(&self.egc_str()[..end])
+	}
+
+	/// Returns the last EGC of the string, or None if it is empty.
+	/// 
+	/// This scans backward from the end of the string, up to a local boundary (see
+	/// [last_local_boundary]), then forward from there if some boundaries require context,
+	/// so it usually only looks at the end of the string, and never allocates.
+	fn egc_last(&self) -> Option<&str> {
+		let s = self.egc_str();
+		if s.is_empty() {
+			return None;
+		}
+		Some(&s[prev_boundary(s, s.len())..])
+	}
+
+	/// Returns the slice made of the EGCs of index `range`, or None if the range
+	/// is decreasing or if there are not enough EGCs.
+	fn egc_slice(&self, range: Range<usize>) -> Option<&str> {
+		if range.start > range.end {
+			return None;
+		}
+		let mut it = self.egc_indices();
+		if it.advance(range.start) > 0 {
+			return None;
+		}
+		let start = it.offset;
+		if it.advance(range.end - range.start) > 0 {
+			return None;
+		}
+		Some(&it.str[start..it.offset])
+	}
+
+	/// Splits the string after its first `n` EGCs, like [str::split_at] with
+	/// an index counted in EGCs.
+	/// 
+	/// Panics if the string has fewer than `n` EGCs. See [checked_egc_split_at](Egc::checked_egc_split_at)
+	/// for a non-panicking version.
+	fn egc_split_at(&self, n: usize) -> (&str, &str) {
+		match self.checked_egc_split_at(n) {
+			Some(split) => split,
+			None => panic!("not enough EGCs to split at {}", n),
+		}
+	}
+
+	/// Splits the string after its first `n` EGCs, or returns None if it has fewer than `n` EGCs.
+	fn checked_egc_split_at(&self, n: usize) -> Option<(&str, &str)> {
+		let mut it = self.egc_indices();
+		if it.advance(n) > 0 {
+			return None;
+		}
+		Some(it.str.split_at(it.offset))
+	}
+
+	/// Returns the prefix made of the first `n` EGCs, or the whole string if it has fewer than `n` EGCs.
+	fn egc_take(&self, n: usize) -> &str {
+		let mut it = self.egc_indices();
+		it.advance(n);
+		&it.str[..it.offset]
+	}
+
+	/// Returns the longest prefix of the string which is at most `max_bytes` long
+	/// and does not split an EGC, eg. to fit a field with a byte limit.
+	/// 
+	/// Unlike [egc_take](Egc::egc_take), this only scans the string around `max_bytes`.
+	fn egc_floor_to_bytes(&self, max_bytes: usize) -> &str {
+		&self.egc_str()[..self.floor_egc_boundary(max_bytes)]
+	}
+
+	/// Returns the string without its first `n` EGCs, or an empty string if it has fewer than `n` EGCs.
+	fn egc_skip(&self, n: usize) -> &str {
+		let mut it = self.egc_indices();
+		it.advance(n);
+		&it.str[it.offset..]
+	}
+
+	/// Returns the number of EGCs in the string if it is at most `n`, or None otherwise.
+	/// 
+	/// This stops scanning the string as soon as the limit is exceeded, so that eg.
+	/// validating the length of user input takes time bounded by the length in bytes
+	/// of its first `n + 1` EGCs, rather than of the whole input. Note that a single
+	/// EGC can be arbitrarily long.
+	fn egc_count_at_most(&self, n: usize) -> Option<usize> {
+		let count = self.egc_indices().take(n.saturating_add(1)).count();
+		if count <= n { Some(count) } else { None }
+	}
+
+	/// Returns the offsets at the end of each EGC in the string, like [egc_indices](Egc::egc_indices),
+	/// but computed in a single loop, which is faster than collecting the iterator.
+	/// 
+	/// The offset 0 is not included, so the length of the result is the number of EGCs.
+	#[cfg(feature = "alloc")]
+	fn egc_break_positions(&self) -> alloc::vec::Vec<usize> {
+		let mut positions = alloc::vec::Vec::new();
+		self.egc_break_positions_into(&mut positions);
+		positions
+	}
+
+	/// Like [egc_break_positions](Egc::egc_break_positions), but appends the offsets
+	/// to `positions`, so that its buffer can be reused.
+	#[cfg(feature = "alloc")]
+	fn egc_break_positions_into(&self, positions: &mut alloc::vec::Vec<usize>) {
+		break_positions_into(self.egc_str(), positions);
+	}
+}
+
+#[cfg(feature = "alloc")]
+fn break_positions_into(s: &str, positions: &mut alloc::vec::Vec<usize>) {
+	for_each_boundary(EgcIndices::new(s, 0), |range| positions.extend(range));
+}
+
+/// Calls `f` with the ranges of the remaining boundaries of `it`, in a single loop
+/// which handles whole ASCII runs at once.
+#[inline]
+fn for_each_boundary(it: EgcIndices<'_>, mut f: impl FnMut(Range<usize>)) {
+	let EgcIndices { str, mut offset, mut next, mut lookup, ascii_end } = it;
+	let bytes = str.as_bytes();
+	if offset + 1 < ascii_end {
+		f(offset + 1..ascii_end);
+		offset = ascii_end - 1;
+		next = None;
+	}
+	while offset < bytes.len() {
+		let run = ascii_run_len(&bytes[offset..]);
+		if run > 1 {
+			f(offset + 1..offset + run);
+			offset += run - 1;
+			next = None;
+		}
+		let (len, props) = first_boundary_with(&str[offset..], next, &mut lookup);
+		offset += len;
+		next = props;
+		f(offset..offset + 1);
+	}
 }
 
 impl Egc for str {
-	fn egc_indices(&self) -> EgcIndices {
-		EgcIndices { str: self, offset: 0 }
+	fn egc_indices(&self) -> EgcIndices<'_> {
+		EgcIndices::new(self, 0)
 	}
-	fn egcs(&self) -> EgcSlices {
+	fn egcs(&self) -> EgcSlices<'_> {
 		EgcSlices { inner: self.egc_indices() }
 	}
 }
+
+/// Returns an iterator over the EGCs of a value of any type implementing `AsRef<str>`,
+/// returned as sub-slices, so that generic code can segment custom string types which
+/// do not implement [Egc].
+pub fn egcs_of<S: AsRef<str> + ?Sized>(s: &S) -> EgcSlices<'_> {
+	s.as_ref().egcs()
+}
+
+fn floor_egc_boundary(s: &str, index: usize) -> usize {
+	let mut index = index.min(s.len());
+	while !s.is_char_boundary(index) {
+		index -= 1;
+	}
+	let (start, end) = surrounding_boundaries(s, index);
+	if end == index { end } else { start }
+}
+
+fn ceil_egc_boundary(s: &str, index: usize) -> usize {
+	let mut index = index.min(s.len());
+	while !s.is_char_boundary(index) {
+		index += 1;
+	}
+	surrounding_boundaries(s, index).1
+}
+
+/// Implements [Egc] for owned or shared string types, by forwarding to [str].
+#[cfg(feature = "alloc")]
+macro_rules! impl_egc_via_str {
+	($($ty:ty),*) => {$(
+		impl Egc for $ty {
+			fn egc_indices(&self) -> EgcIndices<'_> {
+				str::egc_indices(self)
+			}
+			fn egcs(&self) -> EgcSlices<'_> {
+				str::egcs(self)
+			}
+		}
+	)*};
+}
+
+#[cfg(feature = "alloc")]
+impl_egc_via_str!(
+	alloc::string::String,
+	alloc::borrow::Cow<'_, str>,
+	alloc::boxed::Box<str>,
+	alloc::rc::Rc<str>,
+	alloc::sync::Arc<str>
+);
+
+/// Returns the EGC boundaries `(start, end)` with `start < index <= end`,
+/// or `(0, 0)` if `index` is 0.
+fn surrounding_boundaries(s: &str, index: usize) -> (usize, usize) {
+	if index == 0 {
+		return (0, 0);
+	}
+	let start = prev_boundary(s, index);
+	(start, start + first_boundary(&s[start..]))
+}
+
+/// Result of [resolve_offset].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedOffset {
+	/// The closest EGC boundary not above the offset
+	pub snapped: usize,
+	/// Whether the offset was not an EGC boundary of the string: either inside an EGC
+	/// (possibly inside a `char`), or past the end of the string
+	pub was_inside_cluster: bool,
+	/// Byte range of the EGC starting at `snapped`, which is empty at the end of the string
+	pub cluster_range: Range<usize>,
+}
+
+/// Resolves a byte offset coming from an untrusted source (eg. another system which
+/// does not segment text the same way) to an EGC boundary of `s`.
+/// 
+/// Unlike slicing, this never panics: offsets inside a `char` or past the end of the
+/// string are accepted. The offset is snapped down to the start of the EGC containing it
+/// (see [floor_egc_boundary](Egc::floor_egc_boundary)), and the range of that EGC is
+/// returned along with whether any snapping occurred.
+pub fn resolve_offset(s: &str, index: usize) -> ResolvedOffset {
+	let mut snapped = index.min(s.len());
+	while !s.is_char_boundary(snapped) {
+		snapped -= 1;
+	}
+	let (start, end) = surrounding_boundaries(s, snapped);
+	if end != snapped {
+		snapped = start;
+	}
+	ResolvedOffset {
+		snapped,
+		was_inside_cluster: snapped != index,
+		cluster_range: snapped..snapped + first_boundary(&s[snapped..]),
+	}
+}
@@ -10,6 +10,21 @@ This crate allows iterating forward and backward through the EGCs of a string.
 Note that iterating backward is less efficient, and can (in principle at least)
 have problematic time complexity.
 
+The crate is `no_std` compatible: forward iteration never allocates. The default `std`
+feature enables the I/O adapters, and the `alloc` feature (implied by `std`) enables
+the helpers which return owned values, and the faster of the two backward iterators.
+
+# Stability
+
+The segmentation API ([lookup], [logic], [iter] and the modules built on them) follows
+semantic versioning. Enums which may need new variants, either for new versions of Unicode
+(eg. [Ctx](logic::Ctx) and [EgcProps](lookup::EgcProps)) or for new options, are
+`#[non_exhaustive]`, and come with functions to query them without matching on every variant.
+
+The modules implementing heuristics and UI helpers (`fuzzy`, `group`, `shaping`, `style`)
+are experimental, and are only available with the `unstable` feature: their API and results
+may change in any release.
+
 Example of use in a simple CLI utility which prints out the codepoints making up
 each grapheme in the entered line of text:
 ```
@@ -33,7 +48,21 @@ fn main() {
 }
 ```
 */
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(
+	all(feature = "unicode-14-0", feature = "unicode-15-1"),
+	all(feature = "unicode-14-0", feature = "unicode-16-0"),
+	all(feature = "unicode-15-1", feature = "unicode-16-0"),
+))]
+compile_error!("the unicode-* features select a version of the tables: enable at most one of them");
 
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/data.rs")]
 pub(crate) mod data;
 /// Lookup of basic EGC-related data.
 pub mod lookup;
@@ -41,29 +70,147 @@ pub mod lookup;
 pub mod logic;
 /// Iterators over EGCs.
 pub mod iter;
+/// Segmentation at a granularity chosen at runtime.
+pub mod granularity;
+/// Segmentation of text split into several fragments, or packed as delimited records.
+pub mod fragments;
+/// Cursors for moving between EGC boundaries.
+pub mod cursor;
+/// Segmentation with tailored character properties or rules.
+pub mod tailoring;
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/aksara_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/aksara_data.rs")]
+pub(crate) mod aksara_data;
+/// Itemization of text into runs of the same script (UAX #24).
+pub mod script;
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/script_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/script_data.rs")]
+pub(crate) mod script_data;
+/// Segmentation of bytes which may not be valid UTF-8.
+pub mod bytes;
+/// Segmentation of UTF-16 text, which may contain unpaired surrogates.
+pub mod utf16;
+/// Compact storage of the EGC boundaries of a string, and conversion between byte offsets and EGC indices.
+#[cfg(feature = "alloc")]
+pub mod breaks;
+/// Editing of owned strings EGC by EGC.
+#[cfg(feature = "alloc")]
+pub mod edit;
+/// Binary search in lists of strings bucketed by their first EGCs.
+pub mod search;
+/// Fuzzy matching of queries against strings, EGC by EGC.
+#[cfg(all(feature = "unstable", feature = "alloc"))]
+pub mod fuzzy;
+/// Section keys for grouping strings in alphabetical lists.
+#[cfg(feature = "unstable")]
+pub mod group;
+/// Identifier-safe EGCs, for validating user names and handles.
+pub mod ident;
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/xid_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/xid_data.rs")]
+pub(crate) mod xid_data;
+/// Heuristics on the rendering requirements of EGCs.
+#[cfg(feature = "unstable")]
+pub mod shaping;
+/// Adapters for segmenting text read from I/O streams.
+#[cfg(feature = "std")]
+pub mod io;
+/// Self-checks of the data tables.
+#[cfg(all(feature = "alloc", any(debug_assertions, feature = "verify")))]
+pub mod verify;
+/// Slow reference implementation of the segmentation rules, and exhaustive checks against it.
+#[cfg(all(feature = "alloc", any(test, feature = "test-support")))]
+pub mod reference;
+/// Parallel segmentation of large strings.
+#[cfg(feature = "rayon")]
+pub mod par;
+/// Conformance report of the crate, for audits.
+#[cfg(feature = "conformance")]
+pub mod conformance;
+/// Input adapter for parsers built with the `nom` crate, consuming text EGC by EGC.
+#[cfg(feature = "nom")]
+pub mod nom_input;
+/// Facades mirroring the APIs of other segmentation crates.
+#[cfg(feature = "compat")]
+pub mod compat;
+/// Adapters for segmenting text read from asynchronous streams.
+#[cfg(feature = "futures")]
+pub mod stream;
+/// Alternative segmentation engine working directly on UTF-8 bytes.
+#[cfg(feature = "dfa")]
+pub mod dfa;
+#[cfg(feature = "dfa")]
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/dfa_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/dfa_data.rs")]
+pub(crate) mod dfa_data;
+#[cfg(feature = "fast-lookup")]
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/lookup_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/lookup_data.rs")]
+pub(crate) mod lookup_data;
+#[cfg(feature = "width")]
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/width_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/width_data.rs")]
+pub(crate) mod width_data;
+/// Display width of EGCs on a monospace grid.
+#[cfg(feature = "width")]
+pub mod width;
+/// Aligned rendering of tabular text.
+#[cfg(feature = "alloc")]
+pub mod table;
+/// Wrapping of text to a given width.
+#[cfg(feature = "width")]
+pub mod wrap;
+/// Splitting of styled text into segments ready to be rendered.
+#[cfg(all(feature = "unstable", feature = "width"))]
+pub mod style;
 
 pub use iter::Egc;
+#[cfg(feature = "alloc")]
+pub use edit::EgcString;
 
-/// Unicode version this library is up-to-date with (major, minor, patch)
-pub const UNICODE_VERSION: (u8,u8,u8) = (15, 1, 0);
+/// Unicode version of the data tables (major, minor, patch): the latest one this library
+/// is up-to-date with, or the one selected by a `unicode-*` feature (eg. `unicode-15-1`).
+/// 
+/// The features select the tables of all the modules: the segmentation rules (and the
+/// [Aksara](tailoring::Aksara) tailoring), the `width` module, [ident] and [script].
+pub const UNICODE_VERSION: (u8,u8,u8) = if cfg!(feature = "unicode-14-0") {
+	(14, 0, 0)
+} else if cfg!(feature = "unicode-15-1") {
+	(15, 1, 0)
+} else {
+	(16, 0, 0)
+};
 
-#[cfg(test)]
+/// Returns the Unicode version of the data tables compiled into the library (major, minor, patch),
+/// ie. [UNICODE_VERSION].
+pub fn unicode_version() -> (u8, u8, u8) {
+	UNICODE_VERSION
+}
+
+#[cfg(any(test, feature = "conformance"))]
+#[cfg_attr(feature = "unicode-14-0", path = "unicode_14_0/test_data.rs")]
+#[cfg_attr(feature = "unicode-15-1", path = "unicode_15_1/test_data.rs")]
 mod test_data;
 
-#[cfg(test)]
-mod tests {
-	pub struct TestCase {
-		line: u32,
-		str: &'static str,
-		breaks: &'static [usize],
-	}
-	impl TestCase {
-		pub const fn new(line: u32, str: &'static str, breaks: &'static [usize]) -> Self {
-			TestCase { line, str, breaks }
-		}
+/// A test case of `GraphemeBreakTest.txt`: its line, string, and inner boundaries.
+#[cfg(any(test, feature = "conformance"))]
+pub(crate) struct TestCase {
+	line: u32,
+	str: &'static str,
+	breaks: &'static [usize],
+}
+#[cfg(any(test, feature = "conformance"))]
+impl TestCase {
+	pub const fn new(line: u32, str: &'static str, breaks: &'static [usize]) -> Self {
+		TestCase { line, str, breaks }
 	}
+}
 
-  use crate::{test_data::TEST_CASES, Egc};
+#[cfg(test)]
+mod tests {
+  use crate::{test_data::TEST_CASES, Egc, TestCase};
+	use crate::logic::{next_boundary, prev_boundary};
+	use crate::iter::{resolve_offset, ResolvedOffset};
 
 	fn check_breaks(breaks: &[usize], case: &TestCase) {
 		if breaks != case.breaks {
@@ -76,7 +223,7 @@ mod tests {
 
 	#[test]
 	fn ucd_tests() {
-		for case in TEST_CASES {
+		for case in &TEST_CASES {
 			print!("Line {}:", case.line);
 			for c in case.str.chars() {
 				print!(" U+{:04x}", c as u32);
@@ -86,15 +233,1665 @@ mod tests {
 			let mut breaks: Vec<usize> = case.str.egc_indices().collect();
 			let last = breaks.pop().expect("expected at least one grapheme");
 			assert_eq!(last, case.str.len(), "last grapheme should end at .len()");
-			check_breaks(&breaks, &case);
+			check_breaks(&breaks, case);
+			assert_eq!(case.str.egc_count(), breaks.len() + 1);
+			assert_eq!(case.str.egc_count_at_most(breaks.len() + 1), Some(breaks.len() + 1));
+			assert_eq!(case.str.egc_count_at_most(breaks.len()), None);
 
-			let mut breaks: Vec<usize> = case.str.egc_indices().rev().collect();
+			let mut breaks: Vec<usize> = case.str.egc_indices().rev_no_alloc().collect();
 			let last = breaks.pop().expect("expected at least one grapheme");
 			assert_eq!(last, 0, "last grapheme in reverse should start at 0");
 			breaks.reverse();
-			check_breaks(&breaks, &case);
+			check_breaks(&breaks, case);
+
+			#[cfg(feature = "alloc")]
+			{
+				let mut breaks: Vec<usize> = case.str.egc_indices().rev().collect();
+				let last = breaks.pop().expect("expected at least one grapheme");
+				assert_eq!(last, 0, "last grapheme in reverse should start at 0");
+				breaks.reverse();
+				check_breaks(&breaks, case);
+
+				let mut breaks = case.str.egc_break_positions();
+				assert_eq!(breaks.pop(), Some(case.str.len()));
+				check_breaks(&breaks, case);
+			}
 			
 			println!();
 		}
 	}
+
+	#[test]
+	fn ucd_tests_chunk_cursor() {
+		use crate::cursor::{ChunkCursor, NeedContext};
+		for case in &TEST_CASES {
+			// Worst case: every char is in its own chunk
+			let mut chunks = vec![];
+			for (i, c) in case.str.char_indices() {
+				chunks.push((i, &case.str[i..i + c.len_utf8()]));
+			}
+			let chunk_at = |pos: usize, before: bool| {
+				*chunks.iter().find(|(i, c)| if before { pos > *i && pos <= i + c.len() } else { pos >= *i && pos < i + c.len() }).unwrap()
+			};
+			let chunk_ending_at = |pos: usize| *chunks.iter().find(|(i, c)| i + c.len() == pos).unwrap();
+			let mut all_breaks = vec![0];
+			all_breaks.extend_from_slice(case.breaks);
+			all_breaks.push(case.str.len());
+
+			for (from, _) in case.str.char_indices() {
+				let mut cursor = ChunkCursor::new(from, case.str.len());
+				let mut breaks = vec![];
+				let (mut start, mut chunk) = chunk_at(from, false);
+				loop {
+					match cursor.next_boundary(chunk, start) {
+						Ok(Some(b)) => {
+							breaks.push(b);
+							if b < case.str.len() {
+								(start, chunk) = chunk_at(b, false);
+							}
+						},
+						Ok(None) => break,
+						Err(NeedContext::NextChunk) => (start, chunk) = chunk_at(start + chunk.len(), false),
+						Err(NeedContext::PreContext(end)) => {
+							let (s, c) = chunk_ending_at(end);
+							cursor.provide_context(c, s);
+						},
+						Err(NeedContext::PrevChunk) => unreachable!(),
+					}
+				}
+				let expected: Vec<usize> = all_breaks.iter().copied().filter(|&b| b > from).collect();
+				assert_eq!(breaks, expected, "forward from {} (line {})", from, case.line);
+
+				let mut cursor = ChunkCursor::new(from, case.str.len());
+				let mut breaks = vec![];
+				if from > 0 {
+					let (mut start, mut chunk) = chunk_at(from, true);
+					loop {
+						match cursor.prev_boundary(chunk, start) {
+							Ok(Some(b)) => {
+								breaks.push(b);
+								if b > 0 {
+									(start, chunk) = chunk_at(b, true);
+								}
+							},
+							Ok(None) => break,
+							Err(NeedContext::PrevChunk) => (start, chunk) = chunk_ending_at(start),
+							Err(NeedContext::PreContext(end)) => {
+								let (s, c) = chunk_ending_at(end);
+								cursor.provide_context(c, s);
+							},
+							Err(NeedContext::NextChunk) => unreachable!(),
+						}
+					}
+				}
+				let expected: Vec<usize> = all_breaks.iter().copied().filter(|&b| b < from).rev().collect();
+				assert_eq!(breaks, expected, "backward from {} (line {})", from, case.line);
+			}
+		}
+	}
+
+	#[test]
+	fn ucd_tests_segmenter() {
+		use crate::logic::Segmenter;
+		let mut seg = Segmenter::new();
+		for case in &TEST_CASES {
+			let mut breaks = vec![];
+			for (i, c) in case.str.char_indices() {
+				// Push in fragments of varying sizes
+				if i % 2 == 0 {
+					breaks.extend(seg.push_char(c));
+				} else {
+					breaks.extend(seg.push_str(&case.str[i..i + c.len_utf8()]));
+				}
+				// Resuming from a saved state should not make a difference
+				seg = Segmenter::resume(seg.state(), seg.offset());
+			}
+			assert_eq!(seg.finish(), Some(case.str.len()));
+			check_breaks(&breaks, case);
+		}
+	}
+
+	/// A reader which returns a single byte at a time.
+	#[cfg(feature = "std")]
+	struct ByteReader<'a>(&'a [u8]);
+	#[cfg(feature = "std")]
+	impl std::io::Read for ByteReader<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+			let Some((&b, rest)) = self.0.split_first() else {
+				return Ok(0);
+			};
+			buf[0] = b;
+			self.0 = rest;
+			Ok(1)
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn ucd_tests_reader() {
+		use crate::io::EgcReader;
+		for case in &TEST_CASES {
+			let egcs: Vec<String> = EgcReader::new(ByteReader(case.str.as_bytes())).map(Result::unwrap).collect();
+			assert_eq!(egcs, case.str.egcs().collect::<Vec<_>>(), "line {}", case.line);
+		}
+		let mut reader = EgcReader::new(ByteReader(b"ab\xff"));
+		assert_eq!(reader.read_egc().unwrap(), Some("a"));
+		assert_eq!(reader.read_egc().unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+	}
+
+	#[cfg(feature = "futures")]
+	#[test]
+	fn ucd_tests_stream() {
+		use std::pin::Pin;
+		use std::task::{Context, Poll, Waker};
+		use futures_core::Stream;
+		use crate::stream::{EgcChunkStream, EgcStream};
+
+		struct Chunks<'a>(std::slice::Chunks<'a, u8>);
+		impl<'a> Stream for Chunks<'a> {
+			type Item = &'a [u8];
+			fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<&'a [u8]>> {
+				Poll::Ready(self.0.next())
+			}
+		}
+		fn collect<S: Stream<Item = std::io::Result<String>> + Unpin>(mut stream: S) -> Vec<String> {
+			let mut cx = Context::from_waker(Waker::noop());
+			let mut egcs = vec![];
+			while let Poll::Ready(Some(egc)) = Pin::new(&mut stream).poll_next(&mut cx) {
+				egcs.push(egc.unwrap());
+			}
+			egcs
+		}
+
+		for case in &TEST_CASES {
+			let expected: Vec<&str> = case.str.egcs().collect();
+			assert_eq!(collect(EgcStream::new(case.str.as_bytes())), expected, "line {}", case.line);
+			assert_eq!(collect(EgcChunkStream::new(Chunks(case.str.as_bytes().chunks(1)))), expected, "line {}", case.line);
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn ucd_tests_writer() {
+		use std::io::Write;
+		use crate::io::EgcWriter;
+
+		/// A writer which records the offsets at which writes end.
+		#[derive(Default)]
+		struct Recorder(Vec<u8>, Vec<usize>);
+		impl Write for Recorder {
+			fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+				self.0.extend_from_slice(buf);
+				self.1.push(self.0.len());
+				Ok(buf.len())
+			}
+			fn flush(&mut self) -> std::io::Result<()> {
+				Ok(())
+			}
+		}
+
+		for case in &TEST_CASES {
+			let mut writer = EgcWriter::new(Recorder::default());
+			for b in case.str.as_bytes() {
+				writer.write_all(&[*b]).unwrap();
+			}
+			let rec = writer.into_inner().unwrap();
+			assert_eq!(rec.0, case.str.as_bytes());
+			let mut expected: Vec<usize> = case.breaks.to_vec();
+			expected.push(case.str.len());
+			assert_eq!(rec.1, expected, "line {}", case.line);
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn writer_errors() {
+		use std::io::{self, Write};
+		use crate::io::EgcWriter;
+
+		/// A writer which accepts at most 2 bytes at a time, and fails every other call.
+		#[derive(Default)]
+		struct Flaky(Vec<u8>, bool);
+		impl Write for Flaky {
+			fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+				self.1 = !self.1;
+				if self.1 {
+					return Err(io::ErrorKind::Other.into());
+				}
+				let len = buf.len().min(2);
+				self.0.extend_from_slice(&buf[..len]);
+				Ok(len)
+			}
+			fn flush(&mut self) -> io::Result<()> {
+				Ok(())
+			}
+		}
+
+		let text = "abc\u{1f1eb}\u{1f1f7}e\u{301}x";
+		let mut writer = EgcWriter::new(Flaky::default());
+		for chunk in text.as_bytes().chunks(3) {
+			let mut chunk = chunk;
+			while !chunk.is_empty() {
+				match writer.write(chunk) {
+					Ok(n) => chunk = &chunk[n..],
+					Err(err) => assert_eq!(err.kind(), io::ErrorKind::Other),
+				}
+			}
+		}
+		while writer.flush_all().is_err() {}
+		assert_eq!(writer.get_ref().0, text.as_bytes());
+
+		// A failed into_inner must not write the held back bytes again when dropping the writer
+		let mut flaky = Flaky::default();
+		let mut writer = EgcWriter::new(&mut flaky);
+		assert!(writer.write(b"ab").is_err());
+		assert_eq!(writer.write(b"ab").unwrap(), 2);
+		assert!(writer.into_inner().is_err());
+		assert_eq!(flaky.0, b"a");
+	}
+
+	#[cfg(all(feature = "alloc", any(debug_assertions, feature = "verify")))]
+	#[test]
+	fn tables_are_consistent() {
+		for report in crate::verify::verify_tables() {
+			assert!(report.is_ok(), "{}", report);
+		}
+	}
+
+	#[cfg(all(feature = "alloc", any(debug_assertions, feature = "verify")))]
+	#[test]
+	fn verify_modified_tables() {
+		use crate::lookup::{CharRange, EgcProps, RANGES};
+		use crate::verify::{verify_egc_ranges, TableIssue};
+		let mut ranges = RANGES.to_vec();
+		let i = ranges.iter().position(|r| r.kind == EgcProps::V).unwrap();
+		ranges[i] = CharRange::new(ranges[i].start, ranges[i].count, EgcProps::T);
+		let removed = ranges.remove(i + 1);
+		let issues = verify_egc_ranges(&ranges).issues;
+		assert!(issues.contains(&TableIssue::FastPathMismatch(i, ranges[i].start)));
+		assert!(issues.contains(&TableIssue::GapMismatch(removed.start)));
+		assert!(issues.iter().any(|issue| matches!(issue, TableIssue::HangulMismatch(_))));
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn parallel() {
+		use crate::par::{par_egc_break_positions, par_egc_count};
+		let mut s: String = TEST_CASES.iter().map(|case| case.str).collect::<Vec<_>>().concat().repeat(100);
+		s.push_str(&"\u{1f1eb}".repeat(50_001));
+		let positions: Vec<usize> = s.egc_indices().collect();
+		assert_eq!(par_egc_count(&s), positions.len());
+		assert_eq!(par_egc_break_positions(&s), positions);
+		assert_eq!(par_egc_count(""), 0);
+	}
+
+	#[cfg(feature = "conformance")]
+	#[test]
+	fn conformance_report() {
+		let report = crate::conformance::conformance_report();
+		for check in &report.checks {
+			assert_eq!(check.passed, check.cases, "{}", check.name);
+		}
+		let (major, minor, patch) = crate::UNICODE_VERSION;
+		assert!(report.to_json().starts_with(&format!("{{\"unicode_version\":\"{}.{}.{}\"", major, minor, patch)));
+	}
+
+	#[cfg(feature = "nom")]
+	#[test]
+	fn nom_parsers() {
+		use crate::nom_input::{egc, egc_if, EgcInput};
+		use nom::bytes::complete::{tag, take_while1};
+		use nom::Parser;
+		type R<'a, T> = nom::IResult<EgcInput<'a>, T>;
+		let input = EgcInput::new("/wave \u{1f44b}\u{1f3fd}e\u{301}\u{1f1eb}\u{1f1f7}!");
+		let (rest, _) = tag::<_, _, nom::error::Error<_>>("/wave ").parse(input).unwrap();
+		let (rest, wave): (_, &str) = egc::<nom::error::Error<_>>(rest).unwrap();
+		assert_eq!(wave, "\u{1f44b}\u{1f3fd}");
+		assert!(tag::<_, _, nom::error::Error<_>>("e").parse(rest).is_err());
+		let not_bang = egc_if::<nom::error::Error<_>>(|e| e != "!");
+		let (rest, accented) = not_bang(rest).unwrap();
+		let (rest, flag) = not_bang(rest).unwrap();
+		assert_eq!((accented, flag), ("e\u{301}", "\u{1f1eb}\u{1f1f7}"));
+		assert!(not_bang(rest).is_err());
+		let r: R<EgcInput> = take_while1(|e: &str| e.len() > 1).parse(EgcInput::new("\u{1f1eb}\u{1f1f7}a"));
+		assert_eq!(r.unwrap().1.as_str(), "\u{1f1eb}\u{1f1f7}");
+		assert_eq!(egc::<nom::error::Error<_>>(rest).unwrap().0.as_str(), "");
+	}
+
+	#[cfg(feature = "dfa")]
+	#[test]
+	fn dfa_lookup() {
+		use crate::lookup::lookup_packed_props;
+		let mut buf = [0; 4];
+		for c in (0..=0x10ffff).filter_map(char::from_u32) {
+			let bytes = c.encode_utf8(&mut buf).as_bytes();
+			assert_eq!(crate::dfa::props_at(bytes, 0), (lookup_packed_props(c), bytes.len()), "U+{:04X}", c as u32);
+		}
+		for case in &TEST_CASES {
+			let mut breaks = vec![];
+			let mut offset = 0;
+			while offset < case.str.len() {
+				offset += crate::dfa::first_boundary(&case.str[offset..]);
+				breaks.push(offset);
+			}
+			breaks.pop();
+			check_breaks(&breaks, case);
+		}
+	}
+
+	#[cfg(feature = "fast-lookup")]
+	#[test]
+	fn fast_lookup() {
+		use crate::lookup::{lookup_packed_props, search_packed_props};
+		for c in (0..=0x10ffff).filter_map(char::from_u32) {
+			assert_eq!(lookup_packed_props(c), search_packed_props(c), "U+{:04X}", c as u32);
+		}
+	}
+
+	#[test]
+	fn props_lookup_memo() {
+		use crate::lookup::{lookup_egc_props, PropsLookup};
+		let mut lookup = PropsLookup::new();
+		let mut x = 1u32;
+		for c in (0..=0x10ffff).filter_map(char::from_u32) {
+			assert_eq!(lookup.lookup(c), lookup_egc_props(c), "U+{:04X}", c as u32);
+			// Interleave lookups of unrelated characters
+			x ^= x << 13;
+			x ^= x >> 17;
+			x ^= x << 5;
+			if let Some(c) = char::from_u32(x % 0x30000) {
+				assert_eq!(lookup.lookup(c), lookup_egc_props(c), "U+{:04X}", c as u32);
+			}
+		}
+	}
+
+	#[test]
+	fn props_lookup_u32() {
+		use crate::lookup::{lookup_egc_props, lookup_egc_props_u32, EgcProps};
+		for cp in [0, 0x61, 0x301, 0x200d, 0x1f1e6, 0xe0001, 0x10ffff] {
+			assert_eq!(lookup_egc_props_u32(cp), Some(lookup_egc_props(char::from_u32(cp).unwrap())));
+		}
+		assert_eq!(lookup_egc_props_u32(0xd800), Some(EgcProps::CN));
+		assert_eq!(lookup_egc_props_u32(0xdfff), Some(EgcProps::CN));
+		assert_eq!(lookup_egc_props_u32(0x110000), None);
+		assert_eq!(lookup_egc_props_u32(u32::MAX), None);
+	}
+
+	#[test]
+	fn decomposed_props() {
+		use crate::lookup::{gcb, incb, is_ext_pict, GraphemeClusterBreak as G, IndicConjunctBreak as I};
+		assert_eq!((gcb('a'), incb('a'), is_ext_pict('a')), (G::Other, I::None, false));
+		assert_eq!((gcb('\r'), gcb('\n'), gcb('\0')), (G::CR, G::LF, G::Control));
+		assert_eq!((gcb('\u{915}'), gcb('\u{94d}'), gcb('\u{301}'), gcb('\u{200d}')), (G::Other, G::Extend, G::Extend, G::ZWJ));
+		// InCB is new in 15.1
+		if !cfg!(feature = "unicode-14-0") {
+			assert_eq!((incb('\u{915}'), incb('\u{94d}')), (I::Consonant, I::Linker));
+			assert_eq!((incb('\u{301}'), incb('\u{200d}')), (I::Extend, I::Extend));
+		}
+		assert_eq!((gcb('\u{1f600}'), is_ext_pict('\u{1f600}')), (G::Other, true));
+		assert_eq!((gcb('\u{1f1e6}'), gcb('\u{ac00}'), gcb('\u{ac01}')), (G::RegionalIndicator, G::LV, G::LVT));
+	}
+
+	#[cfg(not(any(feature = "unicode-14-0", feature = "unicode-15-1")))]
+	#[test]
+	fn unicode_16_changes() {
+		use crate::lookup::{gcb, incb, GraphemeClusterBreak as G, IndicConjunctBreak as I};
+		use crate::tailoring::{tailored_egc_indices, Legacy};
+		let egcs = |s: &str| s.egc_indices().collect::<Vec<_>>();
+		// All the characters with GCB=Extend (eg. CGJ) have InCB=Extend, except the linkers
+		assert_eq!((gcb('\u{34f}'), incb('\u{34f}')), (G::Extend, I::Extend));
+		assert_eq!(egcs("\u{915}\u{94d}\u{34f}\u{937}"), [11]);
+		// The linkers are the same as in 15.1 (eg. not the Tamil virama)
+		assert_eq!((incb('\u{94d}'), incb('\u{bcd}')), (I::Linker, I::Extend));
+		// Some spacing marks became Extend, and are part of legacy clusters
+		assert_eq!((gcb('\u{cc0}'), gcb('\u{1171e}')), (G::Extend, G::SpacingMark));
+		assert_eq!(tailored_egc_indices("\u{c95}\u{cc0}", Legacy).collect::<Vec<_>>(), [6]);
+		// New characters
+		assert_eq!((gcb('\u{897}'), gcb('\u{16d63}'), gcb('\u{113d1}')), (G::Extend, G::V, G::Prepend));
+	}
+
+	#[test]
+	fn unicode_versions() {
+		use crate::lookup::{gcb, incb, GraphemeClusterBreak as G, IndicConjunctBreak as I};
+		let egcs = |s: &str| s.egc_indices().collect::<Vec<_>>();
+		let v14 = cfg!(feature = "unicode-14-0");
+		assert_eq!(crate::unicode_version(), crate::UNICODE_VERSION);
+		// InCB and rule GB9c are new in 15.1
+		assert_eq!(incb('\u{94d}'), if v14 { I::None } else { I::Linker });
+		assert_eq!(egcs("\u{915}\u{94d}\u{937}"), if v14 { vec![6, 9] } else { vec![9] });
+		// Characters new in 15.0
+		assert_eq!((gcb('\u{ece}'), gcb('\u{11f02}')), if v14 { (G::Other, G::Other) } else { (G::Extend, G::Prepend) });
+	}
+
+	#[test]
+	fn packed_props() {
+		use crate::lookup::{lookup_packed_props, EgcProps, GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};
+		for p in EgcProps::ALL {
+			let packed = PackedProps::from(p);
+			assert_eq!((packed.gcb(), packed.incb(), packed.is_ext_pict()), (p.gcb(), p.incb(), p.is_ext_pict()));
+			assert_eq!(packed.to_egc_props(), Some(p));
+			assert_eq!(PackedProps::from_bits(packed.bits()), Some(packed));
+		}
+		let packed = PackedProps::new(G::SpacingMark, I::Linker, true);
+		assert_eq!((packed.gcb(), packed.incb(), packed.is_ext_pict()), (G::SpacingMark, I::Linker, true));
+		assert_eq!(packed.to_egc_props(), None);
+		assert_eq!(packed.egc_props(), EgcProps::SM);
+		assert_eq!(PackedProps::from_bits(0x0e), None);
+		assert_eq!(PackedProps::from_bits(0x80), None);
+		let linker = if cfg!(feature = "unicode-14-0") { I::None } else { I::Linker };
+		assert_eq!(lookup_packed_props('\u{94d}'), PackedProps::new(G::Extend, linker, false));
+	}
+
+	#[test]
+	fn packed_rules() {
+		use crate::logic::{is_boundary, is_boundary_packed, Ctx};
+		use crate::lookup::{EgcProps, GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};
+		for ctx in Ctx::ALL {
+			for p1 in EgcProps::ALL {
+				for p2 in EgcProps::ALL {
+					assert_eq!(is_boundary_packed(ctx, p1.into(), p2.into()), is_boundary(ctx, p1, p2));
+				}
+			}
+		}
+		// Combinations which do not appear in the UCD follow the rules of each property
+		let linker = PackedProps::from(EgcProps::IN_LI);
+		let sm_consonant = PackedProps::new(G::SpacingMark, I::Consonant, false);
+		assert_eq!(Ctx::Indic(false).step_packed(linker).step_packed(sm_consonant), Ctx::Indic(false));
+		let pp_consonant = PackedProps::new(G::Prepend, I::Consonant, false);
+		let consonant = PackedProps::from(EgcProps::IN_CO);
+		assert!(!is_boundary_packed(Ctx::Indic(true), linker, pp_consonant));
+		assert!(!is_boundary_packed(Ctx::Indic(false), pp_consonant, consonant));
+		let ep_ri = PackedProps::new(G::RegionalIndicator, I::None, true);
+		assert_eq!(Ctx::Start.step_packed(ep_ri), Ctx::Emoji(false));
+		assert!(!is_boundary_packed(Ctx::Emoji(true), EgcProps::ZWJ.into(), ep_ri));
+	}
+
+	#[test]
+	fn props_predicates() {
+		use crate::lookup::*;
+		for c in (0..=0x10ffff).filter_map(char::from_u32) {
+			assert_eq!(is_regional_indicator(c), gcb(c) == GraphemeClusterBreak::RegionalIndicator);
+			assert_eq!(is_zwj(c), gcb(c) == GraphemeClusterBreak::ZWJ);
+			assert_eq!(is_incb_consonant(c), incb(c) == IndicConjunctBreak::Consonant);
+			assert_eq!(is_incb_linker(c), incb(c) == IndicConjunctBreak::Linker);
+			assert_eq!(is_incb_extend(c), incb(c) == IndicConjunctBreak::Extend);
+		}
+		assert!(is_regional_indicator('\u{1f1eb}') && is_zwj('\u{200d}'));
+		assert_eq!(is_incb_linker('\u{94d}'), !cfg!(feature = "unicode-14-0"));
+		assert!(!is_regional_indicator('F') && !is_zwj('\u{200c}') && !is_incb_consonant('a'));
+	}
+
+	#[test]
+	fn gcb_ranges_cover_all() {
+		use crate::lookup::{gcb, gcb_ranges, GraphemeClusterBreak as G};
+		let mut end = 0;
+		let mut prev = None;
+		for (range, g) in gcb_ranges() {
+			assert_eq!(range.start, end);
+			assert!(range.start < range.end && prev != Some(g), "{:?}", range);
+			for c in range.clone().filter_map(char::from_u32) {
+				assert_eq!(gcb(c), g, "U+{:04X}", c as u32);
+			}
+			end = range.end;
+			prev = Some(g);
+		}
+		assert_eq!(end, 0x110000);
+		assert!(gcb_ranges().any(|(r, g)| r == (0xd800..0xe000) && g == G::Control));
+		assert!(G::ALL.iter().all(|&g| gcb_ranges().any(|(_, g2)| g2 == g)));
+	}
+
+	#[test]
+	fn ucd_property_names() {
+		use crate::lookup::{GraphemeClusterBreak as G, IndicConjunctBreak as I};
+		for g in G::ALL {
+			assert_eq!(g.to_string().parse(), Ok(g));
+			assert_eq!(g.ucd_short_name().parse(), Ok(g));
+		}
+		for i in I::ALL {
+			assert_eq!(i.to_string().parse(), Ok(i));
+		}
+		assert_eq!(G::RegionalIndicator.to_string(), "Regional_Indicator");
+		assert_eq!("regional indicator".parse(), Ok(G::RegionalIndicator));
+		assert_eq!("Spacing-Mark".parse(), Ok(G::SpacingMark));
+		assert_eq!("LINKER".parse(), Ok(I::Linker));
+		assert!("Regional".parse::<G>().is_err());
+		assert!("IN_LI".parse::<I>().is_err());
+	}
+
+	#[test]
+	fn reverse_lookup() {
+		use crate::lookup::{chars_with, lookup_egc_props, ranges_with, EgcProps};
+		let mut total = 0;
+		for p in EgcProps::ALL {
+			for c in chars_with(p) {
+				assert_eq!(lookup_egc_props(c), p, "U+{:04X}", c as u32);
+				total += 1;
+			}
+		}
+		assert_eq!(total, 0x110000 - 0x800);
+		assert_eq!(chars_with(EgcProps::RI).count(), 26);
+		assert_eq!(chars_with(EgcProps::ZWJ).collect::<Vec<_>>(), ['\u{200d}']);
+		assert!(ranges_with(EgcProps::IN_CO).all(|r| r.kind == EgcProps::IN_CO));
+	}
+
+	#[test]
+	fn props_codes_and_names() {
+		use crate::lookup::EgcProps;
+		for (i, p) in EgcProps::ALL.into_iter().enumerate() {
+			assert_eq!(p.code() as usize, i);
+			assert_eq!(EgcProps::from_code(p.code()), Some(p));
+			assert_eq!(p.to_string().parse(), Ok(p));
+		}
+		assert_eq!((EgcProps::IN_CO.code(), EgcProps::RI.code()), (11, 17));
+		assert_eq!(EgcProps::from_code(18), None);
+		assert!("Extend".parse::<EgcProps>().is_err());
+	}
+
+	#[test]
+	fn custom_props() {
+		use crate::lookup::{CharRange, EgcProps};
+		use crate::tailoring::CustomProps;
+		const PUA: [CharRange; 2] = [
+			CharRange::new(0xe000, 16, EgcProps::EP),
+			CharRange::new(0xf000, 1, EgcProps::EX),
+		];
+		let props = CustomProps::new(&PUA);
+		assert_eq!((props.lookup('\u{e00f}'), props.lookup('\u{e010}')), (EgcProps::EP, EgcProps::XX));
+		assert_eq!(props.lookup('\u{301}'), crate::lookup::lookup_egc_props('\u{301}'));
+		let s = "a\u{f000}\u{e000}\u{200d}\u{e001}\u{fe0f}b";
+		assert_eq!(props.egc_indices(s).collect::<Vec<_>>(), [4, 16, 17]);
+		assert_eq!(s.egc_indices().collect::<Vec<_>>(), [1, 4, 10, 16, 17]);
+		assert_eq!(props.egc_indices(s).rev().collect::<Vec<_>>(), [16, 4, 0]);
+		for case in &TEST_CASES {
+			assert!(CustomProps::new(&[]).egc_indices(case.str).eq(case.str.egc_indices()), "{:?}", case.str);
+		}
+	}
+
+	#[test]
+	fn break_tailoring() {
+		use crate::logic::Ctx;
+		use crate::lookup::PackedProps;
+		use crate::tailoring::{tailored_egc_indices, BreakTailoring, NoTailoring};
+		struct GlueZwsp;
+		impl BreakTailoring for GlueZwsp {
+			fn is_boundary(&self, ctx: Ctx, c1: char, p1: PackedProps, c2: char, p2: PackedProps) -> bool {
+				c1 != '\u{200b}' && c2 != '\u{200b}' && NoTailoring.is_boundary(ctx, c1, p1, c2, p2)
+			}
+		}
+		let s = "a\u{200b}b c";
+		assert_eq!(tailored_egc_indices(s, GlueZwsp).collect::<Vec<_>>(), [5, 6, 7]);
+		assert_eq!(tailored_egc_indices(s, &NoTailoring).collect::<Vec<_>>(), [1, 4, 5, 6, 7]);
+		for case in &TEST_CASES {
+			assert!(tailored_egc_indices(case.str, NoTailoring).eq(case.str.egc_indices()), "{:?}", case.str);
+			check_tailored_rev(case.str, NoTailoring);
+			check_tailored_rev(case.str, GlueZwsp);
+		}
+		check_tailored_rev(s, GlueZwsp);
+		let mut it = tailored_egc_indices(s, GlueZwsp);
+		it.next();
+		assert_eq!(it.rev().collect::<Vec<_>>(), [6, 5]);
+	}
+
+	/// Checks that the backward iterator of a tailoring returns the same boundaries as the forward one.
+	fn check_tailored_rev<T: crate::tailoring::BreakTailoring>(s: &str, tailoring: T) {
+		let mut expected: Vec<usize> = s.egc_indices_tailored(&tailoring).collect();
+		expected.pop();
+		expected.insert(0, 0);
+		expected.reverse();
+		if s.is_empty() {
+			expected.clear();
+		}
+		assert_eq!(s.egc_indices_tailored(tailoring).rev().collect::<Vec<_>>(), expected, "{:?}", s);
+	}
+
+	/// Legacy boundaries of a UCD test case: the extended boundaries, plus the ones removed
+	/// by rules GB9a (before a SpacingMark) and GB9b (after a Prepend character), unless GB9 applies.
+	fn legacy_breaks(case: &TestCase) -> Vec<usize> {
+		use crate::lookup::{lookup_egc_props, GraphemeClusterBreak as G};
+		let mut breaks = case.breaks.to_vec();
+		let mut chars = case.str.char_indices().peekable();
+		while let Some((_, c1)) = chars.next() {
+			let Some(&(i, c2)) = chars.peek() else { break };
+			let (g1, g2) = (lookup_egc_props(c1).gcb(), lookup_egc_props(c2).gcb());
+			if g2 == G::SpacingMark || (g1 == G::Prepend && !matches!(g2, G::Extend | G::ZWJ)) {
+				breaks.push(i);
+			}
+		}
+		breaks.sort();
+		breaks.dedup();
+		breaks.push(case.str.len());
+		breaks
+	}
+
+	#[test]
+	fn legacy_ucd_tests() {
+		use crate::granularity::{segments, Granularity};
+		use crate::logic::Segmenter;
+		use crate::tailoring::{tailored_egc_indices, Legacy};
+		let mut differences = 0;
+		for case in &TEST_CASES {
+			let expected = legacy_breaks(case);
+			differences += expected.len() - case.breaks.len() - 1;
+			assert!(tailored_egc_indices(case.str, Legacy).eq(expected.iter().copied()), "line {}", case.line);
+			let ends = segments(case.str, Granularity::Legacy).scan(0, |end, seg| {
+				*end += seg.len();
+				Some(*end)
+			});
+			assert!(ends.eq(expected.iter().copied()), "line {}", case.line);
+			let mut seg = Segmenter::with_tailoring(Legacy);
+			let mut breaks: Vec<usize> = seg.push_str(case.str).collect();
+			breaks.extend(seg.finish());
+			assert_eq!(breaks, expected, "line {}", case.line);
+			check_tailored_rev(case.str, Legacy);
+		}
+		assert!(differences > 0);
+	}
+
+	#[test]
+	fn aksaras() {
+		use crate::lookup::lookup_packed_props;
+		use crate::tailoring::{tailored_egc_indices, Aksara, BreakTailoring};
+		let aksaras = |s: &str| tailored_egc_indices(s, Aksara).collect::<Vec<_>>();
+		// Conjuncts with viramas which are not InCB=Linker: Gurmukhi, Kannada, Tamil pulli,
+		// and the invisible stackers of Khmer and Myanmar
+		for s in ["\u{a15}\u{a4d}\u{a30}", "\u{c95}\u{ccd}\u{c95}\u{cbf}", "\u{b95}\u{bcd}\u{bb7}", "\u{1780}\u{17d2}\u{1780}", "\u{1000}\u{1039}\u{1000}"] {
+			assert_eq!(aksaras(s), [s.len()], "{:?}", s);
+			assert_eq!(s.egc_indices().count(), 2, "{:?}", s);
+		}
+		// Conjuncts already kept together by GB9c (also before it existed, in Unicode 14.0)
+		assert_eq!(aksaras("\u{915}\u{94d}\u{937}"), [9]);
+		// Marks and ZWJs between the virama and the consonant, but not ZWNJ
+		for s in ["\u{a15}\u{a3c}\u{a4d}\u{a30}", "\u{c95}\u{ccd}\u{200d}\u{c95}", "\u{1780}\u{17d2}\u{200d}\u{1780}", "\u{915}\u{94d}\u{200d}\u{937}"] {
+			assert_eq!(aksaras(s), [s.len()], "{:?}", s);
+		}
+		assert_eq!(aksaras("\u{915}\u{94d}\u{200c}\u{937}"), [9, 12]);
+		// A virama followed by a vowel
+		assert_eq!(aksaras("\u{a15}\u{a4d}\u{a05}"), [6, 9]);
+		assert_eq!(aksaras("\u{b95}\u{bcd}\u{b85}"), [6, 9]);
+		for case in &TEST_CASES {
+			// Strings whose characters keep their properties are segmented by the standard rules
+			if case.str.chars().all(|c| Aksara.props(c) == lookup_packed_props(c)) {
+				assert!(tailored_egc_indices(case.str, Aksara).eq(case.str.egc_indices()), "{:?}", case.str);
+			}
+			check_tailored_rev(case.str, Aksara);
+		}
+	}
+
+	#[test]
+	fn no_gb9c() {
+		use crate::tailoring::{tailored_egc_indices, NoGb9c};
+		let s = "\u{915}\u{94d}\u{937}\u{600}\u{915}a";
+		assert_eq!(tailored_egc_indices(s, NoGb9c).collect::<Vec<_>>(), [6, 9, 14, 15]);
+		// Without InCB, there is no conjunct to keep together in 14.0
+		let expected: &[usize] = if cfg!(feature = "unicode-14-0") { &[6, 9, 14, 15] } else { &[9, 14, 15] };
+		assert_eq!(s.egc_indices().collect::<Vec<_>>(), expected);
+		check_tailored_rev(s, NoGb9c);
+		for case in &TEST_CASES {
+			check_tailored_rev(case.str, NoGb9c);
+		}
+	}
+
+	/// Toy classification of the characters of the script tests
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	enum Script { Latin, Greek, Devanagari, Bengali }
+	fn classify_script(c: char) -> crate::script::ScriptClass<Script> {
+		use crate::script::ScriptClass as C;
+		match c {
+			'a'..='z' => C::Script(Script::Latin),
+			'\u{3b1}'..='\u{3c9}' => C::Script(Script::Greek),
+			'\u{915}'..='\u{939}' | '\u{93f}' => C::Script(Script::Devanagari),
+			'\u{995}'..='\u{9b9}' => C::Script(Script::Bengali),
+			'\u{964}' => C::Extensions(&[Script::Bengali, Script::Devanagari]),
+			'\u{b7}' => C::Extensions(&[Script::Latin, Script::Greek]),
+			'\u{300}'..='\u{36f}' => C::Inherited,
+			_ => C::Common,
+		}
+	}
+
+	#[test]
+	fn script_runs() {
+		use crate::script::script_runs_with as script_runs;
+		let s = "1 ab\u{301}, \u{3b1}\u{3b2}! \u{915}\u{93f}\u{964}\u{995}\u{964}a";
+		let runs: Vec<_> = script_runs(s, classify_script).map(|(r, script)| (&s[r], script)).collect();
+		assert_eq!(runs, [
+			("1 ab\u{301}, ", Some(Script::Latin)),
+			("\u{3b1}\u{3b2}! ", Some(Script::Greek)),
+			("\u{915}\u{93f}\u{964}", Some(Script::Devanagari)),
+			("\u{995}\u{964}", Some(Script::Bengali)),
+			("a", Some(Script::Latin)),
+		]);
+		assert_eq!(script_runs("12 !", classify_script).collect::<Vec<_>>(), [(0..4, None)]);
+		let s = "\u{964} a \u{964}\u{995}\u{964}\u{b7}\u{3b1}\u{b7}\u{3b2}";
+		let runs: Vec<_> = script_runs(s, classify_script).map(|(r, script)| (&s[r], script)).collect();
+		assert_eq!(runs, [
+			("\u{964} ", Some(Script::Bengali)),
+			("a ", Some(Script::Latin)),
+			("\u{964}\u{995}\u{964}", Some(Script::Bengali)),
+			("\u{b7}\u{3b1}\u{b7}\u{3b2}", Some(Script::Greek)),
+		]);
+		assert_eq!(script_runs("", classify_script).next(), None);
+	}
+
+	#[test]
+	fn script_tables() {
+		use crate::script::{lookup_script, script_runs, Script, ScriptClass as C, SCRIPT_EXTENSIONS, SCRIPT_RANGES};
+		for pair in SCRIPT_RANGES.windows(2) {
+			assert!(pair[0].start + pair[0].count as u32 <= pair[1].start);
+		}
+		for pair in SCRIPT_EXTENSIONS.windows(2) {
+			assert!(pair[0].start + pair[0].count as u32 <= pair[1].start);
+		}
+		assert_eq!((lookup_script('a'), lookup_script('1'), lookup_script('\u{fe00}')), (C::Script(Script::Latin), C::Common, C::Inherited));
+		assert_eq!(lookup_script('\u{3b1}'), C::Script(Script::Greek));
+		// (a script new in Unicode 16.0)
+		#[cfg(not(any(feature = "unicode-14-0", feature = "unicode-15-1")))]
+		assert_eq!(lookup_script('\u{11380}'), C::Script(Script::TuluTigalari));
+		assert_eq!(lookup_script('\u{342}'), C::Extensions(&[Script::Greek]));
+		assert_eq!(lookup_script('\u{660}'), C::Extensions(&[Script::Arabic, Script::Thaana, Script::Yezidi]));
+		let C::Extensions(danda) = lookup_script('\u{964}') else { panic!() };
+		assert!(danda.contains(&Script::Devanagari) && danda.contains(&Script::Bengali));
+		// Unassigned and private use characters
+		assert_eq!((lookup_script('\u{378}'), lookup_script('\u{e000}')), (C::Common, C::Common));
+		for c in (0..=0x7f).filter_map(char::from_u32) {
+			let script = SCRIPT_RANGES.iter().find(|r| r.start <= c as u32 && c as u32 - r.start < r.count as u32).unwrap().kind;
+			assert_eq!(lookup_script(c), if script == Script::Latin { C::Script(script) } else { C::Common });
+		}
+		assert_eq!((Script::OldItalic.name(), Script::OldItalic.short_name()), ("Old_Italic", "Ital"));
+		assert_eq!((Script::Common.name(), Script::Unknown.short_name()), ("Common", "Zzzz"));
+
+		let s = "Cafe\u{301}, \u{3ba}\u{3cc}\u{3c3}\u{3bc}\u{3b5}! \u{928}\u{92e}\u{938}\u{94d}\u{924}\u{947}\u{964} \u{4e16}\u{754c}";
+		let runs: Vec<_> = script_runs(s).map(|(r, script)| (&s[r], script)).collect();
+		assert_eq!(runs, [
+			("Cafe\u{301}, ", Some(Script::Latin)),
+			("\u{3ba}\u{3cc}\u{3c3}\u{3bc}\u{3b5}! ", Some(Script::Greek)),
+			("\u{928}\u{92e}\u{938}\u{94d}\u{924}\u{947}\u{964} ", Some(Script::Devanagari)),
+			("\u{4e16}\u{754c}", Some(Script::Han)),
+		]);
+		assert_eq!(script_runs("\u{660}\u{661}").collect::<Vec<_>>(), [(0..4, Some(Script::Arabic))]);
+	}
+
+	#[test]
+	fn egc_scripts() {
+		use crate::script::{egc_scripts_with as egc_scripts, ScriptClass as C};
+		let s = "\u{301}a\u{301}\u{3b1}\u{300}\u{915}\u{93f}1\u{964}";
+		assert_eq!(egc_scripts(s, classify_script).collect::<Vec<_>>(), [
+			("\u{301}", C::Inherited),
+			("a\u{301}", C::Script(Script::Latin)),
+			("\u{3b1}\u{300}", C::Script(Script::Greek)),
+			("\u{915}\u{93f}", C::Script(Script::Devanagari)),
+			("1", C::Common),
+			("\u{964}", C::Extensions(&[Script::Bengali, Script::Devanagari])),
+		]);
+		use crate::script::Script as S;
+		let s = "\u{fe00}e\u{301}\u{3b1}\u{300}\u{928}\u{93f}\u{4e16}1\u{20e3}\u{660}";
+		assert_eq!(crate::script::egc_scripts(s).collect::<Vec<_>>(), [
+			("\u{fe00}", C::Inherited),
+			("e\u{301}", C::Script(S::Latin)),
+			("\u{3b1}\u{300}", C::Script(S::Greek)),
+			("\u{928}\u{93f}", C::Script(S::Devanagari)),
+			("\u{4e16}", C::Script(S::Han)),
+			("1\u{20e3}", C::Common),
+			("\u{660}", C::Extensions(&[S::Arabic, S::Thaana, S::Yezidi])),
+		]);
+	}
+
+	#[test]
+	fn granularities() {
+		use crate::granularity::{segments, segments_tailored, Granularity};
+		use crate::tailoring::{Legacy, NoGb9c, NoTailoring};
+		let s = "ae\u{301}\u{1f1eb}\u{1f1f7}";
+		assert_eq!(segments(s, Granularity::Codepoint).collect::<Vec<_>>(), ["a", "e", "\u{301}", "\u{1f1eb}", "\u{1f1f7}"]);
+		assert_eq!(segments(s, Granularity::default()).collect::<Vec<_>>(), ["a", "e\u{301}", "\u{1f1eb}\u{1f1f7}"]);
+		assert_eq!(segments("", Granularity::Codepoint).next(), None);
+		let s = "\u{600}1\u{915}\u{93f}\u{600}\u{301}";
+		assert_eq!(segments(s, Granularity::Legacy).collect::<Vec<_>>(), ["\u{600}", "1", "\u{915}", "\u{93f}", "\u{600}\u{301}"]);
+		assert_eq!(segments(s, Granularity::Extended).collect::<Vec<_>>(), ["\u{600}1", "\u{915}\u{93f}", "\u{600}\u{301}"]);
+		assert!(segments_tailored(s, Legacy).eq(segments(s, Granularity::Legacy)));
+		let s = "\u{915}\u{94d}\u{937}";
+		assert_eq!(segments_tailored(s, NoGb9c).collect::<Vec<_>>(), ["\u{915}\u{94d}", "\u{937}"]);
+		assert!(segments_tailored(s, NoTailoring).eq(segments(s, Granularity::Extended)));
+	}
+
+	#[cfg(feature = "compat")]
+	#[test]
+	fn icu_segmenter_facade() {
+		use crate::compat::icu_segmenter::GraphemeClusterSegmenter;
+		let segmenter = GraphemeClusterSegmenter::new();
+		assert_eq!(segmenter.segment_str("Hello \u{1f5fa}").collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 10]);
+		assert_eq!(segmenter.segment_str("e\u{301}\u{1f1eb}\u{1f1f7}").collect::<Vec<_>>(), [0, 3, 11]);
+		assert_eq!(segmenter.segment_str("").collect::<Vec<_>>(), [0]);
+	}
+
+	#[cfg(feature = "compat")]
+	#[test]
+	fn unicode_segmentation_facade() {
+		use crate::compat::unicode_segmentation::UnicodeSegmentation;
+		for case in &TEST_CASES {
+			let mut expected = vec![0];
+			expected.extend(case.str.egc_indices());
+			expected.pop();
+			let starts: Vec<usize> = case.str.grapheme_indices(true).map(|(i, _)| i).collect();
+			assert_eq!(starts, expected);
+			let mut rev: Vec<usize> = case.str.grapheme_indices(true).rev().map(|(i, _)| i).collect();
+			rev.reverse();
+			assert_eq!(rev, expected);
+			let mut expected = vec![0];
+			expected.extend(legacy_breaks(case));
+			expected.pop();
+			let starts: Vec<usize> = case.str.grapheme_indices(false).map(|(i, _)| i).collect();
+			assert_eq!(starts, expected, "line {}", case.line);
+		}
+		let s = "\u{915}\u{93f}\u{600}1\u{600}\u{301}";
+		assert_eq!(s.graphemes(true).collect::<Vec<_>>(), ["\u{915}\u{93f}", "\u{600}1", "\u{600}\u{301}"]);
+		assert_eq!(s.graphemes(false).collect::<Vec<_>>(), ["\u{915}", "\u{93f}", "\u{600}", "1", "\u{600}\u{301}"]);
+		assert_eq!(s.graphemes(false).rev().collect::<Vec<_>>(), ["\u{600}\u{301}", "1", "\u{600}", "\u{93f}", "\u{915}"]);
+		let mut it = s.grapheme_indices(false);
+		assert_eq!((it.next(), it.next_back(), it.next_back()), (Some((0, "\u{915}")), Some((9, "\u{600}\u{301}")), Some((8, "1"))));
+		assert_eq!(it.as_str(), "\u{93f}\u{600}");
+		assert_eq!("".graphemes(true).next(), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn reference_rules() {
+		use crate::reference::{check_rules, check_strings, reference_is_boundary};
+		let mismatches = check_rules(4, reference_is_boundary);
+		assert!(mismatches.is_empty(), "{}", mismatches[0]);
+		let mismatches = check_strings(3, reference_is_boundary);
+		assert!(mismatches.is_empty(), "{}", mismatches[0]);
+		// A tailored reference is reported where it differs
+		let mismatches = check_rules(2, |props, i| props[i] != crate::lookup::EgcProps::SM && reference_is_boundary(props, i));
+		assert_eq!(mismatches.len(), 3);
+	}
+
+	#[test]
+	fn bidirectional_cursor() {
+		use crate::cursor::EgcCursor;
+		let flags = "\u{1f1eb}\u{1f1f7}".repeat(20);
+		for s in [format!("ab{}e\u{301}", flags), "\u{915}\u{94d}\u{937}x\r\n".to_string()] {
+			let mut boundaries = vec![0];
+			boundaries.extend(s.egc_indices());
+			// Pseudo-random walk, alternating directions
+			let mut cursor = EgcCursor::new(&s, s.len());
+			let mut pos = boundaries.len() - 1;
+			let mut x = 7u32;
+			for _ in 0..500 {
+				x = x.wrapping_mul(1103515245).wrapping_add(12345);
+				if x & 0x10000 == 0 {
+					let expected = boundaries.get(pos + 1).copied();
+					assert_eq!(cursor.next_boundary(), expected);
+					pos = (pos + 1).min(boundaries.len() - 1);
+				} else {
+					let expected = pos.checked_sub(1).map(|p| boundaries[p]);
+					assert_eq!(cursor.prev_boundary(), expected);
+					pos = pos.saturating_sub(1);
+				}
+				assert_eq!(cursor.offset(), boundaries[pos]);
+			}
+		}
+		let mut cursor = EgcCursor::new("e\u{301}x", 2);
+		assert_eq!(cursor.offset(), 0);
+		cursor.set_offset(10);
+		assert_eq!((cursor.offset(), cursor.next_boundary(), cursor.prev_boundary()), (4, None, Some(3)));
+	}
+
+	#[test]
+	fn iterator_traits() {
+		fn assert_fused<I: core::iter::FusedIterator + core::fmt::Debug>(_: &I) {}
+		let s = "ae\u{301}\u{1f1eb}\u{1f1f7}";
+		let mut it = s.egc_indices();
+		assert_fused(&it);
+		assert_fused(&s.egcs());
+		assert_eq!(it.size_hint(), (1, Some(s.len())));
+		it.nth(2);
+		assert_eq!((it.size_hint(), it.next(), it.next()), ((0, Some(0)), None, None));
+		assert_eq!("".egcs().size_hint(), (0, Some(0)));
+		let mut egcs = s.egcs();
+		assert_eq!((egcs.offset(), egcs.as_str()), (0, s));
+		egcs.next();
+		assert_eq!((egcs.offset(), egcs.as_str()), (1, &s[1..]));
+		let mut it = s.egc_indices();
+		it.nth(1);
+		assert_eq!((it.offset(), it.as_str()), (4, "\u{1f1eb}\u{1f1f7}"));
+		#[cfg(feature = "alloc")]
+		{
+			let mut rev = s.egcs().rev();
+			assert_fused(&rev);
+			assert_fused(&s.egc_indices().rev());
+			rev.next();
+			assert_eq!(rev.size_hint(), (1, Some(4)));
+		}
+	}
+
+	#[test]
+	fn ctx_codes() {
+		use crate::logic::Ctx;
+		for (i, ctx) in Ctx::ALL.into_iter().enumerate() {
+			assert_eq!(ctx.code() as usize, i);
+			assert_eq!(Ctx::from_code(ctx.code()), Some(ctx));
+			assert_eq!(ctx.is_start(), i == 0);
+		}
+		assert_eq!((Ctx::default(), Ctx::from_code(6)), (Ctx::Start, None));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn egc_string_types() {
+		use std::borrow::Cow;
+		use std::rc::Rc;
+		use std::sync::Arc;
+		fn count<S: Egc + ?Sized>(s: &S) -> usize {
+			s.egcs().count()
+		}
+		let s = "e\u{301}\u{1f1eb}\u{1f1f7}";
+		assert_eq!(count(s), 2);
+		assert_eq!(count(&String::from(s)), 2);
+		assert_eq!(count(&Cow::Borrowed(s)), 2);
+		assert_eq!(count(&Box::<str>::from(s)), 2);
+		assert_eq!(count(&Rc::<str>::from(s)), 2);
+		assert_eq!(count(&Arc::<str>::from(s)), 2);
+		assert_eq!(Arc::<str>::from(s).ceil_egc_boundary(1), 3);
+	}
+
+	#[test]
+	fn egc_as_ref() {
+		struct Name(String);
+		impl AsRef<str> for Name {
+			fn as_ref(&self) -> &str {
+				&self.0
+			}
+		}
+		fn firsts<S: AsRef<str>>(list: &[S]) -> Vec<&str> {
+			list.iter().filter_map(|s| crate::iter::egcs_of(s).next()).collect()
+		}
+		let names = [Name("e\u{301}mile".into()), Name("\u{1f1eb}\u{1f1f7}".into()), Name(String::new())];
+		assert_eq!(firsts(&names), ["e\u{301}", "\u{1f1eb}\u{1f1f7}"]);
+		assert_eq!(firsts(&[&names[0]]), ["e\u{301}"]);
+		assert_eq!(firsts(&["a\u{301}b", "c"]), ["a\u{301}", "c"]);
+	}
+
+	#[test]
+	fn ignorable_policies() {
+		use crate::iter::IgnorablePolicy;
+		let s = "\u{200b}a\u{200e}\u{2060}b\u{feff}";
+		let egcs = |policy| s.egcs_with_ignorables(policy).collect::<Vec<_>>();
+		assert_eq!(egcs(IgnorablePolicy::Yield), [
+			("\u{200b}", false), ("a", false), ("\u{200e}", false), ("\u{2060}", false), ("b", false), ("\u{feff}", false),
+		]);
+		assert_eq!(egcs(IgnorablePolicy::Flag), [
+			("\u{200b}", true), ("a", false), ("\u{200e}", true), ("\u{2060}", true), ("b", false), ("\u{feff}", true),
+		]);
+		assert_eq!(egcs(IgnorablePolicy::Merge), [("\u{200b}a\u{200e}\u{2060}", false), ("b\u{feff}", false)]);
+		assert_eq!("\u{200b}\u{200b}".egcs_with_ignorables(IgnorablePolicy::Merge).collect::<Vec<_>>(), [("\u{200b}\u{200b}", true)]);
+	}
+
+	#[test]
+	fn ucd_tests_fragments() {
+		use crate::fragments::FragmentEgcs;
+		for case in &TEST_CASES {
+			// Every char in its own fragment, with empty fragments in between
+			let mut fragments = vec![""];
+			for (i, c) in case.str.char_indices() {
+				fragments.push(&case.str[i..i + c.len_utf8()]);
+				fragments.push("");
+			}
+			let it = FragmentEgcs::new(&fragments);
+			let egcs: Vec<String> = it.clone().map(|r| it.pieces(r).collect()).collect();
+			assert_eq!(egcs, case.str.egcs().collect::<Vec<_>>(), "line {}", case.line);
+		}
+	}
+
+	#[test]
+	fn ucd_tests_records() {
+		use crate::fragments::RecordEgcs;
+		let buffer = TEST_CASES.iter().map(|case| case.str).collect::<Vec<_>>().join("\u{1e}");
+		let mut record_start = 0;
+		let mut it = RecordEgcs::new(&buffer, '\u{1e}');
+		for (i, case) in TEST_CASES.iter().enumerate() {
+			let mut breaks = vec![];
+			for _ in 0..=case.breaks.len() {
+				let (record, range) = it.next().unwrap();
+				assert_eq!(record, i, "line {}", case.line);
+				assert!(range.end <= record_start + case.str.len(), "line {}", case.line);
+				breaks.push(range.end - record_start);
+			}
+			breaks.pop();
+			check_breaks(&breaks, case);
+			record_start += case.str.len() + 1;
+		}
+		assert_eq!(it.next(), None);
+		let delims = |s| RecordEgcs::new(s, '\u{301}').collect::<Vec<_>>();
+		assert_eq!(delims("a\u{301}\u{301}b\u{301}"), [(0, 0..1), (2, 5..6)]);
+	}
+
+	#[cfg(feature = "unstable")]
+	#[test]
+	fn shaping_heuristic() {
+		use crate::shaping::{egc_shaping, Shaping};
+		assert_eq!(egc_shaping("a"), Shaping::Single);
+		assert_eq!(egc_shaping("\r\n"), Shaping::Single);
+		assert_eq!(egc_shaping("\u{2764}\u{fe0f}"), Shaping::Single);
+		assert_eq!(egc_shaping("e\u{301}\u{323}"), Shaping::Stacked);
+		assert_eq!(egc_shaping("\u{1f1eb}\u{1f1f7}"), Shaping::Complex);
+		assert_eq!(egc_shaping("\u{1f44b}\u{1f3fd}"), Shaping::Complex);
+		assert_eq!(egc_shaping("\u{1f469}\u{200d}\u{1f4bb}"), Shaping::Complex);
+		// A single EGC since GB9c (Unicode 15.1)
+		if !cfg!(feature = "unicode-14-0") {
+			assert_eq!(egc_shaping("\u{915}\u{94d}\u{937}"), Shaping::Complex);
+		}
+		assert_eq!(egc_shaping("\u{915}\u{93f}"), Shaping::Complex);
+		assert_eq!(egc_shaping("\u{1100}\u{1161}"), Shaping::Complex);
+	}
+
+	#[cfg(feature = "width")]
+	#[test]
+	fn egc_widths() {
+		use crate::width::{egc_width, is_zero_width_cluster};
+		for (egc, width, zero) in [
+			("a", 1, false),
+			("\u{65e5}", 2, false),
+			("e\u{301}", 1, false),
+			("\u{301}", 0, true),
+			("\u{200d}", 0, true),
+			("\u{fe0f}", 0, true),
+			("\u{2060}", 0, true),
+			("\r\n", 0, false),
+			("\u{1f1eb}\u{1f1f7}", 2, false),
+			("\u{2764}\u{fe0f}", 2, false),
+			("\u{1f469}\u{200d}\u{1f4bb}", 2, false),
+		] {
+			assert_eq!(egc_width(egc), width, "width of {:?}", egc);
+			assert_eq!(is_zero_width_cluster(egc), zero, "is {:?} zero-width", egc);
+		}
+	}
+
+	#[cfg(feature = "width")]
+	#[test]
+	fn egcs_with_widths() {
+		use crate::width::{egcs_with_width, str_width};
+		let s = "a\u{65e5}e\u{301}\u{1f469}\u{200d}\u{1f4bb}\u{1f1eb}\u{1f1f7}\t";
+		assert_eq!(egcs_with_width(s).collect::<Vec<_>>(), [
+			("a", 1), ("\u{65e5}", 2), ("e\u{301}", 1), ("\u{1f469}\u{200d}\u{1f4bb}", 2), ("\u{1f1eb}\u{1f1f7}", 2), ("\t", 0),
+		]);
+		assert_eq!(egcs_with_width(s).map(|(_, w)| w).sum::<usize>(), str_width(s));
+	}
+
+	#[cfg(feature = "width")]
+	#[test]
+	fn visual_columns() {
+		use crate::width::visual_cols;
+		let s = "a\t\u{65e5}\u{301}\u{200d}\tx\r\n\u{1f1eb}\u{1f1f7}\t";
+		let cols: Vec<_> = visual_cols(s, 4).map(|(r, col, width)| (&s[r], col, width)).collect();
+		assert_eq!(cols, [
+			("a", 0, 1), ("\t", 1, 3), ("\u{65e5}\u{301}\u{200d}", 4, 2), ("\t", 6, 2), ("x", 8, 1),
+			("\r\n", 9, 0), ("\u{1f1eb}\u{1f1f7}", 0, 2), ("\t", 2, 2),
+		]);
+		assert_eq!(visual_cols("\u{301}\tb", 0).map(|(_, col, width)| (col, width)).collect::<Vec<_>>(), [(0, 0), (0, 0), (0, 1)]);
+	}
+
+	#[cfg(feature = "width")]
+	#[test]
+	fn column_slices() {
+		use crate::width::{slice_by_columns, ColumnSnap};
+		let snap = |left_partial, right_partial| ColumnSnap { left_partial, right_partial };
+		let s = "a\u{65e5}\u{301}b\u{1f1eb}\u{1f1f7}\u{200b}c";
+		assert_eq!(slice_by_columns(s, 0..3), ("a\u{65e5}\u{301}", snap(0, 0)));
+		assert_eq!(slice_by_columns(s, 2..5), ("b", snap(1, 1)));
+		assert_eq!(slice_by_columns(s, 2..3), ("", snap(1, 0)));
+		assert_eq!(slice_by_columns(s, 1..2), ("", snap(0, 1)));
+		assert_eq!(slice_by_columns(s, 4..6), ("\u{1f1eb}\u{1f1f7}\u{200b}", snap(0, 0)));
+		assert_eq!(slice_by_columns(s, 6..10), ("c", snap(0, 0)));
+		assert_eq!(slice_by_columns(s, 8..10), ("", snap(0, 0)));
+		assert_eq!(slice_by_columns(s, 3..3), ("", snap(0, 0)));
+		#[allow(clippy::reversed_empty_ranges)]
+		let reversed = 2..1;
+		assert_eq!(slice_by_columns(s, reversed), ("", snap(0, 0)));
+	}
+
+	#[cfg(feature = "width")]
+	#[test]
+	fn hscroll() {
+		use crate::width::hscroll_window;
+		let s = "a\u{65e5}\u{301}b\u{1f1eb}\u{1f1f7}c";
+		for (first_col, width, rendered, left, right) in [
+			(0, 10, "a\u{65e5}\u{301}b\u{1f1eb}\u{1f1f7}c", false, false),
+			(0, 2, "a>", false, true),
+			(2, 3, ">b>", true, true),
+			(3, 4, "b\u{1f1eb}\u{1f1f7}c", true, false),
+			(7, 3, "", true, false),
+		] {
+			let window = hscroll_window(s, first_col, width);
+			let mut out = String::new();
+			window.write_to(&mut out, '>').unwrap();
+			assert_eq!((out.as_str(), window.truncated_left, window.truncated_right), (rendered, left, right), "{}..+{}", first_col, width);
+		}
+	}
+
+	#[cfg(all(feature = "unstable", feature = "width"))]
+	#[test]
+	fn style_runs() {
+		use crate::style::split_style_runs;
+		// Runs which split an EGC, with a gap, and overflowing the column budget
+		let s = "ab\u{301}c\u{65e5}d ef";
+		let runs = [(0..2, 'x'), (2..5, 'y'), (5..9, 'z'), (10..12, 'w')];
+		let mut segments = Vec::new();
+		split_style_runs(s, &runs, 8, |seg| segments.push((seg.text, *seg.style, seg.start_col, seg.width)));
+		assert_eq!(segments, [("ab\u{301}", 'x', 0, 2), ("c", 'y', 2, 1), ("\u{65e5}d", 'z', 3, 3), ("e", 'w', 7, 1)]);
+	}
+
+	#[cfg(all(feature = "width", feature = "alloc"))]
+	#[test]
+	fn wrapping() {
+		use crate::wrap::{wrap, wrap_with_options, BreakPolicy, WrapOptions};
+		let s = "The quick brown fox  jumps over the lazy \u{1f415}\u{200d}\u{1f9ba}.\nunbreakable";
+		let lines = |ranges: Vec<std::ops::Range<usize>>| ranges.into_iter().map(|r| &s[r]).collect::<Vec<_>>();
+		assert_eq!(lines(wrap(s, 10)), [
+			"The quick", "brown fox", "jumps over", "the lazy", "\u{1f415}\u{200d}\u{1f9ba}.", "unbreakabl", "e",
+		]);
+		// Leading spaces are kept on the first line rather than broken at
+		assert_eq!(wrap("  aaaaaa", 3), [0..3, 3..6, 6..8]);
+		assert_eq!(wrap("  ab cd", 4), [0..4, 5..7]);
+
+		let mut wrapped = vec![];
+		let options = WrapOptions { width: 12, first_indent: 0, hanging_indent: 4, policy: BreakPolicy::AtSpaces };
+		wrap_with_options(s, &options, |line| wrapped.push((&s[line.range], line.indent, line.width)));
+		assert_eq!(wrapped, [
+			("The quick", 0, 9), ("brown", 4, 5), ("fox", 4, 3), ("jumps", 4, 5), ("over the", 4, 8),
+			("lazy \u{1f415}\u{200d}\u{1f9ba}.", 4, 8), ("unbreakable", 0, 11),
+		]);
+
+		let options = WrapOptions { width: 8, first_indent: 2, hanging_indent: 0, policy: BreakPolicy::Anywhere };
+		let mut wrapped = vec![];
+		wrap_with_options("\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{6587}", &options, |line| wrapped.push(line.range));
+		assert_eq!(wrapped, [0..9, 9..15]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn table_alignment() {
+		use crate::table::{render_table, Measure, TableOptions};
+		let rows = vec![
+			vec!["Name", "Flag", "Note"],
+			vec!["Fran\u{e7}ais", "\u{1f1eb}\u{1f1f7}", "ok"],
+			vec!["\u{65e5}\u{672c}", "\u{1f1ef}\u{1f1f5}"],
+		];
+		#[cfg(feature = "width")]
+		assert_eq!(render_table(&rows, &TableOptions::default()), concat!(
+			"Name     Flag Note\n",
+			"Fran\u{e7}ais \u{1f1eb}\u{1f1f7}   ok\n",
+			"\u{65e5}\u{672c}     \u{1f1ef}\u{1f1f5}\n",
+		));
+		#[cfg(not(feature = "width"))]
+		assert_eq!(render_table(&rows, &TableOptions::default()), concat!(
+			"Name     Flag Note\n",
+			"Fran\u{e7}ais \u{1f1eb}\u{1f1f7}    ok\n",
+			"\u{65e5}\u{672c}       \u{1f1ef}\u{1f1f5}\n",
+		));
+		let options = TableOptions { measure: Measure::Graphemes, separator: "|", max_width: Some(3) };
+		assert_eq!(render_table(&rows, &options), concat!(
+			"Nam|Fla|Not\n",
+			"Fra|\u{1f1eb}\u{1f1f7}  |ok\n",
+			"\u{65e5}\u{672c} |\u{1f1ef}\u{1f1f5}\n",
+		));
+	}
+
+	#[test]
+	fn rev_no_alloc_long_runs() {
+		let flags = "\u{1f1eb}\u{1f1f7}".repeat(40);
+		for s in [flags.clone(), format!("a{}\u{1f1eb}", flags), format!("{}\u{301}", "\u{915}\u{94d}".repeat(50))] {
+			let mut expected: Vec<usize> = s.egc_indices().collect();
+			expected.pop();
+			expected.insert(0, 0);
+			expected.reverse();
+			assert_eq!(s.egc_indices().rev_no_alloc().collect::<Vec<_>>(), expected);
+		}
+	}
+
+	#[test]
+	fn ascii_runs() {
+		let line = "fn main() {\tlet x = 1;\r\n";
+		for s in [line.repeat(3), format!("{}e\u{301}{}\r", line, line), "abcdefghijklmn\u{1f1eb}\u{1f1f7}\r\r\nxyz\u{200d}\u{1f600}".to_string()] {
+			let mut expected = Vec::new();
+			let mut offset = 0;
+			while offset < s.len() {
+				offset = next_boundary(&s, offset);
+				expected.push(offset);
+			}
+			assert_eq!(s.egc_indices().collect::<Vec<_>>(), expected, "{:?}", s);
+			assert_eq!(s.egc_count(), expected.len());
+			assert!(s.egc_ranges().map(|r| &s[r]).eq(s.egcs()));
+			for i in 0..=s.len() + 1 {
+				let floor = s.floor_egc_boundary(i);
+				assert!(s.egc_indices_from(i).eq(expected.iter().copied().filter(|&end| end > floor)));
+			}
+			let egcs: Vec<&str> = s.egcs().collect();
+			for n in 0..=egcs.len() {
+				let (mut indices, mut slices) = (s.egc_indices(), s.egcs());
+				assert_eq!((indices.nth(n), slices.nth(n)), (expected.get(n).copied(), egcs.get(n).copied()));
+				let rest = (n + 1).min(egcs.len());
+				assert_eq!((indices.last(), slices.last()), (expected[rest..].last().copied(), egcs[rest..].last().copied()));
+				let mut slices = s.egcs();
+				if n > 0 {
+					slices.nth(n - 1);
+				}
+				assert_eq!(slices.count(), egcs.len() - n);
+			}
+			assert!(s.egc_start_indices().eq(s.egc_ranges().map(|r| r.start)));
+			#[cfg(feature = "alloc")]
+			assert!(s.egc_start_indices().collect::<Vec<_>>().into_iter().rev().eq(s.egc_indices().rev()));
+			let mut it = s.egc_indices();
+			it.nth(1);
+			assert!(it.rev_no_alloc().eq(expected[1..expected.len() - 1].iter().copied().rev()));
+			#[cfg(feature = "alloc")]
+			{
+				let mut it = s.egcs();
+				it.nth(1);
+				assert!(it.rev().eq(s.egcs().skip(2).collect::<Vec<_>>().into_iter().rev()));
+				let mut it = s.egc_indices();
+				it.nth(1);
+				assert!(it.rev().eq(expected[1..expected.len() - 1].iter().copied().rev()));
+			}
+			for (n, range, egc) in s.egc_enumerate() {
+				assert_eq!(s.egc_get(n), Some(egc));
+				assert_eq!((range.start, range.end), (if n == 0 { 0 } else { expected[n - 1] }, expected[n]));
+			}
+			assert_eq!(s.egc_get(expected.len()), None);
+			assert_eq!((s.egc_first(), s.egc_last()), (s.egc_get(0), s.egc_get(expected.len() - 1)));
+			for n in 0..=expected.len() {
+				let offset = if n == 0 { 0 } else { expected[n - 1] };
+				assert_eq!(s.egc_split_at(n), s.split_at(offset));
+				assert_eq!((s.egc_take(n), s.egc_skip(n)), s.split_at(offset));
+			}
+			assert_eq!((s.egc_take(usize::MAX), s.egc_skip(expected.len() + 1)), (s.as_str(), ""));
+			for max_bytes in 0..=s.len() + 1 {
+				let floor = expected.iter().copied().rfind(|&i| i <= max_bytes).unwrap_or(0);
+				assert_eq!(s.egc_floor_to_bytes(max_bytes), &s[..floor]);
+			}
+			assert_eq!(s.checked_egc_split_at(expected.len() + 1), None);
+			let n = expected.len();
+			assert_eq!(s.egc_slice(0..n), Some(s.as_str()));
+			assert_eq!(s.egc_slice(1..n - 1), Some(&s[expected[0]..expected[n - 2]]));
+			assert_eq!(s.egc_slice(n..n), Some(""));
+			assert_eq!((s.egc_slice(1..n + 1), s.egc_slice(n - 1..n - 2)), (None, None));
+			#[cfg(feature = "alloc")]
+			assert_eq!(s.egc_break_positions(), expected, "{:?}", s);
+		}
+		assert_eq!(("".egc_count_at_most(0), "ab".egc_count_at_most(usize::MAX)), (Some(0), Some(2)));
+		assert_eq!(("".egc_first(), "".egc_last()), (None, None));
+		assert_eq!("\u{1f1eb}\u{1f1f7}\u{1f1eb}\u{1f1f7}\u{1f1eb}".egc_last(), Some("\u{1f1eb}"));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn breaks_bitset() {
+		use crate::breaks::EgcBreaks;
+		for case in &TEST_CASES {
+			let s = case.str.repeat(20);
+			let breaks = EgcBreaks::new(&s);
+			let mut expected = vec![0];
+			expected.extend(s.egc_indices());
+			assert_eq!((0..=s.len() + 1).filter(|&i| breaks.is_boundary(i)).collect::<Vec<_>>(), expected);
+			for i in 0..=s.len() + 1 {
+				let next = expected.iter().copied().find(|&b| b > i);
+				let prev = expected.iter().copied().rev().find(|&b| b < i);
+				assert_eq!(breaks.next_after(i), next, "next_after({}) in {:?}", i, s);
+				assert_eq!(breaks.prev_before(i), prev, "prev_before({}) in {:?}", i, s);
+			}
+		}
+		let empty = EgcBreaks::new("");
+		assert!(empty.is_boundary(0) && empty.next_after(0).is_none() && empty.prev_before(0).is_none());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn rank_select() {
+		use crate::breaks::EgcIndex;
+		for case in &TEST_CASES {
+			let s = case.str.repeat(30);
+			let index = EgcIndex::new(&s);
+			let mut offsets = vec![0];
+			offsets.extend(s.egc_indices());
+			assert_eq!(index.egc_count(), offsets.len() - 1);
+			for (n, &offset) in offsets.iter().enumerate() {
+				assert_eq!(index.egc_offset(n), Some(offset), "egc_offset({}) in {:?}", n, s);
+			}
+			assert_eq!(index.egc_offset(offsets.len()), None);
+			for (n, w) in offsets.windows(2).enumerate() {
+				assert_eq!(index.egc_range(n), Some(w[0]..w[1]));
+				for i in w[0]..w[1] {
+					assert_eq!(index.egc_at(i), Some(n), "egc_at({}) in {:?}", i, s);
+				}
+			}
+			assert_eq!(index.egc_at(s.len()), None);
+		}
+		let empty = EgcIndex::new("");
+		assert_eq!((empty.egc_count(), empty.egc_offset(0), empty.egc_range(0)), (0, Some(0), None));
+	}
+
+	#[test]
+	fn streaming_flags() {
+		let flags = |s: &'static str| s.egcs().streaming().collect::<Vec<_>>();
+		assert_eq!(flags("ab\u{1f1eb}"), [("a", false), ("b", false), ("\u{1f1eb}", true)]);
+		assert_eq!(flags("e\u{301}"), [("e\u{301}", true)]);
+		assert_eq!(flags("a\r"), [("a", false), ("\r", true)]);
+		assert_eq!(flags("a\r\n"), [("a", false), ("\r\n", false)]);
+		assert_eq!(flags(""), []);
+	}
+
+	#[test]
+	fn safe_split_points() {
+		use crate::logic::safe_split_point;
+		for case in &TEST_CASES {
+			let s = case.str.repeat(3);
+			for index in 0..=s.len() + 1 {
+				let split = safe_split_point(&s, index);
+				let mut expected: Vec<usize> = s[..split].egc_indices().collect();
+				expected.extend(s[split..].egc_indices().map(|i| split + i));
+				assert_eq!(s.egc_indices().collect::<Vec<_>>(), expected, "split at {} of {:?}", split, s);
+			}
+		}
+		assert_eq!(safe_split_point("ab\u{301}\u{302}cd", 2), 1);
+		assert_eq!(safe_split_point("ab\u{301}\u{302}\u{303}cd", 5), 8);
+		assert_eq!(safe_split_point(&"\u{1f1eb}".repeat(3), 5), 12);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn drain() {
+		use crate::edit::drain_egcs;
+		let mut s = String::from("ae\u{301}\u{1f1eb}\u{1f1f7}b");
+		assert_eq!(drain_egcs(&mut s, 1..3), "e\u{301}\u{1f1eb}\u{1f1f7}");
+		assert_eq!(s, "ab");
+		assert_eq!(drain_egcs(&mut s, 2..2), "");
+		assert_eq!(drain_egcs(&mut s, 0..2), "ab");
+		assert_eq!(s, "");
+		assert!(std::panic::catch_unwind(|| drain_egcs(&mut String::from("ab"), 1..3)).is_err());
+		assert!(std::panic::catch_unwind(|| drain_egcs(&mut String::from("ab"), 3..3)).is_err());
+	}
+
+	#[test]
+	fn lossy_bytes() {
+		use crate::bytes::egc_ranges_lossy;
+		for case in &TEST_CASES {
+			assert!(egc_ranges_lossy(case.str.as_bytes()).eq(case.str.egc_ranges()), "{:?}", case.str);
+		}
+		let bytes = b"e\xcc\x81\xff\xe2\x82\xffa\xcc";
+		let ranges: Vec<_> = egc_ranges_lossy(bytes).collect();
+		assert_eq!(ranges, [0..3, 3..4, 4..6, 6..7, 7..8, 8..9]);
+		let ranges: Vec<_> = egc_ranges_lossy(b"\xff\xcc\x81\xe2\x80\x8d\xff\xe0\xa4\x95").collect();
+		assert_eq!(ranges, [0..6, 6..7, 7..10]);
+		assert_eq!(egc_ranges_lossy(b"").next(), None);
+
+		// Invalid sequences are segmented like the U+FFFD which replaces them
+		for case in &TEST_CASES {
+			for (i, _) in case.str.char_indices() {
+				for invalid in [&b"\xff"[..], b"\xe2\x82", b"\xed\xa0\x80"] {
+					let bytes = [&case.str.as_bytes()[..i], invalid, &case.str.as_bytes()[i..]].concat();
+					let lossy = String::from_utf8_lossy(&bytes);
+					// Offsets in `bytes` of the char boundaries of `lossy`
+					let mut offsets = vec![0; lossy.len() + 1];
+					let mut pos = (0, 0);
+					for chunk in bytes.utf8_chunks() {
+						for (j, _) in chunk.valid().char_indices() {
+							offsets[pos.0 + j] = pos.1 + j;
+						}
+						pos = (pos.0 + chunk.valid().len(), pos.1 + chunk.valid().len());
+						if !chunk.invalid().is_empty() {
+							offsets[pos.0] = pos.1;
+							pos = (pos.0 + '\u{fffd}'.len_utf8(), pos.1 + chunk.invalid().len());
+						}
+					}
+					offsets[lossy.len()] = bytes.len();
+					let expected = lossy.egc_indices().map(|end| offsets[end]);
+					assert!(egc_ranges_lossy(&bytes).map(|r| r.end).eq(expected), "{:?}", bytes);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn utf16_indices() {
+		for case in &TEST_CASES {
+			let expected = case.str.egc_indices().map(|i| case.str[..i].encode_utf16().count());
+			assert!(case.str.egc_indices_utf16().eq(expected), "{:?}", case.str);
+		}
+		let indices: Vec<usize> = "a\u{1f1eb}\u{1f1f7}e\u{301}".egc_indices_utf16().collect();
+		assert_eq!(indices, [1, 5, 7]);
+	}
+
+	#[test]
+	fn char_count_indices() {
+		for case in &TEST_CASES {
+			let expected = case.str.egc_indices().map(|i| case.str[..i].chars().count());
+			assert!(case.str.egc_indices_chars().eq(expected), "{:?}", case.str);
+		}
+		let indices: Vec<usize> = "a\u{1f1eb}\u{1f1f7}e\u{301}".egc_indices_chars().collect();
+		assert_eq!(indices, [1, 3, 5]);
+	}
+
+	#[test]
+	fn utf16_slices() {
+		use crate::utf16::egc_indices_u16;
+		for case in &TEST_CASES {
+			let units: Vec<u16> = case.str.encode_utf16().collect();
+			let expected = case.str.egc_indices().map(|i| case.str[..i].encode_utf16().count());
+			assert!(egc_indices_u16(&units).eq(expected), "{:?}", case.str);
+		}
+		let units = [0x61, 0xd800, 0x301, 0xdc00, 0xd83d, 0xde00, 0x301];
+		let indices: Vec<usize> = egc_indices_u16(&units).collect();
+		assert_eq!(indices, [1, 2, 3, 4, 7]);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn os_str() {
+		use crate::bytes::egc_ranges_os;
+		use std::ffi::OsStr;
+		let ranges: Vec<_> = egc_ranges_os(OsStr::new("e\u{301}.txt")).collect();
+		assert_eq!(ranges, [0..3, 3..4, 4..5, 5..6, 6..7]);
+		#[cfg(unix)] {
+			use std::os::unix::ffi::OsStrExt;
+			let name = OsStr::from_bytes(b"a\xed\xa0\x80\xed\xa0b\xff");
+			let ranges: Vec<_> = egc_ranges_os(name).collect();
+			assert_eq!(ranges, [0..1, 1..4, 4..5, 5..6, 6..7, 7..8]);
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn char_iterator_egcs() {
+		use crate::iter::egcs_from_chars;
+		for case in &TEST_CASES {
+			let egcs: Vec<String> = egcs_from_chars(case.str.chars()).collect();
+			assert!(egcs.iter().map(String::as_str).eq(case.str.egcs()), "{:?}", case.str);
+		}
+		let upper: Vec<String> = egcs_from_chars("e\u{301}t\u{e9}".chars().flat_map(char::to_uppercase)).collect();
+		assert_eq!(upper, ["E\u{301}", "T", "\u{c9}"]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn truncate() {
+		use crate::EgcString;
+		let mut s = String::from("ae\u{301}\u{1f1eb}\u{1f1f7}b");
+		s.egc_truncate(4);
+		assert_eq!(s, "ae\u{301}\u{1f1eb}\u{1f1f7}b");
+		s.egc_truncate(2);
+		assert_eq!(s, "ae\u{301}");
+		s.egc_truncate(0);
+		assert_eq!(s, "");
+		let egcs = String::from("ae\u{301}\u{1f1eb}\u{1f1f7}").into_egcs();
+		let handle = std::thread::spawn(move || egcs.collect::<Vec<String>>());
+		assert_eq!(handle.join().unwrap(), ["a", "e\u{301}", "\u{1f1eb}\u{1f1f7}"]);
+		assert_eq!(s.into_egcs().next(), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn move_clusters() {
+		use crate::edit::move_egcs;
+		let moved = |s: &str, egcs, to| {
+			let mut s = String::from(s);
+			let range = move_egcs(&mut s, egcs, to);
+			(s.clone(), s[range].to_string())
+		};
+		assert_eq!(moved("abcd", 1..3, 0), ("bcad".into(), "bc".into()));
+		assert_eq!(moved("abcd", 1..3, 2), ("adbc".into(), "bc".into()));
+		assert_eq!(moved("abcd", 1..3, 1), ("abcd".into(), "bc".into()));
+		let s = "e\u{301}\u{1f469}\u{200d}\u{1f4bb}x\u{1f1eb}\u{1f1f7}";
+		assert_eq!(moved(s, 0..1, 3), ("\u{1f469}\u{200d}\u{1f4bb}x\u{1f1eb}\u{1f1f7}e\u{301}".into(), "e\u{301}".into()));
+		assert_eq!(moved(s, 3..4, 1), ("e\u{301}\u{1f1eb}\u{1f1f7}\u{1f469}\u{200d}\u{1f4bb}x".into(), "\u{1f1eb}\u{1f1f7}".into()));
+		assert!(std::panic::catch_unwind(|| moved("abcd", 1..3, 3)).is_err());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn index_splices() {
+		use crate::breaks::EgcIndex;
+		let inserts = ["", "x", "\u{1f1eb}", "\u{200d}", "\u{301}\u{1f1f7}", "\u{1f600}\u{200d}"];
+		let check = |s: &str, range: std::ops::Range<usize>| {
+			for insert in inserts {
+				let mut index = EgcIndex::new(s);
+				let mut new = String::from(s);
+				new.replace_range(range.clone(), insert);
+				index.splice(&new, range.clone(), insert.len());
+				assert_eq!(index, EgcIndex::new(&new), "{:?} with {:?} replaced by {:?}", s, range, insert);
+			}
+		};
+		for case in &TEST_CASES {
+			let s = format!("{}a{}", case.str, case.str);
+			for (start, _) in s.char_indices() {
+				for (end, _) in s[start..].char_indices().take(3) {
+					check(&s, start..start + end);
+				}
+			}
+		}
+		// Edits in long runs of flags and ZWJ sequences, crossing 64-byte blocks
+		let flags = "\u{1f1eb}".repeat(71);
+		let zwj = "\u{1f468}\u{200d}".repeat(41);
+		for s in [flags, zwj] {
+			for (start, _) in s.char_indices().step_by(3) {
+				check(&s, start..start);
+				check(&s, start..s[start..].char_indices().nth(2).map_or(s.len(), |(i, _)| start + i));
+			}
+			check(&s, s.len()..s.len());
+		}
+	}
+
+	#[test]
+	fn identifier_safe_clusters() {
+		use crate::ident::{is_identifier_safe_cluster, lookup_xid_class, XidClass};
+		assert_eq!(lookup_xid_class('a'), Some(XidClass::Start));
+		assert_eq!(lookup_xid_class('_'), Some(XidClass::Continue));
+		assert_eq!(lookup_xid_class('\u{301}'), Some(XidClass::Continue));
+		assert_eq!(lookup_xid_class('-'), None);
+		for (egc, safe) in [
+			("a", true),
+			("7", true),
+			("e\u{301}", true),
+			("\u{915}\u{94d}\u{937}", true),
+			("\u{d55c}", true),
+			("-", false),
+			(" ", false),
+			("", false),
+			("a\u{200d}", false),
+			("a\u{34f}", false),
+			("e\u{fe0f}", false),
+			("\u{200b}", false),
+			("\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}", true),
+			("\u{2764}\u{fe0f}", true),
+			("\u{1f1eb}\u{1f1f7}", true),
+			("\u{1f3f4}\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", true),
+			("\u{1f469}\u{200d}", false),
+			("\u{1f469}\u{301}", false),
+		] {
+			assert_eq!(is_identifier_safe_cluster(egc), safe, "{:?}", egc);
+		}
+	}
+
+	#[test]
+	fn prefix_buckets() {
+		use crate::search::{cmp_egc_prefix, egc_prefix, egc_prefix_range};
+		assert_eq!(egc_prefix("e\u{301}te\u{301}", 2), "e\u{301}t");
+		assert_eq!(egc_prefix("\u{1f1eb}\u{1f1f7}", 3), "\u{1f1eb}\u{1f1f7}");
+		assert_eq!(egc_prefix("abc", 0), "");
+		let mut names = ["Zo\u{eb}", "e\u{4e00}", "e\u{301}mile", "\u{c9}lodie", "Eve", "e\u{301}ric", "emma"];
+		names.sort_by(|a, b| cmp_egc_prefix(a, b, 1).then(a.cmp(b)));
+		assert_eq!(egc_prefix_range(&names, "e\u{301}", 1).map(|i| names[i]).collect::<Vec<_>>(), ["e\u{301}mile", "e\u{301}ric"]);
+		assert_eq!(egc_prefix_range(&names, "elle", 1).map(|i| names[i]).collect::<Vec<_>>(), ["emma", "e\u{4e00}"]);
+		assert_eq!(egc_prefix_range(&names, "Al", 1), 0..0);
+	}
+
+	#[cfg(all(feature = "unstable", feature = "alloc"))]
+	#[test]
+	fn fuzzy_matching() {
+		use crate::fuzzy::fuzzy_match;
+		let matched = |query, candidate| {
+			fuzzy_match(query, candidate).map(|m| (m.score, m.matched.into_iter().map(|e| (e.range.start, e.range.end)).collect::<Vec<_>>()))
+		};
+		assert_eq!(matched("gc", "git commit"), Some((2 * 16 + 2 * 8 - 3, vec![(0, 1), (4, 5)])));
+		assert_eq!(matched("CAF\u{c9}", "caf\u{e9} cr\u{e8}me"), Some((4 * 16 + 3 * 8 + 8, vec![(0, 1), (1, 2), (2, 3), (3, 5)])));
+		assert_eq!(matched("e", "e\u{301}t\u{e9}"), None);
+		assert_eq!(matched("e\u{301}", "le\u{301}"), Some((16, vec![(1, 4)])));
+		assert_eq!(matched("\u{1f1eb}\u{1f1f7}x", "\u{1f1fa}\u{1f1f8}\u{1f1eb}\u{1f1f7} x"), Some((2 * 16 + 8 - 1, vec![(8, 16), (17, 18)])));
+		assert_eq!(matched("", "abc"), Some((0, vec![])));
+		assert_eq!(matched("ab", "ba"), None);
+	}
+
+	#[cfg(all(feature = "unstable", feature = "alloc"))]
+	#[test]
+	fn highlights() {
+		use crate::fuzzy::{fuzzy_match, highlight_spans};
+		let spans = fuzzy_match("cafe", "my cafeteria").unwrap().highlight_spans();
+		assert_eq!((spans.len(), &spans[0]), (1, &(3..7)));
+		assert_eq!(fuzzy_match("mcfa", "my cafeteria").unwrap().highlight_spans(), [0..1, 3..4, 5..6, 11..12]);
+		let s = "e\u{301}t\u{e9} \u{1f1eb}\u{1f1f7}!";
+		assert_eq!(highlight_spans(s, [10..12, 0..1, 3..4, 1..2, 6..6, 4..5]), [0..6, 7..15]);
+	}
+
+	#[cfg(feature = "unstable")]
+	#[test]
+	fn group_keys() {
+		use crate::group::{group_key_first_egc, GroupKey, GroupOptions};
+		let keys = |options| ["e\u{301}mile", "\u{e9}ric", "\u{df}", "9lives", "\u{1f1eb}\u{1f1f7}", "1\u{fe0f}\u{20e3}", "", "\u{1f44b}\u{1f3fd}"]
+			.map(|s| group_key_first_egc(s, &options));
+		assert_eq!(keys(GroupOptions::default()), [
+			GroupKey::Egc("e\u{301}"), GroupKey::Egc("\u{e9}"), GroupKey::Egc("\u{df}"), GroupKey::Egc("9"),
+			GroupKey::Egc("\u{1f1eb}\u{1f1f7}"), GroupKey::Egc("1\u{fe0f}\u{20e3}"), GroupKey::Empty, GroupKey::Egc("\u{1f44b}\u{1f3fd}"),
+		]);
+		assert_eq!(keys(GroupOptions { strip_marks: true, uppercase: true, ..Default::default() }), [
+			GroupKey::Upper('E', ""), GroupKey::Upper('\u{c9}', ""), GroupKey::Egc("\u{df}"), GroupKey::Egc("9"),
+			GroupKey::Egc("\u{1f1eb}\u{1f1f7}"), GroupKey::Egc("1"), GroupKey::Empty, GroupKey::Egc("\u{1f44b}"),
+		]);
+		assert_eq!(keys(GroupOptions::LIST), [
+			GroupKey::Upper('E', ""), GroupKey::Upper('\u{c9}', ""), GroupKey::Egc("\u{df}"), GroupKey::Digit,
+			GroupKey::Emoji, GroupKey::Emoji, GroupKey::Empty, GroupKey::Emoji,
+		]);
+		assert_eq!(group_key_first_egc("e\u{301}", &GroupOptions { uppercase: true, ..Default::default() }).to_string(), "E\u{301}");
+	}
+
+	#[test]
+	fn ucd_tests_boundary_queries() {
+		for case in &TEST_CASES {
+			let mut all_breaks = vec![0];
+			all_breaks.extend_from_slice(case.breaks);
+			all_breaks.push(case.str.len());
+			for (i, _) in case.str.char_indices().chain([(case.str.len(), ' ')]) {
+				let next = all_breaks.iter().copied().find(|&b| b > i).unwrap_or(case.str.len());
+				let prev = all_breaks.iter().copied().rfind(|&b| b < i).unwrap_or(0);
+				assert_eq!(next_boundary(case.str, i), next, "next_boundary from {} (line {})", i, case.line);
+				assert_eq!(prev_boundary(case.str, i), prev, "prev_boundary from {} (line {})", i, case.line);
+			}
+			for i in 0..=case.str.len() + 1 {
+				let floor = all_breaks.iter().copied().rfind(|&b| b <= i).unwrap();
+				let ceil = all_breaks.iter().copied().find(|&b| b >= i).unwrap_or(case.str.len());
+				assert_eq!(case.str.floor_egc_boundary(i), floor, "floor_egc_boundary of {} (line {})", i, case.line);
+				assert_eq!(case.str.ceil_egc_boundary(i), ceil, "ceil_egc_boundary of {} (line {})", i, case.line);
+
+				let resolved = resolve_offset(case.str, i);
+				let next = all_breaks.iter().copied().find(|&b| b > floor).unwrap_or(floor);
+				assert_eq!(resolved, ResolvedOffset { snapped: floor, was_inside_cluster: floor != i, cluster_range: floor..next }, "resolve_offset of {} (line {})", i, case.line);
+			}
+		}
+	}
 }
\ No newline at end of file
@@ -1,13 +1,21 @@
-use crate::lookup::{lookup_egc_props, EgcProps};
+use crate::lookup::{lookup_packed_props, EgcProps, GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps, PropsLookup};
+use crate::tailoring::{BreakTailoring, NoTailoring};
 use EgcProps as EP;
 
 /// Context for EGC segmentation.
 /// 
 /// Implements a state machine which recognizes the patterns
 /// necessary to apply the full segmentation rules.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// 
+/// New rules in future versions of Unicode may require new states, so the enum
+/// is `#[non_exhaustive]`: use [code](Ctx::code) or [is_start](Ctx::is_start)
+/// rather than matching on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Ctx {
 	/// At EGC boundary (including start of string)
+	#[default]
 	Start,
 	/// State machine for rule GB9c
 	Indic(bool),
@@ -18,35 +26,67 @@ pub enum Ctx {
 }
 
 impl Ctx {
+	/// All contexts, in the order of their codes.
+	pub const ALL: [Ctx; 6] = [Ctx::Start, Ctx::Indic(false), Ctx::Indic(true), Ctx::Emoji(false), Ctx::Emoji(true), Ctx::Ri];
+
+	/// Returns the numeric code of the context, which is its index in [ALL](Ctx::ALL).
+	pub const fn code(self) -> u8 {
+		match self {
+			Ctx::Start => 0,
+			Ctx::Indic(false) => 1,
+			Ctx::Indic(true) => 2,
+			Ctx::Emoji(false) => 3,
+			Ctx::Emoji(true) => 4,
+			Ctx::Ri => 5,
+		}
+	}
+	/// Returns the context with the given code, if any.
+	pub const fn from_code(code: u8) -> Option<Ctx> {
+		if (code as usize) < Ctx::ALL.len() {
+			Some(Ctx::ALL[code as usize])
+		} else {
+			None
+		}
+	}
+	/// Is the context the one at an EGC boundary, where no pattern is in progress
+	pub const fn is_start(self) -> bool {
+		matches!(self, Ctx::Start)
+	}
+
 	/// Computes the new context after a character with the given EGC properties.
-	pub fn step(self, p: EgcProps) -> Ctx {
+	pub const fn step(self, p: EgcProps) -> Ctx {
+		self.step_packed(PackedProps::from_egc_props(p))
+	}
+
+	/// Like [step](Ctx::step), with packed properties.
+	pub const fn step_packed(self, p: PackedProps) -> Ctx {
 		// GB9c
-		if p == EP::IN_CO {
+		if matches!(p.incb(), I::Consonant) {
 			return Ctx::Indic(false);
 		}
 		if let Ctx::Indic(s) = self {
-			if p == EP::IN_LI {
-				return Ctx::Indic(true);
-			} else if p.is_incb_ex() {
-				return Ctx::Indic(s);
+			match p.incb() {
+				I::Linker => return Ctx::Indic(true),
+				I::Extend => return Ctx::Indic(s),
+				_ => (),
 			}
 		}
 
 		// GB11
-		if p == EP::EP {
+		if p.is_ext_pict() {
 			return Ctx::Emoji(false);
 		}
-		if let Ctx::Emoji(s) = self {
-			match (s, p) {
-				(false, _) if p.is_gcb_ex() => return Ctx::Emoji(false),
-				(false, EP::ZWJ) => return Ctx::Emoji(true),
+		if let Ctx::Emoji(false) = self {
+			match p.gcb() {
+				G::Extend => return Ctx::Emoji(false),
+				G::ZWJ => return Ctx::Emoji(true),
 				_ => (),
 			}
 		}
 
 		// GB12/13
-		if p == EP::RI {
-			return if self == Ctx::Ri { Ctx::Start } else { Ctx::Ri };
+		if matches!(p.gcb(), G::RegionalIndicator) {
+			return if matches!(self, Ctx::Ri) { Ctx::Start } else { Ctx::Ri };
 		}
 
 		Ctx::Start
@@ -57,55 +97,82 @@ impl Ctx {
 /// properties `p1` and `p2`, with no further context.
 /// 
 /// If context is needed to decide, returns None.
-pub fn is_local_boundary(p1: EP, p2: EP) -> Option<bool> {
-	if p1 == EP::XX && p2 == EP::XX { // Fast path
-		return Some(true);
+pub const fn is_local_boundary(p1: EP, p2: EP) -> Option<bool> {
+	is_local_boundary_packed(PackedProps::from_egc_props(p1), PackedProps::from_egc_props(p2))
+}
+
+/// Like [is_local_boundary], with packed properties.
+pub const fn is_local_boundary_packed(p1: PackedProps, p2: PackedProps) -> Option<bool> {
+	match GCB_PAIRS[p1.gcb() as usize][p2.gcb() as usize] {
+		Some(boundary) => Some(boundary),
+		None => {
+			let context_needed =
+				(matches!(p1.incb(), I::Extend | I::Linker) && matches!(p2.incb(), I::Consonant)) || // GB9c
+				(matches!(p1.gcb(), G::ZWJ) && p2.is_ext_pict()) || // GB11
+				matches!((p1.gcb(), p2.gcb()), (G::RegionalIndicator, G::RegionalIndicator)); // GB12/13
+			if context_needed { None } else { Some(true) }
+		},
+	}
+}
+
+/// Decisions of the rules which only depend on the Grapheme_Cluster_Break properties
+/// of the two characters (GB3 to GB9b), indexed by them, or None if they do not apply.
+const GCB_PAIRS: [[Option<bool>; 14]; 14] = {
+	let mut table = [[None; 14]; 14];
+	let mut i = 0;
+	while i < 14 {
+		let mut j = 0;
+		while j < 14 {
+			table[i][j] = gcb_pair_rule(G::ALL[i], G::ALL[j]);
+			j += 1;
+		}
+		i += 1;
 	}
-	if p1 == EP::CR && p2 == EP::LF { // GB3
+	table
+};
+
+const fn gcb_pair_rule(g1: G, g2: G) -> Option<bool> {
+	// (`matches!` is used instead of `==`, which is not available in const fns)
+	if matches!((g1, g2), (G::CR, G::LF)) { // GB3
 		return Some(false);
 	}
-	if p1.is_control() || p2.is_control() { // GB4/5
+	if matches!(g1, G::CR | G::LF | G::Control) || matches!(g2, G::CR | G::LF | G::Control) { // GB4/5
 		return Some(true);
 	}
-	if p1.is_hangul() && p2.is_hangul() {
+	if matches!(g1, G::L | G::V | G::T | G::LV | G::LVT) && matches!(g2, G::L | G::V | G::T | G::LV | G::LVT) {
 		let merge =
-			(p1 == EP::L && p2 != EP::T) || // GB6
-			((p1 == EP::LV || p1 == EP::V) && (p2 == EP::V || p2 == EP::T)) || // GB7
-			((p1 == EP::LVT || p1 == EP::T) && p2 == EP::T); // GB8
+			(matches!(g1, G::L) && !matches!(g2, G::T)) || // GB6
+			(matches!(g1, G::LV | G::V) && matches!(g2, G::V | G::T)) || // GB7
+			(matches!(g1, G::LVT | G::T) && matches!(g2, G::T)); // GB8
 		return if merge { Some(false) } else { Some(true) };
 	}
-	if p2.is_gcb_ex() || p2 == EP::ZWJ { // GB9
+	if matches!(g2, G::Extend | G::ZWJ) { // GB9
 		return Some(false);
 	}
 
 	// Extended rules:
-	if p2 == EP::SM || p1 == EP::PP { // GB9a/b
+	if matches!(g2, G::SpacingMark) || matches!(g1, G::Prepend) { // GB9a/b
 		return Some(false);
 	}
-	if (p1.is_incb_ex() || p1 == EP::IN_LI) && p2 == EP::IN_CO { // GB9c
-		return None;
-	}
-	
-	if p1 == EP::ZWJ && p2 == EP::EP { // GB11
-		return None;
-	}
-	if p1 == EP::RI && p2 == EP::RI { // GB12/13
-		return None;
-	}
-	return Some(true);
+	None
 }
 
 /// Computes whether an EGC boundary exists between characters with
 /// properties `p1` and `p2`, given the context `c` *up to and including* the first character.
-pub fn is_boundary(c: Ctx, p1: EP, p2: EP) -> bool {
-	if let Some(boundary) = is_local_boundary(p1, p2) {
+pub const fn is_boundary(c: Ctx, p1: EP, p2: EP) -> bool {
+	is_boundary_packed(c, PackedProps::from_egc_props(p1), PackedProps::from_egc_props(p2))
+}
+
+/// Like [is_boundary], with packed properties.
+pub const fn is_boundary_packed(c: Ctx, p1: PackedProps, p2: PackedProps) -> bool {
+	if let Some(boundary) = is_local_boundary_packed(p1, p2) {
 		return boundary;
 	}
-	return !(
-		(c == Ctx::Indic(true) && p2 == EP::IN_CO) || // GB9c
-		(c == Ctx::Emoji(true) && p2 == EP::EP) || // GB11
-		(c == Ctx::Ri && p2 == EP::RI) // GB12/13
-	);
+	!(
+		(matches!(c, Ctx::Indic(true)) && matches!(p2.incb(), I::Consonant)) || // GB9c
+		(matches!(c, Ctx::Emoji(true)) && p2.is_ext_pict()) || // GB11
+		(matches!(c, Ctx::Ri) && matches!(p2.gcb(), G::RegionalIndicator)) // GB12/13
+	)
 }
 
 /// Returns the offset of the first EGC boundary in the string,
@@ -113,19 +180,50 @@ pub fn is_boundary(c: Ctx, p1: EP, p2: EP) -> bool {
 /// 
 /// If the string is empty, returns 0.
 pub fn first_boundary(s: &str) -> usize {
-	let mut ctx = Ctx::Start;
-	let mut p1 = None;
-	for (i, c) in s.char_indices() {
-		let p2 = lookup_egc_props(c);
-		if let Some(p1) = p1 {
-			if is_boundary(ctx, p1, p2) {
-				return i;
-			}
+	first_boundary_with(s, None, &mut PropsLookup::new()).0
+}
+
+/// Like [first_boundary], but takes the properties of the first character of the
+/// string if they are already known, and also returns the properties of the character
+/// following the boundary if they were looked up.
+/// 
+/// This allows segmenting a string while looking up each character only once.
+/// Lookups go through `lookup`, so that it can be kept across calls.
+#[inline]
+#[allow(clippy::needless_return)] // the returned expression depends on the engine
+pub(crate) fn first_boundary_with(s: &str, first: Option<PackedProps>, lookup: &mut PropsLookup) -> (usize, Option<PackedProps>) {
+	// Fast path: there is always a boundary between two ASCII characters, except for CR LF
+	match *s.as_bytes() {
+		[] => return (0, None),
+		[b1] if b1 < 0x80 => return (1, None),
+		[b1, b2, ..] if b1 < 0x80 && b2 < 0x80 && !(b1 == b'\r' && b2 == b'\n') => return (1, None),
+		_ => (),
+	}
+	#[cfg(feature = "dfa")]
+	{
+		// The DFA engine has its own lookup tables, indexed by UTF-8 bytes
+		let _ = lookup;
+		return crate::dfa::first_boundary_with(s, first);
+	}
+	#[cfg(not(feature = "dfa"))]
+	return first_boundary_slow(s, first, lookup);
+}
+
+#[cfg(not(feature = "dfa"))]
+fn first_boundary_slow(s: &str, first: Option<PackedProps>, lookup: &mut PropsLookup) -> (usize, Option<PackedProps>) {
+	let mut chars = s.char_indices();
+	let (_, c) = chars.next().unwrap();
+	let mut p1 = first.unwrap_or_else(|| lookup.lookup_packed(c));
+	let mut ctx = Ctx::Start.step_packed(p1);
+	for (i, c) in chars {
+		let p2 = lookup.lookup_packed(c);
+		if is_boundary_packed(ctx, p1, p2) {
+			return (i, Some(p2));
 		}
-		ctx = ctx.step(p2);
-		p1 = Some(p2);
+		ctx = ctx.step_packed(p2);
+		p1 = p2;
 	}
-	s.len()
+	(s.len(), None)
 }
 
 /// Returns the offset of the last local EGC boundary in the string,
@@ -138,9 +236,9 @@ pub fn last_local_boundary(s: &str) -> (usize, bool) {
 	let mut p2 = None;
 	let mut maybe_skipped = false;
 	for (i, c) in s.char_indices().rev() {
-		let p1 = lookup_egc_props(c);
+		let p1 = lookup_packed_props(c);
 		if let Some(p2) = p2 {
-			if let Some(boundary) = is_local_boundary(p1, p2) {
+			if let Some(boundary) = is_local_boundary_packed(p1, p2) {
 				if boundary {
 					return (i + c.len_utf8(), maybe_skipped);
 				}
@@ -152,3 +250,344 @@ pub fn last_local_boundary(s: &str) -> (usize, bool) {
 	}
 	(0, maybe_skipped)
 }
+
+/// Returns the offset of the first local EGC boundary at or after `from`,
+/// or `s.len()` if there is none.
+/// 
+/// Since a local boundary does not depend on the text before it, segmentation
+/// can start there without knowing that text.
+/// 
+/// Panics if `from` is out of bounds or not on a `char` boundary.
+pub fn next_local_boundary(s: &str, from: usize) -> usize {
+	let Some(c1) = s[..from].chars().next_back() else {
+		return 0;
+	};
+	let mut p1 = lookup_packed_props(c1);
+	for (i, c) in s[from..].char_indices() {
+		let p2 = lookup_packed_props(c);
+		if is_local_boundary_packed(p1, p2) == Some(true) {
+			return from + i;
+		}
+		p1 = p2;
+	}
+	s.len()
+}
+
+/// Returns the local EGC boundary closest to `index`, where the string can be split
+/// so that both parts are segmented independently, with the same result as the whole string.
+/// 
+/// Segmentation of the second part can start there from scratch (ie. with [Ctx::Start]),
+/// which makes this suitable for splitting text into chunks for parallel processing,
+/// scanning through memory-mapped windows, or packetization. `index` does not need
+/// to be on a `char` boundary, and is clamped to the length of the string.
+/// 
+/// Local boundaries are usually found within a few characters, but the scan may go
+/// further in unusual text (eg. a long run of regional indicators). The start and end
+/// of the string are always safe split points.
+pub fn safe_split_point(s: &str, index: usize) -> usize {
+	let mut index = index.min(s.len());
+	while !s.is_char_boundary(index) {
+		index += 1;
+	}
+	let next = next_local_boundary(s, index);
+	if next == index {
+		return index;
+	}
+	let (prev, _) = last_local_boundary(&s[..index]);
+	if index - prev < next - index { prev } else { next }
+}
+
+/// Returns the offset of the first EGC boundary strictly after `from`,
+/// or `s.len()` if `from` is already at the end of the string.
+/// 
+/// `from` does not need to be an EGC boundary itself: if it points inside
+/// an EGC, the end of that EGC is returned.
+/// 
+/// Panics if `from` is out of bounds or not on a `char` boundary.
+pub fn next_boundary(s: &str, from: usize) -> usize {
+	let rest = &s[from..];
+	let known_boundary = match (s[..from].chars().next_back(), rest.chars().next()) {
+		(Some(c1), Some(c2)) => is_local_boundary_packed(lookup_packed_props(c1), lookup_packed_props(c2)) == Some(true),
+		_ => true,
+	};
+	if !known_boundary {
+		// We need the context before `from`: segment the EGC containing it.
+		let start = prev_boundary(s, from);
+		let end = start + first_boundary(&s[start..]);
+		if end > from {
+			return end;
+		}
+	}
+	from + first_boundary(rest)
+}
+
+/// Returns the offset of the last EGC boundary strictly before `from`,
+/// or 0 if `from` is already at the start of the string.
+/// 
+/// `from` does not need to be an EGC boundary itself: if it points inside
+/// an EGC, the start of that EGC is returned.
+/// 
+/// Panics if `from` is out of bounds or not on a `char` boundary.
+pub fn prev_boundary(s: &str, from: usize) -> usize {
+	// Boundaries before `from` only depend on the text before it.
+	let rest = &s[..from];
+	let (mut i, maybe_skipped) = last_local_boundary(rest);
+	if maybe_skipped {
+		loop {
+			let end = i + first_boundary(&rest[i..]);
+			if end == from {
+				break;
+			}
+			i = end;
+		}
+	}
+	i
+}
+
+/// A backward scan which determines the context at some offset,
+/// ie. the [Ctx] after the last character before the offset.
+/// 
+/// The text before the offset can be fed in several pieces,
+/// starting from the piece closest to the offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ContextScan {
+	/// Properties of the character right before the offset
+	last: Option<PackedProps>,
+	/// Number of Extend/ZWJ characters seen so far
+	tail_len: usize,
+	/// Whether the Extend/ZWJ characters seen so far match GB9c
+	indic_ok: bool,
+	/// Whether an InCB=Linker character has been seen
+	has_linker: bool,
+	/// Whether the Extend/ZWJ characters seen so far match GB11
+	emoji_ok: bool,
+	/// Whether the character right before the offset is a ZWJ
+	zwj_last: bool,
+	/// Number of consecutive RI characters seen so far
+	ri_count: usize,
+	result: Option<Ctx>,
+}
+
+impl ContextScan {
+	pub fn new() -> Self {
+		ContextScan {
+			last: None,
+			tail_len: 0,
+			indic_ok: true,
+			has_linker: false,
+			emoji_ok: true,
+			zwj_last: false,
+			ri_count: 0,
+			result: None,
+		}
+	}
+
+	/// Returns the context, if it has been determined.
+	pub fn result(&self) -> Option<Ctx> {
+		self.result
+	}
+
+	/// Returns the properties of the character right before the offset, if any has been fed.
+	pub fn last(&self) -> Option<PackedProps> {
+		self.last
+	}
+
+	/// Feeds the text preceding the text fed so far.
+	/// 
+	/// Returns the context if it could be determined.
+	pub fn feed(&mut self, s: &str) -> Option<Ctx> {
+		if self.result.is_some() {
+			return self.result;
+		}
+		for c in s.chars().rev() {
+			let p = lookup_packed_props(c);
+			if self.last.is_none() {
+				self.last = Some(p);
+			}
+			let gcb = p.gcb();
+			if self.ri_count > 0 {
+				if gcb == G::RegionalIndicator {
+					self.ri_count += 1;
+					continue;
+				}
+				return Some(self.finish());
+			}
+			if matches!(gcb, G::Extend | G::ZWJ) {
+				let first = self.tail_len == 0;
+				self.indic_ok &= matches!(p.incb(), I::Extend | I::Linker);
+				self.has_linker |= p.incb() == I::Linker;
+				self.emoji_ok &= gcb != G::ZWJ || first;
+				self.zwj_last |= first && gcb == G::ZWJ;
+				self.tail_len += 1;
+				continue;
+			}
+			if gcb == G::RegionalIndicator && self.tail_len == 0 {
+				self.ri_count = 1;
+				continue;
+			}
+			let ctx = if p.incb() == I::Consonant && self.indic_ok {
+				Ctx::Indic(self.has_linker)
+			} else if p.is_ext_pict() && self.emoji_ok {
+				Ctx::Emoji(self.zwj_last)
+			} else {
+				Ctx::Start
+			};
+			self.result = Some(ctx);
+			return self.result;
+		}
+		None
+	}
+
+	/// Signals that the start of the text has been reached,
+	/// and returns the context.
+	pub fn finish(&mut self) -> Ctx {
+		*self.result.get_or_insert(if self.ri_count % 2 == 1 { Ctx::Ri } else { Ctx::Start })
+	}
+}
+
+/// A push-based EGC segmenter, for text which is not available all at once.
+/// 
+/// Characters are fed one at a time with [push_char](Segmenter::push_char),
+/// or by fragments with [push_str](Segmenter::push_str), and EGC boundaries are
+/// reported as soon as they can be determined, that is when the character
+/// following them is pushed. Boundaries are reported as byte offsets in the
+/// UTF-8 concatenation of everything pushed so far.
+/// 
+/// The start of the text is not reported as a boundary, and the end of the
+/// text is reported by [finish](Segmenter::finish).
+/// 
+/// The rules can be tailored with [with_tailoring](Segmenter::with_tailoring).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segmenter<T = NoTailoring> {
+	state: SegState,
+	offset: usize,
+	tailoring: T,
+}
+
+/// The state of a [Segmenter], which summarizes the text pushed so far.
+/// 
+/// This can be saved to resume segmentation later, for instance when processing a
+/// large text split into shards. With the `serde` feature, it can be serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SegState {
+	ctx: Ctx,
+	/// Last character pushed, and its properties
+	last: Option<(char, PackedProps)>,
+}
+
+impl SegState {
+	/// The state at the start of a text.
+	pub const START: SegState = SegState { ctx: Ctx::Start, last: None };
+
+	/// Returns the context after the last character pushed.
+	pub fn ctx(&self) -> Ctx {
+		self.ctx
+	}
+
+	/// Returns the properties of the last character pushed, if any.
+	pub fn last(&self) -> Option<PackedProps> {
+		self.last.map(|(_, p)| p)
+	}
+}
+
+impl Default for Segmenter {
+	fn default() -> Self {
+		Segmenter::new()
+	}
+}
+
+impl Segmenter {
+	/// Creates a segmenter at the start of a text.
+	pub fn new() -> Self {
+		Segmenter::with_tailoring(NoTailoring)
+	}
+
+	/// Creates a segmenter resuming from a saved state, at byte offset `offset`.
+	pub fn resume(state: SegState, offset: usize) -> Self {
+		Segmenter { state, offset, tailoring: NoTailoring }
+	}
+}
+
+impl<T: BreakTailoring> Segmenter<T> {
+	/// Creates a segmenter at the start of a text, which determines boundaries with the given tailoring.
+	pub fn with_tailoring(tailoring: T) -> Self {
+		Segmenter { state: SegState::START, offset: 0, tailoring }
+	}
+
+	/// Like [resume](Segmenter::resume), with the given tailoring, which should be
+	/// the one the state was saved with.
+	pub fn resume_with_tailoring(state: SegState, offset: usize, tailoring: T) -> Self {
+		Segmenter { state, offset, tailoring }
+	}
+
+	/// Returns the current state, which can be used to [resume](Segmenter::resume) later.
+	pub fn state(&self) -> SegState {
+		self.state
+	}
+
+	/// Returns the number of bytes pushed so far.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the tailoring of the rules.
+	pub fn tailoring(&self) -> &T {
+		&self.tailoring
+	}
+
+	/// Pushes a character, and returns the offset of the EGC boundary before it, if there is one.
+	pub fn push_char(&mut self, c: char) -> Option<usize> {
+		self.push_props(c, self.tailoring.props(c), c.len_utf8())
+	}
+
+	/// Pushes a unit of `len` bytes segmented as `c` with properties `p2`
+	/// (eg. invalid UTF-8), and returns the offset of the EGC boundary before it, if there is one.
+	pub(crate) fn push_props(&mut self, c: char, p2: PackedProps, len: usize) -> Option<usize> {
+		let SegState { ctx, last } = self.state;
+		let boundary = match last {
+			Some((c1, p1)) if self.tailoring.is_boundary(ctx, c1, p1, c, p2) => Some(self.offset),
+			_ => None,
+		};
+		self.state = SegState { ctx: ctx.step_packed(p2), last: Some((c, p2)) };
+		self.offset += len;
+		boundary
+	}
+
+	/// Pushes a fragment of text, and returns an iterator over the EGC boundaries
+	/// which can be determined within it.
+	/// 
+	/// The fragment is only fully pushed once the iterator has been exhausted.
+	pub fn push_str<'a>(&'a mut self, s: &'a str) -> PushStr<'a, T> {
+		PushStr { seg: self, chars: s.chars() }
+	}
+
+	/// Signals the end of the text, and returns its offset if it is an EGC boundary,
+	/// ie. if any character was pushed.
+	/// 
+	/// The segmenter is then reset to the start of a new text.
+	pub fn finish(&mut self) -> Option<usize> {
+		let end = self.state.last.map(|_| self.offset);
+		self.state = SegState::START;
+		self.offset = 0;
+		end
+	}
+}
+
+/// Iterator returned by [Segmenter::push_str].
+#[derive(Debug)]
+pub struct PushStr<'a, T = NoTailoring> {
+	seg: &'a mut Segmenter<T>,
+	chars: core::str::Chars<'a>,
+}
+impl<T: BreakTailoring> Iterator for PushStr<'_, T> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		for c in self.chars.by_ref() {
+			if let Some(boundary) = self.seg.push_char(c) {
+				return Some(boundary);
+			}
+		}
+		None
+	}
+}
@@ -1,116 +1,802 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use core::str::FromStr;
+
 /// Enum summarizing the three character properties relevant for EGC segmentation.
 /// 
 /// - Grapheme_Cluster_Break (GCB)
 /// - Indic_Conjunct_Break (InCB)
 /// - Extended_Pictographic (ExtPict)
 /// 
-/// To keep the data tables light, we only have enum variants for the
-/// combinations of property values that actually appear in the Unicode Character Database.
+/// We only have enum variants for the combinations of property values that actually appear
+/// in the Unicode Character Database. The data tables and the segmentation rules use
+/// [PackedProps] instead, which can represent any combination: this enum is a view of it,
+/// kept for compatibility.
+/// 
+/// The values are meant to be exchanged with other programs: each variant has a stable
+/// numeric [code](EgcProps::code) and [name](EgcProps::name) (also used by [Display](fmt::Display),
+/// [FromStr], and serialization with the `serde` feature), which will not change across
+/// versions of this crate. The names of the underlying properties in the Unicode Character
+/// Database are given by the [Display](fmt::Display) implementations of [gcb](EgcProps::gcb)
+/// and [incb](EgcProps::incb). Future versions of Unicode may require new variants, which will
+/// get new codes: this is why the enum is `#[non_exhaustive]`.
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum EgcProps {
 	/// Default
-	XX,
+	XX = 0,
 	
 	/// GCB=LF  (line feed)
-	LF,
+	LF = 1,
 	/// GCB=CR  (carriage return)
-	CR,
+	CR = 2,
 	/// GCB=Control
-	CN,
+	CN = 3,
 	/// GCB=L   (hangul leading consonant)
 	
-	L,
+	L = 4,
 	/// GCB=V   (hangul vowel)
-	V,
+	V = 5,
 	/// GCB=T   (hangul trailing consonant)
-	T,
+	T = 6,
 	/// GCB=LV  (hangul precombined leading + vowel)
-	LV,
+	LV = 7,
 	/// GCB=LVT (hangul precombined leading + vowel + trailing)
-	LVT,
+	LVT = 8,
 
 	/// GCB=SpacingMark
-	SM,
+	SM = 9,
 	/// GCB=Prepend
-	PP,
+	PP = 10,
 	
 	/// InCB=Consonant
-	IN_CO,
+	IN_CO = 11,
 	/// GCB=ZWJ, InCB=Extend (zero-width joiner)
-	ZWJ,
+	ZWJ = 12,
 	/// GCB=Extend, InCB=Extend
-	IN_EX,
+	IN_EX = 13,
 	/// GCB=Extend, InCB=Linker
-	IN_LI,
+	IN_LI = 14,
 	/// GCB=Extend, InCB=None
-	EX,
+	EX = 15,
 
 	/// ExtPict=Yes
-	EP,
+	EP = 16,
 	
 	/// GCB=Regional_Indicator
-	RI,
+	RI = 17,
 }
 
 impl EgcProps {
+	/// All variants, in the order of their codes.
+	pub const ALL: [EgcProps; 18] = [
+		EgcProps::XX, EgcProps::LF, EgcProps::CR, EgcProps::CN,
+		EgcProps::L, EgcProps::V, EgcProps::T, EgcProps::LV, EgcProps::LVT,
+		EgcProps::SM, EgcProps::PP, EgcProps::IN_CO, EgcProps::ZWJ,
+		EgcProps::IN_EX, EgcProps::IN_LI, EgcProps::EX, EgcProps::EP, EgcProps::RI,
+	];
+
+	/// Returns the stable numeric code of the variant.
+	pub const fn code(self) -> u8 {
+		self as u8
+	}
+	/// Returns the variant with the given code, if any.
+	pub const fn from_code(code: u8) -> Option<EgcProps> {
+		if (code as usize) < EgcProps::ALL.len() {
+			Some(EgcProps::ALL[code as usize])
+		} else {
+			None
+		}
+	}
+	/// Returns the stable name of the variant, which is the name of the Rust variant (eg. "IN_CO").
+	pub const fn name(self) -> &'static str {
+		const NAMES: [&str; 18] = ["XX", "LF", "CR", "CN", "L", "V", "T", "LV", "LVT", "SM", "PP", "IN_CO", "ZWJ", "IN_EX", "IN_LI", "EX", "EP", "RI"];
+		NAMES[self as usize]
+	}
+
 	/// Is character a control character (LF, CR, CN)
-	pub fn is_control(self) -> bool {
-		EgcProps::LF <= self && self <= EgcProps::CN
+	pub const fn is_control(self) -> bool {
+		matches!(self, EgcProps::LF | EgcProps::CR | EgcProps::CN)
 	}
 	/// Is character hangul (L, V, T, LV, LVT)
-	pub fn is_hangul(self) -> bool {
-		EgcProps::L <= self && self <= EgcProps::LVT
+	pub const fn is_hangul(self) -> bool {
+		matches!(self, EgcProps::L | EgcProps::V | EgcProps::T | EgcProps::LV | EgcProps::LVT)
 	}
 
 	/// Does the character have InCB=Extend (ZWJ, IN_EX)
-	pub fn is_incb_ex(self) -> bool {
-		EgcProps::ZWJ <= self && self <= EgcProps::IN_EX
+	pub const fn is_incb_ex(self) -> bool {
+		matches!(self, EgcProps::ZWJ | EgcProps::IN_EX)
 	}
 	/// Does the character have GCB=Extend (IN_EX, IN_LI, EX)
-	pub fn is_gcb_ex(self) -> bool {
-		EgcProps::IN_EX <= self && self <= EgcProps::EX
+	pub const fn is_gcb_ex(self) -> bool {
+		matches!(self, EgcProps::IN_EX | EgcProps::IN_LI | EgcProps::EX)
+	}
+}
+
+/// Values of the Grapheme_Cluster_Break (GCB) property, as listed in the Unicode Character Database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum GraphemeClusterBreak {
+	/// Default
+	Other,
+	/// Carriage return
+	CR,
+	/// Line feed
+	LF,
+	/// Other control characters and separators
+	Control,
+	/// Combining marks and other extending characters
+	Extend,
+	/// Zero-width joiner
+	ZWJ,
+	/// Regional indicator symbols, used in pairs for flags
+	RegionalIndicator,
+	/// Prepended concatenation marks
+	Prepend,
+	/// Spacing combining marks
+	SpacingMark,
+	/// Hangul leading consonant
+	L,
+	/// Hangul vowel
+	V,
+	/// Hangul trailing consonant
+	T,
+	/// Hangul precombined leading + vowel
+	LV,
+	/// Hangul precombined leading + vowel + trailing
+	LVT,
+}
+
+impl GraphemeClusterBreak {
+	/// All values, in the order of the enum.
+	pub const ALL: [GraphemeClusterBreak; 14] = {
+		use GraphemeClusterBreak as G;
+		[G::Other, G::CR, G::LF, G::Control, G::Extend, G::ZWJ, G::RegionalIndicator,
+			G::Prepend, G::SpacingMark, G::L, G::V, G::T, G::LV, G::LVT]
+	};
+}
+
+impl GraphemeClusterBreak {
+	/// Returns the long name of the value in the Unicode Character Database (eg. "Regional_Indicator").
+	pub const fn ucd_name(self) -> &'static str {
+		const NAMES: [&str; 14] = ["Other", "CR", "LF", "Control", "Extend", "ZWJ", "Regional_Indicator",
+			"Prepend", "SpacingMark", "L", "V", "T", "LV", "LVT"];
+		NAMES[self as usize]
+	}
+	/// Returns the short alias of the value in the Unicode Character Database (eg. "RI").
+	pub const fn ucd_short_name(self) -> &'static str {
+		const NAMES: [&str; 14] = ["XX", "CR", "LF", "CN", "EX", "ZWJ", "RI", "PP", "SM", "L", "V", "T", "LV", "LVT"];
+		NAMES[self as usize]
+	}
+}
+
+/// Displays the [long UCD name](GraphemeClusterBreak::ucd_name) of the value.
+impl fmt::Display for GraphemeClusterBreak {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.ucd_name())
+	}
+}
+
+/// Parses the long name or short alias of the value, with the loose matching of UAX #44
+/// (ignoring case, spaces, hyphens and underscores).
+impl FromStr for GraphemeClusterBreak {
+	type Err = ParsePropertyError;
+	fn from_str(s: &str) -> Result<Self, ParsePropertyError> {
+		GraphemeClusterBreak::ALL.into_iter()
+			.find(|g| loose_eq(g.ucd_name(), s) || loose_eq(g.ucd_short_name(), s))
+			.ok_or(ParsePropertyError)
+	}
+}
+
+impl From<EgcProps> for GraphemeClusterBreak {
+	fn from(props: EgcProps) -> Self {
+		props.gcb()
+	}
+}
+
+/// Values of the Indic_Conjunct_Break (InCB) property, as listed in the Unicode Character Database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum IndicConjunctBreak {
+	/// Default
+	None,
+	/// Virama, linking consonants into a conjunct
+	Linker,
+	/// Consonant which can take part in a conjunct
+	Consonant,
+	/// Combining mark allowed within a conjunct
+	Extend,
+}
+
+impl IndicConjunctBreak {
+	/// All values, in the order of the enum.
+	pub const ALL: [IndicConjunctBreak; 4] = [
+		IndicConjunctBreak::None, IndicConjunctBreak::Linker, IndicConjunctBreak::Consonant, IndicConjunctBreak::Extend,
+	];
+
+	/// Returns the name of the value in the Unicode Character Database (eg. "Linker").
+	pub const fn ucd_name(self) -> &'static str {
+		const NAMES: [&str; 4] = ["None", "Linker", "Consonant", "Extend"];
+		NAMES[self as usize]
+	}
+}
+
+/// Displays the [UCD name](IndicConjunctBreak::ucd_name) of the value.
+impl fmt::Display for IndicConjunctBreak {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.ucd_name())
+	}
+}
+
+/// Parses the name of the value, with the loose matching of UAX #44.
+impl FromStr for IndicConjunctBreak {
+	type Err = ParsePropertyError;
+	fn from_str(s: &str) -> Result<Self, ParsePropertyError> {
+		IndicConjunctBreak::ALL.into_iter().find(|i| loose_eq(i.ucd_name(), s)).ok_or(ParsePropertyError)
+	}
+}
+
+/// Error returned when parsing an unknown property value name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsePropertyError;
+
+impl fmt::Display for ParsePropertyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("unknown property value name")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePropertyError {}
+
+/// Are the names equal, ignoring case, spaces, hyphens and underscores (rule UAX44-LM3)
+fn loose_eq(a: &str, b: &str) -> bool {
+	fn key(s: &str) -> impl Iterator<Item = u8> + '_ {
+		s.bytes().filter(|b| !matches!(b, b' ' | b'-' | b'_')).map(|b| b.to_ascii_lowercase())
+	}
+	key(a).eq(key(b))
+}
+
+impl EgcProps {
+	/// Returns the Grapheme_Cluster_Break property of characters with these properties.
+	pub const fn gcb(self) -> GraphemeClusterBreak {
+		use GraphemeClusterBreak as G;
+		match self {
+			EgcProps::XX | EgcProps::IN_CO | EgcProps::EP => G::Other,
+			EgcProps::LF => G::LF,
+			EgcProps::CR => G::CR,
+			EgcProps::CN => G::Control,
+			EgcProps::L => G::L,
+			EgcProps::V => G::V,
+			EgcProps::T => G::T,
+			EgcProps::LV => G::LV,
+			EgcProps::LVT => G::LVT,
+			EgcProps::SM => G::SpacingMark,
+			EgcProps::PP => G::Prepend,
+			EgcProps::ZWJ => G::ZWJ,
+			EgcProps::IN_EX | EgcProps::IN_LI | EgcProps::EX => G::Extend,
+			EgcProps::RI => G::RegionalIndicator,
+		}
+	}
+	/// Returns the Indic_Conjunct_Break property of characters with these properties.
+	pub const fn incb(self) -> IndicConjunctBreak {
+		match self {
+			EgcProps::IN_CO => IndicConjunctBreak::Consonant,
+			EgcProps::IN_LI => IndicConjunctBreak::Linker,
+			EgcProps::ZWJ | EgcProps::IN_EX => IndicConjunctBreak::Extend,
+			_ => IndicConjunctBreak::None,
+		}
+	}
+	/// Do characters with these properties have Extended_Pictographic=Yes
+	pub const fn is_ext_pict(self) -> bool {
+		matches!(self, EgcProps::EP)
+	}
+}
+
+/// The three character properties relevant for EGC segmentation, packed independently
+/// in a byte. This is what the data tables store, and what the segmentation rules read.
+/// 
+/// Any combination of values can be represented, including ones which do not appear
+/// in the current version of Unicode, so that new versions do not require changes
+/// to the rules or new [EgcProps] variants. The layout of the [bits](PackedProps::bits) is:
+/// GCB in bits 0 to 3 (in the order of [GraphemeClusterBreak::ALL]), InCB in bits 4 and 5
+/// (in the order of [IndicConjunctBreak::ALL]), and ExtPict in bit 6.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedProps(pub(crate) u8);
+
+impl PackedProps {
+	const INCB_SHIFT: u8 = 4;
+	const EXT_PICT: u8 = 1 << 6;
+
+	/// Packs the given property values.
+	pub const fn new(gcb: GraphemeClusterBreak, incb: IndicConjunctBreak, ext_pict: bool) -> Self {
+		let ext_pict = if ext_pict { PackedProps::EXT_PICT } else { 0 };
+		PackedProps(gcb as u8 | (incb as u8) << PackedProps::INCB_SHIFT | ext_pict)
+	}
+
+	/// Returns the packed representation.
+	pub const fn bits(self) -> u8 {
+		self.0
+	}
+	/// Returns the properties with the given packed representation, if it is valid.
+	pub const fn from_bits(bits: u8) -> Option<Self> {
+		let valid = ((bits & 0xf) as usize) < GraphemeClusterBreak::ALL.len() && bits & 0x80 == 0;
+		if valid { Some(PackedProps(bits)) } else { None }
+	}
+
+	/// Returns the Grapheme_Cluster_Break property.
+	pub const fn gcb(self) -> GraphemeClusterBreak {
+		use GraphemeClusterBreak as G;
+		// (a match rather than an index in ALL, which needs no bounds check)
+		match self.0 & 0xf {
+			0 => G::Other,
+			1 => G::CR,
+			2 => G::LF,
+			3 => G::Control,
+			4 => G::Extend,
+			5 => G::ZWJ,
+			6 => G::RegionalIndicator,
+			7 => G::Prepend,
+			8 => G::SpacingMark,
+			9 => G::L,
+			10 => G::V,
+			11 => G::T,
+			12 => G::LV,
+			_ => G::LVT,
+		}
+	}
+	/// Returns the Indic_Conjunct_Break property.
+	pub const fn incb(self) -> IndicConjunctBreak {
+		IndicConjunctBreak::ALL[(self.0 >> PackedProps::INCB_SHIFT & 0x3) as usize]
+	}
+	/// Is Extended_Pictographic=Yes
+	pub const fn is_ext_pict(self) -> bool {
+		self.0 & PackedProps::EXT_PICT != 0
+	}
+
+	/// Packs the property values of an [EgcProps] variant.
+	pub const fn from_egc_props(props: EgcProps) -> Self {
+		PackedProps::new(props.gcb(), props.incb(), props.is_ext_pict())
+	}
+
+	/// Returns the [EgcProps] variant for this combination of values, or None if it
+	/// does not have one (because no character has it in the supported version of Unicode).
+	pub const fn to_egc_props(self) -> Option<EgcProps> {
+		let props = self.egc_props();
+		if PackedProps::from_egc_props(props).0 == self.0 { Some(props) } else { None }
+	}
+
+	/// Returns the [EgcProps] variant for this combination of values, or if it does not
+	/// have one, the first variant with the same Grapheme_Cluster_Break property.
+	pub const fn egc_props(self) -> EgcProps {
+		const VIEW: [EgcProps; 128] = {
+			let mut view = [EgcProps::XX; 128];
+			let mut bits = 0;
+			while bits < 128 {
+				// Exact match, or else the first variant with the same GCB
+				let mut i = EgcProps::ALL.len();
+				while i > 0 {
+					i -= 1;
+					let packed = PackedProps::from_egc_props(EgcProps::ALL[i]).0 as usize;
+					if packed & 0xf == bits & 0xf && PackedProps::from_egc_props(view[bits]).0 as usize != bits {
+						view[bits] = EgcProps::ALL[i];
+					}
+				}
+				bits += 1;
+			}
+			view
+		};
+		VIEW[self.0 as usize]
+	}
+}
+
+impl From<EgcProps> for PackedProps {
+	fn from(props: EgcProps) -> Self {
+		PackedProps::from_egc_props(props)
+	}
+}
+
+impl From<PackedProps> for EgcProps {
+	fn from(props: PackedProps) -> Self {
+		props.egc_props()
+	}
+}
+
+/// Looks up the character properties of `c` that are relevant to EGCs, packed independently.
+/// 
+/// With the `fast-lookup` feature, this uses a two-stage table instead of
+/// a binary search in [RANGES], at the cost of about 30 kB of extra data.
+#[cfg(feature = "fast-lookup")]
+pub fn lookup_packed_props(c: char) -> PackedProps {
+	use crate::lookup_data::{PAGE_SIZE, STAGE1, STAGE2};
+	let cp = c as usize;
+	let page = STAGE1[cp / PAGE_SIZE] as usize;
+	PackedProps(STAGE2[page * PAGE_SIZE + cp % PAGE_SIZE])
+}
+
+/// Looks up the character properties of `c` that are relevant to EGCs, packed independently.
+#[cfg(not(feature = "fast-lookup"))]
+pub fn lookup_packed_props(c: char) -> PackedProps {
+	search_packed_props(c)
+}
+
+/// Returns the Grapheme_Cluster_Break property of `c`.
+pub fn gcb(c: char) -> GraphemeClusterBreak {
+	lookup_packed_props(c).gcb()
+}
+
+/// Returns an iterator over all code points (including surrogates) split into maximal
+/// ranges with the same Grapheme_Cluster_Break property, in order.
+/// 
+/// This lists the property as in the Unicode Character Database, for tools inspecting it:
+/// unlike [RANGES], precomposed Hangul syllables and surrogates are included, and
+/// characters which only differ by other properties are merged.
+pub fn gcb_ranges() -> GcbRanges {
+	GcbRanges { cp: 0 }
+}
+
+/// An iterator over ranges of code points with the same Grapheme_Cluster_Break property.
+/// 
+/// Obtained with [gcb_ranges].
+#[derive(Debug, Clone)]
+pub struct GcbRanges {
+	// Start of the next range
+	cp: u32,
+}
+
+impl GcbRanges {
+	/// Returns the end of the interval of code points starting at `cp` with the same
+	/// properties, and its Grapheme_Cluster_Break property
+	fn interval(cp: u32) -> (u32, GraphemeClusterBreak) {
+		match cp {
+			0xac00..=0xd7a3 => {
+				// LV followed by 27 LVT
+				let lv = 0xac00 + (cp - 0xac00) / 28 * 28;
+				if cp == lv { (lv + 1, GraphemeClusterBreak::LV) } else { (lv + 28, GraphemeClusterBreak::LVT) }
+			},
+			0xd800..=0xdfff => (0xe000, GraphemeClusterBreak::Control),
+			_ => {
+				let (_, mut end, props) = search_interval(cp);
+				for limit in [0xac00, 0xd800] {
+					if cp < limit {
+						end = end.min(limit);
+					}
+				}
+				(end, props.gcb())
+			},
+		}
+	}
+}
+
+impl Iterator for GcbRanges {
+	type Item = (Range<u32>, GraphemeClusterBreak);
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.cp;
+		if start >= 0x110000 {
+			return None;
+		}
+		let (mut end, gcb) = Self::interval(start);
+		while end < 0x110000 {
+			let (next_end, next_gcb) = Self::interval(end);
+			if next_gcb != gcb {
+				break;
+			}
+			end = next_end;
+		}
+		self.cp = end;
+		Some((start..end, gcb))
+	}
+}
+impl FusedIterator for GcbRanges {}
+
+/// Returns the Indic_Conjunct_Break property of `c`.
+pub fn incb(c: char) -> IndicConjunctBreak {
+	lookup_packed_props(c).incb()
+}
+
+/// Does `c` have Extended_Pictographic=Yes
+pub fn is_ext_pict(c: char) -> bool {
+	lookup_packed_props(c).is_ext_pict()
+}
+
+/// Is `c` a regional indicator symbol (GCB=Regional_Indicator), used in pairs for flags
+pub fn is_regional_indicator(c: char) -> bool {
+	matches!(lookup_packed_props(c).gcb(), GraphemeClusterBreak::RegionalIndicator)
+}
+
+/// Is `c` the zero-width joiner (GCB=ZWJ)
+pub fn is_zwj(c: char) -> bool {
+	matches!(lookup_packed_props(c).gcb(), GraphemeClusterBreak::ZWJ)
+}
+
+/// Does `c` have InCB=Consonant
+pub fn is_incb_consonant(c: char) -> bool {
+	matches!(lookup_packed_props(c).incb(), IndicConjunctBreak::Consonant)
+}
+
+/// Does `c` have InCB=Linker
+pub fn is_incb_linker(c: char) -> bool {
+	matches!(lookup_packed_props(c).incb(), IndicConjunctBreak::Linker)
+}
+
+/// Does `c` have InCB=Extend
+pub fn is_incb_extend(c: char) -> bool {
+	matches!(lookup_packed_props(c).incb(), IndicConjunctBreak::Extend)
+}
+
+impl fmt::Display for EgcProps {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+/// Error returned when parsing an unknown [EgcProps] name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEgcPropsError;
+
+impl fmt::Display for ParseEgcPropsError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("unknown EGC properties name")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEgcPropsError {}
+
+impl FromStr for EgcProps {
+	type Err = ParseEgcPropsError;
+	fn from_str(s: &str) -> Result<EgcProps, ParseEgcPropsError> {
+		EgcProps::ALL.into_iter().find(|p| p.name() == s).ok_or(ParseEgcPropsError)
 	}
 }
 
 /// A range of Unicode codepoints, and the associated EGC-related properties.
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct CharRange {
 	pub start: u32,
 	pub count: u16,
 	pub kind: EgcProps,
+	/// The same properties, packed independently: this is what lookups return
+	pub packed: PackedProps,
+}
+
+impl CharRange {
+	/// Creates a range of `count` codepoints from `start`, with the properties of an [EgcProps] variant.
+	pub const fn new(start: u32, count: u16, kind: EgcProps) -> Self {
+		CharRange { start, count, kind, packed: PackedProps::from_egc_props(kind) }
+	}
 }
+
 pub(crate) const fn ran(start: u32, count: u16, kind: EgcProps) -> CharRange {
-	CharRange { start, count, kind }
+	CharRange::new(start, count, kind)
 }
 
 /// The main data table.
 pub use crate::data::RANGES;
 
+/// Returns an iterator over all ranges of characters with the given properties, in order.
+/// 
+/// Unlike [RANGES], this covers precomposed Hangul syllables and, for [XX](EgcProps::XX),
+/// the characters between the ranges (split so that they fit in a [CharRange]).
+pub fn ranges_with(props: EgcProps) -> impl Iterator<Item = CharRange> {
+	all_ranges().filter(move |r| r.kind == props)
+}
+
+/// Returns an iterator over all characters with the given properties, in order.
+pub fn chars_with(props: EgcProps) -> impl Iterator<Item = char> {
+	ranges_with(props).flat_map(|r| (r.start..r.start + r.count as u32).filter_map(char::from_u32))
+}
+
+/// Returns an iterator over ranges covering all characters, in order.
+fn all_ranges() -> impl Iterator<Item = CharRange> {
+	let mut cp = 0u32;
+	let mut i = 0;
+	core::iter::from_fn(move || {
+		if cp == 0xd800 {
+			cp = 0xe000;
+		}
+		if cp >= 0x110000 {
+			return None;
+		}
+		let range = if (0xac00..=0xd7a3).contains(&cp) {
+			// LV followed by 27 LVT
+			if (cp - 0xac00) % 28 == 0 { ran(cp, 1, EgcProps::LV) } else { ran(cp, 27, EgcProps::LVT) }
+		} else if RANGES.get(i).is_some_and(|r| r.start == cp) {
+			i += 1;
+			RANGES[i - 1]
+		} else {
+			let mut end = RANGES.get(i).map_or(0x110000, |r| r.start).min(cp + u16::MAX as u32);
+			for limit in [0xac00, 0xd800] {
+				if cp < limit {
+					end = end.min(limit);
+				}
+			}
+			ran(cp, (end - cp) as u16, EgcProps::XX)
+		};
+		cp = range.start + range.count as u32;
+		Some(range)
+	})
+}
+
 /// Looks up the character properties of `c` that are relevant to EGCs.
+/// 
+/// This is a view of [lookup_packed_props].
 pub fn lookup_egc_props(c: char) -> EgcProps {
+	lookup_packed_props(c).egc_props()
+}
+
+/// Like [lookup_egc_props], but accepts any code point, for text which cannot be
+/// represented with `char`s (eg. WTF-16, or fuzzer inputs).
+/// 
+/// Surrogates have GCB=Control, as in the Unicode Character Database. Returns None
+/// for values above U+10FFFF.
+pub fn lookup_egc_props_u32(cp: u32) -> Option<EgcProps> {
+	match char::from_u32(cp) {
+		Some(c) => Some(lookup_egc_props(c)),
+		None if (0xd800..=0xdfff).contains(&cp) => Some(EgcProps::CN),
+		None => None,
+	}
+}
+
+const XX: PackedProps = PackedProps::from_egc_props(EgcProps::XX);
+const LV: PackedProps = PackedProps::from_egc_props(EgcProps::LV);
+const LVT: PackedProps = PackedProps::from_egc_props(EgcProps::LVT);
+
+/// Looks up the character properties of `c` with a binary search in [RANGES].
+#[cfg_attr(all(feature = "fast-lookup", not(test)), allow(dead_code))]
+pub(crate) fn search_packed_props(c: char) -> PackedProps {
 	let cp = c as u32;
-	if (cp >= 0x20 && cp < 0x7f) || (cp >= 0x3300 && cp < 0xa000) {
+	if (0x20..0x7f).contains(&cp) || (0x3300..0xa000).contains(&cp) {
 		// fast path for printable ASCII and CJK characters
-		return EgcProps::XX;
+		return XX;
 	}
-	if cp >= 0xac00 && cp <= 0xd7a3 {
+	if (0xac00..=0xd7a3).contains(&cp) {
 		// precomposed hangul makes up most of the data but is very predictable: don't store it
-		return if (cp - 0xac00) % 28 == 0 { EgcProps::LV } else { EgcProps::LVT };
-	}
-	let mut start = 0usize;
-	let mut end = RANGES.len();
-	while end > start {
-		let pivot_idx = (start + end) / 2;
-		let pivot = RANGES[pivot_idx];
-		if cp < pivot.start {
-			end = pivot_idx;
-		} else if cp >= pivot.start + pivot.count as u32 {
-			start = pivot_idx + 1;
+		return if (cp - 0xac00) % 28 == 0 { LV } else { LVT };
+	}
+	search_interval(cp).2
+}
+
+/// Finds the largest interval of codepoints `start..end` around `cp` which is either
+/// a range of [RANGES], or a gap between two of them, and returns it along with its properties.
+#[inline(never)]
+fn search_interval(cp: u32) -> (u32, u32, PackedProps) {
+	// Index of the first range starting after `cp`
+	let next = STARTS.partition_point(|&start| start <= cp);
+	let gap_end = STARTS.get(next).map_or(0x110000, |&start| start);
+	match next.checked_sub(1).map(|i| RANGES[i]) {
+		Some(range) if cp < range.start + range.count as u32 => (range.start, range.start + range.count as u32, range.packed),
+		// `cp` is in the gap before the range `next`
+		Some(range) => (range.start + range.count as u32, gap_end, XX),
+		None => (0, gap_end, XX),
+	}
+}
+
+/// Start codepoints of the ranges in [RANGES], which the binary search goes through.
+/// 
+/// Being contiguous, they take half as many cache lines as the ranges themselves,
+/// and the first levels of the search share even fewer.
+static STARTS: [u32; RANGES.len()] = {
+	let mut starts = [0; RANGES.len()];
+	let mut i = 0;
+	while i < RANGES.len() {
+		starts[i] = RANGES[i].start;
+		i += 1;
+	}
+	starts
+};
+
+/// A stateful version of [lookup_egc_props], which remembers the interval of codepoints
+/// with the same properties found by the last lookup, and checks it first.
+/// 
+/// Consecutive characters usually come from the same script block, so this turns
+/// most lookups into a single comparison. This is what the forward iterators use.
+/// With the `fast-lookup` feature, this simply calls [lookup_egc_props].
+#[derive(Debug, Clone, Copy)]
+pub struct PropsLookup {
+	/// Start, end, and properties of the last interval found
+	#[cfg(not(feature = "fast-lookup"))]
+	last: (u32, u32, PackedProps),
+}
+
+impl PropsLookup {
+	/// Creates a lookup helper with no memorized interval.
+	pub const fn new() -> Self {
+		PropsLookup {
+			#[cfg(not(feature = "fast-lookup"))]
+			last: (0, 0, XX),
+		}
+	}
+
+	/// Looks up the character properties of `c` that are relevant to EGCs.
+	#[inline]
+	pub fn lookup(&mut self, c: char) -> EgcProps {
+		self.lookup_packed(c).egc_props()
+	}
+
+	/// Looks up the character properties of `c` that are relevant to EGCs, packed independently.
+	#[cfg(feature = "fast-lookup")]
+	#[inline]
+	pub fn lookup_packed(&mut self, c: char) -> PackedProps {
+		lookup_packed_props(c)
+	}
+
+	/// Looks up the character properties of `c` that are relevant to EGCs, packed independently.
+	#[cfg(not(feature = "fast-lookup"))]
+	#[inline]
+	pub fn lookup_packed(&mut self, c: char) -> PackedProps {
+		let cp = c as u32;
+		let (start, end, kind) = self.last;
+		if cp.wrapping_sub(start) < end - start {
+			return kind;
+		}
+		if (0x20..0x7f).contains(&cp) || (0x3300..0xa000).contains(&cp) || (0xac00..=0xd7a3).contains(&cp) {
+			// Fast paths: do not forget the last interval
+			return search_packed_props(c);
+		}
+		let (mut start, mut end, kind) = search_interval(cp);
+		// Precomposed hangul is not in the table: keep it out of the interval
+		if cp < 0xac00 {
+			end = end.min(0xac00);
 		} else {
-			return pivot.kind;
+			start = start.max(0xd7a4);
 		}
+		self.last = (start, end, kind);
+		kind
 	}
-	return EgcProps::XX;
+}
+
+impl Default for PropsLookup {
+	fn default() -> Self {
+		PropsLookup::new()
+	}
+}
+
+/// Ranges of characters with Default_Ignorable_Code_Point=Yes (inclusive),
+/// from `DerivedCoreProperties.txt` in the UCD.
+/// 
+/// The property is the same in all the versions selected by the `unicode-*` features,
+/// so this table is shared by them.
+pub(crate) const DEFAULT_IGNORABLE: [(u32, u32); 17] = [
+	(0x000ad, 0x000ad),
+	(0x0034f, 0x0034f),
+	(0x0061c, 0x0061c),
+	(0x0115f, 0x01160),
+	(0x017b4, 0x017b5),
+	(0x0180b, 0x0180f),
+	(0x0200b, 0x0200f),
+	(0x0202a, 0x0202e),
+	(0x02060, 0x0206f),
+	(0x03164, 0x03164),
+	(0x0fe00, 0x0fe0f),
+	(0x0feff, 0x0feff),
+	(0x0ffa0, 0x0ffa0),
+	(0x0fff0, 0x0fff8),
+	(0x1bca0, 0x1bca3),
+	(0x1d173, 0x1d17a),
+	(0xe0000, 0xe0fff),
+];
+
+/// Does the character have Default_Ignorable_Code_Point=Yes
+/// 
+/// These characters should be invisible when not supported: joiners, variation
+/// selectors, bidirectional controls, fillers, tags, etc.
+pub fn is_default_ignorable(c: char) -> bool {
+	let cp = c as u32;
+	if cp < 0xad {
+		return false;
+	}
+	DEFAULT_IGNORABLE.iter().any(|&(start, end)| start <= cp && cp <= end)
+}
+
+/// Does the string only contain default-ignorable characters (and at least one)
+pub fn is_default_ignorable_str(s: &str) -> bool {
+	!s.is_empty() && s.chars().all(is_default_ignorable)
 }
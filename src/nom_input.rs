@@ -0,0 +1,134 @@
+use nom::error::{ErrorKind, ParseError};
+use nom::{Compare, CompareResult, Err, IResult, Input, Needed, Offset};
+
+use crate::iter::{EgcIndices, EgcSlices};
+use crate::Egc;
+
+/// A string used as input of [nom] parsers, whose elements are EGCs rather than `char`s.
+/// 
+/// Parsers which consume elements one by one (eg. `take_while`, or [egc]) thus never
+/// split an EGC, and [tag](nom::bytes::complete::tag) only matches strings which end
+/// on an EGC boundary of the input: `tag("e")` does not match an "e" followed by
+/// a combining accent. Offsets are counted in bytes, as with `&str` inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EgcInput<'a> {
+	str: &'a str,
+}
+
+impl<'a> EgcInput<'a> {
+	/// Wraps a string.
+	pub fn new(str: &'a str) -> Self {
+		EgcInput { str }
+	}
+
+	/// Returns the remaining input, as a string.
+	pub fn as_str(&self) -> &'a str {
+		self.str
+	}
+}
+
+impl<'a> From<&'a str> for EgcInput<'a> {
+	fn from(str: &'a str) -> Self {
+		EgcInput { str }
+	}
+}
+
+/// Iterator over the EGCs of an [EgcInput] and their offsets.
+#[derive(Clone)]
+pub struct EgcInputIndices<'a> {
+	str: &'a str,
+	inner: EgcIndices<'a>,
+	start: usize,
+}
+impl<'a> Iterator for EgcInputIndices<'a> {
+	type Item = (usize, &'a str);
+	fn next(&mut self) -> Option<(usize, &'a str)> {
+		let start = self.start;
+		self.start = self.inner.next()?;
+		Some((start, &self.str[start..self.start]))
+	}
+}
+
+impl<'a> Input for EgcInput<'a> {
+	type Item = &'a str;
+	type Iter = EgcSlices<'a>;
+	type IterIndices = EgcInputIndices<'a>;
+
+	fn input_len(&self) -> usize {
+		self.str.len()
+	}
+	fn take(&self, index: usize) -> Self {
+		EgcInput::new(&self.str[..index])
+	}
+	fn take_from(&self, index: usize) -> Self {
+		EgcInput::new(&self.str[index..])
+	}
+	fn take_split(&self, index: usize) -> (Self, Self) {
+		let (prefix, suffix) = self.str.split_at(index);
+		(EgcInput::new(suffix), EgcInput::new(prefix))
+	}
+	fn position<P>(&self, predicate: P) -> Option<usize>
+	where
+		P: Fn(Self::Item) -> bool,
+	{
+		self.iter_indices().find(|&(_, egc)| predicate(egc)).map(|(i, _)| i)
+	}
+	fn iter_elements(&self) -> Self::Iter {
+		self.str.egcs()
+	}
+	fn iter_indices(&self) -> Self::IterIndices {
+		EgcInputIndices { str: self.str, inner: self.str.egc_indices(), start: 0 }
+	}
+	fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+		self.str.checked_egc_split_at(count).map(|(prefix, _)| prefix.len()).ok_or(Needed::Unknown)
+	}
+}
+
+impl<'a, 'b> Compare<&'b str> for EgcInput<'a> {
+	fn compare(&self, t: &'b str) -> CompareResult {
+		self.on_boundary(self.str.compare(t), t.len())
+	}
+	fn compare_no_case(&self, t: &'b str) -> CompareResult {
+		self.on_boundary(self.str.compare_no_case(t), t.len())
+	}
+}
+
+impl EgcInput<'_> {
+	/// Turns a successful match of `len` bytes into an error if it would split an EGC.
+	fn on_boundary(&self, result: CompareResult, len: usize) -> CompareResult {
+		match result {
+			CompareResult::Ok if self.str.floor_egc_boundary(len) != len => CompareResult::Error,
+			result => result,
+		}
+	}
+}
+
+impl Offset for EgcInput<'_> {
+	fn offset(&self, second: &Self) -> usize {
+		self.str.offset(second.str)
+	}
+}
+
+/// Parses a single EGC.
+/// 
+/// Fails with [ErrorKind::Eof] if the input is empty.
+pub fn egc<'a, E: ParseError<EgcInput<'a>>>(input: EgcInput<'a>) -> IResult<EgcInput<'a>, &'a str, E> {
+	match input.str.egcs().next() {
+		Some(egc) => Ok((input.take_from(egc.len()), egc)),
+		None => Err(Err::Error(E::from_error_kind(input, ErrorKind::Eof))),
+	}
+}
+
+/// Returns a parser of a single EGC satisfying `predicate`.
+/// 
+/// The parser fails with [ErrorKind::Satisfy] if the EGC does not satisfy it,
+/// or with [ErrorKind::Eof] if the input is empty.
+pub fn egc_if<'a, E: ParseError<EgcInput<'a>>>(predicate: impl Fn(&str) -> bool) -> impl Fn(EgcInput<'a>) -> IResult<EgcInput<'a>, &'a str, E> {
+	move |input| {
+		let (rest, egc) = egc(input)?;
+		if !predicate(egc) {
+			return Err(Err::Error(E::from_error_kind(input, ErrorKind::Satisfy)));
+		}
+		Ok((rest, egc))
+	}
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::logic::next_local_boundary;
+use crate::Egc;
+
+/// Approximate size of the chunks segmented in parallel, in bytes
+const CHUNK_SIZE: usize = 1 << 16;
+
+/// Whether `s` is worth segmenting in parallel, rather than sequentially
+fn is_parallel(s: &str) -> bool {
+	s.len() >= 2 * CHUNK_SIZE && rayon::current_num_threads() > 1
+}
+
+/// Splits `s` into chunks of roughly [CHUNK_SIZE] bytes, at local boundaries,
+/// so that each chunk can be segmented independently.
+fn chunks(s: &str) -> Vec<Range<usize>> {
+	let mut chunks = Vec::with_capacity(s.len() / CHUNK_SIZE + 1);
+	let mut start = 0;
+	while start < s.len() {
+		let mut target = (start + CHUNK_SIZE).min(s.len());
+		while !s.is_char_boundary(target) {
+			target += 1;
+		}
+		let end = next_local_boundary(s, target);
+		chunks.push(start..end);
+		start = end;
+	}
+	chunks
+}
+
+/// Counts the EGCs of `s`, segmenting chunks of it in parallel.
+/// 
+/// The string is split at boundaries which do not depend on the text before them,
+/// so the result is the same as `s.egcs().count()`. Strings shorter than
+/// two chunks, or on a single thread, are segmented sequentially.
+pub fn par_egc_count(s: &str) -> usize {
+	if !is_parallel(s) {
+		return s.egc_count();
+	}
+	chunks(s).into_par_iter().map(|chunk| s[chunk].egc_indices().count()).sum()
+}
+
+/// Returns the offsets at the end of each EGC in `s`, like
+/// [egc_break_positions](Egc::egc_break_positions), segmenting chunks of it in parallel.
+pub fn par_egc_break_positions(s: &str) -> Vec<usize> {
+	if !is_parallel(s) {
+		return s.egc_break_positions();
+	}
+	let positions: Vec<Vec<usize>> = chunks(s)
+		.into_par_iter()
+		.map(|chunk| {
+			let mut positions = s[chunk.clone()].egc_break_positions();
+			for pos in &mut positions {
+				*pos += chunk.start;
+			}
+			positions
+		})
+		.collect();
+	positions.concat()
+}
@@ -0,0 +1,189 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::logic::{is_boundary, is_local_boundary, prev_boundary, Ctx};
+use crate::lookup::{EgcProps as EP, RANGES};
+use crate::Egc;
+
+/// Decides whether there is an EGC boundary before `props[i]`, in a sequence of
+/// characters with the given properties, by applying the rules of UAX #29 in order,
+/// as written in the standard.
+/// 
+/// This matches the rules against the whole sequence before the boundary, like the
+/// regular expressions of the standard, rather than keeping track of a context: it is
+/// slow, but simple enough to be checked against the standard by reading it.
+/// 
+/// Panics if `i` is not in `1..props.len()`.
+pub fn reference_is_boundary(props: &[EP], i: usize) -> bool {
+	assert!(i > 0 && i < props.len(), "{} is not an inner position", i);
+	let before = &props[..i];
+	let (p1, p2) = (props[i - 1], props[i]);
+	let is_extend = |p: EP| matches!(p, EP::IN_EX | EP::IN_LI | EP::EX);
+	let is_incb_extend = |p: EP| matches!(p, EP::IN_EX | EP::ZWJ);
+	let is_incb_linker = |p: EP| matches!(p, EP::IN_LI);
+	// GB3
+	if (p1, p2) == (EP::CR, EP::LF) {
+		return false;
+	}
+	// GB4, GB5
+	if matches!(p1, EP::CN | EP::CR | EP::LF) || matches!(p2, EP::CN | EP::CR | EP::LF) {
+		return true;
+	}
+	// GB6, GB7, GB8
+	if p1 == EP::L && matches!(p2, EP::L | EP::V | EP::LV | EP::LVT)
+		|| matches!(p1, EP::LV | EP::V) && matches!(p2, EP::V | EP::T)
+		|| matches!(p1, EP::LVT | EP::T) && p2 == EP::T {
+		return false;
+	}
+	// GB9
+	if is_extend(p2) || p2 == EP::ZWJ {
+		return false;
+	}
+	// GB9a, GB9b
+	if p2 == EP::SM || p1 == EP::PP {
+		return false;
+	}
+	// GB9c: Consonant [Extend Linker]* Linker [Extend Linker]* × Consonant
+	if p2 == EP::IN_CO {
+		let run = before.iter().rev().take_while(|&&p| is_incb_extend(p) || is_incb_linker(p)).count();
+		let has_linker = before[i - run..].iter().any(|&p| is_incb_linker(p));
+		if has_linker && run < i && before[i - run - 1] == EP::IN_CO {
+			return false;
+		}
+	}
+	// GB11: ExtPict Extend* ZWJ × ExtPict
+	if p1 == EP::ZWJ && p2 == EP::EP {
+		let run = before[..i - 1].iter().rev().take_while(|&&p| is_extend(p)).count();
+		if run < i - 1 && before[i - 2 - run] == EP::EP {
+			return false;
+		}
+	}
+	// GB12, GB13: an odd number of RI before the boundary
+	if p2 == EP::RI {
+		let run = before.iter().rev().take_while(|&&p| p == EP::RI).count();
+		if run % 2 == 1 {
+			return false;
+		}
+	}
+	// GB999
+	true
+}
+
+/// A boundary where the segmentation logic of the crate disagrees with a reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMismatch {
+	/// Properties of the characters of the sequence
+	pub props: Vec<EP>,
+	/// Position of the boundary in the sequence
+	pub index: usize,
+	/// Whether the reference found a boundary
+	pub expected: bool,
+}
+
+impl fmt::Display for RuleMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (i, p) in self.props.iter().enumerate() {
+			if i == self.index {
+				f.write_str(if self.expected { " ÷" } else { " ×" })?;
+			}
+			write!(f, " {}", p)?;
+		}
+		Ok(())
+	}
+}
+
+/// Calls `f` with every sequence of [EgcProps](EP) of length `len`.
+fn for_each_sequence(len: usize, mut f: impl FnMut(&[EP])) {
+	let mut digits = alloc::vec![0; len];
+	let mut props = alloc::vec![EP::ALL[0]; len];
+	loop {
+		f(&props);
+		let Some(pos) = digits.iter().rposition(|&d| d + 1 < EP::ALL.len()) else {
+			return;
+		};
+		digits[pos] += 1;
+		props[pos] = EP::ALL[digits[pos]];
+		for j in pos + 1..len {
+			digits[j] = 0;
+			props[j] = EP::ALL[0];
+		}
+	}
+}
+
+/// Checks the segmentation rules of the crate ([Ctx::step], [is_local_boundary] and [is_boundary])
+/// against `reference` on every sequence of properties of length 2 to `max_len`, and returns
+/// the boundaries where they disagree.
+/// 
+/// `reference` decides whether there is a boundary before the character at the given index,
+/// like [reference_is_boundary]. Forks tailoring the rules can pass their own reference.
+/// A local boundary is checked to be the same whatever the characters preceding it.
+pub fn check_rules(max_len: usize, reference: impl Fn(&[EP], usize) -> bool) -> Vec<RuleMismatch> {
+	let mut mismatches = Vec::new();
+	for len in 2..=max_len {
+		for_each_sequence(len, |props| {
+			let mut ctx = Ctx::Start.step(props[0]);
+			for i in 1..len {
+				let (p1, p2) = (props[i - 1], props[i]);
+				let expected = reference(props, i);
+				let local = is_local_boundary(p1, p2);
+				let boundary = is_boundary(ctx, p1, p2);
+				if boundary != expected || local.is_some_and(|b| b != expected) {
+					mismatches.push(RuleMismatch { props: props.to_vec(), index: i, expected });
+				}
+				ctx = if boundary { Ctx::Start.step(p2) } else { ctx.step(p2) };
+			}
+		});
+	}
+	mismatches
+}
+
+/// Returns a character with the given properties, or None if there is none.
+pub fn sample_char(p: EP) -> Option<char> {
+	match p {
+		EP::XX => return Some('a'),
+		// Precomposed Hangul syllables are not listed in RANGES
+		EP::LV => return Some('\u{ac00}'),
+		EP::LVT => return Some('\u{ac01}'),
+		_ => (),
+	}
+	RANGES.iter().find(|r| r.packed == p.into()).and_then(|r| char::from_u32(r.start))
+}
+
+/// Like [check_rules], but checks whole strings made of a [sample character](sample_char)
+/// for each property, segmented forward with [egc_indices](Egc::egc_indices) and backward
+/// with [prev_boundary], so that the lookup tables and segmentation engines are covered too.
+/// The sequences with a property that no character has (eg. InCB before Unicode 15.1) are skipped.
+pub fn check_strings(max_len: usize, reference: impl Fn(&[EP], usize) -> bool) -> Vec<RuleMismatch> {
+	let mut mismatches = Vec::new();
+	let mut s = String::new();
+	let mut offsets = Vec::new();
+	for len in 2..=max_len {
+		for_each_sequence(len, |props| {
+			s.clear();
+			offsets.clear();
+			for &p in props {
+				let Some(c) = sample_char(p) else {
+					return;
+				};
+				offsets.push(s.len());
+				s.push(c);
+			}
+			let mut forward = s.egc_indices().peekable();
+			let mut backward = Vec::new();
+			let mut end = s.len();
+			while end > 0 {
+				end = prev_boundary(&s, end);
+				backward.push(end);
+			}
+			for (i, &offset) in offsets.iter().enumerate().skip(1) {
+				let expected = reference(props, i);
+				let is_forward = forward.next_if(|&o| o <= offset).is_some_and(|o| o == offset);
+				let is_backward = backward.contains(&offset);
+				if is_forward != expected || is_backward != expected {
+					mismatches.push(RuleMismatch { props: props.to_vec(), index: i, expected });
+				}
+			}
+		});
+	}
+	mismatches
+}
@@ -0,0 +1,232 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::iter::EgcSlices;
+use crate::script_data::SCRIPT_NAMES;
+use crate::Egc;
+
+pub use crate::script_data::Script;
+
+/// Script of a character, as used for itemization, given by its Script and Script_Extensions
+/// properties (UAX #24).
+/// 
+/// It is looked up in the tables of the crate by [lookup_script], but another classification
+/// can be supplied as a function returning this type, eg. with custom scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptClass<S: 'static = Script> {
+	/// Script=Common (eg. punctuation and digits), which takes the script of the text around it
+	Common,
+	/// Script=Inherited (eg. combining marks), which takes the script of the character before it
+	Inherited,
+	/// A specific script
+	Script(S),
+	/// A character only used with the given scripts (Script_Extensions), eg. a punctuation
+	/// mark shared by several scripts. Its Script comes first if it is a specific one.
+	Extensions(&'static [S]),
+}
+
+impl Script {
+	/// Short name of the script, ie. its ISO 15924 code (eg. `Latn`)
+	pub const fn short_name(self) -> &'static str {
+		SCRIPT_NAMES[self as usize].0
+	}
+
+	/// Name of the script in the UCD (eg. `Old_Italic`)
+	pub const fn name(self) -> &'static str {
+		SCRIPT_NAMES[self as usize].1
+	}
+}
+
+/// A range of Unicode codepoints, and the associated script.
+#[derive(Clone, Copy)]
+pub struct ScriptRange {
+	pub start: u32,
+	pub count: u16,
+	pub kind: Script,
+}
+pub(crate) const fn ran(start: u32, count: u16, kind: Script) -> ScriptRange {
+	ScriptRange { start, count, kind }
+}
+
+/// A range of Unicode codepoints, and the associated script extensions.
+#[derive(Clone, Copy)]
+pub struct ScriptExtRange {
+	pub start: u32,
+	pub count: u16,
+	pub kind: &'static [Script],
+}
+pub(crate) const fn ext(start: u32, count: u16, kind: &'static [Script]) -> ScriptExtRange {
+	ScriptExtRange { start, count, kind }
+}
+
+/// The Script table. Characters not listed have Script=[Unknown](Script::Unknown).
+pub use crate::script_data::SCRIPT_RANGES;
+
+/// The Script_Extensions table. Characters not listed are only used with their Script.
+pub use crate::script_data::SCRIPT_EXTENSIONS;
+
+/// Finds the range of a sorted table which contains `cp`
+fn find_range<T: Copy>(ranges: &[T], cp: u32, bounds: impl Fn(T) -> (u32, u16)) -> Option<T> {
+	let mut start = 0usize;
+	let mut end = ranges.len();
+	while end > start {
+		let pivot_idx = (start + end) / 2;
+		let pivot = ranges[pivot_idx];
+		let (pivot_start, pivot_count) = bounds(pivot);
+		if cp < pivot_start {
+			end = pivot_idx;
+		} else if cp >= pivot_start + pivot_count as u32 {
+			start = pivot_idx + 1;
+		} else {
+			return Some(pivot);
+		}
+	}
+	None
+}
+
+/// Looks up the script of `c` for itemization, from its Script and Script_Extensions properties.
+/// 
+/// Characters with Script=Unknown (unassigned and private use characters) are treated as
+/// Common, so that they stay in the run they are in.
+pub fn lookup_script(c: char) -> ScriptClass {
+	let cp = c as u32;
+	if cp < 0x80 {
+		// fast path for ASCII, where only letters have a specific script
+		return if c.is_ascii_alphabetic() { ScriptClass::Script(Script::Latin) } else { ScriptClass::Common };
+	}
+	if let Some(range) = find_range(&SCRIPT_EXTENSIONS, cp, |r| (r.start, r.count)) {
+		return ScriptClass::Extensions(range.kind);
+	}
+	match find_range(&SCRIPT_RANGES, cp, |r| (r.start, r.count)).map(|r| r.kind) {
+		Some(Script::Inherited) => ScriptClass::Inherited,
+		Some(Script::Common | Script::Unknown) | None => ScriptClass::Common,
+		Some(script) => ScriptClass::Script(script),
+	}
+}
+
+/// An iterator over the maximal runs of a string with the same script, returned as byte ranges
+/// along with their script, or None for runs of Common and Inherited characters only.
+/// 
+/// Obtained with [script_runs], or [script_runs_with] for another classification.
+#[derive(Debug, Clone, Copy)]
+pub struct ScriptRuns<'a, F = fn(char) -> ScriptClass> {
+	str: &'a str,
+	offset: usize,
+	classify: F,
+}
+
+impl<S: Copy + PartialEq + 'static, F: Fn(char) -> ScriptClass<S>> ScriptRuns<'_, F> {
+	/// Whether all the characters of `run` can be in a run of the given script
+	fn allows(&self, run: &str, script: S) -> bool {
+		run.chars().all(|c| match (self.classify)(c) {
+			ScriptClass::Common | ScriptClass::Inherited => true,
+			ScriptClass::Script(s) => s == script,
+			ScriptClass::Extensions(scripts) => scripts.contains(&script),
+		})
+	}
+}
+
+impl<'a, S: Copy + PartialEq + 'static, F: Fn(char) -> ScriptClass<S>> Iterator for ScriptRuns<'a, F> {
+	type Item = (Range<usize>, Option<S>);
+	fn next(&mut self) -> Option<Self::Item> {
+		let start = self.offset;
+		let rest = &self.str[start..];
+		if rest.is_empty() {
+			return None;
+		}
+		let mut script: Option<S> = None;
+		let mut len = rest.len();
+		for (i, c) in rest.char_indices() {
+			let fits = match ((self.classify)(c), script) {
+				(ScriptClass::Common | ScriptClass::Inherited, _) => true,
+				(ScriptClass::Script(s), None) => {
+					let fits = self.allows(&rest[..i], s);
+					if fits {
+						script = Some(s);
+					}
+					fits
+				},
+				(ScriptClass::Script(s), Some(cur)) => s == cur,
+				(ScriptClass::Extensions(scripts), None) => {
+					scripts.iter().any(|&s| self.allows(&rest[..i], s))
+				},
+				(ScriptClass::Extensions(scripts), Some(cur)) => scripts.contains(&cur),
+			};
+			if !fits {
+				len = i;
+				break;
+			}
+		}
+		// A run of characters with Script_Extensions takes the first script they all share
+		let script = script.or_else(|| rest[..len].chars().find_map(|c| match (self.classify)(c) {
+			ScriptClass::Extensions(scripts) => scripts.iter().copied().find(|&s| self.allows(&rest[..len], s)),
+			_ => None,
+		}));
+		self.offset += len;
+		Some((start..self.offset, script))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.str.len() - self.offset;
+		(remaining.min(1), Some(remaining))
+	}
+}
+impl<S: Copy + PartialEq + 'static, F: Fn(char) -> ScriptClass<S>> FusedIterator for ScriptRuns<'_, F> {}
+
+/// Returns an iterator over the maximal runs of `s` with the same script, for syntax
+/// highlighting or shaping, with the script of each character given by [lookup_script].
+/// 
+/// Common and Inherited characters are merged into the run they are in, or the
+/// following one at the start of the string. Characters with Script_Extensions stay
+/// in the current run if its script is one of them. Before the first character with a
+/// specific script, they narrow down the scripts the run can take: a run only made of
+/// such characters (and Common or Inherited ones) takes the first script they all share.
+pub fn script_runs(s: &str) -> ScriptRuns<'_> {
+	script_runs_with(s, lookup_script)
+}
+
+/// Like [script_runs], with the script of each character given by `classify`.
+pub fn script_runs_with<S: Copy + PartialEq + 'static, F: Fn(char) -> ScriptClass<S>>(s: &str, classify: F) -> ScriptRuns<'_, F> {
+	ScriptRuns { str: s, offset: 0, classify }
+}
+
+/// An iterator over the EGCs of a string, returned along with the script of their base character.
+/// 
+/// Obtained with [egc_scripts], or [egc_scripts_with] for another classification.
+#[derive(Debug, Clone)]
+pub struct EgcScripts<'a, F = fn(char) -> ScriptClass> {
+	egcs: EgcSlices<'a>,
+	classify: F,
+}
+
+impl<'a, S: Copy + 'static, F: Fn(char) -> ScriptClass<S>> Iterator for EgcScripts<'a, F> {
+	type Item = (&'a str, ScriptClass<S>);
+	fn next(&mut self) -> Option<Self::Item> {
+		let egc = self.egcs.next()?;
+		let class = egc.chars()
+			.map(&self.classify)
+			.find(|class| !matches!(class, ScriptClass::Inherited))
+			.unwrap_or(ScriptClass::Inherited);
+		Some((egc, class))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.egcs.size_hint()
+	}
+}
+impl<S: Copy + 'static, F: Fn(char) -> ScriptClass<S>> FusedIterator for EgcScripts<'_, F> {}
+
+/// Returns an iterator over the EGCs of `s`, along with their script, eg. to pick a fallback
+/// font for each EGC, with the script of each character given by [lookup_script].
+/// 
+/// The script of an EGC is the one of its first character which is not Inherited
+/// (usually its base character), so that combining marks take the script of their base.
+/// It is only Inherited if all the characters of the EGC are.
+pub fn egc_scripts(s: &str) -> EgcScripts<'_> {
+	egc_scripts_with(s, lookup_script)
+}
+
+/// Like [egc_scripts], with the script of each character given by `classify`.
+pub fn egc_scripts_with<S: Copy + 'static, F: Fn(char) -> ScriptClass<S>>(s: &str, classify: F) -> EgcScripts<'_, F> {
+	EgcScripts { egcs: s.egcs(), classify }
+}
@@ -0,0 +1,39 @@
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::Egc;
+
+/// Returns the prefix of `s` made of its first `n` EGCs, or the whole string
+/// if it has fewer than `n` EGCs.
+/// 
+/// This does not allocate, and only looks at the start of the string.
+pub fn egc_prefix(s: &str, n: usize) -> &str {
+	let end = s.egc_indices().take(n).last().unwrap_or(0);
+	&s[..end]
+}
+
+/// Compares two strings by their first `n` EGCs, in byte order.
+/// 
+/// Strings are considered equal if they start with the same `n` EGCs.
+/// 
+/// Note that this order is _not_ always compatible with the byte order of
+/// the whole strings: for instance, `"e\u{301}"` (e with a combining acute accent)
+/// sorts before `"e\u{4e00}"`, but its first EGC sorts after `"e"`. Lists meant
+/// to be searched with [egc_prefix_range] should thus be sorted with this function
+/// (eg. `list.sort_by(|a, b| cmp_egc_prefix(a, b, n).then(a.cmp(b)))`).
+pub fn cmp_egc_prefix(a: &str, b: &str, n: usize) -> Ordering {
+	egc_prefix(a, n).cmp(egc_prefix(b, n))
+}
+
+/// Returns the range of indices of the strings in `sorted` which start with the
+/// same `n` EGCs as `key` (ie. which are in the same prefix bucket), using binary search.
+/// 
+/// `sorted` must be sorted according to [cmp_egc_prefix] with the same `n`.
+/// If there are no such strings, the returned range is empty, and its start
+/// is the position where `key` would be inserted.
+pub fn egc_prefix_range<S: AsRef<str>>(sorted: &[S], key: &str, n: usize) -> Range<usize> {
+	let prefix = egc_prefix(key, n);
+	let start = sorted.partition_point(|s| egc_prefix(s.as_ref(), n) < prefix);
+	let len = sorted[start..].partition_point(|s| egc_prefix(s.as_ref(), n) == prefix);
+	start..start + len
+}
@@ -0,0 +1,60 @@
+use crate::lookup::{lookup_egc_props, EgcProps as EP};
+
+/// Heuristic classification of the rendering requirements of an EGC.
+/// 
+/// Ordered from least to most demanding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Shaping {
+	/// A single character, possibly followed by variation selectors:
+	/// a single glyph lookup should suffice.
+	Single,
+	/// A base character followed by combining marks, which can be rendered
+	/// passably by drawing the mark glyphs over the base glyph.
+	Stacked,
+	/// Requires a full shaping engine: ZWJ sequences, emoji modifier and tag sequences,
+	/// flags, Indic conjuncts and spacing marks, prepended characters, Hangul jamo sequences.
+	Complex,
+}
+
+/// Is `c` a variation selector (Variation_Selector=Yes)
+fn is_variation_selector(c: char) -> bool {
+	matches!(c, '\u{180b}'..='\u{180d}' | '\u{180f}' | '\u{fe00}'..='\u{fe0f}' | '\u{e0100}'..='\u{e01ef}')
+}
+
+/// Is `c` an emoji modifier (skin tone) or tag character, which form ligatures with the preceding emoji
+fn is_emoji_ligature_part(c: char) -> bool {
+	matches!(c, '\u{1f3fb}'..='\u{1f3ff}' | '\u{e0020}'..='\u{e007f}')
+}
+
+/// Heuristically classifies how demanding an EGC is to render.
+/// 
+/// This is meant to let simple renderers know when they need to call a full
+/// text shaper, and when they can get away with looking up glyphs directly.
+/// It only relies on the character properties used for segmentation, and
+/// will not be accurate for every font: for instance, a font may well contain
+/// a precomposed glyph for some [Stacked](Shaping::Stacked) sequence.
+/// 
+/// The result is unspecified if `egc` is not a single EGC.
+pub fn egc_shaping(egc: &str) -> Shaping {
+	let mut chars = egc.chars();
+	let Some(first) = chars.next() else {
+		return Shaping::Single;
+	};
+	let p1 = lookup_egc_props(first);
+	if p1 == EP::PP {
+		return Shaping::Complex;
+	}
+	let mut shaping = Shaping::Single;
+	for c in chars {
+		let p = lookup_egc_props(c);
+		if is_variation_selector(c) || p.is_control() {
+			continue;
+		}
+		if is_emoji_ligature_part(c) || p.is_hangul() || matches!(p, EP::ZWJ | EP::IN_LI | EP::SM | EP::RI) {
+			return Shaping::Complex;
+		}
+		shaping = Shaping::Stacked;
+	}
+	shaping
+}
@@ -0,0 +1,106 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::{ready, Stream};
+use futures_io::AsyncRead;
+
+use crate::io::{EgcBuffer, CHUNK_SIZE};
+
+/// Reads UTF-8 text from an [AsyncRead] implementation and returns it EGC by EGC,
+/// as a [Stream] of owned [String]s.
+/// 
+/// This is the asynchronous counterpart to [EgcReader](crate::io::EgcReader):
+/// EGCs which straddle the boundary between two reads are held back until
+/// they are complete, and invalid UTF-8 results in an error of kind
+/// [InvalidData](io::ErrorKind::InvalidData).
+pub struct EgcStream<R> {
+	reader: R,
+	buf: EgcBuffer,
+}
+
+impl<R: AsyncRead + Unpin> EgcStream<R> {
+	/// Creates a new stream of EGCs.
+	pub fn new(reader: R) -> Self {
+		EgcStream { reader, buf: EgcBuffer::default() }
+	}
+
+	/// Returns the underlying reader.
+	/// 
+	/// Any text which was read from it but not returned yet is lost.
+	pub fn into_inner(self) -> R {
+		self.reader
+	}
+}
+
+impl<R: AsyncRead + Unpin> Stream for EgcStream<R> {
+	type Item = io::Result<String>;
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<io::Result<String>>> {
+		let this = self.get_mut();
+		let mut chunk = [0u8; CHUNK_SIZE];
+		loop {
+			if let Some(egc) = this.buf.next_egc() {
+				return Poll::Ready(Some(Ok(egc.to_owned())));
+			}
+			if this.buf.is_done() {
+				return Poll::Ready(None);
+			}
+			let res = match ready!(Pin::new(&mut this.reader).poll_read(cx, &mut chunk)) {
+				Ok(0) => this.buf.push_eof(),
+				Ok(n) => this.buf.push_bytes(&chunk[..n]),
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => Err(err),
+			};
+			if let Err(err) = res {
+				return Poll::Ready(Some(Err(err)));
+			}
+		}
+	}
+}
+
+/// Segments UTF-8 text received as a [Stream] of byte chunks (eg. network message
+/// fragments), and returns it EGC by EGC, as a [Stream] of owned [String]s.
+/// 
+/// EGCs which straddle the boundary between two chunks are held back until
+/// they are complete, and invalid UTF-8 results in an error of kind
+/// [InvalidData](io::ErrorKind::InvalidData).
+pub struct EgcChunkStream<S> {
+	stream: S,
+	buf: EgcBuffer,
+}
+
+impl<S: Stream + Unpin> EgcChunkStream<S> where S::Item: AsRef<[u8]> {
+	/// Creates a new stream of EGCs.
+	pub fn new(stream: S) -> Self {
+		EgcChunkStream { stream, buf: EgcBuffer::default() }
+	}
+
+	/// Returns the underlying stream.
+	/// 
+	/// Any text which was received from it but not returned yet is lost.
+	pub fn into_inner(self) -> S {
+		self.stream
+	}
+}
+
+impl<S: Stream + Unpin> Stream for EgcChunkStream<S> where S::Item: AsRef<[u8]> {
+	type Item = io::Result<String>;
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<io::Result<String>>> {
+		let this = self.get_mut();
+		loop {
+			if let Some(egc) = this.buf.next_egc() {
+				return Poll::Ready(Some(Ok(egc.to_owned())));
+			}
+			if this.buf.is_done() {
+				return Poll::Ready(None);
+			}
+			let res = match ready!(Pin::new(&mut this.stream).poll_next(cx)) {
+				Some(chunk) => this.buf.push_bytes(chunk.as_ref()),
+				None => this.buf.push_eof(),
+			};
+			if let Err(err) = res {
+				return Poll::Ready(Some(Err(err)));
+			}
+		}
+	}
+}
@@ -0,0 +1,64 @@
+use core::ops::Range;
+
+use crate::width::egc_width;
+use crate::Egc;
+
+/// A segment of styled text ready to be rendered, produced by [split_style_runs].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StyledSegment<'a, S> {
+	/// Text of the segment, made of whole EGCs
+	pub text: &'a str,
+	/// Style of the segment
+	pub style: &'a S,
+	/// Column at which the segment starts
+	pub start_col: usize,
+	/// Number of columns taken up by the segment
+	pub width: usize,
+}
+
+/// Splits `s` into segments of uniform style fitting in `max_cols` columns,
+/// calling `on_segment` with each segment, in order.
+/// 
+/// `runs` gives the style of byte ranges of `s`, as produced by eg. a syntax highlighter,
+/// and should be sorted. Since style runs may not be aligned on EGCs, each EGC takes the
+/// style of the run containing its first byte, so EGCs are never split. EGCs starting
+/// outside of any run are left out, but still take up columns. Adjacent runs are not
+/// merged, even if they have the same style.
+/// 
+/// Widths are measured with [egc_width]. Rendering stops at the first EGC which does
+/// not fit in `max_cols` columns.
+pub fn split_style_runs<'a, S>(s: &'a str, runs: &'a [(Range<usize>, S)], max_cols: usize, mut on_segment: impl FnMut(StyledSegment<'a, S>)) {
+	// Current segment: its start offset, run index, start column and width
+	let mut current: Option<(usize, usize, usize, usize)> = None;
+	let mut run = 0;
+	let mut offset = 0;
+	let mut col = 0;
+	let mut emit = |seg: Option<(usize, usize, usize, usize)>, end: usize| {
+		if let Some((start, run, start_col, width)) = seg {
+			on_segment(StyledSegment { text: &s[start..end], style: &runs[run].1, start_col, width });
+		}
+	};
+	for egc in s.egcs() {
+		while run < runs.len() && runs[run].0.end <= offset {
+			run += 1;
+		}
+		let width = egc_width(egc);
+		if col + width > max_cols {
+			break;
+		}
+		if run < runs.len() && runs[run].0.start <= offset {
+			match &mut current {
+				Some((_, seg_run, _, seg_width)) if *seg_run == run => *seg_width += width,
+				_ => {
+					emit(current, offset);
+					current = Some((offset, run, col, width));
+				},
+			}
+		} else {
+			emit(current.take(), offset);
+		}
+		col += width;
+		offset += egc.len();
+	}
+	emit(current, offset);
+}
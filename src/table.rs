@@ -0,0 +1,124 @@
+use alloc::string::String;
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "width")]
+use crate::width::{egc_width, str_width};
+use crate::Egc;
+
+/// How the width of a table cell is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Measure {
+	/// Number of EGCs in the cell
+	Graphemes,
+	/// Number of columns taken up on a monospace grid (see [str_width], requires the `width` feature)
+	#[cfg(feature = "width")]
+	Columns,
+}
+
+impl Measure {
+	/// Measures the width of `s`.
+	pub fn of(self, s: &str) -> usize {
+		match self {
+			Measure::Graphemes => s.egcs().count(),
+			#[cfg(feature = "width")]
+			Measure::Columns => str_width(s),
+		}
+	}
+
+	/// Measures the width of a single EGC.
+	#[cfg_attr(not(feature = "width"), allow(unused_variables))]
+	fn of_egc(self, egc: &str) -> usize {
+		match self {
+			Measure::Graphemes => 1,
+			#[cfg(feature = "width")]
+			Measure::Columns => egc_width(egc),
+		}
+	}
+}
+
+/// Options for [render_table].
+#[derive(Debug, Clone, Copy)]
+pub struct TableOptions<'a> {
+	/// How cell widths are measured, in columns by default if the `width` feature is enabled,
+	/// and in EGCs otherwise
+	pub measure: Measure,
+	/// String inserted between columns
+	pub separator: &'a str,
+	/// If set, cells wider than this are truncated on an EGC boundary
+	pub max_width: Option<usize>,
+}
+
+impl Default for TableOptions<'_> {
+	fn default() -> Self {
+		#[cfg(feature = "width")]
+		let measure = Measure::Columns;
+		#[cfg(not(feature = "width"))]
+		let measure = Measure::Graphemes;
+		TableOptions { measure, separator: " ", max_width: None }
+	}
+}
+
+/// Returns the longest prefix of `s` whose width does not exceed `max_width`.
+/// 
+/// EGCs are never split.
+pub fn truncate_to_width(s: &str, max_width: usize, measure: Measure) -> &str {
+	let mut width = 0;
+	let mut end = 0;
+	for egc in s.egcs() {
+		width += measure.of_egc(egc);
+		if width > max_width {
+			break;
+		}
+		end += egc.len();
+	}
+	&s[..end]
+}
+
+/// Computes the width of each column, ie. the maximum width of its cells.
+/// 
+/// Rows may have differing numbers of cells.
+pub fn column_widths<S: AsRef<str>>(rows: &[Vec<S>], options: &TableOptions) -> Vec<usize> {
+	let mut widths = vec![];
+	for row in rows {
+		if row.len() > widths.len() {
+			widths.resize(row.len(), 0);
+		}
+		for (i, cell) in row.iter().enumerate() {
+			let mut cell = cell.as_ref();
+			if let Some(max_width) = options.max_width {
+				cell = truncate_to_width(cell, max_width, options.measure);
+			}
+			widths[i] = widths[i].max(options.measure.of(cell));
+		}
+	}
+	widths
+}
+
+/// Renders rows of cells as left-aligned columns, padding cells with spaces.
+/// 
+/// Each row is terminated by a newline, and trailing padding is omitted.
+/// If [max_width](TableOptions::max_width) is set, wider cells are truncated,
+/// without splitting EGCs.
+pub fn render_table<S: AsRef<str>>(rows: &[Vec<S>], options: &TableOptions) -> String {
+	let widths = column_widths(rows, options);
+	let mut out = String::new();
+	for row in rows {
+		for (i, cell) in row.iter().enumerate() {
+			let mut cell = cell.as_ref();
+			if let Some(max_width) = options.max_width {
+				cell = truncate_to_width(cell, max_width, options.measure);
+			}
+			if i > 0 {
+				out.push_str(options.separator);
+			}
+			out.push_str(cell);
+			if i + 1 < row.len() {
+				let padding = widths[i] - options.measure.of(cell);
+				out.extend(core::iter::repeat(' ').take(padding));
+			}
+		}
+		out.push('\n');
+	}
+	out
+}
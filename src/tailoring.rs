@@ -0,0 +1,280 @@
+use core::iter::FusedIterator;
+
+use crate::aksara_data::AKSARA_RANGES;
+use crate::logic::{is_boundary_packed, Ctx, Segmenter};
+use crate::lookup::{lookup_packed_props, CharRange, EgcProps, GraphemeClusterBreak as G, IndicConjunctBreak as I, PackedProps};
+
+/// A tailoring of the segmentation rules, as allowed by UAX #29, eg. to never break around
+/// some characters, or to treat some sequences specially.
+/// 
+/// Both methods default to the rules of the standard, so that implementations only
+/// need to override the decisions they change.
+/// 
+/// A tailoring is applied with [Segmenter::with_tailoring], or with
+/// [egc_indices_tailored](crate::Egc::egc_indices_tailored), which can also iterate backward.
+pub trait BreakTailoring {
+	/// Returns the properties of `c` used by the rules.
+	fn props(&self, c: char) -> PackedProps {
+		lookup_packed_props(c)
+	}
+
+	/// Decides whether there is an EGC boundary between `c1` and `c2`, given their properties,
+	/// and the context after `c1` (as computed with [Ctx::step_packed] since the start of the EGC).
+	fn is_boundary(&self, ctx: Ctx, c1: char, p1: PackedProps, c2: char, p2: PackedProps) -> bool {
+		let _ = (c1, c2);
+		is_boundary_packed(ctx, p1, p2)
+	}
+}
+
+/// The rules of the standard, without tailoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoTailoring;
+
+impl BreakTailoring for NoTailoring {}
+
+impl<T: BreakTailoring + ?Sized> BreakTailoring for &T {
+	fn props(&self, c: char) -> PackedProps {
+		(**self).props(c)
+	}
+	fn is_boundary(&self, ctx: Ctx, c1: char, p1: PackedProps, c2: char, p2: PackedProps) -> bool {
+		(**self).is_boundary(ctx, c1, p1, c2, p2)
+	}
+}
+
+/// Legacy grapheme clusters, as defined by UAX #29: they are not extended by spacing marks
+/// (rule GB9a) or by prepended characters (rule GB9b).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Legacy;
+
+impl BreakTailoring for Legacy {
+	fn is_boundary(&self, ctx: Ctx, _c1: char, p1: PackedProps, _c2: char, p2: PackedProps) -> bool {
+		is_boundary_packed(ctx, p1, p2) || is_legacy_only_boundary(p1, p2)
+	}
+}
+
+/// The rules without GB9c, which keeps Indic conjuncts in a single EGC, as in Unicode 15.0 and
+/// earlier versions, where it did not exist.
+/// 
+/// This only disables GB9c: the other changes since 15.0 still apply (eg. new characters, or
+/// characters whose Grapheme_Cluster_Break property changed), so segmentation does not fully
+/// match older platforms (eg. older ICU versions). The `unicode-14-0` feature selects the tables
+/// of Unicode 14.0 for that.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoGb9c;
+
+impl BreakTailoring for NoGb9c {
+	fn is_boundary(&self, ctx: Ctx, _c1: char, p1: PackedProps, _c2: char, p2: PackedProps) -> bool {
+		// GB9c is the only rule which depends on the Indic_Conjunct_Break property of `c2`
+		is_boundary_packed(ctx, p1, PackedProps::new(p2.gcb(), I::None, p2.is_ext_pict()))
+	}
+}
+
+/// Orthographic syllables (aksaras) of Brahmic scripts, for moving the caret syllable by syllable,
+/// following the aksara tailoring of CLDR.
+/// 
+/// Rule GB9c keeps consonants joined by a virama together, but only in the few scripts where
+/// the virama has Indic_Conjunct_Break=Linker (eg. Devanagari, Bengali and Malayalam). This
+/// applies it to all scripts, with the Indic_Syllabic_Category property: viramas (including
+/// the visible ones, such as the Tamil pulli) and invisible stackers (eg. in Khmer and Myanmar)
+/// are treated as InCB=Linker, and consonants as InCB=Consonant. As in GB9c, combining marks
+/// and ZWJs may come between the virama and the next consonant (all the characters with
+/// GCB=Extend or ZWJ but ZWNJ, as in the derivation of InCB=Extend, so that this also works
+/// with the `unicode-14-0` feature, where InCB did not exist yet).
+/// 
+/// A virama followed by anything else than a consonant (eg. an independent vowel) still ends
+/// the syllable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Aksara;
+
+/// A range of Unicode codepoints, and the InCB value they are given by the [Aksara] tailoring:
+/// [Linker](I::Linker) for viramas and invisible stackers, [Consonant](I::Consonant) for consonants.
+#[derive(Clone, Copy)]
+pub(crate) struct AksaraRange {
+	pub start: u32,
+	pub count: u16,
+	pub kind: I,
+}
+pub(crate) const fn ran(start: u32, count: u16, kind: I) -> AksaraRange {
+	AksaraRange { start, count, kind }
+}
+
+/// Looks up the InCB value given to `c` by the [Aksara] tailoring, if any.
+fn lookup_aksara_incb(c: char) -> Option<I> {
+	let cp = c as u32;
+	let i = AKSARA_RANGES.partition_point(|r| r.start <= cp);
+	match i.checked_sub(1).map(|i| &AKSARA_RANGES[i]) {
+		Some(r) if cp - r.start < r.count as u32 => Some(r.kind),
+		_ => None,
+	}
+}
+
+impl BreakTailoring for Aksara {
+	fn props(&self, c: char) -> PackedProps {
+		let props = lookup_packed_props(c);
+		let incb = match lookup_aksara_incb(c) {
+			Some(incb) => incb,
+			None if matches!(props.gcb(), G::Extend | G::ZWJ) && c != '\u{200c}' => I::Extend,
+			None => return props,
+		};
+		PackedProps::new(props.gcb(), incb, props.is_ext_pict())
+	}
+}
+
+/// Is there a legacy grapheme cluster boundary between characters with properties `p1` and `p2`
+/// where there is no extended one (ie. one prevented by rules GB9a or GB9b)
+pub(crate) const fn is_legacy_only_boundary(p1: PackedProps, p2: PackedProps) -> bool {
+	// GB9 still applies after a prepended character
+	matches!(p2.gcb(), G::SpacingMark) || (matches!(p1.gcb(), G::Prepend) && !matches!(p2.gcb(), G::Extend | G::ZWJ))
+}
+
+/// Returns an iterator over the end indices of the EGCs of `s`, determined with the given tailoring.
+pub fn tailored_egc_indices<T: BreakTailoring>(s: &str, tailoring: T) -> TailoredEgcIndices<'_, T> {
+	TailoredEgcIndices { str: s, offset: 0, tailoring }
+}
+
+/// Character properties replacing the ones of the Unicode Character Database for some
+/// characters, eg. to make Private Use Area glyphs of an icon font cluster like emojis.
+/// 
+/// The custom ranges are consulted first, and [lookup_egc_props](crate::lookup::lookup_egc_props) is used for the
+/// characters outside of them.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomProps<'a> {
+	ranges: &'a [CharRange],
+}
+
+impl<'a> CustomProps<'a> {
+	/// Creates custom properties from a list of ranges, which must be sorted and must not
+	/// overlap (like [RANGES](crate::lookup::RANGES)). Panics otherwise.
+	pub const fn new(ranges: &'a [CharRange]) -> Self {
+		let mut i = 1;
+		while i < ranges.len() {
+			let prev = &ranges[i - 1];
+			assert!(prev.start + prev.count as u32 <= ranges[i].start, "custom ranges are not sorted");
+			i += 1;
+		}
+		CustomProps { ranges }
+	}
+
+	/// Looks up the character properties of `c`, in the custom ranges first.
+	pub fn lookup(&self, c: char) -> EgcProps {
+		self.lookup_packed(c).egc_props()
+	}
+
+	/// Like [lookup](CustomProps::lookup), with packed properties.
+	pub fn lookup_packed(&self, c: char) -> PackedProps {
+		let cp = c as u32;
+		let i = self.ranges.partition_point(|r| r.start <= cp);
+		match i.checked_sub(1).map(|i| &self.ranges[i]) {
+			Some(r) if cp - r.start < r.count as u32 => r.packed,
+			_ => lookup_packed_props(c),
+		}
+	}
+
+	/// Returns an iterator over the end indices of the EGCs of `s`, determined with
+	/// the custom properties.
+	pub fn egc_indices<'s>(&self, s: &'s str) -> TailoredEgcIndices<'s, CustomProps<'a>> {
+		tailored_egc_indices(s, *self)
+	}
+}
+
+impl BreakTailoring for CustomProps<'_> {
+	fn props(&self, c: char) -> PackedProps {
+		self.lookup_packed(c)
+	}
+}
+
+/// A forward iterator over the end indices of EGCs in a string, with tailored rules.
+/// 
+/// Obtained with [tailored_egc_indices] or [CustomProps::egc_indices].
+#[derive(Debug, Clone, Copy)]
+pub struct TailoredEgcIndices<'s, T> {
+	str: &'s str,
+	offset: usize,
+	tailoring: T,
+}
+
+impl<'s, T> TailoredEgcIndices<'s, T> {
+	/// Returns the offset of the next EGC.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns a backwards iterator over the indices, which returns the _start_ indices
+	/// of the EGCs, like [EgcIndices::rev](crate::iter::EgcIndices::rev).
+	/// 
+	/// If some indices were already consumed, the backward iterator only walks the rest
+	/// of the string. The indices it returns are still offsets in the whole string.
+	pub fn rev(self) -> TailoredEgcRevIndices<'s, T> {
+		let rest = &self.str[self.offset..];
+		TailoredEgcRevIndices { str: rest, base: self.offset, offset: rest.len(), tailoring: self.tailoring }
+	}
+}
+
+impl<T: BreakTailoring> Iterator for TailoredEgcIndices<'_, T> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		let rest = &self.str[self.offset..];
+		if rest.is_empty() {
+			return None;
+		}
+		let mut seg = Segmenter::with_tailoring(&self.tailoring);
+		self.offset += rest.chars().find_map(|c| seg.push_char(c)).unwrap_or(rest.len());
+		Some(self.offset)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.str.len() - self.offset;
+		(remaining.min(1), Some(remaining))
+	}
+}
+impl<T: BreakTailoring> FusedIterator for TailoredEgcIndices<'_, T> {}
+
+/// A backward iterator over the start indices of EGCs in a string, with tailored rules.
+/// 
+/// Obtained with [TailoredEgcIndices::rev]. Since the rules may depend on the text since the
+/// start of the EGC, each step looks back for a boundary which the tailoring places whatever
+/// the context, and segments forward from there. With tailorings which rarely place such
+/// boundaries, a step can thus take time linear in the length of the string.
+#[derive(Debug, Clone, Copy)]
+pub struct TailoredEgcRevIndices<'s, T> {
+	str: &'s str,
+	// Offset of `str` in the whole string
+	base: usize,
+	offset: usize,
+	tailoring: T,
+}
+
+impl<T: BreakTailoring> Iterator for TailoredEgcRevIndices<'_, T> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		let s = &self.str[..self.offset];
+		let mut chars = s.char_indices().rev();
+		let (_, mut c2) = chars.next()?;
+		let mut p2 = self.tailoring.props(c2);
+		let mut restart = 0;
+		for (i, c1) in chars {
+			let p1 = self.tailoring.props(c1);
+			if Ctx::ALL.iter().all(|&ctx| self.tailoring.is_boundary(ctx, c1, p1, c2, p2)) {
+				restart = i + c1.len_utf8();
+				break;
+			}
+			(c2, p2) = (c1, p1);
+		}
+		// Segment forward from the boundary found to the start of the last EGC
+		let mut start = restart;
+		let forward = TailoredEgcIndices { str: s, offset: restart, tailoring: &self.tailoring };
+		for end in forward {
+			if end == s.len() {
+				break;
+			}
+			start = end;
+		}
+		self.offset = start;
+		Some(self.base + start)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.offset.min(1), Some(self.offset))
+	}
+}
+impl<T: BreakTailoring> FusedIterator for TailoredEgcRevIndices<'_, T> {}
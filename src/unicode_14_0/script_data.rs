@@ -0,0 +1,1407 @@
+use crate::script::{Script as S, ScriptRange, ScriptExtRange, ran, ext};
+
+/// Value of the Script property of a character (UAX #24).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Script {
+	Adlam,
+	CaucasianAlbanian,
+	Ahom,
+	Arabic,
+	ImperialAramaic,
+	Armenian,
+	Avestan,
+	Balinese,
+	Bamum,
+	BassaVah,
+	Batak,
+	Bengali,
+	Bhaiksuki,
+	Bopomofo,
+	Brahmi,
+	Braille,
+	Buginese,
+	Buhid,
+	Chakma,
+	CanadianAboriginal,
+	Carian,
+	Cham,
+	Cherokee,
+	Chorasmian,
+	Coptic,
+	CyproMinoan,
+	Cypriot,
+	Cyrillic,
+	Devanagari,
+	DivesAkuru,
+	Dogra,
+	Deseret,
+	Duployan,
+	EgyptianHieroglyphs,
+	Elbasan,
+	Elymaic,
+	Ethiopic,
+	Georgian,
+	Glagolitic,
+	GunjalaGondi,
+	MasaramGondi,
+	Gothic,
+	Grantha,
+	Greek,
+	Gujarati,
+	Gurmukhi,
+	Hangul,
+	Han,
+	Hanunoo,
+	Hatran,
+	Hebrew,
+	Hiragana,
+	AnatolianHieroglyphs,
+	PahawhHmong,
+	NyiakengPuachueHmong,
+	KatakanaOrHiragana,
+	OldHungarian,
+	OldItalic,
+	Javanese,
+	KayahLi,
+	Katakana,
+	Kharoshthi,
+	Khmer,
+	Khojki,
+	KhitanSmallScript,
+	Kannada,
+	Kaithi,
+	TaiTham,
+	Lao,
+	Latin,
+	Lepcha,
+	Limbu,
+	LinearA,
+	LinearB,
+	Lisu,
+	Lycian,
+	Lydian,
+	Mahajani,
+	Makasar,
+	Mandaic,
+	Manichaean,
+	Marchen,
+	Medefaidrin,
+	MendeKikakui,
+	MeroiticCursive,
+	MeroiticHieroglyphs,
+	Malayalam,
+	Modi,
+	Mongolian,
+	Mro,
+	MeeteiMayek,
+	Multani,
+	Myanmar,
+	Nandinagari,
+	OldNorthArabian,
+	Nabataean,
+	Newa,
+	Nko,
+	Nushu,
+	Ogham,
+	OlChiki,
+	OldTurkic,
+	Oriya,
+	Osage,
+	Osmanya,
+	OldUyghur,
+	Palmyrene,
+	PauCinHau,
+	OldPermic,
+	PhagsPa,
+	InscriptionalPahlavi,
+	PsalterPahlavi,
+	Phoenician,
+	Miao,
+	InscriptionalParthian,
+	Rejang,
+	HanifiRohingya,
+	Runic,
+	Samaritan,
+	OldSouthArabian,
+	Saurashtra,
+	SignWriting,
+	Shavian,
+	Sharada,
+	Siddham,
+	Khudawadi,
+	Sinhala,
+	Sogdian,
+	OldSogdian,
+	SoraSompeng,
+	Soyombo,
+	Sundanese,
+	SylotiNagri,
+	Syriac,
+	Tagbanwa,
+	Takri,
+	TaiLe,
+	NewTaiLue,
+	Tamil,
+	Tangut,
+	TaiViet,
+	Telugu,
+	Tifinagh,
+	Tagalog,
+	Thaana,
+	Thai,
+	Tibetan,
+	Tirhuta,
+	Tangsa,
+	Toto,
+	Ugaritic,
+	Vai,
+	Vithkuqi,
+	WarangCiti,
+	Wancho,
+	OldPersian,
+	Cuneiform,
+	Yezidi,
+	Yi,
+	ZanabazarSquare,
+	Inherited,
+	Common,
+	Unknown,
+}
+
+/// Short and long names of the scripts, in the order of [Script]
+pub(crate) const SCRIPT_NAMES: [(&str, &str); 163] = [
+	("Adlm", "Adlam"),
+	("Aghb", "Caucasian_Albanian"),
+	("Ahom", "Ahom"),
+	("Arab", "Arabic"),
+	("Armi", "Imperial_Aramaic"),
+	("Armn", "Armenian"),
+	("Avst", "Avestan"),
+	("Bali", "Balinese"),
+	("Bamu", "Bamum"),
+	("Bass", "Bassa_Vah"),
+	("Batk", "Batak"),
+	("Beng", "Bengali"),
+	("Bhks", "Bhaiksuki"),
+	("Bopo", "Bopomofo"),
+	("Brah", "Brahmi"),
+	("Brai", "Braille"),
+	("Bugi", "Buginese"),
+	("Buhd", "Buhid"),
+	("Cakm", "Chakma"),
+	("Cans", "Canadian_Aboriginal"),
+	("Cari", "Carian"),
+	("Cham", "Cham"),
+	("Cher", "Cherokee"),
+	("Chrs", "Chorasmian"),
+	("Copt", "Coptic"),
+	("Cpmn", "Cypro_Minoan"),
+	("Cprt", "Cypriot"),
+	("Cyrl", "Cyrillic"),
+	("Deva", "Devanagari"),
+	("Diak", "Dives_Akuru"),
+	("Dogr", "Dogra"),
+	("Dsrt", "Deseret"),
+	("Dupl", "Duployan"),
+	("Egyp", "Egyptian_Hieroglyphs"),
+	("Elba", "Elbasan"),
+	("Elym", "Elymaic"),
+	("Ethi", "Ethiopic"),
+	("Geor", "Georgian"),
+	("Glag", "Glagolitic"),
+	("Gong", "Gunjala_Gondi"),
+	("Gonm", "Masaram_Gondi"),
+	("Goth", "Gothic"),
+	("Gran", "Grantha"),
+	("Grek", "Greek"),
+	("Gujr", "Gujarati"),
+	("Guru", "Gurmukhi"),
+	("Hang", "Hangul"),
+	("Hani", "Han"),
+	("Hano", "Hanunoo"),
+	("Hatr", "Hatran"),
+	("Hebr", "Hebrew"),
+	("Hira", "Hiragana"),
+	("Hluw", "Anatolian_Hieroglyphs"),
+	("Hmng", "Pahawh_Hmong"),
+	("Hmnp", "Nyiakeng_Puachue_Hmong"),
+	("Hrkt", "Katakana_Or_Hiragana"),
+	("Hung", "Old_Hungarian"),
+	("Ital", "Old_Italic"),
+	("Java", "Javanese"),
+	("Kali", "Kayah_Li"),
+	("Kana", "Katakana"),
+	("Khar", "Kharoshthi"),
+	("Khmr", "Khmer"),
+	("Khoj", "Khojki"),
+	("Kits", "Khitan_Small_Script"),
+	("Knda", "Kannada"),
+	("Kthi", "Kaithi"),
+	("Lana", "Tai_Tham"),
+	("Laoo", "Lao"),
+	("Latn", "Latin"),
+	("Lepc", "Lepcha"),
+	("Limb", "Limbu"),
+	("Lina", "Linear_A"),
+	("Linb", "Linear_B"),
+	("Lisu", "Lisu"),
+	("Lyci", "Lycian"),
+	("Lydi", "Lydian"),
+	("Mahj", "Mahajani"),
+	("Maka", "Makasar"),
+	("Mand", "Mandaic"),
+	("Mani", "Manichaean"),
+	("Marc", "Marchen"),
+	("Medf", "Medefaidrin"),
+	("Mend", "Mende_Kikakui"),
+	("Merc", "Meroitic_Cursive"),
+	("Mero", "Meroitic_Hieroglyphs"),
+	("Mlym", "Malayalam"),
+	("Modi", "Modi"),
+	("Mong", "Mongolian"),
+	("Mroo", "Mro"),
+	("Mtei", "Meetei_Mayek"),
+	("Mult", "Multani"),
+	("Mymr", "Myanmar"),
+	("Nand", "Nandinagari"),
+	("Narb", "Old_North_Arabian"),
+	("Nbat", "Nabataean"),
+	("Newa", "Newa"),
+	("Nkoo", "Nko"),
+	("Nshu", "Nushu"),
+	("Ogam", "Ogham"),
+	("Olck", "Ol_Chiki"),
+	("Orkh", "Old_Turkic"),
+	("Orya", "Oriya"),
+	("Osge", "Osage"),
+	("Osma", "Osmanya"),
+	("Ougr", "Old_Uyghur"),
+	("Palm", "Palmyrene"),
+	("Pauc", "Pau_Cin_Hau"),
+	("Perm", "Old_Permic"),
+	("Phag", "Phags_Pa"),
+	("Phli", "Inscriptional_Pahlavi"),
+	("Phlp", "Psalter_Pahlavi"),
+	("Phnx", "Phoenician"),
+	("Plrd", "Miao"),
+	("Prti", "Inscriptional_Parthian"),
+	("Rjng", "Rejang"),
+	("Rohg", "Hanifi_Rohingya"),
+	("Runr", "Runic"),
+	("Samr", "Samaritan"),
+	("Sarb", "Old_South_Arabian"),
+	("Saur", "Saurashtra"),
+	("Sgnw", "SignWriting"),
+	("Shaw", "Shavian"),
+	("Shrd", "Sharada"),
+	("Sidd", "Siddham"),
+	("Sind", "Khudawadi"),
+	("Sinh", "Sinhala"),
+	("Sogd", "Sogdian"),
+	("Sogo", "Old_Sogdian"),
+	("Sora", "Sora_Sompeng"),
+	("Soyo", "Soyombo"),
+	("Sund", "Sundanese"),
+	("Sylo", "Syloti_Nagri"),
+	("Syrc", "Syriac"),
+	("Tagb", "Tagbanwa"),
+	("Takr", "Takri"),
+	("Tale", "Tai_Le"),
+	("Talu", "New_Tai_Lue"),
+	("Taml", "Tamil"),
+	("Tang", "Tangut"),
+	("Tavt", "Tai_Viet"),
+	("Telu", "Telugu"),
+	("Tfng", "Tifinagh"),
+	("Tglg", "Tagalog"),
+	("Thaa", "Thaana"),
+	("Thai", "Thai"),
+	("Tibt", "Tibetan"),
+	("Tirh", "Tirhuta"),
+	("Tnsa", "Tangsa"),
+	("Toto", "Toto"),
+	("Ugar", "Ugaritic"),
+	("Vaii", "Vai"),
+	("Vith", "Vithkuqi"),
+	("Wara", "Warang_Citi"),
+	("Wcho", "Wancho"),
+	("Xpeo", "Old_Persian"),
+	("Xsux", "Cuneiform"),
+	("Yezi", "Yezidi"),
+	("Yiii", "Yi"),
+	("Zanb", "Zanabazar_Square"),
+	("Zinh", "Inherited"),
+	("Zyyy", "Common"),
+	("Zzzz", "Unknown"),
+];
+
+pub const SCRIPT_RANGES: [ScriptRange; 942] = [
+	ran(0x00000,    65, S::Common),
+	ran(0x00041,    26, S::Latin),
+	ran(0x0005b,     6, S::Common),
+	ran(0x00061,    26, S::Latin),
+	ran(0x0007b,    47, S::Common),
+	ran(0x000aa,     1, S::Latin),
+	ran(0x000ab,    15, S::Common),
+	ran(0x000ba,     1, S::Latin),
+	ran(0x000bb,     5, S::Common),
+	ran(0x000c0,    23, S::Latin),
+	ran(0x000d7,     1, S::Common),
+	ran(0x000d8,    31, S::Latin),
+	ran(0x000f7,     1, S::Common),
+	ran(0x000f8,   449, S::Latin),
+	ran(0x002b9,    39, S::Common),
+	ran(0x002e0,     5, S::Latin),
+	ran(0x002e5,     5, S::Common),
+	ran(0x002ea,     2, S::Bopomofo),
+	ran(0x002ec,    20, S::Common),
+	ran(0x00300,   112, S::Inherited),
+	ran(0x00370,     4, S::Greek),
+	ran(0x00374,     1, S::Common),
+	ran(0x00375,     3, S::Greek),
+	ran(0x0037a,     4, S::Greek),
+	ran(0x0037e,     1, S::Common),
+	ran(0x0037f,     1, S::Greek),
+	ran(0x00384,     1, S::Greek),
+	ran(0x00385,     1, S::Common),
+	ran(0x00386,     1, S::Greek),
+	ran(0x00387,     1, S::Common),
+	ran(0x00388,     3, S::Greek),
+	ran(0x0038c,     1, S::Greek),
+	ran(0x0038e,    20, S::Greek),
+	ran(0x003a3,    63, S::Greek),
+	ran(0x003e2,    14, S::Coptic),
+	ran(0x003f0,    16, S::Greek),
+	ran(0x00400,   133, S::Cyrillic),
+	ran(0x00485,     2, S::Inherited),
+	ran(0x00487,   169, S::Cyrillic),
+	ran(0x00531,    38, S::Armenian),
+	ran(0x00559,    50, S::Armenian),
+	ran(0x0058d,     3, S::Armenian),
+	ran(0x00591,    55, S::Hebrew),
+	ran(0x005d0,    27, S::Hebrew),
+	ran(0x005ef,     6, S::Hebrew),
+	ran(0x00600,     5, S::Arabic),
+	ran(0x00605,     1, S::Common),
+	ran(0x00606,     6, S::Arabic),
+	ran(0x0060c,     1, S::Common),
+	ran(0x0060d,    14, S::Arabic),
+	ran(0x0061b,     1, S::Common),
+	ran(0x0061c,     3, S::Arabic),
+	ran(0x0061f,     1, S::Common),
+	ran(0x00620,    32, S::Arabic),
+	ran(0x00640,     1, S::Common),
+	ran(0x00641,    10, S::Arabic),
+	ran(0x0064b,    11, S::Inherited),
+	ran(0x00656,    26, S::Arabic),
+	ran(0x00670,     1, S::Inherited),
+	ran(0x00671,   108, S::Arabic),
+	ran(0x006dd,     1, S::Common),
+	ran(0x006de,    34, S::Arabic),
+	ran(0x00700,    14, S::Syriac),
+	ran(0x0070f,    60, S::Syriac),
+	ran(0x0074d,     3, S::Syriac),
+	ran(0x00750,    48, S::Arabic),
+	ran(0x00780,    50, S::Thaana),
+	ran(0x007c0,    59, S::Nko),
+	ran(0x007fd,     3, S::Nko),
+	ran(0x00800,    46, S::Samaritan),
+	ran(0x00830,    15, S::Samaritan),
+	ran(0x00840,    28, S::Mandaic),
+	ran(0x0085e,     1, S::Mandaic),
+	ran(0x00860,    11, S::Syriac),
+	ran(0x00870,    31, S::Arabic),
+	ran(0x00890,     2, S::Arabic),
+	ran(0x00898,    74, S::Arabic),
+	ran(0x008e2,     1, S::Common),
+	ran(0x008e3,    29, S::Arabic),
+	ran(0x00900,    81, S::Devanagari),
+	ran(0x00951,     4, S::Inherited),
+	ran(0x00955,    15, S::Devanagari),
+	ran(0x00964,     2, S::Common),
+	ran(0x00966,    26, S::Devanagari),
+	ran(0x00980,     4, S::Bengali),
+	ran(0x00985,     8, S::Bengali),
+	ran(0x0098f,     2, S::Bengali),
+	ran(0x00993,    22, S::Bengali),
+	ran(0x009aa,     7, S::Bengali),
+	ran(0x009b2,     1, S::Bengali),
+	ran(0x009b6,     4, S::Bengali),
+	ran(0x009bc,     9, S::Bengali),
+	ran(0x009c7,     2, S::Bengali),
+	ran(0x009cb,     4, S::Bengali),
+	ran(0x009d7,     1, S::Bengali),
+	ran(0x009dc,     2, S::Bengali),
+	ran(0x009df,     5, S::Bengali),
+	ran(0x009e6,    25, S::Bengali),
+	ran(0x00a01,     3, S::Gurmukhi),
+	ran(0x00a05,     6, S::Gurmukhi),
+	ran(0x00a0f,     2, S::Gurmukhi),
+	ran(0x00a13,    22, S::Gurmukhi),
+	ran(0x00a2a,     7, S::Gurmukhi),
+	ran(0x00a32,     2, S::Gurmukhi),
+	ran(0x00a35,     2, S::Gurmukhi),
+	ran(0x00a38,     2, S::Gurmukhi),
+	ran(0x00a3c,     1, S::Gurmukhi),
+	ran(0x00a3e,     5, S::Gurmukhi),
+	ran(0x00a47,     2, S::Gurmukhi),
+	ran(0x00a4b,     3, S::Gurmukhi),
+	ran(0x00a51,     1, S::Gurmukhi),
+	ran(0x00a59,     4, S::Gurmukhi),
+	ran(0x00a5e,     1, S::Gurmukhi),
+	ran(0x00a66,    17, S::Gurmukhi),
+	ran(0x00a81,     3, S::Gujarati),
+	ran(0x00a85,     9, S::Gujarati),
+	ran(0x00a8f,     3, S::Gujarati),
+	ran(0x00a93,    22, S::Gujarati),
+	ran(0x00aaa,     7, S::Gujarati),
+	ran(0x00ab2,     2, S::Gujarati),
+	ran(0x00ab5,     5, S::Gujarati),
+	ran(0x00abc,    10, S::Gujarati),
+	ran(0x00ac7,     3, S::Gujarati),
+	ran(0x00acb,     3, S::Gujarati),
+	ran(0x00ad0,     1, S::Gujarati),
+	ran(0x00ae0,     4, S::Gujarati),
+	ran(0x00ae6,    12, S::Gujarati),
+	ran(0x00af9,     7, S::Gujarati),
+	ran(0x00b01,     3, S::Oriya),
+	ran(0x00b05,     8, S::Oriya),
+	ran(0x00b0f,     2, S::Oriya),
+	ran(0x00b13,    22, S::Oriya),
+	ran(0x00b2a,     7, S::Oriya),
+	ran(0x00b32,     2, S::Oriya),
+	ran(0x00b35,     5, S::Oriya),
+	ran(0x00b3c,     9, S::Oriya),
+	ran(0x00b47,     2, S::Oriya),
+	ran(0x00b4b,     3, S::Oriya),
+	ran(0x00b55,     3, S::Oriya),
+	ran(0x00b5c,     2, S::Oriya),
+	ran(0x00b5f,     5, S::Oriya),
+	ran(0x00b66,    18, S::Oriya),
+	ran(0x00b82,     2, S::Tamil),
+	ran(0x00b85,     6, S::Tamil),
+	ran(0x00b8e,     3, S::Tamil),
+	ran(0x00b92,     4, S::Tamil),
+	ran(0x00b99,     2, S::Tamil),
+	ran(0x00b9c,     1, S::Tamil),
+	ran(0x00b9e,     2, S::Tamil),
+	ran(0x00ba3,     2, S::Tamil),
+	ran(0x00ba8,     3, S::Tamil),
+	ran(0x00bae,    12, S::Tamil),
+	ran(0x00bbe,     5, S::Tamil),
+	ran(0x00bc6,     3, S::Tamil),
+	ran(0x00bca,     4, S::Tamil),
+	ran(0x00bd0,     1, S::Tamil),
+	ran(0x00bd7,     1, S::Tamil),
+	ran(0x00be6,    21, S::Tamil),
+	ran(0x00c00,    13, S::Telugu),
+	ran(0x00c0e,     3, S::Telugu),
+	ran(0x00c12,    23, S::Telugu),
+	ran(0x00c2a,    16, S::Telugu),
+	ran(0x00c3c,     9, S::Telugu),
+	ran(0x00c46,     3, S::Telugu),
+	ran(0x00c4a,     4, S::Telugu),
+	ran(0x00c55,     2, S::Telugu),
+	ran(0x00c58,     3, S::Telugu),
+	ran(0x00c5d,     1, S::Telugu),
+	ran(0x00c60,     4, S::Telugu),
+	ran(0x00c66,    10, S::Telugu),
+	ran(0x00c77,     9, S::Telugu),
+	ran(0x00c80,    13, S::Kannada),
+	ran(0x00c8e,     3, S::Kannada),
+	ran(0x00c92,    23, S::Kannada),
+	ran(0x00caa,    10, S::Kannada),
+	ran(0x00cb5,     5, S::Kannada),
+	ran(0x00cbc,     9, S::Kannada),
+	ran(0x00cc6,     3, S::Kannada),
+	ran(0x00cca,     4, S::Kannada),
+	ran(0x00cd5,     2, S::Kannada),
+	ran(0x00cdd,     2, S::Kannada),
+	ran(0x00ce0,     4, S::Kannada),
+	ran(0x00ce6,    10, S::Kannada),
+	ran(0x00cf1,     2, S::Kannada),
+	ran(0x00d00,    13, S::Malayalam),
+	ran(0x00d0e,     3, S::Malayalam),
+	ran(0x00d12,    51, S::Malayalam),
+	ran(0x00d46,     3, S::Malayalam),
+	ran(0x00d4a,     6, S::Malayalam),
+	ran(0x00d54,    16, S::Malayalam),
+	ran(0x00d66,    26, S::Malayalam),
+	ran(0x00d81,     3, S::Sinhala),
+	ran(0x00d85,    18, S::Sinhala),
+	ran(0x00d9a,    24, S::Sinhala),
+	ran(0x00db3,     9, S::Sinhala),
+	ran(0x00dbd,     1, S::Sinhala),
+	ran(0x00dc0,     7, S::Sinhala),
+	ran(0x00dca,     1, S::Sinhala),
+	ran(0x00dcf,     6, S::Sinhala),
+	ran(0x00dd6,     1, S::Sinhala),
+	ran(0x00dd8,     8, S::Sinhala),
+	ran(0x00de6,    10, S::Sinhala),
+	ran(0x00df2,     3, S::Sinhala),
+	ran(0x00e01,    58, S::Thai),
+	ran(0x00e3f,     1, S::Common),
+	ran(0x00e40,    28, S::Thai),
+	ran(0x00e81,     2, S::Lao),
+	ran(0x00e84,     1, S::Lao),
+	ran(0x00e86,     5, S::Lao),
+	ran(0x00e8c,    24, S::Lao),
+	ran(0x00ea5,     1, S::Lao),
+	ran(0x00ea7,    23, S::Lao),
+	ran(0x00ec0,     5, S::Lao),
+	ran(0x00ec6,     1, S::Lao),
+	ran(0x00ec8,     6, S::Lao),
+	ran(0x00ed0,    10, S::Lao),
+	ran(0x00edc,     4, S::Lao),
+	ran(0x00f00,    72, S::Tibetan),
+	ran(0x00f49,    36, S::Tibetan),
+	ran(0x00f71,    39, S::Tibetan),
+	ran(0x00f99,    36, S::Tibetan),
+	ran(0x00fbe,    15, S::Tibetan),
+	ran(0x00fce,     7, S::Tibetan),
+	ran(0x00fd5,     4, S::Common),
+	ran(0x00fd9,     2, S::Tibetan),
+	ran(0x01000,   160, S::Myanmar),
+	ran(0x010a0,    38, S::Georgian),
+	ran(0x010c7,     1, S::Georgian),
+	ran(0x010cd,     1, S::Georgian),
+	ran(0x010d0,    43, S::Georgian),
+	ran(0x010fb,     1, S::Common),
+	ran(0x010fc,     4, S::Georgian),
+	ran(0x01100,   256, S::Hangul),
+	ran(0x01200,    73, S::Ethiopic),
+	ran(0x0124a,     4, S::Ethiopic),
+	ran(0x01250,     7, S::Ethiopic),
+	ran(0x01258,     1, S::Ethiopic),
+	ran(0x0125a,     4, S::Ethiopic),
+	ran(0x01260,    41, S::Ethiopic),
+	ran(0x0128a,     4, S::Ethiopic),
+	ran(0x01290,    33, S::Ethiopic),
+	ran(0x012b2,     4, S::Ethiopic),
+	ran(0x012b8,     7, S::Ethiopic),
+	ran(0x012c0,     1, S::Ethiopic),
+	ran(0x012c2,     4, S::Ethiopic),
+	ran(0x012c8,    15, S::Ethiopic),
+	ran(0x012d8,    57, S::Ethiopic),
+	ran(0x01312,     4, S::Ethiopic),
+	ran(0x01318,    67, S::Ethiopic),
+	ran(0x0135d,    32, S::Ethiopic),
+	ran(0x01380,    26, S::Ethiopic),
+	ran(0x013a0,    86, S::Cherokee),
+	ran(0x013f8,     6, S::Cherokee),
+	ran(0x01400,   640, S::CanadianAboriginal),
+	ran(0x01680,    29, S::Ogham),
+	ran(0x016a0,    75, S::Runic),
+	ran(0x016eb,     3, S::Common),
+	ran(0x016ee,    11, S::Runic),
+	ran(0x01700,    22, S::Tagalog),
+	ran(0x0171f,     1, S::Tagalog),
+	ran(0x01720,    21, S::Hanunoo),
+	ran(0x01735,     2, S::Common),
+	ran(0x01740,    20, S::Buhid),
+	ran(0x01760,    13, S::Tagbanwa),
+	ran(0x0176e,     3, S::Tagbanwa),
+	ran(0x01772,     2, S::Tagbanwa),
+	ran(0x01780,    94, S::Khmer),
+	ran(0x017e0,    10, S::Khmer),
+	ran(0x017f0,    10, S::Khmer),
+	ran(0x01800,     2, S::Mongolian),
+	ran(0x01802,     2, S::Common),
+	ran(0x01804,     1, S::Mongolian),
+	ran(0x01805,     1, S::Common),
+	ran(0x01806,    20, S::Mongolian),
+	ran(0x01820,    89, S::Mongolian),
+	ran(0x01880,    43, S::Mongolian),
+	ran(0x018b0,    70, S::CanadianAboriginal),
+	ran(0x01900,    31, S::Limbu),
+	ran(0x01920,    12, S::Limbu),
+	ran(0x01930,    12, S::Limbu),
+	ran(0x01940,     1, S::Limbu),
+	ran(0x01944,    12, S::Limbu),
+	ran(0x01950,    30, S::TaiLe),
+	ran(0x01970,     5, S::TaiLe),
+	ran(0x01980,    44, S::NewTaiLue),
+	ran(0x019b0,    26, S::NewTaiLue),
+	ran(0x019d0,    11, S::NewTaiLue),
+	ran(0x019de,     2, S::NewTaiLue),
+	ran(0x019e0,    32, S::Khmer),
+	ran(0x01a00,    28, S::Buginese),
+	ran(0x01a1e,     2, S::Buginese),
+	ran(0x01a20,    63, S::TaiTham),
+	ran(0x01a60,    29, S::TaiTham),
+	ran(0x01a7f,    11, S::TaiTham),
+	ran(0x01a90,    10, S::TaiTham),
+	ran(0x01aa0,    14, S::TaiTham),
+	ran(0x01ab0,    31, S::Inherited),
+	ran(0x01b00,    77, S::Balinese),
+	ran(0x01b50,    47, S::Balinese),
+	ran(0x01b80,    64, S::Sundanese),
+	ran(0x01bc0,    52, S::Batak),
+	ran(0x01bfc,     4, S::Batak),
+	ran(0x01c00,    56, S::Lepcha),
+	ran(0x01c3b,    15, S::Lepcha),
+	ran(0x01c4d,     3, S::Lepcha),
+	ran(0x01c50,    48, S::OlChiki),
+	ran(0x01c80,     9, S::Cyrillic),
+	ran(0x01c90,    43, S::Georgian),
+	ran(0x01cbd,     3, S::Georgian),
+	ran(0x01cc0,     8, S::Sundanese),
+	ran(0x01cd0,     3, S::Inherited),
+	ran(0x01cd3,     1, S::Common),
+	ran(0x01cd4,    13, S::Inherited),
+	ran(0x01ce1,     1, S::Common),
+	ran(0x01ce2,     7, S::Inherited),
+	ran(0x01ce9,     4, S::Common),
+	ran(0x01ced,     1, S::Inherited),
+	ran(0x01cee,     6, S::Common),
+	ran(0x01cf4,     1, S::Inherited),
+	ran(0x01cf5,     3, S::Common),
+	ran(0x01cf8,     2, S::Inherited),
+	ran(0x01cfa,     1, S::Common),
+	ran(0x01d00,    38, S::Latin),
+	ran(0x01d26,     5, S::Greek),
+	ran(0x01d2b,     1, S::Cyrillic),
+	ran(0x01d2c,    49, S::Latin),
+	ran(0x01d5d,     5, S::Greek),
+	ran(0x01d62,     4, S::Latin),
+	ran(0x01d66,     5, S::Greek),
+	ran(0x01d6b,    13, S::Latin),
+	ran(0x01d78,     1, S::Cyrillic),
+	ran(0x01d79,    70, S::Latin),
+	ran(0x01dbf,     1, S::Greek),
+	ran(0x01dc0,    64, S::Inherited),
+	ran(0x01e00,   256, S::Latin),
+	ran(0x01f00,    22, S::Greek),
+	ran(0x01f18,     6, S::Greek),
+	ran(0x01f20,    38, S::Greek),
+	ran(0x01f48,     6, S::Greek),
+	ran(0x01f50,     8, S::Greek),
+	ran(0x01f59,     1, S::Greek),
+	ran(0x01f5b,     1, S::Greek),
+	ran(0x01f5d,     1, S::Greek),
+	ran(0x01f5f,    31, S::Greek),
+	ran(0x01f80,    53, S::Greek),
+	ran(0x01fb6,    15, S::Greek),
+	ran(0x01fc6,    14, S::Greek),
+	ran(0x01fd6,     6, S::Greek),
+	ran(0x01fdd,    19, S::Greek),
+	ran(0x01ff2,     3, S::Greek),
+	ran(0x01ff6,     9, S::Greek),
+	ran(0x02000,    12, S::Common),
+	ran(0x0200c,     2, S::Inherited),
+	ran(0x0200e,    87, S::Common),
+	ran(0x02066,    11, S::Common),
+	ran(0x02071,     1, S::Latin),
+	ran(0x02074,    11, S::Common),
+	ran(0x0207f,     1, S::Latin),
+	ran(0x02080,    15, S::Common),
+	ran(0x02090,    13, S::Latin),
+	ran(0x020a0,    33, S::Common),
+	ran(0x020d0,    33, S::Inherited),
+	ran(0x02100,    38, S::Common),
+	ran(0x02126,     1, S::Greek),
+	ran(0x02127,     3, S::Common),
+	ran(0x0212a,     2, S::Latin),
+	ran(0x0212c,     6, S::Common),
+	ran(0x02132,     1, S::Latin),
+	ran(0x02133,    27, S::Common),
+	ran(0x0214e,     1, S::Latin),
+	ran(0x0214f,    17, S::Common),
+	ran(0x02160,    41, S::Latin),
+	ran(0x02189,     3, S::Common),
+	ran(0x02190,   663, S::Common),
+	ran(0x02440,    11, S::Common),
+	ran(0x02460,   928, S::Common),
+	ran(0x02800,   256, S::Braille),
+	ran(0x02900,   628, S::Common),
+	ran(0x02b76,    32, S::Common),
+	ran(0x02b97,   105, S::Common),
+	ran(0x02c00,    96, S::Glagolitic),
+	ran(0x02c60,    32, S::Latin),
+	ran(0x02c80,   116, S::Coptic),
+	ran(0x02cf9,     7, S::Coptic),
+	ran(0x02d00,    38, S::Georgian),
+	ran(0x02d27,     1, S::Georgian),
+	ran(0x02d2d,     1, S::Georgian),
+	ran(0x02d30,    56, S::Tifinagh),
+	ran(0x02d6f,     2, S::Tifinagh),
+	ran(0x02d7f,     1, S::Tifinagh),
+	ran(0x02d80,    23, S::Ethiopic),
+	ran(0x02da0,     7, S::Ethiopic),
+	ran(0x02da8,     7, S::Ethiopic),
+	ran(0x02db0,     7, S::Ethiopic),
+	ran(0x02db8,     7, S::Ethiopic),
+	ran(0x02dc0,     7, S::Ethiopic),
+	ran(0x02dc8,     7, S::Ethiopic),
+	ran(0x02dd0,     7, S::Ethiopic),
+	ran(0x02dd8,     7, S::Ethiopic),
+	ran(0x02de0,    32, S::Cyrillic),
+	ran(0x02e00,    94, S::Common),
+	ran(0x02e80,    26, S::Han),
+	ran(0x02e9b,    89, S::Han),
+	ran(0x02f00,   214, S::Han),
+	ran(0x02ff0,    12, S::Common),
+	ran(0x03000,     5, S::Common),
+	ran(0x03005,     1, S::Han),
+	ran(0x03006,     1, S::Common),
+	ran(0x03007,     1, S::Han),
+	ran(0x03008,    25, S::Common),
+	ran(0x03021,     9, S::Han),
+	ran(0x0302a,     4, S::Inherited),
+	ran(0x0302e,     2, S::Hangul),
+	ran(0x03030,     8, S::Common),
+	ran(0x03038,     4, S::Han),
+	ran(0x0303c,     4, S::Common),
+	ran(0x03041,    86, S::Hiragana),
+	ran(0x03099,     2, S::Inherited),
+	ran(0x0309b,     2, S::Common),
+	ran(0x0309d,     3, S::Hiragana),
+	ran(0x030a0,     1, S::Common),
+	ran(0x030a1,    90, S::Katakana),
+	ran(0x030fb,     2, S::Common),
+	ran(0x030fd,     3, S::Katakana),
+	ran(0x03105,    43, S::Bopomofo),
+	ran(0x03131,    94, S::Hangul),
+	ran(0x03190,    16, S::Common),
+	ran(0x031a0,    32, S::Bopomofo),
+	ran(0x031c0,    36, S::Common),
+	ran(0x031f0,    16, S::Katakana),
+	ran(0x03200,    31, S::Hangul),
+	ran(0x03220,    64, S::Common),
+	ran(0x03260,    31, S::Hangul),
+	ran(0x0327f,    81, S::Common),
+	ran(0x032d0,    47, S::Katakana),
+	ran(0x032ff,     1, S::Common),
+	ran(0x03300,    88, S::Katakana),
+	ran(0x03358,   168, S::Common),
+	ran(0x03400,  6592, S::Han),
+	ran(0x04dc0,    64, S::Common),
+	ran(0x04e00, 20992, S::Han),
+	ran(0x0a000,  1165, S::Yi),
+	ran(0x0a490,    55, S::Yi),
+	ran(0x0a4d0,    48, S::Lisu),
+	ran(0x0a500,   300, S::Vai),
+	ran(0x0a640,    96, S::Cyrillic),
+	ran(0x0a6a0,    88, S::Bamum),
+	ran(0x0a700,    34, S::Common),
+	ran(0x0a722,   102, S::Latin),
+	ran(0x0a788,     3, S::Common),
+	ran(0x0a78b,    64, S::Latin),
+	ran(0x0a7d0,     2, S::Latin),
+	ran(0x0a7d3,     1, S::Latin),
+	ran(0x0a7d5,     5, S::Latin),
+	ran(0x0a7f2,    14, S::Latin),
+	ran(0x0a800,    45, S::SylotiNagri),
+	ran(0x0a830,    10, S::Common),
+	ran(0x0a840,    56, S::PhagsPa),
+	ran(0x0a880,    70, S::Saurashtra),
+	ran(0x0a8ce,    12, S::Saurashtra),
+	ran(0x0a8e0,    32, S::Devanagari),
+	ran(0x0a900,    46, S::KayahLi),
+	ran(0x0a92e,     1, S::Common),
+	ran(0x0a92f,     1, S::KayahLi),
+	ran(0x0a930,    36, S::Rejang),
+	ran(0x0a95f,     1, S::Rejang),
+	ran(0x0a960,    29, S::Hangul),
+	ran(0x0a980,    78, S::Javanese),
+	ran(0x0a9cf,     1, S::Common),
+	ran(0x0a9d0,    10, S::Javanese),
+	ran(0x0a9de,     2, S::Javanese),
+	ran(0x0a9e0,    31, S::Myanmar),
+	ran(0x0aa00,    55, S::Cham),
+	ran(0x0aa40,    14, S::Cham),
+	ran(0x0aa50,    10, S::Cham),
+	ran(0x0aa5c,     4, S::Cham),
+	ran(0x0aa60,    32, S::Myanmar),
+	ran(0x0aa80,    67, S::TaiViet),
+	ran(0x0aadb,     5, S::TaiViet),
+	ran(0x0aae0,    23, S::MeeteiMayek),
+	ran(0x0ab01,     6, S::Ethiopic),
+	ran(0x0ab09,     6, S::Ethiopic),
+	ran(0x0ab11,     6, S::Ethiopic),
+	ran(0x0ab20,     7, S::Ethiopic),
+	ran(0x0ab28,     7, S::Ethiopic),
+	ran(0x0ab30,    43, S::Latin),
+	ran(0x0ab5b,     1, S::Common),
+	ran(0x0ab5c,     9, S::Latin),
+	ran(0x0ab65,     1, S::Greek),
+	ran(0x0ab66,     4, S::Latin),
+	ran(0x0ab6a,     2, S::Common),
+	ran(0x0ab70,    80, S::Cherokee),
+	ran(0x0abc0,    46, S::MeeteiMayek),
+	ran(0x0abf0,    10, S::MeeteiMayek),
+	ran(0x0ac00, 11172, S::Hangul),
+	ran(0x0d7b0,    23, S::Hangul),
+	ran(0x0d7cb,    49, S::Hangul),
+	ran(0x0f900,   366, S::Han),
+	ran(0x0fa70,   106, S::Han),
+	ran(0x0fb00,     7, S::Latin),
+	ran(0x0fb13,     5, S::Armenian),
+	ran(0x0fb1d,    26, S::Hebrew),
+	ran(0x0fb38,     5, S::Hebrew),
+	ran(0x0fb3e,     1, S::Hebrew),
+	ran(0x0fb40,     2, S::Hebrew),
+	ran(0x0fb43,     2, S::Hebrew),
+	ran(0x0fb46,    10, S::Hebrew),
+	ran(0x0fb50,   115, S::Arabic),
+	ran(0x0fbd3,   363, S::Arabic),
+	ran(0x0fd3e,     2, S::Common),
+	ran(0x0fd40,    80, S::Arabic),
+	ran(0x0fd92,    54, S::Arabic),
+	ran(0x0fdcf,     1, S::Arabic),
+	ran(0x0fdf0,    16, S::Arabic),
+	ran(0x0fe00,    16, S::Inherited),
+	ran(0x0fe10,    10, S::Common),
+	ran(0x0fe20,    14, S::Inherited),
+	ran(0x0fe2e,     2, S::Cyrillic),
+	ran(0x0fe30,    35, S::Common),
+	ran(0x0fe54,    19, S::Common),
+	ran(0x0fe68,     4, S::Common),
+	ran(0x0fe70,     5, S::Arabic),
+	ran(0x0fe76,   135, S::Arabic),
+	ran(0x0feff,     1, S::Common),
+	ran(0x0ff01,    32, S::Common),
+	ran(0x0ff21,    26, S::Latin),
+	ran(0x0ff3b,     6, S::Common),
+	ran(0x0ff41,    26, S::Latin),
+	ran(0x0ff5b,    11, S::Common),
+	ran(0x0ff66,    10, S::Katakana),
+	ran(0x0ff70,     1, S::Common),
+	ran(0x0ff71,    45, S::Katakana),
+	ran(0x0ff9e,     2, S::Common),
+	ran(0x0ffa0,    31, S::Hangul),
+	ran(0x0ffc2,     6, S::Hangul),
+	ran(0x0ffca,     6, S::Hangul),
+	ran(0x0ffd2,     6, S::Hangul),
+	ran(0x0ffda,     3, S::Hangul),
+	ran(0x0ffe0,     7, S::Common),
+	ran(0x0ffe8,     7, S::Common),
+	ran(0x0fff9,     5, S::Common),
+	ran(0x10000,    12, S::LinearB),
+	ran(0x1000d,    26, S::LinearB),
+	ran(0x10028,    19, S::LinearB),
+	ran(0x1003c,     2, S::LinearB),
+	ran(0x1003f,    15, S::LinearB),
+	ran(0x10050,    14, S::LinearB),
+	ran(0x10080,   123, S::LinearB),
+	ran(0x10100,     3, S::Common),
+	ran(0x10107,    45, S::Common),
+	ran(0x10137,     9, S::Common),
+	ran(0x10140,    79, S::Greek),
+	ran(0x10190,    13, S::Common),
+	ran(0x101a0,     1, S::Greek),
+	ran(0x101d0,    45, S::Common),
+	ran(0x101fd,     1, S::Inherited),
+	ran(0x10280,    29, S::Lycian),
+	ran(0x102a0,    49, S::Carian),
+	ran(0x102e0,     1, S::Inherited),
+	ran(0x102e1,    27, S::Common),
+	ran(0x10300,    36, S::OldItalic),
+	ran(0x1032d,     3, S::OldItalic),
+	ran(0x10330,    27, S::Gothic),
+	ran(0x10350,    43, S::OldPermic),
+	ran(0x10380,    30, S::Ugaritic),
+	ran(0x1039f,     1, S::Ugaritic),
+	ran(0x103a0,    36, S::OldPersian),
+	ran(0x103c8,    14, S::OldPersian),
+	ran(0x10400,    80, S::Deseret),
+	ran(0x10450,    48, S::Shavian),
+	ran(0x10480,    30, S::Osmanya),
+	ran(0x104a0,    10, S::Osmanya),
+	ran(0x104b0,    36, S::Osage),
+	ran(0x104d8,    36, S::Osage),
+	ran(0x10500,    40, S::Elbasan),
+	ran(0x10530,    52, S::CaucasianAlbanian),
+	ran(0x1056f,     1, S::CaucasianAlbanian),
+	ran(0x10570,    11, S::Vithkuqi),
+	ran(0x1057c,    15, S::Vithkuqi),
+	ran(0x1058c,     7, S::Vithkuqi),
+	ran(0x10594,     2, S::Vithkuqi),
+	ran(0x10597,    11, S::Vithkuqi),
+	ran(0x105a3,    15, S::Vithkuqi),
+	ran(0x105b3,     7, S::Vithkuqi),
+	ran(0x105bb,     2, S::Vithkuqi),
+	ran(0x10600,   311, S::LinearA),
+	ran(0x10740,    22, S::LinearA),
+	ran(0x10760,     8, S::LinearA),
+	ran(0x10780,     6, S::Latin),
+	ran(0x10787,    42, S::Latin),
+	ran(0x107b2,     9, S::Latin),
+	ran(0x10800,     6, S::Cypriot),
+	ran(0x10808,     1, S::Cypriot),
+	ran(0x1080a,    44, S::Cypriot),
+	ran(0x10837,     2, S::Cypriot),
+	ran(0x1083c,     1, S::Cypriot),
+	ran(0x1083f,     1, S::Cypriot),
+	ran(0x10840,    22, S::ImperialAramaic),
+	ran(0x10857,     9, S::ImperialAramaic),
+	ran(0x10860,    32, S::Palmyrene),
+	ran(0x10880,    31, S::Nabataean),
+	ran(0x108a7,     9, S::Nabataean),
+	ran(0x108e0,    19, S::Hatran),
+	ran(0x108f4,     2, S::Hatran),
+	ran(0x108fb,     5, S::Hatran),
+	ran(0x10900,    28, S::Phoenician),
+	ran(0x1091f,     1, S::Phoenician),
+	ran(0x10920,    26, S::Lydian),
+	ran(0x1093f,     1, S::Lydian),
+	ran(0x10980,    32, S::MeroiticHieroglyphs),
+	ran(0x109a0,    24, S::MeroiticCursive),
+	ran(0x109bc,    20, S::MeroiticCursive),
+	ran(0x109d2,    46, S::MeroiticCursive),
+	ran(0x10a00,     4, S::Kharoshthi),
+	ran(0x10a05,     2, S::Kharoshthi),
+	ran(0x10a0c,     8, S::Kharoshthi),
+	ran(0x10a15,     3, S::Kharoshthi),
+	ran(0x10a19,    29, S::Kharoshthi),
+	ran(0x10a38,     3, S::Kharoshthi),
+	ran(0x10a3f,    10, S::Kharoshthi),
+	ran(0x10a50,     9, S::Kharoshthi),
+	ran(0x10a60,    32, S::OldSouthArabian),
+	ran(0x10a80,    32, S::OldNorthArabian),
+	ran(0x10ac0,    39, S::Manichaean),
+	ran(0x10aeb,    12, S::Manichaean),
+	ran(0x10b00,    54, S::Avestan),
+	ran(0x10b39,     7, S::Avestan),
+	ran(0x10b40,    22, S::InscriptionalParthian),
+	ran(0x10b58,     8, S::InscriptionalParthian),
+	ran(0x10b60,    19, S::InscriptionalPahlavi),
+	ran(0x10b78,     8, S::InscriptionalPahlavi),
+	ran(0x10b80,    18, S::PsalterPahlavi),
+	ran(0x10b99,     4, S::PsalterPahlavi),
+	ran(0x10ba9,     7, S::PsalterPahlavi),
+	ran(0x10c00,    73, S::OldTurkic),
+	ran(0x10c80,    51, S::OldHungarian),
+	ran(0x10cc0,    51, S::OldHungarian),
+	ran(0x10cfa,     6, S::OldHungarian),
+	ran(0x10d00,    40, S::HanifiRohingya),
+	ran(0x10d30,    10, S::HanifiRohingya),
+	ran(0x10e60,    31, S::Arabic),
+	ran(0x10e80,    42, S::Yezidi),
+	ran(0x10eab,     3, S::Yezidi),
+	ran(0x10eb0,     2, S::Yezidi),
+	ran(0x10f00,    40, S::OldSogdian),
+	ran(0x10f30,    42, S::Sogdian),
+	ran(0x10f70,    26, S::OldUyghur),
+	ran(0x10fb0,    28, S::Chorasmian),
+	ran(0x10fe0,    23, S::Elymaic),
+	ran(0x11000,    78, S::Brahmi),
+	ran(0x11052,    36, S::Brahmi),
+	ran(0x1107f,     1, S::Brahmi),
+	ran(0x11080,    67, S::Kaithi),
+	ran(0x110cd,     1, S::Kaithi),
+	ran(0x110d0,    25, S::SoraSompeng),
+	ran(0x110f0,    10, S::SoraSompeng),
+	ran(0x11100,    53, S::Chakma),
+	ran(0x11136,    18, S::Chakma),
+	ran(0x11150,    39, S::Mahajani),
+	ran(0x11180,    96, S::Sharada),
+	ran(0x111e1,    20, S::Sinhala),
+	ran(0x11200,    18, S::Khojki),
+	ran(0x11213,    44, S::Khojki),
+	ran(0x11280,     7, S::Multani),
+	ran(0x11288,     1, S::Multani),
+	ran(0x1128a,     4, S::Multani),
+	ran(0x1128f,    15, S::Multani),
+	ran(0x1129f,    11, S::Multani),
+	ran(0x112b0,    59, S::Khudawadi),
+	ran(0x112f0,    10, S::Khudawadi),
+	ran(0x11300,     4, S::Grantha),
+	ran(0x11305,     8, S::Grantha),
+	ran(0x1130f,     2, S::Grantha),
+	ran(0x11313,    22, S::Grantha),
+	ran(0x1132a,     7, S::Grantha),
+	ran(0x11332,     2, S::Grantha),
+	ran(0x11335,     5, S::Grantha),
+	ran(0x1133b,     1, S::Inherited),
+	ran(0x1133c,     9, S::Grantha),
+	ran(0x11347,     2, S::Grantha),
+	ran(0x1134b,     3, S::Grantha),
+	ran(0x11350,     1, S::Grantha),
+	ran(0x11357,     1, S::Grantha),
+	ran(0x1135d,     7, S::Grantha),
+	ran(0x11366,     7, S::Grantha),
+	ran(0x11370,     5, S::Grantha),
+	ran(0x11400,    92, S::Newa),
+	ran(0x1145d,     5, S::Newa),
+	ran(0x11480,    72, S::Tirhuta),
+	ran(0x114d0,    10, S::Tirhuta),
+	ran(0x11580,    54, S::Siddham),
+	ran(0x115b8,    38, S::Siddham),
+	ran(0x11600,    69, S::Modi),
+	ran(0x11650,    10, S::Modi),
+	ran(0x11660,    13, S::Mongolian),
+	ran(0x11680,    58, S::Takri),
+	ran(0x116c0,    10, S::Takri),
+	ran(0x11700,    27, S::Ahom),
+	ran(0x1171d,    15, S::Ahom),
+	ran(0x11730,    23, S::Ahom),
+	ran(0x11800,    60, S::Dogra),
+	ran(0x118a0,    83, S::WarangCiti),
+	ran(0x118ff,     1, S::WarangCiti),
+	ran(0x11900,     7, S::DivesAkuru),
+	ran(0x11909,     1, S::DivesAkuru),
+	ran(0x1190c,     8, S::DivesAkuru),
+	ran(0x11915,     2, S::DivesAkuru),
+	ran(0x11918,    30, S::DivesAkuru),
+	ran(0x11937,     2, S::DivesAkuru),
+	ran(0x1193b,    12, S::DivesAkuru),
+	ran(0x11950,    10, S::DivesAkuru),
+	ran(0x119a0,     8, S::Nandinagari),
+	ran(0x119aa,    46, S::Nandinagari),
+	ran(0x119da,    11, S::Nandinagari),
+	ran(0x11a00,    72, S::ZanabazarSquare),
+	ran(0x11a50,    83, S::Soyombo),
+	ran(0x11ab0,    16, S::CanadianAboriginal),
+	ran(0x11ac0,    57, S::PauCinHau),
+	ran(0x11c00,     9, S::Bhaiksuki),
+	ran(0x11c0a,    45, S::Bhaiksuki),
+	ran(0x11c38,    14, S::Bhaiksuki),
+	ran(0x11c50,    29, S::Bhaiksuki),
+	ran(0x11c70,    32, S::Marchen),
+	ran(0x11c92,    22, S::Marchen),
+	ran(0x11ca9,    14, S::Marchen),
+	ran(0x11d00,     7, S::MasaramGondi),
+	ran(0x11d08,     2, S::MasaramGondi),
+	ran(0x11d0b,    44, S::MasaramGondi),
+	ran(0x11d3a,     1, S::MasaramGondi),
+	ran(0x11d3c,     2, S::MasaramGondi),
+	ran(0x11d3f,     9, S::MasaramGondi),
+	ran(0x11d50,    10, S::MasaramGondi),
+	ran(0x11d60,     6, S::GunjalaGondi),
+	ran(0x11d67,     2, S::GunjalaGondi),
+	ran(0x11d6a,    37, S::GunjalaGondi),
+	ran(0x11d90,     2, S::GunjalaGondi),
+	ran(0x11d93,     6, S::GunjalaGondi),
+	ran(0x11da0,    10, S::GunjalaGondi),
+	ran(0x11ee0,    25, S::Makasar),
+	ran(0x11fb0,     1, S::Lisu),
+	ran(0x11fc0,    50, S::Tamil),
+	ran(0x11fff,     1, S::Tamil),
+	ran(0x12000,   922, S::Cuneiform),
+	ran(0x12400,   111, S::Cuneiform),
+	ran(0x12470,     5, S::Cuneiform),
+	ran(0x12480,   196, S::Cuneiform),
+	ran(0x12f90,    99, S::CyproMinoan),
+	ran(0x13000,  1071, S::EgyptianHieroglyphs),
+	ran(0x13430,     9, S::EgyptianHieroglyphs),
+	ran(0x14400,   583, S::AnatolianHieroglyphs),
+	ran(0x16800,   569, S::Bamum),
+	ran(0x16a40,    31, S::Mro),
+	ran(0x16a60,    10, S::Mro),
+	ran(0x16a6e,     2, S::Mro),
+	ran(0x16a70,    79, S::Tangsa),
+	ran(0x16ac0,    10, S::Tangsa),
+	ran(0x16ad0,    30, S::BassaVah),
+	ran(0x16af0,     6, S::BassaVah),
+	ran(0x16b00,    70, S::PahawhHmong),
+	ran(0x16b50,    10, S::PahawhHmong),
+	ran(0x16b5b,     7, S::PahawhHmong),
+	ran(0x16b63,    21, S::PahawhHmong),
+	ran(0x16b7d,    19, S::PahawhHmong),
+	ran(0x16e40,    91, S::Medefaidrin),
+	ran(0x16f00,    75, S::Miao),
+	ran(0x16f4f,    57, S::Miao),
+	ran(0x16f8f,    17, S::Miao),
+	ran(0x16fe0,     1, S::Tangut),
+	ran(0x16fe1,     1, S::Nushu),
+	ran(0x16fe2,     2, S::Han),
+	ran(0x16fe4,     1, S::KhitanSmallScript),
+	ran(0x16ff0,     2, S::Han),
+	ran(0x17000,  6136, S::Tangut),
+	ran(0x18800,   768, S::Tangut),
+	ran(0x18b00,   470, S::KhitanSmallScript),
+	ran(0x18d00,     9, S::Tangut),
+	ran(0x1aff0,     4, S::Katakana),
+	ran(0x1aff5,     7, S::Katakana),
+	ran(0x1affd,     2, S::Katakana),
+	ran(0x1b000,     1, S::Katakana),
+	ran(0x1b001,   287, S::Hiragana),
+	ran(0x1b120,     3, S::Katakana),
+	ran(0x1b150,     3, S::Hiragana),
+	ran(0x1b164,     4, S::Katakana),
+	ran(0x1b170,   396, S::Nushu),
+	ran(0x1bc00,   107, S::Duployan),
+	ran(0x1bc70,    13, S::Duployan),
+	ran(0x1bc80,     9, S::Duployan),
+	ran(0x1bc90,    10, S::Duployan),
+	ran(0x1bc9c,     4, S::Duployan),
+	ran(0x1bca0,     4, S::Common),
+	ran(0x1cf00,    46, S::Inherited),
+	ran(0x1cf30,    23, S::Inherited),
+	ran(0x1cf50,   116, S::Common),
+	ran(0x1d000,   246, S::Common),
+	ran(0x1d100,    39, S::Common),
+	ran(0x1d129,    62, S::Common),
+	ran(0x1d167,     3, S::Inherited),
+	ran(0x1d16a,    17, S::Common),
+	ran(0x1d17b,     8, S::Inherited),
+	ran(0x1d183,     2, S::Common),
+	ran(0x1d185,     7, S::Inherited),
+	ran(0x1d18c,    30, S::Common),
+	ran(0x1d1aa,     4, S::Inherited),
+	ran(0x1d1ae,    61, S::Common),
+	ran(0x1d200,    70, S::Greek),
+	ran(0x1d2e0,    20, S::Common),
+	ran(0x1d300,    87, S::Common),
+	ran(0x1d360,    25, S::Common),
+	ran(0x1d400,    85, S::Common),
+	ran(0x1d456,    71, S::Common),
+	ran(0x1d49e,     2, S::Common),
+	ran(0x1d4a2,     1, S::Common),
+	ran(0x1d4a5,     2, S::Common),
+	ran(0x1d4a9,     4, S::Common),
+	ran(0x1d4ae,    12, S::Common),
+	ran(0x1d4bb,     1, S::Common),
+	ran(0x1d4bd,     7, S::Common),
+	ran(0x1d4c5,    65, S::Common),
+	ran(0x1d507,     4, S::Common),
+	ran(0x1d50d,     8, S::Common),
+	ran(0x1d516,     7, S::Common),
+	ran(0x1d51e,    28, S::Common),
+	ran(0x1d53b,     4, S::Common),
+	ran(0x1d540,     5, S::Common),
+	ran(0x1d546,     1, S::Common),
+	ran(0x1d54a,     7, S::Common),
+	ran(0x1d552,   340, S::Common),
+	ran(0x1d6a8,   292, S::Common),
+	ran(0x1d7ce,    50, S::Common),
+	ran(0x1d800,   652, S::SignWriting),
+	ran(0x1da9b,     5, S::SignWriting),
+	ran(0x1daa1,    15, S::SignWriting),
+	ran(0x1df00,    31, S::Latin),
+	ran(0x1e000,     7, S::Glagolitic),
+	ran(0x1e008,    17, S::Glagolitic),
+	ran(0x1e01b,     7, S::Glagolitic),
+	ran(0x1e023,     2, S::Glagolitic),
+	ran(0x1e026,     5, S::Glagolitic),
+	ran(0x1e100,    45, S::NyiakengPuachueHmong),
+	ran(0x1e130,    14, S::NyiakengPuachueHmong),
+	ran(0x1e140,    10, S::NyiakengPuachueHmong),
+	ran(0x1e14e,     2, S::NyiakengPuachueHmong),
+	ran(0x1e290,    31, S::Toto),
+	ran(0x1e2c0,    58, S::Wancho),
+	ran(0x1e2ff,     1, S::Wancho),
+	ran(0x1e7e0,     7, S::Ethiopic),
+	ran(0x1e7e8,     4, S::Ethiopic),
+	ran(0x1e7ed,     2, S::Ethiopic),
+	ran(0x1e7f0,    15, S::Ethiopic),
+	ran(0x1e800,   197, S::MendeKikakui),
+	ran(0x1e8c7,    16, S::MendeKikakui),
+	ran(0x1e900,    76, S::Adlam),
+	ran(0x1e950,    10, S::Adlam),
+	ran(0x1e95e,     2, S::Adlam),
+	ran(0x1ec71,    68, S::Common),
+	ran(0x1ed01,    61, S::Common),
+	ran(0x1ee00,     4, S::Arabic),
+	ran(0x1ee05,    27, S::Arabic),
+	ran(0x1ee21,     2, S::Arabic),
+	ran(0x1ee24,     1, S::Arabic),
+	ran(0x1ee27,     1, S::Arabic),
+	ran(0x1ee29,    10, S::Arabic),
+	ran(0x1ee34,     4, S::Arabic),
+	ran(0x1ee39,     1, S::Arabic),
+	ran(0x1ee3b,     1, S::Arabic),
+	ran(0x1ee42,     1, S::Arabic),
+	ran(0x1ee47,     1, S::Arabic),
+	ran(0x1ee49,     1, S::Arabic),
+	ran(0x1ee4b,     1, S::Arabic),
+	ran(0x1ee4d,     3, S::Arabic),
+	ran(0x1ee51,     2, S::Arabic),
+	ran(0x1ee54,     1, S::Arabic),
+	ran(0x1ee57,     1, S::Arabic),
+	ran(0x1ee59,     1, S::Arabic),
+	ran(0x1ee5b,     1, S::Arabic),
+	ran(0x1ee5d,     1, S::Arabic),
+	ran(0x1ee5f,     1, S::Arabic),
+	ran(0x1ee61,     2, S::Arabic),
+	ran(0x1ee64,     1, S::Arabic),
+	ran(0x1ee67,     4, S::Arabic),
+	ran(0x1ee6c,     7, S::Arabic),
+	ran(0x1ee74,     4, S::Arabic),
+	ran(0x1ee79,     4, S::Arabic),
+	ran(0x1ee7e,     1, S::Arabic),
+	ran(0x1ee80,    10, S::Arabic),
+	ran(0x1ee8b,    17, S::Arabic),
+	ran(0x1eea1,     3, S::Arabic),
+	ran(0x1eea5,     5, S::Arabic),
+	ran(0x1eeab,    17, S::Arabic),
+	ran(0x1eef0,     2, S::Arabic),
+	ran(0x1f000,    44, S::Common),
+	ran(0x1f030,   100, S::Common),
+	ran(0x1f0a0,    15, S::Common),
+	ran(0x1f0b1,    15, S::Common),
+	ran(0x1f0c1,    15, S::Common),
+	ran(0x1f0d1,    37, S::Common),
+	ran(0x1f100,   174, S::Common),
+	ran(0x1f1e6,    26, S::Common),
+	ran(0x1f200,     1, S::Hiragana),
+	ran(0x1f201,     2, S::Common),
+	ran(0x1f210,    44, S::Common),
+	ran(0x1f240,     9, S::Common),
+	ran(0x1f250,     2, S::Common),
+	ran(0x1f260,     6, S::Common),
+	ran(0x1f300,   984, S::Common),
+	ran(0x1f6dd,    16, S::Common),
+	ran(0x1f6f0,    13, S::Common),
+	ran(0x1f700,   116, S::Common),
+	ran(0x1f780,    89, S::Common),
+	ran(0x1f7e0,    12, S::Common),
+	ran(0x1f7f0,     1, S::Common),
+	ran(0x1f800,    12, S::Common),
+	ran(0x1f810,    56, S::Common),
+	ran(0x1f850,    10, S::Common),
+	ran(0x1f860,    40, S::Common),
+	ran(0x1f890,    30, S::Common),
+	ran(0x1f8b0,     2, S::Common),
+	ran(0x1f900,   340, S::Common),
+	ran(0x1fa60,    14, S::Common),
+	ran(0x1fa70,     5, S::Common),
+	ran(0x1fa78,     5, S::Common),
+	ran(0x1fa80,     7, S::Common),
+	ran(0x1fa90,    29, S::Common),
+	ran(0x1fab0,    11, S::Common),
+	ran(0x1fac0,     6, S::Common),
+	ran(0x1fad0,    10, S::Common),
+	ran(0x1fae0,     8, S::Common),
+	ran(0x1faf0,     7, S::Common),
+	ran(0x1fb00,   147, S::Common),
+	ran(0x1fb94,    55, S::Common),
+	ran(0x1fbf0,    10, S::Common),
+	ran(0x20000, 42720, S::Han),
+	ran(0x2a700,  4153, S::Han),
+	ran(0x2b740,   222, S::Han),
+	ran(0x2b820,  5762, S::Han),
+	ran(0x2ceb0,  7473, S::Han),
+	ran(0x2f800,   542, S::Han),
+	ran(0x30000,  4939, S::Han),
+	ran(0xe0001,     1, S::Common),
+	ran(0xe0020,    96, S::Common),
+	ran(0xe0100,   240, S::Inherited),
+];
+
+pub const SCRIPT_EXTENSIONS: [ScriptExtRange; 121] = [
+	ext(0x00342,     1, &[S::Greek]),
+	ext(0x00345,     1, &[S::Greek]),
+	ext(0x00363,    13, &[S::Latin]),
+	ext(0x00483,     1, &[S::Cyrillic, S::OldPermic]),
+	ext(0x00484,     1, &[S::Cyrillic, S::Glagolitic]),
+	ext(0x00485,     2, &[S::Cyrillic, S::Latin]),
+	ext(0x00487,     1, &[S::Cyrillic, S::Glagolitic]),
+	ext(0x0060c,     1, &[S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+	ext(0x0061b,     1, &[S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+	ext(0x0061c,     1, &[S::Arabic, S::Syriac, S::Thaana]),
+	ext(0x0061f,     1, &[S::Adlam, S::Arabic, S::Nko, S::HanifiRohingya, S::Syriac, S::Thaana, S::Yezidi]),
+	ext(0x00640,     1, &[S::Adlam, S::Arabic, S::Mandaic, S::Manichaean, S::OldUyghur, S::PsalterPahlavi, S::HanifiRohingya, S::Sogdian, S::Syriac]),
+	ext(0x0064b,    11, &[S::Arabic, S::Syriac]),
+	ext(0x00660,    10, &[S::Arabic, S::Thaana, S::Yezidi]),
+	ext(0x00670,     1, &[S::Arabic, S::Syriac]),
+	ext(0x006d4,     1, &[S::Arabic, S::HanifiRohingya]),
+	ext(0x00951,     1, &[S::Bengali, S::Devanagari, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Latin, S::Malayalam, S::Oriya, S::Sharada, S::Tamil, S::Telugu, S::Tirhuta]),
+	ext(0x00952,     1, &[S::Bengali, S::Devanagari, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Latin, S::Malayalam, S::Oriya, S::Tamil, S::Telugu, S::Tirhuta]),
+	ext(0x00964,     1, &[S::Bengali, S::Devanagari, S::Dogra, S::GunjalaGondi, S::MasaramGondi, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Mahajani, S::Malayalam, S::Nandinagari, S::Oriya, S::Khudawadi, S::Sinhala, S::SylotiNagri, S::Takri, S::Tamil, S::Telugu, S::Tirhuta]),
+	ext(0x00965,     1, &[S::Bengali, S::Devanagari, S::Dogra, S::GunjalaGondi, S::MasaramGondi, S::Grantha, S::Gujarati, S::Gurmukhi, S::Kannada, S::Limbu, S::Mahajani, S::Malayalam, S::Nandinagari, S::Oriya, S::Khudawadi, S::Sinhala, S::SylotiNagri, S::Takri, S::Tamil, S::Telugu, S::Tirhuta]),
+	ext(0x00966,    10, &[S::Devanagari, S::Dogra, S::Kaithi, S::Mahajani]),
+	ext(0x009e6,    10, &[S::Bengali, S::Chakma, S::SylotiNagri]),
+	ext(0x00a66,    10, &[S::Gurmukhi, S::Multani]),
+	ext(0x00ae6,    10, &[S::Gujarati, S::Khojki]),
+	ext(0x00be6,    14, &[S::Tamil, S::Grantha]),
+	ext(0x00ce6,    10, &[S::Kannada, S::Nandinagari]),
+	ext(0x01040,    10, &[S::Myanmar, S::Chakma, S::TaiLe]),
+	ext(0x010fb,     1, &[S::Georgian, S::Latin]),
+	ext(0x01735,     2, &[S::Buhid, S::Hanunoo, S::Tagbanwa, S::Tagalog]),
+	ext(0x01802,     2, &[S::Mongolian, S::PhagsPa]),
+	ext(0x01805,     1, &[S::Mongolian, S::PhagsPa]),
+	ext(0x01cd0,     1, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada]),
+	ext(0x01cd1,     1, &[S::Devanagari]),
+	ext(0x01cd2,     1, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada]),
+	ext(0x01cd3,     1, &[S::Devanagari, S::Grantha]),
+	ext(0x01cd4,     1, &[S::Devanagari]),
+	ext(0x01cd5,     2, &[S::Bengali, S::Devanagari]),
+	ext(0x01cd7,     1, &[S::Devanagari, S::Sharada]),
+	ext(0x01cd8,     1, &[S::Bengali, S::Devanagari]),
+	ext(0x01cd9,     1, &[S::Devanagari, S::Sharada]),
+	ext(0x01cda,     1, &[S::Devanagari, S::Kannada, S::Malayalam, S::Oriya, S::Tamil, S::Telugu]),
+	ext(0x01cdb,     1, &[S::Devanagari]),
+	ext(0x01cdc,     2, &[S::Devanagari, S::Sharada]),
+	ext(0x01cde,     2, &[S::Devanagari]),
+	ext(0x01ce0,     1, &[S::Devanagari, S::Sharada]),
+	ext(0x01ce1,     1, &[S::Bengali, S::Devanagari]),
+	ext(0x01ce2,     7, &[S::Devanagari]),
+	ext(0x01ce9,     1, &[S::Devanagari, S::Nandinagari]),
+	ext(0x01cea,     1, &[S::Bengali, S::Devanagari]),
+	ext(0x01ceb,     2, &[S::Devanagari]),
+	ext(0x01ced,     1, &[S::Bengali, S::Devanagari]),
+	ext(0x01cee,     4, &[S::Devanagari]),
+	ext(0x01cf2,     1, &[S::Bengali, S::Devanagari, S::Grantha, S::Kannada, S::Nandinagari, S::Oriya, S::Telugu, S::Tirhuta]),
+	ext(0x01cf3,     1, &[S::Devanagari, S::Grantha]),
+	ext(0x01cf4,     1, &[S::Devanagari, S::Grantha, S::Kannada]),
+	ext(0x01cf5,     2, &[S::Bengali, S::Devanagari]),
+	ext(0x01cf7,     1, &[S::Bengali]),
+	ext(0x01cf8,     2, &[S::Devanagari, S::Grantha]),
+	ext(0x01cfa,     1, &[S::Nandinagari]),
+	ext(0x01dc0,     2, &[S::Greek]),
+	ext(0x01df8,     1, &[S::Cyrillic, S::Syriac]),
+	ext(0x01dfa,     1, &[S::Syriac]),
+	ext(0x0202f,     1, &[S::Latin, S::Mongolian]),
+	ext(0x020f0,     1, &[S::Devanagari, S::Grantha, S::Latin]),
+	ext(0x02e43,     1, &[S::Cyrillic, S::Glagolitic]),
+	ext(0x03001,     2, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+	ext(0x03003,     1, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x03006,     1, &[S::Han]),
+	ext(0x03008,    10, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+	ext(0x03013,     1, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x03014,     8, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+	ext(0x0301c,     4, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x0302a,     4, &[S::Bopomofo, S::Han]),
+	ext(0x03030,     1, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x03031,     5, &[S::Hiragana, S::Katakana]),
+	ext(0x03037,     1, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x0303c,     2, &[S::Han, S::Hiragana, S::Katakana]),
+	ext(0x0303e,     2, &[S::Han]),
+	ext(0x03099,     4, &[S::Hiragana, S::Katakana]),
+	ext(0x030a0,     1, &[S::Hiragana, S::Katakana]),
+	ext(0x030fb,     1, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+	ext(0x030fc,     1, &[S::Hiragana, S::Katakana]),
+	ext(0x03190,    16, &[S::Han]),
+	ext(0x031c0,    36, &[S::Han]),
+	ext(0x03220,    40, &[S::Han]),
+	ext(0x03280,    49, &[S::Han]),
+	ext(0x032c0,    12, &[S::Han]),
+	ext(0x032ff,     1, &[S::Han]),
+	ext(0x03358,    25, &[S::Han]),
+	ext(0x0337b,     5, &[S::Han]),
+	ext(0x033e0,    31, &[S::Han]),
+	ext(0x0a66f,     1, &[S::Cyrillic, S::Glagolitic]),
+	ext(0x0a700,     8, &[S::Han, S::Latin]),
+	ext(0x0a830,     3, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kannada, S::Kaithi, S::Mahajani, S::Malayalam, S::Modi, S::Nandinagari, S::Khudawadi, S::Takri, S::Tirhuta]),
+	ext(0x0a833,     3, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kannada, S::Kaithi, S::Mahajani, S::Modi, S::Nandinagari, S::Khudawadi, S::Takri, S::Tirhuta]),
+	ext(0x0a836,     4, &[S::Devanagari, S::Dogra, S::Gujarati, S::Gurmukhi, S::Khojki, S::Kaithi, S::Mahajani, S::Modi, S::Khudawadi, S::Takri, S::Tirhuta]),
+	ext(0x0a8f1,     1, &[S::Devanagari, S::Bengali]),
+	ext(0x0a8f3,     1, &[S::Devanagari, S::Tamil]),
+	ext(0x0a92e,     1, &[S::KayahLi, S::Latin, S::Myanmar]),
+	ext(0x0a9cf,     1, &[S::Buginese, S::Javanese]),
+	ext(0x0fd3e,     2, &[S::Arabic, S::Nko]),
+	ext(0x0fdf2,     1, &[S::Arabic, S::Thaana]),
+	ext(0x0fdfd,     1, &[S::Arabic, S::Thaana]),
+	ext(0x0fe45,     2, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana]),
+	ext(0x0ff61,     5, &[S::Bopomofo, S::Hangul, S::Han, S::Hiragana, S::Katakana, S::Yi]),
+	ext(0x0ff70,     1, &[S::Hiragana, S::Katakana]),
+	ext(0x0ff9e,     2, &[S::Hiragana, S::Katakana]),
+	ext(0x10100,     2, &[S::CyproMinoan, S::Cypriot, S::LinearB]),
+	ext(0x10102,     1, &[S::Cypriot, S::LinearB]),
+	ext(0x10107,    45, &[S::Cypriot, S::LinearA, S::LinearB]),
+	ext(0x10137,     9, &[S::Cypriot, S::LinearB]),
+	ext(0x102e0,    28, &[S::Arabic, S::Coptic]),
+	ext(0x10af2,     1, &[S::Manichaean, S::OldUyghur]),
+	ext(0x11301,     1, &[S::Grantha, S::Tamil]),
+	ext(0x11303,     1, &[S::Grantha, S::Tamil]),
+	ext(0x1133b,     2, &[S::Grantha, S::Tamil]),
+	ext(0x11fd0,     2, &[S::Tamil, S::Grantha]),
+	ext(0x11fd3,     1, &[S::Tamil, S::Grantha]),
+	ext(0x1bca0,     4, &[S::Duployan]),
+	ext(0x1d360,    18, &[S::Han]),
+	ext(0x1f250,     2, &[S::Han]),
+];
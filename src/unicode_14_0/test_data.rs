@@ -0,0 +1,605 @@
+use crate::TestCase;
+pub static TEST_CASES: [TestCase; 602] = [
+	TestCase::new(25, "\x20\x20", &[1]),
+	TestCase::new(26, "\x20\u{0308}\x20", &[3]),
+	TestCase::new(27, "\x20\x0d", &[1]),
+	TestCase::new(28, "\x20\u{0308}\x0d", &[3]),
+	TestCase::new(29, "\x20\x0a", &[1]),
+	TestCase::new(30, "\x20\u{0308}\x0a", &[3]),
+	TestCase::new(31, "\x20\x01", &[1]),
+	TestCase::new(32, "\x20\u{0308}\x01", &[3]),
+	TestCase::new(33, "\x20\u{034f}", &[]),
+	TestCase::new(34, "\x20\u{0308}\u{034f}", &[]),
+	TestCase::new(35, "\x20\u{1f1e6}", &[1]),
+	TestCase::new(36, "\x20\u{0308}\u{1f1e6}", &[3]),
+	TestCase::new(37, "\x20\u{0600}", &[1]),
+	TestCase::new(38, "\x20\u{0308}\u{0600}", &[3]),
+	TestCase::new(39, "\x20\u{0903}", &[]),
+	TestCase::new(40, "\x20\u{0308}\u{0903}", &[]),
+	TestCase::new(41, "\x20\u{1100}", &[1]),
+	TestCase::new(42, "\x20\u{0308}\u{1100}", &[3]),
+	TestCase::new(43, "\x20\u{1160}", &[1]),
+	TestCase::new(44, "\x20\u{0308}\u{1160}", &[3]),
+	TestCase::new(45, "\x20\u{11a8}", &[1]),
+	TestCase::new(46, "\x20\u{0308}\u{11a8}", &[3]),
+	TestCase::new(47, "\x20\u{ac00}", &[1]),
+	TestCase::new(48, "\x20\u{0308}\u{ac00}", &[3]),
+	TestCase::new(49, "\x20\u{ac01}", &[1]),
+	TestCase::new(50, "\x20\u{0308}\u{ac01}", &[3]),
+	TestCase::new(51, "\x20\u{231a}", &[1]),
+	TestCase::new(52, "\x20\u{0308}\u{231a}", &[3]),
+	TestCase::new(53, "\x20\u{0300}", &[]),
+	TestCase::new(54, "\x20\u{0308}\u{0300}", &[]),
+	TestCase::new(55, "\x20\u{200d}", &[]),
+	TestCase::new(56, "\x20\u{0308}\u{200d}", &[]),
+	TestCase::new(57, "\x20\u{0378}", &[1]),
+	TestCase::new(58, "\x20\u{0308}\u{0378}", &[3]),
+	TestCase::new(59, "\x0d\x20", &[1]),
+	TestCase::new(60, "\x0d\u{0308}\x20", &[1,3]),
+	TestCase::new(61, "\x0d\x0d", &[1]),
+	TestCase::new(62, "\x0d\u{0308}\x0d", &[1,3]),
+	TestCase::new(63, "\x0d\x0a", &[]),
+	TestCase::new(64, "\x0d\u{0308}\x0a", &[1,3]),
+	TestCase::new(65, "\x0d\x01", &[1]),
+	TestCase::new(66, "\x0d\u{0308}\x01", &[1,3]),
+	TestCase::new(67, "\x0d\u{034f}", &[1]),
+	TestCase::new(68, "\x0d\u{0308}\u{034f}", &[1]),
+	TestCase::new(69, "\x0d\u{1f1e6}", &[1]),
+	TestCase::new(70, "\x0d\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(71, "\x0d\u{0600}", &[1]),
+	TestCase::new(72, "\x0d\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(73, "\x0d\u{0903}", &[1]),
+	TestCase::new(74, "\x0d\u{0308}\u{0903}", &[1]),
+	TestCase::new(75, "\x0d\u{1100}", &[1]),
+	TestCase::new(76, "\x0d\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(77, "\x0d\u{1160}", &[1]),
+	TestCase::new(78, "\x0d\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(79, "\x0d\u{11a8}", &[1]),
+	TestCase::new(80, "\x0d\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(81, "\x0d\u{ac00}", &[1]),
+	TestCase::new(82, "\x0d\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(83, "\x0d\u{ac01}", &[1]),
+	TestCase::new(84, "\x0d\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(85, "\x0d\u{231a}", &[1]),
+	TestCase::new(86, "\x0d\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(87, "\x0d\u{0300}", &[1]),
+	TestCase::new(88, "\x0d\u{0308}\u{0300}", &[1]),
+	TestCase::new(89, "\x0d\u{200d}", &[1]),
+	TestCase::new(90, "\x0d\u{0308}\u{200d}", &[1]),
+	TestCase::new(91, "\x0d\u{0378}", &[1]),
+	TestCase::new(92, "\x0d\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(93, "\x0a\x20", &[1]),
+	TestCase::new(94, "\x0a\u{0308}\x20", &[1,3]),
+	TestCase::new(95, "\x0a\x0d", &[1]),
+	TestCase::new(96, "\x0a\u{0308}\x0d", &[1,3]),
+	TestCase::new(97, "\x0a\x0a", &[1]),
+	TestCase::new(98, "\x0a\u{0308}\x0a", &[1,3]),
+	TestCase::new(99, "\x0a\x01", &[1]),
+	TestCase::new(100, "\x0a\u{0308}\x01", &[1,3]),
+	TestCase::new(101, "\x0a\u{034f}", &[1]),
+	TestCase::new(102, "\x0a\u{0308}\u{034f}", &[1]),
+	TestCase::new(103, "\x0a\u{1f1e6}", &[1]),
+	TestCase::new(104, "\x0a\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(105, "\x0a\u{0600}", &[1]),
+	TestCase::new(106, "\x0a\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(107, "\x0a\u{0903}", &[1]),
+	TestCase::new(108, "\x0a\u{0308}\u{0903}", &[1]),
+	TestCase::new(109, "\x0a\u{1100}", &[1]),
+	TestCase::new(110, "\x0a\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(111, "\x0a\u{1160}", &[1]),
+	TestCase::new(112, "\x0a\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(113, "\x0a\u{11a8}", &[1]),
+	TestCase::new(114, "\x0a\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(115, "\x0a\u{ac00}", &[1]),
+	TestCase::new(116, "\x0a\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(117, "\x0a\u{ac01}", &[1]),
+	TestCase::new(118, "\x0a\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(119, "\x0a\u{231a}", &[1]),
+	TestCase::new(120, "\x0a\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(121, "\x0a\u{0300}", &[1]),
+	TestCase::new(122, "\x0a\u{0308}\u{0300}", &[1]),
+	TestCase::new(123, "\x0a\u{200d}", &[1]),
+	TestCase::new(124, "\x0a\u{0308}\u{200d}", &[1]),
+	TestCase::new(125, "\x0a\u{0378}", &[1]),
+	TestCase::new(126, "\x0a\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(127, "\x01\x20", &[1]),
+	TestCase::new(128, "\x01\u{0308}\x20", &[1,3]),
+	TestCase::new(129, "\x01\x0d", &[1]),
+	TestCase::new(130, "\x01\u{0308}\x0d", &[1,3]),
+	TestCase::new(131, "\x01\x0a", &[1]),
+	TestCase::new(132, "\x01\u{0308}\x0a", &[1,3]),
+	TestCase::new(133, "\x01\x01", &[1]),
+	TestCase::new(134, "\x01\u{0308}\x01", &[1,3]),
+	TestCase::new(135, "\x01\u{034f}", &[1]),
+	TestCase::new(136, "\x01\u{0308}\u{034f}", &[1]),
+	TestCase::new(137, "\x01\u{1f1e6}", &[1]),
+	TestCase::new(138, "\x01\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(139, "\x01\u{0600}", &[1]),
+	TestCase::new(140, "\x01\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(141, "\x01\u{0903}", &[1]),
+	TestCase::new(142, "\x01\u{0308}\u{0903}", &[1]),
+	TestCase::new(143, "\x01\u{1100}", &[1]),
+	TestCase::new(144, "\x01\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(145, "\x01\u{1160}", &[1]),
+	TestCase::new(146, "\x01\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(147, "\x01\u{11a8}", &[1]),
+	TestCase::new(148, "\x01\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(149, "\x01\u{ac00}", &[1]),
+	TestCase::new(150, "\x01\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(151, "\x01\u{ac01}", &[1]),
+	TestCase::new(152, "\x01\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(153, "\x01\u{231a}", &[1]),
+	TestCase::new(154, "\x01\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(155, "\x01\u{0300}", &[1]),
+	TestCase::new(156, "\x01\u{0308}\u{0300}", &[1]),
+	TestCase::new(157, "\x01\u{200d}", &[1]),
+	TestCase::new(158, "\x01\u{0308}\u{200d}", &[1]),
+	TestCase::new(159, "\x01\u{0378}", &[1]),
+	TestCase::new(160, "\x01\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(161, "\u{034f}\x20", &[2]),
+	TestCase::new(162, "\u{034f}\u{0308}\x20", &[4]),
+	TestCase::new(163, "\u{034f}\x0d", &[2]),
+	TestCase::new(164, "\u{034f}\u{0308}\x0d", &[4]),
+	TestCase::new(165, "\u{034f}\x0a", &[2]),
+	TestCase::new(166, "\u{034f}\u{0308}\x0a", &[4]),
+	TestCase::new(167, "\u{034f}\x01", &[2]),
+	TestCase::new(168, "\u{034f}\u{0308}\x01", &[4]),
+	TestCase::new(169, "\u{034f}\u{034f}", &[]),
+	TestCase::new(170, "\u{034f}\u{0308}\u{034f}", &[]),
+	TestCase::new(171, "\u{034f}\u{1f1e6}", &[2]),
+	TestCase::new(172, "\u{034f}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(173, "\u{034f}\u{0600}", &[2]),
+	TestCase::new(174, "\u{034f}\u{0308}\u{0600}", &[4]),
+	TestCase::new(175, "\u{034f}\u{0903}", &[]),
+	TestCase::new(176, "\u{034f}\u{0308}\u{0903}", &[]),
+	TestCase::new(177, "\u{034f}\u{1100}", &[2]),
+	TestCase::new(178, "\u{034f}\u{0308}\u{1100}", &[4]),
+	TestCase::new(179, "\u{034f}\u{1160}", &[2]),
+	TestCase::new(180, "\u{034f}\u{0308}\u{1160}", &[4]),
+	TestCase::new(181, "\u{034f}\u{11a8}", &[2]),
+	TestCase::new(182, "\u{034f}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(183, "\u{034f}\u{ac00}", &[2]),
+	TestCase::new(184, "\u{034f}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(185, "\u{034f}\u{ac01}", &[2]),
+	TestCase::new(186, "\u{034f}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(187, "\u{034f}\u{231a}", &[2]),
+	TestCase::new(188, "\u{034f}\u{0308}\u{231a}", &[4]),
+	TestCase::new(189, "\u{034f}\u{0300}", &[]),
+	TestCase::new(190, "\u{034f}\u{0308}\u{0300}", &[]),
+	TestCase::new(191, "\u{034f}\u{200d}", &[]),
+	TestCase::new(192, "\u{034f}\u{0308}\u{200d}", &[]),
+	TestCase::new(193, "\u{034f}\u{0378}", &[2]),
+	TestCase::new(194, "\u{034f}\u{0308}\u{0378}", &[4]),
+	TestCase::new(195, "\u{1f1e6}\x20", &[4]),
+	TestCase::new(196, "\u{1f1e6}\u{0308}\x20", &[6]),
+	TestCase::new(197, "\u{1f1e6}\x0d", &[4]),
+	TestCase::new(198, "\u{1f1e6}\u{0308}\x0d", &[6]),
+	TestCase::new(199, "\u{1f1e6}\x0a", &[4]),
+	TestCase::new(200, "\u{1f1e6}\u{0308}\x0a", &[6]),
+	TestCase::new(201, "\u{1f1e6}\x01", &[4]),
+	TestCase::new(202, "\u{1f1e6}\u{0308}\x01", &[6]),
+	TestCase::new(203, "\u{1f1e6}\u{034f}", &[]),
+	TestCase::new(204, "\u{1f1e6}\u{0308}\u{034f}", &[]),
+	TestCase::new(205, "\u{1f1e6}\u{1f1e6}", &[]),
+	TestCase::new(206, "\u{1f1e6}\u{0308}\u{1f1e6}", &[6]),
+	TestCase::new(207, "\u{1f1e6}\u{0600}", &[4]),
+	TestCase::new(208, "\u{1f1e6}\u{0308}\u{0600}", &[6]),
+	TestCase::new(209, "\u{1f1e6}\u{0903}", &[]),
+	TestCase::new(210, "\u{1f1e6}\u{0308}\u{0903}", &[]),
+	TestCase::new(211, "\u{1f1e6}\u{1100}", &[4]),
+	TestCase::new(212, "\u{1f1e6}\u{0308}\u{1100}", &[6]),
+	TestCase::new(213, "\u{1f1e6}\u{1160}", &[4]),
+	TestCase::new(214, "\u{1f1e6}\u{0308}\u{1160}", &[6]),
+	TestCase::new(215, "\u{1f1e6}\u{11a8}", &[4]),
+	TestCase::new(216, "\u{1f1e6}\u{0308}\u{11a8}", &[6]),
+	TestCase::new(217, "\u{1f1e6}\u{ac00}", &[4]),
+	TestCase::new(218, "\u{1f1e6}\u{0308}\u{ac00}", &[6]),
+	TestCase::new(219, "\u{1f1e6}\u{ac01}", &[4]),
+	TestCase::new(220, "\u{1f1e6}\u{0308}\u{ac01}", &[6]),
+	TestCase::new(221, "\u{1f1e6}\u{231a}", &[4]),
+	TestCase::new(222, "\u{1f1e6}\u{0308}\u{231a}", &[6]),
+	TestCase::new(223, "\u{1f1e6}\u{0300}", &[]),
+	TestCase::new(224, "\u{1f1e6}\u{0308}\u{0300}", &[]),
+	TestCase::new(225, "\u{1f1e6}\u{200d}", &[]),
+	TestCase::new(226, "\u{1f1e6}\u{0308}\u{200d}", &[]),
+	TestCase::new(227, "\u{1f1e6}\u{0378}", &[4]),
+	TestCase::new(228, "\u{1f1e6}\u{0308}\u{0378}", &[6]),
+	TestCase::new(229, "\u{0600}\x20", &[]),
+	TestCase::new(230, "\u{0600}\u{0308}\x20", &[4]),
+	TestCase::new(231, "\u{0600}\x0d", &[2]),
+	TestCase::new(232, "\u{0600}\u{0308}\x0d", &[4]),
+	TestCase::new(233, "\u{0600}\x0a", &[2]),
+	TestCase::new(234, "\u{0600}\u{0308}\x0a", &[4]),
+	TestCase::new(235, "\u{0600}\x01", &[2]),
+	TestCase::new(236, "\u{0600}\u{0308}\x01", &[4]),
+	TestCase::new(237, "\u{0600}\u{034f}", &[]),
+	TestCase::new(238, "\u{0600}\u{0308}\u{034f}", &[]),
+	TestCase::new(239, "\u{0600}\u{1f1e6}", &[]),
+	TestCase::new(240, "\u{0600}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(241, "\u{0600}\u{0600}", &[]),
+	TestCase::new(242, "\u{0600}\u{0308}\u{0600}", &[4]),
+	TestCase::new(243, "\u{0600}\u{0903}", &[]),
+	TestCase::new(244, "\u{0600}\u{0308}\u{0903}", &[]),
+	TestCase::new(245, "\u{0600}\u{1100}", &[]),
+	TestCase::new(246, "\u{0600}\u{0308}\u{1100}", &[4]),
+	TestCase::new(247, "\u{0600}\u{1160}", &[]),
+	TestCase::new(248, "\u{0600}\u{0308}\u{1160}", &[4]),
+	TestCase::new(249, "\u{0600}\u{11a8}", &[]),
+	TestCase::new(250, "\u{0600}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(251, "\u{0600}\u{ac00}", &[]),
+	TestCase::new(252, "\u{0600}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(253, "\u{0600}\u{ac01}", &[]),
+	TestCase::new(254, "\u{0600}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(255, "\u{0600}\u{231a}", &[]),
+	TestCase::new(256, "\u{0600}\u{0308}\u{231a}", &[4]),
+	TestCase::new(257, "\u{0600}\u{0300}", &[]),
+	TestCase::new(258, "\u{0600}\u{0308}\u{0300}", &[]),
+	TestCase::new(259, "\u{0600}\u{200d}", &[]),
+	TestCase::new(260, "\u{0600}\u{0308}\u{200d}", &[]),
+	TestCase::new(261, "\u{0600}\u{0378}", &[]),
+	TestCase::new(262, "\u{0600}\u{0308}\u{0378}", &[4]),
+	TestCase::new(263, "\u{0903}\x20", &[3]),
+	TestCase::new(264, "\u{0903}\u{0308}\x20", &[5]),
+	TestCase::new(265, "\u{0903}\x0d", &[3]),
+	TestCase::new(266, "\u{0903}\u{0308}\x0d", &[5]),
+	TestCase::new(267, "\u{0903}\x0a", &[3]),
+	TestCase::new(268, "\u{0903}\u{0308}\x0a", &[5]),
+	TestCase::new(269, "\u{0903}\x01", &[3]),
+	TestCase::new(270, "\u{0903}\u{0308}\x01", &[5]),
+	TestCase::new(271, "\u{0903}\u{034f}", &[]),
+	TestCase::new(272, "\u{0903}\u{0308}\u{034f}", &[]),
+	TestCase::new(273, "\u{0903}\u{1f1e6}", &[3]),
+	TestCase::new(274, "\u{0903}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(275, "\u{0903}\u{0600}", &[3]),
+	TestCase::new(276, "\u{0903}\u{0308}\u{0600}", &[5]),
+	TestCase::new(277, "\u{0903}\u{0903}", &[]),
+	TestCase::new(278, "\u{0903}\u{0308}\u{0903}", &[]),
+	TestCase::new(279, "\u{0903}\u{1100}", &[3]),
+	TestCase::new(280, "\u{0903}\u{0308}\u{1100}", &[5]),
+	TestCase::new(281, "\u{0903}\u{1160}", &[3]),
+	TestCase::new(282, "\u{0903}\u{0308}\u{1160}", &[5]),
+	TestCase::new(283, "\u{0903}\u{11a8}", &[3]),
+	TestCase::new(284, "\u{0903}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(285, "\u{0903}\u{ac00}", &[3]),
+	TestCase::new(286, "\u{0903}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(287, "\u{0903}\u{ac01}", &[3]),
+	TestCase::new(288, "\u{0903}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(289, "\u{0903}\u{231a}", &[3]),
+	TestCase::new(290, "\u{0903}\u{0308}\u{231a}", &[5]),
+	TestCase::new(291, "\u{0903}\u{0300}", &[]),
+	TestCase::new(292, "\u{0903}\u{0308}\u{0300}", &[]),
+	TestCase::new(293, "\u{0903}\u{200d}", &[]),
+	TestCase::new(294, "\u{0903}\u{0308}\u{200d}", &[]),
+	TestCase::new(295, "\u{0903}\u{0378}", &[3]),
+	TestCase::new(296, "\u{0903}\u{0308}\u{0378}", &[5]),
+	TestCase::new(297, "\u{1100}\x20", &[3]),
+	TestCase::new(298, "\u{1100}\u{0308}\x20", &[5]),
+	TestCase::new(299, "\u{1100}\x0d", &[3]),
+	TestCase::new(300, "\u{1100}\u{0308}\x0d", &[5]),
+	TestCase::new(301, "\u{1100}\x0a", &[3]),
+	TestCase::new(302, "\u{1100}\u{0308}\x0a", &[5]),
+	TestCase::new(303, "\u{1100}\x01", &[3]),
+	TestCase::new(304, "\u{1100}\u{0308}\x01", &[5]),
+	TestCase::new(305, "\u{1100}\u{034f}", &[]),
+	TestCase::new(306, "\u{1100}\u{0308}\u{034f}", &[]),
+	TestCase::new(307, "\u{1100}\u{1f1e6}", &[3]),
+	TestCase::new(308, "\u{1100}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(309, "\u{1100}\u{0600}", &[3]),
+	TestCase::new(310, "\u{1100}\u{0308}\u{0600}", &[5]),
+	TestCase::new(311, "\u{1100}\u{0903}", &[]),
+	TestCase::new(312, "\u{1100}\u{0308}\u{0903}", &[]),
+	TestCase::new(313, "\u{1100}\u{1100}", &[]),
+	TestCase::new(314, "\u{1100}\u{0308}\u{1100}", &[5]),
+	TestCase::new(315, "\u{1100}\u{1160}", &[]),
+	TestCase::new(316, "\u{1100}\u{0308}\u{1160}", &[5]),
+	TestCase::new(317, "\u{1100}\u{11a8}", &[3]),
+	TestCase::new(318, "\u{1100}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(319, "\u{1100}\u{ac00}", &[]),
+	TestCase::new(320, "\u{1100}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(321, "\u{1100}\u{ac01}", &[]),
+	TestCase::new(322, "\u{1100}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(323, "\u{1100}\u{231a}", &[3]),
+	TestCase::new(324, "\u{1100}\u{0308}\u{231a}", &[5]),
+	TestCase::new(325, "\u{1100}\u{0300}", &[]),
+	TestCase::new(326, "\u{1100}\u{0308}\u{0300}", &[]),
+	TestCase::new(327, "\u{1100}\u{200d}", &[]),
+	TestCase::new(328, "\u{1100}\u{0308}\u{200d}", &[]),
+	TestCase::new(329, "\u{1100}\u{0378}", &[3]),
+	TestCase::new(330, "\u{1100}\u{0308}\u{0378}", &[5]),
+	TestCase::new(331, "\u{1160}\x20", &[3]),
+	TestCase::new(332, "\u{1160}\u{0308}\x20", &[5]),
+	TestCase::new(333, "\u{1160}\x0d", &[3]),
+	TestCase::new(334, "\u{1160}\u{0308}\x0d", &[5]),
+	TestCase::new(335, "\u{1160}\x0a", &[3]),
+	TestCase::new(336, "\u{1160}\u{0308}\x0a", &[5]),
+	TestCase::new(337, "\u{1160}\x01", &[3]),
+	TestCase::new(338, "\u{1160}\u{0308}\x01", &[5]),
+	TestCase::new(339, "\u{1160}\u{034f}", &[]),
+	TestCase::new(340, "\u{1160}\u{0308}\u{034f}", &[]),
+	TestCase::new(341, "\u{1160}\u{1f1e6}", &[3]),
+	TestCase::new(342, "\u{1160}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(343, "\u{1160}\u{0600}", &[3]),
+	TestCase::new(344, "\u{1160}\u{0308}\u{0600}", &[5]),
+	TestCase::new(345, "\u{1160}\u{0903}", &[]),
+	TestCase::new(346, "\u{1160}\u{0308}\u{0903}", &[]),
+	TestCase::new(347, "\u{1160}\u{1100}", &[3]),
+	TestCase::new(348, "\u{1160}\u{0308}\u{1100}", &[5]),
+	TestCase::new(349, "\u{1160}\u{1160}", &[]),
+	TestCase::new(350, "\u{1160}\u{0308}\u{1160}", &[5]),
+	TestCase::new(351, "\u{1160}\u{11a8}", &[]),
+	TestCase::new(352, "\u{1160}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(353, "\u{1160}\u{ac00}", &[3]),
+	TestCase::new(354, "\u{1160}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(355, "\u{1160}\u{ac01}", &[3]),
+	TestCase::new(356, "\u{1160}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(357, "\u{1160}\u{231a}", &[3]),
+	TestCase::new(358, "\u{1160}\u{0308}\u{231a}", &[5]),
+	TestCase::new(359, "\u{1160}\u{0300}", &[]),
+	TestCase::new(360, "\u{1160}\u{0308}\u{0300}", &[]),
+	TestCase::new(361, "\u{1160}\u{200d}", &[]),
+	TestCase::new(362, "\u{1160}\u{0308}\u{200d}", &[]),
+	TestCase::new(363, "\u{1160}\u{0378}", &[3]),
+	TestCase::new(364, "\u{1160}\u{0308}\u{0378}", &[5]),
+	TestCase::new(365, "\u{11a8}\x20", &[3]),
+	TestCase::new(366, "\u{11a8}\u{0308}\x20", &[5]),
+	TestCase::new(367, "\u{11a8}\x0d", &[3]),
+	TestCase::new(368, "\u{11a8}\u{0308}\x0d", &[5]),
+	TestCase::new(369, "\u{11a8}\x0a", &[3]),
+	TestCase::new(370, "\u{11a8}\u{0308}\x0a", &[5]),
+	TestCase::new(371, "\u{11a8}\x01", &[3]),
+	TestCase::new(372, "\u{11a8}\u{0308}\x01", &[5]),
+	TestCase::new(373, "\u{11a8}\u{034f}", &[]),
+	TestCase::new(374, "\u{11a8}\u{0308}\u{034f}", &[]),
+	TestCase::new(375, "\u{11a8}\u{1f1e6}", &[3]),
+	TestCase::new(376, "\u{11a8}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(377, "\u{11a8}\u{0600}", &[3]),
+	TestCase::new(378, "\u{11a8}\u{0308}\u{0600}", &[5]),
+	TestCase::new(379, "\u{11a8}\u{0903}", &[]),
+	TestCase::new(380, "\u{11a8}\u{0308}\u{0903}", &[]),
+	TestCase::new(381, "\u{11a8}\u{1100}", &[3]),
+	TestCase::new(382, "\u{11a8}\u{0308}\u{1100}", &[5]),
+	TestCase::new(383, "\u{11a8}\u{1160}", &[3]),
+	TestCase::new(384, "\u{11a8}\u{0308}\u{1160}", &[5]),
+	TestCase::new(385, "\u{11a8}\u{11a8}", &[]),
+	TestCase::new(386, "\u{11a8}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(387, "\u{11a8}\u{ac00}", &[3]),
+	TestCase::new(388, "\u{11a8}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(389, "\u{11a8}\u{ac01}", &[3]),
+	TestCase::new(390, "\u{11a8}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(391, "\u{11a8}\u{231a}", &[3]),
+	TestCase::new(392, "\u{11a8}\u{0308}\u{231a}", &[5]),
+	TestCase::new(393, "\u{11a8}\u{0300}", &[]),
+	TestCase::new(394, "\u{11a8}\u{0308}\u{0300}", &[]),
+	TestCase::new(395, "\u{11a8}\u{200d}", &[]),
+	TestCase::new(396, "\u{11a8}\u{0308}\u{200d}", &[]),
+	TestCase::new(397, "\u{11a8}\u{0378}", &[3]),
+	TestCase::new(398, "\u{11a8}\u{0308}\u{0378}", &[5]),
+	TestCase::new(399, "\u{ac00}\x20", &[3]),
+	TestCase::new(400, "\u{ac00}\u{0308}\x20", &[5]),
+	TestCase::new(401, "\u{ac00}\x0d", &[3]),
+	TestCase::new(402, "\u{ac00}\u{0308}\x0d", &[5]),
+	TestCase::new(403, "\u{ac00}\x0a", &[3]),
+	TestCase::new(404, "\u{ac00}\u{0308}\x0a", &[5]),
+	TestCase::new(405, "\u{ac00}\x01", &[3]),
+	TestCase::new(406, "\u{ac00}\u{0308}\x01", &[5]),
+	TestCase::new(407, "\u{ac00}\u{034f}", &[]),
+	TestCase::new(408, "\u{ac00}\u{0308}\u{034f}", &[]),
+	TestCase::new(409, "\u{ac00}\u{1f1e6}", &[3]),
+	TestCase::new(410, "\u{ac00}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(411, "\u{ac00}\u{0600}", &[3]),
+	TestCase::new(412, "\u{ac00}\u{0308}\u{0600}", &[5]),
+	TestCase::new(413, "\u{ac00}\u{0903}", &[]),
+	TestCase::new(414, "\u{ac00}\u{0308}\u{0903}", &[]),
+	TestCase::new(415, "\u{ac00}\u{1100}", &[3]),
+	TestCase::new(416, "\u{ac00}\u{0308}\u{1100}", &[5]),
+	TestCase::new(417, "\u{ac00}\u{1160}", &[]),
+	TestCase::new(418, "\u{ac00}\u{0308}\u{1160}", &[5]),
+	TestCase::new(419, "\u{ac00}\u{11a8}", &[]),
+	TestCase::new(420, "\u{ac00}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(421, "\u{ac00}\u{ac00}", &[3]),
+	TestCase::new(422, "\u{ac00}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(423, "\u{ac00}\u{ac01}", &[3]),
+	TestCase::new(424, "\u{ac00}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(425, "\u{ac00}\u{231a}", &[3]),
+	TestCase::new(426, "\u{ac00}\u{0308}\u{231a}", &[5]),
+	TestCase::new(427, "\u{ac00}\u{0300}", &[]),
+	TestCase::new(428, "\u{ac00}\u{0308}\u{0300}", &[]),
+	TestCase::new(429, "\u{ac00}\u{200d}", &[]),
+	TestCase::new(430, "\u{ac00}\u{0308}\u{200d}", &[]),
+	TestCase::new(431, "\u{ac00}\u{0378}", &[3]),
+	TestCase::new(432, "\u{ac00}\u{0308}\u{0378}", &[5]),
+	TestCase::new(433, "\u{ac01}\x20", &[3]),
+	TestCase::new(434, "\u{ac01}\u{0308}\x20", &[5]),
+	TestCase::new(435, "\u{ac01}\x0d", &[3]),
+	TestCase::new(436, "\u{ac01}\u{0308}\x0d", &[5]),
+	TestCase::new(437, "\u{ac01}\x0a", &[3]),
+	TestCase::new(438, "\u{ac01}\u{0308}\x0a", &[5]),
+	TestCase::new(439, "\u{ac01}\x01", &[3]),
+	TestCase::new(440, "\u{ac01}\u{0308}\x01", &[5]),
+	TestCase::new(441, "\u{ac01}\u{034f}", &[]),
+	TestCase::new(442, "\u{ac01}\u{0308}\u{034f}", &[]),
+	TestCase::new(443, "\u{ac01}\u{1f1e6}", &[3]),
+	TestCase::new(444, "\u{ac01}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(445, "\u{ac01}\u{0600}", &[3]),
+	TestCase::new(446, "\u{ac01}\u{0308}\u{0600}", &[5]),
+	TestCase::new(447, "\u{ac01}\u{0903}", &[]),
+	TestCase::new(448, "\u{ac01}\u{0308}\u{0903}", &[]),
+	TestCase::new(449, "\u{ac01}\u{1100}", &[3]),
+	TestCase::new(450, "\u{ac01}\u{0308}\u{1100}", &[5]),
+	TestCase::new(451, "\u{ac01}\u{1160}", &[3]),
+	TestCase::new(452, "\u{ac01}\u{0308}\u{1160}", &[5]),
+	TestCase::new(453, "\u{ac01}\u{11a8}", &[]),
+	TestCase::new(454, "\u{ac01}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(455, "\u{ac01}\u{ac00}", &[3]),
+	TestCase::new(456, "\u{ac01}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(457, "\u{ac01}\u{ac01}", &[3]),
+	TestCase::new(458, "\u{ac01}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(459, "\u{ac01}\u{231a}", &[3]),
+	TestCase::new(460, "\u{ac01}\u{0308}\u{231a}", &[5]),
+	TestCase::new(461, "\u{ac01}\u{0300}", &[]),
+	TestCase::new(462, "\u{ac01}\u{0308}\u{0300}", &[]),
+	TestCase::new(463, "\u{ac01}\u{200d}", &[]),
+	TestCase::new(464, "\u{ac01}\u{0308}\u{200d}", &[]),
+	TestCase::new(465, "\u{ac01}\u{0378}", &[3]),
+	TestCase::new(466, "\u{ac01}\u{0308}\u{0378}", &[5]),
+	TestCase::new(467, "\u{231a}\x20", &[3]),
+	TestCase::new(468, "\u{231a}\u{0308}\x20", &[5]),
+	TestCase::new(469, "\u{231a}\x0d", &[3]),
+	TestCase::new(470, "\u{231a}\u{0308}\x0d", &[5]),
+	TestCase::new(471, "\u{231a}\x0a", &[3]),
+	TestCase::new(472, "\u{231a}\u{0308}\x0a", &[5]),
+	TestCase::new(473, "\u{231a}\x01", &[3]),
+	TestCase::new(474, "\u{231a}\u{0308}\x01", &[5]),
+	TestCase::new(475, "\u{231a}\u{034f}", &[]),
+	TestCase::new(476, "\u{231a}\u{0308}\u{034f}", &[]),
+	TestCase::new(477, "\u{231a}\u{1f1e6}", &[3]),
+	TestCase::new(478, "\u{231a}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(479, "\u{231a}\u{0600}", &[3]),
+	TestCase::new(480, "\u{231a}\u{0308}\u{0600}", &[5]),
+	TestCase::new(481, "\u{231a}\u{0903}", &[]),
+	TestCase::new(482, "\u{231a}\u{0308}\u{0903}", &[]),
+	TestCase::new(483, "\u{231a}\u{1100}", &[3]),
+	TestCase::new(484, "\u{231a}\u{0308}\u{1100}", &[5]),
+	TestCase::new(485, "\u{231a}\u{1160}", &[3]),
+	TestCase::new(486, "\u{231a}\u{0308}\u{1160}", &[5]),
+	TestCase::new(487, "\u{231a}\u{11a8}", &[3]),
+	TestCase::new(488, "\u{231a}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(489, "\u{231a}\u{ac00}", &[3]),
+	TestCase::new(490, "\u{231a}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(491, "\u{231a}\u{ac01}", &[3]),
+	TestCase::new(492, "\u{231a}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(493, "\u{231a}\u{231a}", &[3]),
+	TestCase::new(494, "\u{231a}\u{0308}\u{231a}", &[5]),
+	TestCase::new(495, "\u{231a}\u{0300}", &[]),
+	TestCase::new(496, "\u{231a}\u{0308}\u{0300}", &[]),
+	TestCase::new(497, "\u{231a}\u{200d}", &[]),
+	TestCase::new(498, "\u{231a}\u{0308}\u{200d}", &[]),
+	TestCase::new(499, "\u{231a}\u{0378}", &[3]),
+	TestCase::new(500, "\u{231a}\u{0308}\u{0378}", &[5]),
+	TestCase::new(501, "\u{0300}\x20", &[2]),
+	TestCase::new(502, "\u{0300}\u{0308}\x20", &[4]),
+	TestCase::new(503, "\u{0300}\x0d", &[2]),
+	TestCase::new(504, "\u{0300}\u{0308}\x0d", &[4]),
+	TestCase::new(505, "\u{0300}\x0a", &[2]),
+	TestCase::new(506, "\u{0300}\u{0308}\x0a", &[4]),
+	TestCase::new(507, "\u{0300}\x01", &[2]),
+	TestCase::new(508, "\u{0300}\u{0308}\x01", &[4]),
+	TestCase::new(509, "\u{0300}\u{034f}", &[]),
+	TestCase::new(510, "\u{0300}\u{0308}\u{034f}", &[]),
+	TestCase::new(511, "\u{0300}\u{1f1e6}", &[2]),
+	TestCase::new(512, "\u{0300}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(513, "\u{0300}\u{0600}", &[2]),
+	TestCase::new(514, "\u{0300}\u{0308}\u{0600}", &[4]),
+	TestCase::new(515, "\u{0300}\u{0903}", &[]),
+	TestCase::new(516, "\u{0300}\u{0308}\u{0903}", &[]),
+	TestCase::new(517, "\u{0300}\u{1100}", &[2]),
+	TestCase::new(518, "\u{0300}\u{0308}\u{1100}", &[4]),
+	TestCase::new(519, "\u{0300}\u{1160}", &[2]),
+	TestCase::new(520, "\u{0300}\u{0308}\u{1160}", &[4]),
+	TestCase::new(521, "\u{0300}\u{11a8}", &[2]),
+	TestCase::new(522, "\u{0300}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(523, "\u{0300}\u{ac00}", &[2]),
+	TestCase::new(524, "\u{0300}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(525, "\u{0300}\u{ac01}", &[2]),
+	TestCase::new(526, "\u{0300}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(527, "\u{0300}\u{231a}", &[2]),
+	TestCase::new(528, "\u{0300}\u{0308}\u{231a}", &[4]),
+	TestCase::new(529, "\u{0300}\u{0300}", &[]),
+	TestCase::new(530, "\u{0300}\u{0308}\u{0300}", &[]),
+	TestCase::new(531, "\u{0300}\u{200d}", &[]),
+	TestCase::new(532, "\u{0300}\u{0308}\u{200d}", &[]),
+	TestCase::new(533, "\u{0300}\u{0378}", &[2]),
+	TestCase::new(534, "\u{0300}\u{0308}\u{0378}", &[4]),
+	TestCase::new(535, "\u{200d}\x20", &[3]),
+	TestCase::new(536, "\u{200d}\u{0308}\x20", &[5]),
+	TestCase::new(537, "\u{200d}\x0d", &[3]),
+	TestCase::new(538, "\u{200d}\u{0308}\x0d", &[5]),
+	TestCase::new(539, "\u{200d}\x0a", &[3]),
+	TestCase::new(540, "\u{200d}\u{0308}\x0a", &[5]),
+	TestCase::new(541, "\u{200d}\x01", &[3]),
+	TestCase::new(542, "\u{200d}\u{0308}\x01", &[5]),
+	TestCase::new(543, "\u{200d}\u{034f}", &[]),
+	TestCase::new(544, "\u{200d}\u{0308}\u{034f}", &[]),
+	TestCase::new(545, "\u{200d}\u{1f1e6}", &[3]),
+	TestCase::new(546, "\u{200d}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(547, "\u{200d}\u{0600}", &[3]),
+	TestCase::new(548, "\u{200d}\u{0308}\u{0600}", &[5]),
+	TestCase::new(549, "\u{200d}\u{0903}", &[]),
+	TestCase::new(550, "\u{200d}\u{0308}\u{0903}", &[]),
+	TestCase::new(551, "\u{200d}\u{1100}", &[3]),
+	TestCase::new(552, "\u{200d}\u{0308}\u{1100}", &[5]),
+	TestCase::new(553, "\u{200d}\u{1160}", &[3]),
+	TestCase::new(554, "\u{200d}\u{0308}\u{1160}", &[5]),
+	TestCase::new(555, "\u{200d}\u{11a8}", &[3]),
+	TestCase::new(556, "\u{200d}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(557, "\u{200d}\u{ac00}", &[3]),
+	TestCase::new(558, "\u{200d}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(559, "\u{200d}\u{ac01}", &[3]),
+	TestCase::new(560, "\u{200d}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(561, "\u{200d}\u{231a}", &[3]),
+	TestCase::new(562, "\u{200d}\u{0308}\u{231a}", &[5]),
+	TestCase::new(563, "\u{200d}\u{0300}", &[]),
+	TestCase::new(564, "\u{200d}\u{0308}\u{0300}", &[]),
+	TestCase::new(565, "\u{200d}\u{200d}", &[]),
+	TestCase::new(566, "\u{200d}\u{0308}\u{200d}", &[]),
+	TestCase::new(567, "\u{200d}\u{0378}", &[3]),
+	TestCase::new(568, "\u{200d}\u{0308}\u{0378}", &[5]),
+	TestCase::new(569, "\u{0378}\x20", &[2]),
+	TestCase::new(570, "\u{0378}\u{0308}\x20", &[4]),
+	TestCase::new(571, "\u{0378}\x0d", &[2]),
+	TestCase::new(572, "\u{0378}\u{0308}\x0d", &[4]),
+	TestCase::new(573, "\u{0378}\x0a", &[2]),
+	TestCase::new(574, "\u{0378}\u{0308}\x0a", &[4]),
+	TestCase::new(575, "\u{0378}\x01", &[2]),
+	TestCase::new(576, "\u{0378}\u{0308}\x01", &[4]),
+	TestCase::new(577, "\u{0378}\u{034f}", &[]),
+	TestCase::new(578, "\u{0378}\u{0308}\u{034f}", &[]),
+	TestCase::new(579, "\u{0378}\u{1f1e6}", &[2]),
+	TestCase::new(580, "\u{0378}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(581, "\u{0378}\u{0600}", &[2]),
+	TestCase::new(582, "\u{0378}\u{0308}\u{0600}", &[4]),
+	TestCase::new(583, "\u{0378}\u{0903}", &[]),
+	TestCase::new(584, "\u{0378}\u{0308}\u{0903}", &[]),
+	TestCase::new(585, "\u{0378}\u{1100}", &[2]),
+	TestCase::new(586, "\u{0378}\u{0308}\u{1100}", &[4]),
+	TestCase::new(587, "\u{0378}\u{1160}", &[2]),
+	TestCase::new(588, "\u{0378}\u{0308}\u{1160}", &[4]),
+	TestCase::new(589, "\u{0378}\u{11a8}", &[2]),
+	TestCase::new(590, "\u{0378}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(591, "\u{0378}\u{ac00}", &[2]),
+	TestCase::new(592, "\u{0378}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(593, "\u{0378}\u{ac01}", &[2]),
+	TestCase::new(594, "\u{0378}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(595, "\u{0378}\u{231a}", &[2]),
+	TestCase::new(596, "\u{0378}\u{0308}\u{231a}", &[4]),
+	TestCase::new(597, "\u{0378}\u{0300}", &[]),
+	TestCase::new(598, "\u{0378}\u{0308}\u{0300}", &[]),
+	TestCase::new(599, "\u{0378}\u{200d}", &[]),
+	TestCase::new(600, "\u{0378}\u{0308}\u{200d}", &[]),
+	TestCase::new(601, "\u{0378}\u{0378}", &[2]),
+	TestCase::new(602, "\u{0378}\u{0308}\u{0378}", &[4]),
+	TestCase::new(603, "\x0d\x0a\x61\x0a\u{0308}", &[2,3,4]),
+	TestCase::new(604, "\x61\u{0308}", &[]),
+	TestCase::new(605, "\x20\u{200d}\u{0646}", &[4]),
+	TestCase::new(606, "\u{0646}\u{200d}\x20", &[5]),
+	TestCase::new(607, "\u{1100}\u{1100}", &[]),
+	TestCase::new(608, "\u{ac00}\u{11a8}\u{1100}", &[6]),
+	TestCase::new(609, "\u{ac01}\u{11a8}\u{1100}", &[6]),
+	TestCase::new(610, "\u{1f1e6}\u{1f1e7}\u{1f1e8}\x62", &[8,12]),
+	TestCase::new(611, "\x61\u{1f1e6}\u{1f1e7}\u{1f1e8}\x62", &[1,9,13]),
+	TestCase::new(612, "\x61\u{1f1e6}\u{1f1e7}\u{200d}\u{1f1e8}\x62", &[1,12,16]),
+	TestCase::new(613, "\x61\u{1f1e6}\u{200d}\u{1f1e7}\u{1f1e8}\x62", &[1,8,16]),
+	TestCase::new(614, "\x61\u{1f1e6}\u{1f1e7}\u{1f1e8}\u{1f1e9}\x62", &[1,9,17]),
+	TestCase::new(615, "\x61\u{200d}", &[]),
+	TestCase::new(616, "\x61\u{0308}\x62", &[3]),
+	TestCase::new(617, "\x61\u{0903}\x62", &[4]),
+	TestCase::new(618, "\x61\u{0600}\x62", &[1]),
+	TestCase::new(619, "\u{1f476}\u{1f3ff}\u{1f476}", &[8]),
+	TestCase::new(620, "\x61\u{1f3ff}\u{1f476}", &[5]),
+	TestCase::new(621, "\x61\u{1f3ff}\u{1f476}\u{200d}\u{1f6d1}", &[5]),
+	TestCase::new(622, "\u{1f476}\u{1f3ff}\u{0308}\u{200d}\u{1f476}\u{1f3ff}", &[]),
+	TestCase::new(623, "\u{1f6d1}\u{200d}\u{1f6d1}", &[]),
+	TestCase::new(624, "\x61\u{200d}\u{1f6d1}", &[4]),
+	TestCase::new(625, "\u{2701}\u{200d}\u{2701}", &[]),
+	TestCase::new(626, "\x61\u{200d}\u{2701}", &[4]),
+];
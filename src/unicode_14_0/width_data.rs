@@ -0,0 +1,1073 @@
+use crate::width::{CharWidth as W, WidthRange, ran};
+pub const WIDTH_RANGES: [WidthRange; 1070] = [
+	ran(0x00300,   112, W::Zero),
+	ran(0x00378,     2, W::Wide),
+	ran(0x00380,     4, W::Wide),
+	ran(0x0038b,     1, W::Wide),
+	ran(0x0038d,     1, W::Wide),
+	ran(0x003a2,     1, W::Wide),
+	ran(0x00483,     7, W::Zero),
+	ran(0x00530,     1, W::Wide),
+	ran(0x00557,     2, W::Wide),
+	ran(0x0058b,     2, W::Wide),
+	ran(0x00590,     1, W::Wide),
+	ran(0x00591,    45, W::Zero),
+	ran(0x005bf,     1, W::Zero),
+	ran(0x005c1,     2, W::Zero),
+	ran(0x005c4,     2, W::Zero),
+	ran(0x005c7,     1, W::Zero),
+	ran(0x005c8,     8, W::Wide),
+	ran(0x005eb,     4, W::Wide),
+	ran(0x005f5,    11, W::Wide),
+	ran(0x00600,     6, W::Zero),
+	ran(0x00610,    11, W::Zero),
+	ran(0x0061c,     1, W::Zero),
+	ran(0x0064b,    21, W::Zero),
+	ran(0x00670,     1, W::Zero),
+	ran(0x006d6,     8, W::Zero),
+	ran(0x006df,     6, W::Zero),
+	ran(0x006e7,     2, W::Zero),
+	ran(0x006ea,     4, W::Zero),
+	ran(0x0070e,     1, W::Wide),
+	ran(0x0070f,     1, W::Zero),
+	ran(0x00711,     1, W::Zero),
+	ran(0x00730,    27, W::Zero),
+	ran(0x0074b,     2, W::Wide),
+	ran(0x007a6,    11, W::Zero),
+	ran(0x007b2,    14, W::Wide),
+	ran(0x007eb,     9, W::Zero),
+	ran(0x007fb,     2, W::Wide),
+	ran(0x007fd,     1, W::Zero),
+	ran(0x00816,     4, W::Zero),
+	ran(0x0081b,     9, W::Zero),
+	ran(0x00825,     3, W::Zero),
+	ran(0x00829,     5, W::Zero),
+	ran(0x0082e,     2, W::Wide),
+	ran(0x0083f,     1, W::Wide),
+	ran(0x00859,     3, W::Zero),
+	ran(0x0085c,     2, W::Wide),
+	ran(0x0085f,     1, W::Wide),
+	ran(0x0086b,     5, W::Wide),
+	ran(0x0088f,     1, W::Wide),
+	ran(0x00890,     2, W::Zero),
+	ran(0x00892,     6, W::Wide),
+	ran(0x00898,     8, W::Zero),
+	ran(0x008ca,    57, W::Zero),
+	ran(0x0093a,     1, W::Zero),
+	ran(0x0093c,     1, W::Zero),
+	ran(0x00941,     8, W::Zero),
+	ran(0x0094d,     1, W::Zero),
+	ran(0x00951,     7, W::Zero),
+	ran(0x00962,     2, W::Zero),
+	ran(0x00981,     1, W::Zero),
+	ran(0x00984,     1, W::Wide),
+	ran(0x0098d,     2, W::Wide),
+	ran(0x00991,     2, W::Wide),
+	ran(0x009a9,     1, W::Wide),
+	ran(0x009b1,     1, W::Wide),
+	ran(0x009b3,     3, W::Wide),
+	ran(0x009ba,     2, W::Wide),
+	ran(0x009bc,     1, W::Zero),
+	ran(0x009c1,     4, W::Zero),
+	ran(0x009c5,     2, W::Wide),
+	ran(0x009c9,     2, W::Wide),
+	ran(0x009cd,     1, W::Zero),
+	ran(0x009cf,     8, W::Wide),
+	ran(0x009d8,     4, W::Wide),
+	ran(0x009de,     1, W::Wide),
+	ran(0x009e2,     2, W::Zero),
+	ran(0x009e4,     2, W::Wide),
+	ran(0x009fe,     1, W::Zero),
+	ran(0x009ff,     2, W::Wide),
+	ran(0x00a01,     2, W::Zero),
+	ran(0x00a04,     1, W::Wide),
+	ran(0x00a0b,     4, W::Wide),
+	ran(0x00a11,     2, W::Wide),
+	ran(0x00a29,     1, W::Wide),
+	ran(0x00a31,     1, W::Wide),
+	ran(0x00a34,     1, W::Wide),
+	ran(0x00a37,     1, W::Wide),
+	ran(0x00a3a,     2, W::Wide),
+	ran(0x00a3c,     1, W::Zero),
+	ran(0x00a3d,     1, W::Wide),
+	ran(0x00a41,     2, W::Zero),
+	ran(0x00a43,     4, W::Wide),
+	ran(0x00a47,     2, W::Zero),
+	ran(0x00a49,     2, W::Wide),
+	ran(0x00a4b,     3, W::Zero),
+	ran(0x00a4e,     3, W::Wide),
+	ran(0x00a51,     1, W::Zero),
+	ran(0x00a52,     7, W::Wide),
+	ran(0x00a5d,     1, W::Wide),
+	ran(0x00a5f,     7, W::Wide),
+	ran(0x00a70,     2, W::Zero),
+	ran(0x00a75,     1, W::Zero),
+	ran(0x00a77,    10, W::Wide),
+	ran(0x00a81,     2, W::Zero),
+	ran(0x00a84,     1, W::Wide),
+	ran(0x00a8e,     1, W::Wide),
+	ran(0x00a92,     1, W::Wide),
+	ran(0x00aa9,     1, W::Wide),
+	ran(0x00ab1,     1, W::Wide),
+	ran(0x00ab4,     1, W::Wide),
+	ran(0x00aba,     2, W::Wide),
+	ran(0x00abc,     1, W::Zero),
+	ran(0x00ac1,     5, W::Zero),
+	ran(0x00ac6,     1, W::Wide),
+	ran(0x00ac7,     2, W::Zero),
+	ran(0x00aca,     1, W::Wide),
+	ran(0x00acd,     1, W::Zero),
+	ran(0x00ace,     2, W::Wide),
+	ran(0x00ad1,    15, W::Wide),
+	ran(0x00ae2,     2, W::Zero),
+	ran(0x00ae4,     2, W::Wide),
+	ran(0x00af2,     7, W::Wide),
+	ran(0x00afa,     6, W::Zero),
+	ran(0x00b00,     1, W::Wide),
+	ran(0x00b01,     1, W::Zero),
+	ran(0x00b04,     1, W::Wide),
+	ran(0x00b0d,     2, W::Wide),
+	ran(0x00b11,     2, W::Wide),
+	ran(0x00b29,     1, W::Wide),
+	ran(0x00b31,     1, W::Wide),
+	ran(0x00b34,     1, W::Wide),
+	ran(0x00b3a,     2, W::Wide),
+	ran(0x00b3c,     1, W::Zero),
+	ran(0x00b3f,     1, W::Zero),
+	ran(0x00b41,     4, W::Zero),
+	ran(0x00b45,     2, W::Wide),
+	ran(0x00b49,     2, W::Wide),
+	ran(0x00b4d,     1, W::Zero),
+	ran(0x00b4e,     7, W::Wide),
+	ran(0x00b55,     2, W::Zero),
+	ran(0x00b58,     4, W::Wide),
+	ran(0x00b5e,     1, W::Wide),
+	ran(0x00b62,     2, W::Zero),
+	ran(0x00b64,     2, W::Wide),
+	ran(0x00b78,    10, W::Wide),
+	ran(0x00b82,     1, W::Zero),
+	ran(0x00b84,     1, W::Wide),
+	ran(0x00b8b,     3, W::Wide),
+	ran(0x00b91,     1, W::Wide),
+	ran(0x00b96,     3, W::Wide),
+	ran(0x00b9b,     1, W::Wide),
+	ran(0x00b9d,     1, W::Wide),
+	ran(0x00ba0,     3, W::Wide),
+	ran(0x00ba5,     3, W::Wide),
+	ran(0x00bab,     3, W::Wide),
+	ran(0x00bba,     4, W::Wide),
+	ran(0x00bc0,     1, W::Zero),
+	ran(0x00bc3,     3, W::Wide),
+	ran(0x00bc9,     1, W::Wide),
+	ran(0x00bcd,     1, W::Zero),
+	ran(0x00bce,     2, W::Wide),
+	ran(0x00bd1,     6, W::Wide),
+	ran(0x00bd8,    14, W::Wide),
+	ran(0x00bfb,     5, W::Wide),
+	ran(0x00c00,     1, W::Zero),
+	ran(0x00c04,     1, W::Zero),
+	ran(0x00c0d,     1, W::Wide),
+	ran(0x00c11,     1, W::Wide),
+	ran(0x00c29,     1, W::Wide),
+	ran(0x00c3a,     2, W::Wide),
+	ran(0x00c3c,     1, W::Zero),
+	ran(0x00c3e,     3, W::Zero),
+	ran(0x00c45,     1, W::Wide),
+	ran(0x00c46,     3, W::Zero),
+	ran(0x00c49,     1, W::Wide),
+	ran(0x00c4a,     4, W::Zero),
+	ran(0x00c4e,     7, W::Wide),
+	ran(0x00c55,     2, W::Zero),
+	ran(0x00c57,     1, W::Wide),
+	ran(0x00c5b,     2, W::Wide),
+	ran(0x00c5e,     2, W::Wide),
+	ran(0x00c62,     2, W::Zero),
+	ran(0x00c64,     2, W::Wide),
+	ran(0x00c70,     7, W::Wide),
+	ran(0x00c81,     1, W::Zero),
+	ran(0x00c8d,     1, W::Wide),
+	ran(0x00c91,     1, W::Wide),
+	ran(0x00ca9,     1, W::Wide),
+	ran(0x00cb4,     1, W::Wide),
+	ran(0x00cba,     2, W::Wide),
+	ran(0x00cbc,     1, W::Zero),
+	ran(0x00cbf,     1, W::Zero),
+	ran(0x00cc5,     1, W::Wide),
+	ran(0x00cc6,     1, W::Zero),
+	ran(0x00cc9,     1, W::Wide),
+	ran(0x00ccc,     2, W::Zero),
+	ran(0x00cce,     7, W::Wide),
+	ran(0x00cd7,     6, W::Wide),
+	ran(0x00cdf,     1, W::Wide),
+	ran(0x00ce2,     2, W::Zero),
+	ran(0x00ce4,     2, W::Wide),
+	ran(0x00cf0,     1, W::Wide),
+	ran(0x00cf3,    13, W::Wide),
+	ran(0x00d00,     2, W::Zero),
+	ran(0x00d0d,     1, W::Wide),
+	ran(0x00d11,     1, W::Wide),
+	ran(0x00d3b,     2, W::Zero),
+	ran(0x00d41,     4, W::Zero),
+	ran(0x00d45,     1, W::Wide),
+	ran(0x00d49,     1, W::Wide),
+	ran(0x00d4d,     1, W::Zero),
+	ran(0x00d50,     4, W::Wide),
+	ran(0x00d62,     2, W::Zero),
+	ran(0x00d64,     2, W::Wide),
+	ran(0x00d80,     1, W::Wide),
+	ran(0x00d81,     1, W::Zero),
+	ran(0x00d84,     1, W::Wide),
+	ran(0x00d97,     3, W::Wide),
+	ran(0x00db2,     1, W::Wide),
+	ran(0x00dbc,     1, W::Wide),
+	ran(0x00dbe,     2, W::Wide),
+	ran(0x00dc7,     3, W::Wide),
+	ran(0x00dca,     1, W::Zero),
+	ran(0x00dcb,     4, W::Wide),
+	ran(0x00dd2,     3, W::Zero),
+	ran(0x00dd5,     1, W::Wide),
+	ran(0x00dd6,     1, W::Zero),
+	ran(0x00dd7,     1, W::Wide),
+	ran(0x00de0,     6, W::Wide),
+	ran(0x00df0,     2, W::Wide),
+	ran(0x00df5,    12, W::Wide),
+	ran(0x00e31,     1, W::Zero),
+	ran(0x00e34,     7, W::Zero),
+	ran(0x00e3b,     4, W::Wide),
+	ran(0x00e47,     8, W::Zero),
+	ran(0x00e5c,    37, W::Wide),
+	ran(0x00e83,     1, W::Wide),
+	ran(0x00e85,     1, W::Wide),
+	ran(0x00e8b,     1, W::Wide),
+	ran(0x00ea4,     1, W::Wide),
+	ran(0x00ea6,     1, W::Wide),
+	ran(0x00eb1,     1, W::Zero),
+	ran(0x00eb4,     9, W::Zero),
+	ran(0x00ebe,     2, W::Wide),
+	ran(0x00ec5,     1, W::Wide),
+	ran(0x00ec7,     1, W::Wide),
+	ran(0x00ec8,     6, W::Zero),
+	ran(0x00ece,     2, W::Wide),
+	ran(0x00eda,     2, W::Wide),
+	ran(0x00ee0,    32, W::Wide),
+	ran(0x00f18,     2, W::Zero),
+	ran(0x00f35,     1, W::Zero),
+	ran(0x00f37,     1, W::Zero),
+	ran(0x00f39,     1, W::Zero),
+	ran(0x00f48,     1, W::Wide),
+	ran(0x00f6d,     4, W::Wide),
+	ran(0x00f71,    14, W::Zero),
+	ran(0x00f80,     5, W::Zero),
+	ran(0x00f86,     2, W::Zero),
+	ran(0x00f8d,    11, W::Zero),
+	ran(0x00f98,     1, W::Wide),
+	ran(0x00f99,    36, W::Zero),
+	ran(0x00fbd,     1, W::Wide),
+	ran(0x00fc6,     1, W::Zero),
+	ran(0x00fcd,     1, W::Wide),
+	ran(0x00fdb,    37, W::Wide),
+	ran(0x0102d,     4, W::Zero),
+	ran(0x01032,     6, W::Zero),
+	ran(0x01039,     2, W::Zero),
+	ran(0x0103d,     2, W::Zero),
+	ran(0x01058,     2, W::Zero),
+	ran(0x0105e,     3, W::Zero),
+	ran(0x01071,     4, W::Zero),
+	ran(0x01082,     1, W::Zero),
+	ran(0x01085,     2, W::Zero),
+	ran(0x0108d,     1, W::Zero),
+	ran(0x0109d,     1, W::Zero),
+	ran(0x010c6,     1, W::Wide),
+	ran(0x010c8,     5, W::Wide),
+	ran(0x010ce,     2, W::Wide),
+	ran(0x01100,    95, W::Wide),
+	ran(0x0115f,   161, W::Zero),
+	ran(0x01249,     1, W::Wide),
+	ran(0x0124e,     2, W::Wide),
+	ran(0x01257,     1, W::Wide),
+	ran(0x01259,     1, W::Wide),
+	ran(0x0125e,     2, W::Wide),
+	ran(0x01289,     1, W::Wide),
+	ran(0x0128e,     2, W::Wide),
+	ran(0x012b1,     1, W::Wide),
+	ran(0x012b6,     2, W::Wide),
+	ran(0x012bf,     1, W::Wide),
+	ran(0x012c1,     1, W::Wide),
+	ran(0x012c6,     2, W::Wide),
+	ran(0x012d7,     1, W::Wide),
+	ran(0x01311,     1, W::Wide),
+	ran(0x01316,     2, W::Wide),
+	ran(0x0135b,     2, W::Wide),
+	ran(0x0135d,     3, W::Zero),
+	ran(0x0137d,     3, W::Wide),
+	ran(0x0139a,     6, W::Wide),
+	ran(0x013f6,     2, W::Wide),
+	ran(0x013fe,     2, W::Wide),
+	ran(0x0169d,     3, W::Wide),
+	ran(0x016f9,     7, W::Wide),
+	ran(0x01712,     3, W::Zero),
+	ran(0x01716,     9, W::Wide),
+	ran(0x01732,     2, W::Zero),
+	ran(0x01737,     9, W::Wide),
+	ran(0x01752,     2, W::Zero),
+	ran(0x01754,    12, W::Wide),
+	ran(0x0176d,     1, W::Wide),
+	ran(0x01771,     1, W::Wide),
+	ran(0x01772,     2, W::Zero),
+	ran(0x01774,    12, W::Wide),
+	ran(0x017b4,     2, W::Zero),
+	ran(0x017b7,     7, W::Zero),
+	ran(0x017c6,     1, W::Zero),
+	ran(0x017c9,    11, W::Zero),
+	ran(0x017dd,     1, W::Zero),
+	ran(0x017de,     2, W::Wide),
+	ran(0x017ea,     6, W::Wide),
+	ran(0x017fa,     6, W::Wide),
+	ran(0x0180b,     5, W::Zero),
+	ran(0x0181a,     6, W::Wide),
+	ran(0x01879,     7, W::Wide),
+	ran(0x01885,     2, W::Zero),
+	ran(0x018a9,     1, W::Zero),
+	ran(0x018ab,     5, W::Wide),
+	ran(0x018f6,    10, W::Wide),
+	ran(0x0191f,     1, W::Wide),
+	ran(0x01920,     3, W::Zero),
+	ran(0x01927,     2, W::Zero),
+	ran(0x0192c,     4, W::Wide),
+	ran(0x01932,     1, W::Zero),
+	ran(0x01939,     3, W::Zero),
+	ran(0x0193c,     4, W::Wide),
+	ran(0x01941,     3, W::Wide),
+	ran(0x0196e,     2, W::Wide),
+	ran(0x01975,    11, W::Wide),
+	ran(0x019ac,     4, W::Wide),
+	ran(0x019ca,     6, W::Wide),
+	ran(0x019db,     3, W::Wide),
+	ran(0x01a17,     2, W::Zero),
+	ran(0x01a1b,     1, W::Zero),
+	ran(0x01a1c,     2, W::Wide),
+	ran(0x01a56,     1, W::Zero),
+	ran(0x01a58,     7, W::Zero),
+	ran(0x01a5f,     1, W::Wide),
+	ran(0x01a60,     1, W::Zero),
+	ran(0x01a62,     1, W::Zero),
+	ran(0x01a65,     8, W::Zero),
+	ran(0x01a73,    10, W::Zero),
+	ran(0x01a7d,     2, W::Wide),
+	ran(0x01a7f,     1, W::Zero),
+	ran(0x01a8a,     6, W::Wide),
+	ran(0x01a9a,     6, W::Wide),
+	ran(0x01aae,     2, W::Wide),
+	ran(0x01ab0,    31, W::Zero),
+	ran(0x01acf,    49, W::Wide),
+	ran(0x01b00,     4, W::Zero),
+	ran(0x01b34,     1, W::Zero),
+	ran(0x01b36,     5, W::Zero),
+	ran(0x01b3c,     1, W::Zero),
+	ran(0x01b42,     1, W::Zero),
+	ran(0x01b4d,     3, W::Wide),
+	ran(0x01b6b,     9, W::Zero),
+	ran(0x01b7f,     1, W::Wide),
+	ran(0x01b80,     2, W::Zero),
+	ran(0x01ba2,     4, W::Zero),
+	ran(0x01ba8,     2, W::Zero),
+	ran(0x01bab,     3, W::Zero),
+	ran(0x01be6,     1, W::Zero),
+	ran(0x01be8,     2, W::Zero),
+	ran(0x01bed,     1, W::Zero),
+	ran(0x01bef,     3, W::Zero),
+	ran(0x01bf4,     8, W::Wide),
+	ran(0x01c2c,     8, W::Zero),
+	ran(0x01c36,     2, W::Zero),
+	ran(0x01c38,     3, W::Wide),
+	ran(0x01c4a,     3, W::Wide),
+	ran(0x01c89,     7, W::Wide),
+	ran(0x01cbb,     2, W::Wide),
+	ran(0x01cc8,     8, W::Wide),
+	ran(0x01cd0,     3, W::Zero),
+	ran(0x01cd4,    13, W::Zero),
+	ran(0x01ce2,     7, W::Zero),
+	ran(0x01ced,     1, W::Zero),
+	ran(0x01cf4,     1, W::Zero),
+	ran(0x01cf8,     2, W::Zero),
+	ran(0x01cfb,     5, W::Wide),
+	ran(0x01dc0,    64, W::Zero),
+	ran(0x01f16,     2, W::Wide),
+	ran(0x01f1e,     2, W::Wide),
+	ran(0x01f46,     2, W::Wide),
+	ran(0x01f4e,     2, W::Wide),
+	ran(0x01f58,     1, W::Wide),
+	ran(0x01f5a,     1, W::Wide),
+	ran(0x01f5c,     1, W::Wide),
+	ran(0x01f5e,     1, W::Wide),
+	ran(0x01f7e,     2, W::Wide),
+	ran(0x01fb5,     1, W::Wide),
+	ran(0x01fc5,     1, W::Wide),
+	ran(0x01fd4,     2, W::Wide),
+	ran(0x01fdc,     1, W::Wide),
+	ran(0x01ff0,     2, W::Wide),
+	ran(0x01ff5,     1, W::Wide),
+	ran(0x01fff,     1, W::Wide),
+	ran(0x0200b,     5, W::Zero),
+	ran(0x0202a,     5, W::Zero),
+	ran(0x02060,    16, W::Zero),
+	ran(0x02072,     2, W::Wide),
+	ran(0x0208f,     1, W::Wide),
+	ran(0x0209d,     3, W::Wide),
+	ran(0x020c1,    15, W::Wide),
+	ran(0x020d0,    33, W::Zero),
+	ran(0x020f1,    15, W::Wide),
+	ran(0x0218c,     4, W::Wide),
+	ran(0x0231a,     2, W::Wide),
+	ran(0x02329,     2, W::Wide),
+	ran(0x023e9,     4, W::Wide),
+	ran(0x023f0,     1, W::Wide),
+	ran(0x023f3,     1, W::Wide),
+	ran(0x02427,    25, W::Wide),
+	ran(0x0244b,    21, W::Wide),
+	ran(0x025fd,     2, W::Wide),
+	ran(0x02614,     2, W::Wide),
+	ran(0x02648,    12, W::Wide),
+	ran(0x0267f,     1, W::Wide),
+	ran(0x02693,     1, W::Wide),
+	ran(0x026a1,     1, W::Wide),
+	ran(0x026aa,     2, W::Wide),
+	ran(0x026bd,     2, W::Wide),
+	ran(0x026c4,     2, W::Wide),
+	ran(0x026ce,     1, W::Wide),
+	ran(0x026d4,     1, W::Wide),
+	ran(0x026ea,     1, W::Wide),
+	ran(0x026f2,     2, W::Wide),
+	ran(0x026f5,     1, W::Wide),
+	ran(0x026fa,     1, W::Wide),
+	ran(0x026fd,     1, W::Wide),
+	ran(0x02705,     1, W::Wide),
+	ran(0x0270a,     2, W::Wide),
+	ran(0x02728,     1, W::Wide),
+	ran(0x0274c,     1, W::Wide),
+	ran(0x0274e,     1, W::Wide),
+	ran(0x02753,     3, W::Wide),
+	ran(0x02757,     1, W::Wide),
+	ran(0x02795,     3, W::Wide),
+	ran(0x027b0,     1, W::Wide),
+	ran(0x027bf,     1, W::Wide),
+	ran(0x02b1b,     2, W::Wide),
+	ran(0x02b50,     1, W::Wide),
+	ran(0x02b55,     1, W::Wide),
+	ran(0x02b74,     2, W::Wide),
+	ran(0x02b96,     1, W::Wide),
+	ran(0x02cef,     3, W::Zero),
+	ran(0x02cf4,     5, W::Wide),
+	ran(0x02d26,     1, W::Wide),
+	ran(0x02d28,     5, W::Wide),
+	ran(0x02d2e,     2, W::Wide),
+	ran(0x02d68,     7, W::Wide),
+	ran(0x02d71,    14, W::Wide),
+	ran(0x02d7f,     1, W::Zero),
+	ran(0x02d97,     9, W::Wide),
+	ran(0x02da7,     1, W::Wide),
+	ran(0x02daf,     1, W::Wide),
+	ran(0x02db7,     1, W::Wide),
+	ran(0x02dbf,     1, W::Wide),
+	ran(0x02dc7,     1, W::Wide),
+	ran(0x02dcf,     1, W::Wide),
+	ran(0x02dd7,     1, W::Wide),
+	ran(0x02ddf,     1, W::Wide),
+	ran(0x02de0,    32, W::Zero),
+	ran(0x02e5e,   460, W::Wide),
+	ran(0x0302a,     4, W::Zero),
+	ran(0x0302e,    17, W::Wide),
+	ran(0x03040,    89, W::Wide),
+	ran(0x03099,     2, W::Zero),
+	ran(0x0309b,   201, W::Wide),
+	ran(0x03164,     1, W::Zero),
+	ran(0x03165,   227, W::Wide),
+	ran(0x03250,  7024, W::Wide),
+	ran(0x04e00, 22224, W::Wide),
+	ran(0x0a62c,    20, W::Wide),
+	ran(0x0a66f,     4, W::Zero),
+	ran(0x0a674,    10, W::Zero),
+	ran(0x0a69e,     2, W::Zero),
+	ran(0x0a6f0,     2, W::Zero),
+	ran(0x0a6f8,     8, W::Wide),
+	ran(0x0a7cb,     5, W::Wide),
+	ran(0x0a7d2,     1, W::Wide),
+	ran(0x0a7d4,     1, W::Wide),
+	ran(0x0a7da,    24, W::Wide),
+	ran(0x0a802,     1, W::Zero),
+	ran(0x0a806,     1, W::Zero),
+	ran(0x0a80b,     1, W::Zero),
+	ran(0x0a825,     2, W::Zero),
+	ran(0x0a82c,     1, W::Zero),
+	ran(0x0a82d,     3, W::Wide),
+	ran(0x0a83a,     6, W::Wide),
+	ran(0x0a878,     8, W::Wide),
+	ran(0x0a8c4,     2, W::Zero),
+	ran(0x0a8c6,     8, W::Wide),
+	ran(0x0a8da,     6, W::Wide),
+	ran(0x0a8e0,    18, W::Zero),
+	ran(0x0a8ff,     1, W::Zero),
+	ran(0x0a926,     8, W::Zero),
+	ran(0x0a947,    11, W::Zero),
+	ran(0x0a954,    11, W::Wide),
+	ran(0x0a960,    32, W::Wide),
+	ran(0x0a980,     3, W::Zero),
+	ran(0x0a9b3,     1, W::Zero),
+	ran(0x0a9b6,     4, W::Zero),
+	ran(0x0a9bc,     2, W::Zero),
+	ran(0x0a9ce,     1, W::Wide),
+	ran(0x0a9da,     4, W::Wide),
+	ran(0x0a9e5,     1, W::Zero),
+	ran(0x0a9ff,     1, W::Wide),
+	ran(0x0aa29,     6, W::Zero),
+	ran(0x0aa31,     2, W::Zero),
+	ran(0x0aa35,     2, W::Zero),
+	ran(0x0aa37,     9, W::Wide),
+	ran(0x0aa43,     1, W::Zero),
+	ran(0x0aa4c,     1, W::Zero),
+	ran(0x0aa4e,     2, W::Wide),
+	ran(0x0aa5a,     2, W::Wide),
+	ran(0x0aa7c,     1, W::Zero),
+	ran(0x0aab0,     1, W::Zero),
+	ran(0x0aab2,     3, W::Zero),
+	ran(0x0aab7,     2, W::Zero),
+	ran(0x0aabe,     2, W::Zero),
+	ran(0x0aac1,     1, W::Zero),
+	ran(0x0aac3,    24, W::Wide),
+	ran(0x0aaec,     2, W::Zero),
+	ran(0x0aaf6,     1, W::Zero),
+	ran(0x0aaf7,    10, W::Wide),
+	ran(0x0ab07,     2, W::Wide),
+	ran(0x0ab0f,     2, W::Wide),
+	ran(0x0ab17,     9, W::Wide),
+	ran(0x0ab27,     1, W::Wide),
+	ran(0x0ab2f,     1, W::Wide),
+	ran(0x0ab6c,     4, W::Wide),
+	ran(0x0abe5,     1, W::Zero),
+	ran(0x0abe8,     1, W::Zero),
+	ran(0x0abed,     1, W::Zero),
+	ran(0x0abee,     2, W::Wide),
+	ran(0x0abfa, 11190, W::Wide),
+	ran(0x0d7c7,     4, W::Wide),
+	ran(0x0d7fc,     4, W::Wide),
+	ran(0x0f900,   512, W::Wide),
+	ran(0x0fb07,    12, W::Wide),
+	ran(0x0fb18,     5, W::Wide),
+	ran(0x0fb1e,     1, W::Zero),
+	ran(0x0fb37,     1, W::Wide),
+	ran(0x0fb3d,     1, W::Wide),
+	ran(0x0fb3f,     1, W::Wide),
+	ran(0x0fb42,     1, W::Wide),
+	ran(0x0fb45,     1, W::Wide),
+	ran(0x0fbc3,    16, W::Wide),
+	ran(0x0fd90,     2, W::Wide),
+	ran(0x0fdc8,     7, W::Wide),
+	ran(0x0fdd0,    32, W::Wide),
+	ran(0x0fe00,    16, W::Zero),
+	ran(0x0fe10,    16, W::Wide),
+	ran(0x0fe20,    16, W::Zero),
+	ran(0x0fe30,    64, W::Wide),
+	ran(0x0fe75,     1, W::Wide),
+	ran(0x0fefd,     2, W::Wide),
+	ran(0x0feff,     1, W::Zero),
+	ran(0x0ff00,    97, W::Wide),
+	ran(0x0ffa0,     1, W::Zero),
+	ran(0x0ffbf,     3, W::Wide),
+	ran(0x0ffc8,     2, W::Wide),
+	ran(0x0ffd0,     2, W::Wide),
+	ran(0x0ffd8,     2, W::Wide),
+	ran(0x0ffdd,    11, W::Wide),
+	ran(0x0ffef,     1, W::Wide),
+	ran(0x0fff0,    12, W::Zero),
+	ran(0x0fffe,     2, W::Wide),
+	ran(0x1000c,     1, W::Wide),
+	ran(0x10027,     1, W::Wide),
+	ran(0x1003b,     1, W::Wide),
+	ran(0x1003e,     1, W::Wide),
+	ran(0x1004e,     2, W::Wide),
+	ran(0x1005e,    34, W::Wide),
+	ran(0x100fb,     5, W::Wide),
+	ran(0x10103,     4, W::Wide),
+	ran(0x10134,     3, W::Wide),
+	ran(0x1018f,     1, W::Wide),
+	ran(0x1019d,     3, W::Wide),
+	ran(0x101a1,    47, W::Wide),
+	ran(0x101fd,     1, W::Zero),
+	ran(0x101fe,   130, W::Wide),
+	ran(0x1029d,     3, W::Wide),
+	ran(0x102d1,    15, W::Wide),
+	ran(0x102e0,     1, W::Zero),
+	ran(0x102fc,     4, W::Wide),
+	ran(0x10324,     9, W::Wide),
+	ran(0x1034b,     5, W::Wide),
+	ran(0x10376,     5, W::Zero),
+	ran(0x1037b,     5, W::Wide),
+	ran(0x1039e,     1, W::Wide),
+	ran(0x103c4,     4, W::Wide),
+	ran(0x103d6,    42, W::Wide),
+	ran(0x1049e,     2, W::Wide),
+	ran(0x104aa,     6, W::Wide),
+	ran(0x104d4,     4, W::Wide),
+	ran(0x104fc,     4, W::Wide),
+	ran(0x10528,     8, W::Wide),
+	ran(0x10564,    11, W::Wide),
+	ran(0x1057b,     1, W::Wide),
+	ran(0x1058b,     1, W::Wide),
+	ran(0x10593,     1, W::Wide),
+	ran(0x10596,     1, W::Wide),
+	ran(0x105a2,     1, W::Wide),
+	ran(0x105b2,     1, W::Wide),
+	ran(0x105ba,     1, W::Wide),
+	ran(0x105bd,    67, W::Wide),
+	ran(0x10737,     9, W::Wide),
+	ran(0x10756,    10, W::Wide),
+	ran(0x10768,    24, W::Wide),
+	ran(0x10786,     1, W::Wide),
+	ran(0x107b1,     1, W::Wide),
+	ran(0x107bb,    69, W::Wide),
+	ran(0x10806,     2, W::Wide),
+	ran(0x10809,     1, W::Wide),
+	ran(0x10836,     1, W::Wide),
+	ran(0x10839,     3, W::Wide),
+	ran(0x1083d,     2, W::Wide),
+	ran(0x10856,     1, W::Wide),
+	ran(0x1089f,     8, W::Wide),
+	ran(0x108b0,    48, W::Wide),
+	ran(0x108f3,     1, W::Wide),
+	ran(0x108f6,     5, W::Wide),
+	ran(0x1091c,     3, W::Wide),
+	ran(0x1093a,     5, W::Wide),
+	ran(0x10940,    64, W::Wide),
+	ran(0x109b8,     4, W::Wide),
+	ran(0x109d0,     2, W::Wide),
+	ran(0x10a01,     3, W::Zero),
+	ran(0x10a04,     1, W::Wide),
+	ran(0x10a05,     2, W::Zero),
+	ran(0x10a07,     5, W::Wide),
+	ran(0x10a0c,     4, W::Zero),
+	ran(0x10a14,     1, W::Wide),
+	ran(0x10a18,     1, W::Wide),
+	ran(0x10a36,     2, W::Wide),
+	ran(0x10a38,     3, W::Zero),
+	ran(0x10a3b,     4, W::Wide),
+	ran(0x10a3f,     1, W::Zero),
+	ran(0x10a49,     7, W::Wide),
+	ran(0x10a59,     7, W::Wide),
+	ran(0x10aa0,    32, W::Wide),
+	ran(0x10ae5,     2, W::Zero),
+	ran(0x10ae7,     4, W::Wide),
+	ran(0x10af7,     9, W::Wide),
+	ran(0x10b36,     3, W::Wide),
+	ran(0x10b56,     2, W::Wide),
+	ran(0x10b73,     5, W::Wide),
+	ran(0x10b92,     7, W::Wide),
+	ran(0x10b9d,    12, W::Wide),
+	ran(0x10bb0,    80, W::Wide),
+	ran(0x10c49,    55, W::Wide),
+	ran(0x10cb3,    13, W::Wide),
+	ran(0x10cf3,     7, W::Wide),
+	ran(0x10d24,     4, W::Zero),
+	ran(0x10d28,     8, W::Wide),
+	ran(0x10d3a,   294, W::Wide),
+	ran(0x10e7f,     1, W::Wide),
+	ran(0x10eaa,     1, W::Wide),
+	ran(0x10eab,     2, W::Zero),
+	ran(0x10eae,     2, W::Wide),
+	ran(0x10eb2,    78, W::Wide),
+	ran(0x10f28,     8, W::Wide),
+	ran(0x10f46,    11, W::Zero),
+	ran(0x10f5a,    22, W::Wide),
+	ran(0x10f82,     4, W::Zero),
+	ran(0x10f8a,    38, W::Wide),
+	ran(0x10fcc,    20, W::Wide),
+	ran(0x10ff7,     9, W::Wide),
+	ran(0x11001,     1, W::Zero),
+	ran(0x11038,    15, W::Zero),
+	ran(0x1104e,     4, W::Wide),
+	ran(0x11070,     1, W::Zero),
+	ran(0x11073,     2, W::Zero),
+	ran(0x11076,     9, W::Wide),
+	ran(0x1107f,     3, W::Zero),
+	ran(0x110b3,     4, W::Zero),
+	ran(0x110b9,     2, W::Zero),
+	ran(0x110bd,     1, W::Zero),
+	ran(0x110c2,     1, W::Zero),
+	ran(0x110c3,    10, W::Wide),
+	ran(0x110cd,     1, W::Zero),
+	ran(0x110ce,     2, W::Wide),
+	ran(0x110e9,     7, W::Wide),
+	ran(0x110fa,     6, W::Wide),
+	ran(0x11100,     3, W::Zero),
+	ran(0x11127,     5, W::Zero),
+	ran(0x1112d,     8, W::Zero),
+	ran(0x11135,     1, W::Wide),
+	ran(0x11148,     8, W::Wide),
+	ran(0x11173,     1, W::Zero),
+	ran(0x11177,     9, W::Wide),
+	ran(0x11180,     2, W::Zero),
+	ran(0x111b6,     9, W::Zero),
+	ran(0x111c9,     4, W::Zero),
+	ran(0x111cf,     1, W::Zero),
+	ran(0x111e0,     1, W::Wide),
+	ran(0x111f5,    11, W::Wide),
+	ran(0x11212,     1, W::Wide),
+	ran(0x1122f,     3, W::Zero),
+	ran(0x11234,     1, W::Zero),
+	ran(0x11236,     2, W::Zero),
+	ran(0x1123e,     1, W::Zero),
+	ran(0x1123f,    65, W::Wide),
+	ran(0x11287,     1, W::Wide),
+	ran(0x11289,     1, W::Wide),
+	ran(0x1128e,     1, W::Wide),
+	ran(0x1129e,     1, W::Wide),
+	ran(0x112aa,     6, W::Wide),
+	ran(0x112df,     1, W::Zero),
+	ran(0x112e3,     8, W::Zero),
+	ran(0x112eb,     5, W::Wide),
+	ran(0x112fa,     6, W::Wide),
+	ran(0x11300,     2, W::Zero),
+	ran(0x11304,     1, W::Wide),
+	ran(0x1130d,     2, W::Wide),
+	ran(0x11311,     2, W::Wide),
+	ran(0x11329,     1, W::Wide),
+	ran(0x11331,     1, W::Wide),
+	ran(0x11334,     1, W::Wide),
+	ran(0x1133a,     1, W::Wide),
+	ran(0x1133b,     2, W::Zero),
+	ran(0x11340,     1, W::Zero),
+	ran(0x11345,     2, W::Wide),
+	ran(0x11349,     2, W::Wide),
+	ran(0x1134e,     2, W::Wide),
+	ran(0x11351,     6, W::Wide),
+	ran(0x11358,     5, W::Wide),
+	ran(0x11364,     2, W::Wide),
+	ran(0x11366,     7, W::Zero),
+	ran(0x1136d,     3, W::Wide),
+	ran(0x11370,     5, W::Zero),
+	ran(0x11375,   139, W::Wide),
+	ran(0x11438,     8, W::Zero),
+	ran(0x11442,     3, W::Zero),
+	ran(0x11446,     1, W::Zero),
+	ran(0x1145c,     1, W::Wide),
+	ran(0x1145e,     1, W::Zero),
+	ran(0x11462,    30, W::Wide),
+	ran(0x114b3,     6, W::Zero),
+	ran(0x114ba,     1, W::Zero),
+	ran(0x114bf,     2, W::Zero),
+	ran(0x114c2,     2, W::Zero),
+	ran(0x114c8,     8, W::Wide),
+	ran(0x114da,   166, W::Wide),
+	ran(0x115b2,     4, W::Zero),
+	ran(0x115b6,     2, W::Wide),
+	ran(0x115bc,     2, W::Zero),
+	ran(0x115bf,     2, W::Zero),
+	ran(0x115dc,     2, W::Zero),
+	ran(0x115de,    34, W::Wide),
+	ran(0x11633,     8, W::Zero),
+	ran(0x1163d,     1, W::Zero),
+	ran(0x1163f,     2, W::Zero),
+	ran(0x11645,    11, W::Wide),
+	ran(0x1165a,     6, W::Wide),
+	ran(0x1166d,    19, W::Wide),
+	ran(0x116ab,     1, W::Zero),
+	ran(0x116ad,     1, W::Zero),
+	ran(0x116b0,     6, W::Zero),
+	ran(0x116b7,     1, W::Zero),
+	ran(0x116ba,     6, W::Wide),
+	ran(0x116ca,    54, W::Wide),
+	ran(0x1171b,     2, W::Wide),
+	ran(0x1171d,     3, W::Zero),
+	ran(0x11722,     4, W::Zero),
+	ran(0x11727,     5, W::Zero),
+	ran(0x1172c,     4, W::Wide),
+	ran(0x11747,   185, W::Wide),
+	ran(0x1182f,     9, W::Zero),
+	ran(0x11839,     2, W::Zero),
+	ran(0x1183c,   100, W::Wide),
+	ran(0x118f3,    12, W::Wide),
+	ran(0x11907,     2, W::Wide),
+	ran(0x1190a,     2, W::Wide),
+	ran(0x11914,     1, W::Wide),
+	ran(0x11917,     1, W::Wide),
+	ran(0x11936,     1, W::Wide),
+	ran(0x11939,     2, W::Wide),
+	ran(0x1193b,     2, W::Zero),
+	ran(0x1193e,     1, W::Zero),
+	ran(0x11943,     1, W::Zero),
+	ran(0x11947,     9, W::Wide),
+	ran(0x1195a,    70, W::Wide),
+	ran(0x119a8,     2, W::Wide),
+	ran(0x119d4,     4, W::Zero),
+	ran(0x119d8,     2, W::Wide),
+	ran(0x119da,     2, W::Zero),
+	ran(0x119e0,     1, W::Zero),
+	ran(0x119e5,    27, W::Wide),
+	ran(0x11a01,    10, W::Zero),
+	ran(0x11a33,     6, W::Zero),
+	ran(0x11a3b,     4, W::Zero),
+	ran(0x11a47,     1, W::Zero),
+	ran(0x11a48,     8, W::Wide),
+	ran(0x11a51,     6, W::Zero),
+	ran(0x11a59,     3, W::Zero),
+	ran(0x11a8a,    13, W::Zero),
+	ran(0x11a98,     2, W::Zero),
+	ran(0x11aa3,    13, W::Wide),
+	ran(0x11af9,   263, W::Wide),
+	ran(0x11c09,     1, W::Wide),
+	ran(0x11c30,     7, W::Zero),
+	ran(0x11c37,     1, W::Wide),
+	ran(0x11c38,     6, W::Zero),
+	ran(0x11c3f,     1, W::Zero),
+	ran(0x11c46,    10, W::Wide),
+	ran(0x11c6d,     3, W::Wide),
+	ran(0x11c90,     2, W::Wide),
+	ran(0x11c92,    22, W::Zero),
+	ran(0x11ca8,     1, W::Wide),
+	ran(0x11caa,     7, W::Zero),
+	ran(0x11cb2,     2, W::Zero),
+	ran(0x11cb5,     2, W::Zero),
+	ran(0x11cb7,    73, W::Wide),
+	ran(0x11d07,     1, W::Wide),
+	ran(0x11d0a,     1, W::Wide),
+	ran(0x11d31,     6, W::Zero),
+	ran(0x11d37,     3, W::Wide),
+	ran(0x11d3a,     1, W::Zero),
+	ran(0x11d3b,     1, W::Wide),
+	ran(0x11d3c,     2, W::Zero),
+	ran(0x11d3e,     1, W::Wide),
+	ran(0x11d3f,     7, W::Zero),
+	ran(0x11d47,     1, W::Zero),
+	ran(0x11d48,     8, W::Wide),
+	ran(0x11d5a,     6, W::Wide),
+	ran(0x11d66,     1, W::Wide),
+	ran(0x11d69,     1, W::Wide),
+	ran(0x11d8f,     1, W::Wide),
+	ran(0x11d90,     2, W::Zero),
+	ran(0x11d92,     1, W::Wide),
+	ran(0x11d95,     1, W::Zero),
+	ran(0x11d97,     1, W::Zero),
+	ran(0x11d99,     7, W::Wide),
+	ran(0x11daa,   310, W::Wide),
+	ran(0x11ef3,     2, W::Zero),
+	ran(0x11ef9,   183, W::Wide),
+	ran(0x11fb1,    15, W::Wide),
+	ran(0x11ff2,    13, W::Wide),
+	ran(0x1239a,   102, W::Wide),
+	ran(0x1246f,     1, W::Wide),
+	ran(0x12475,    11, W::Wide),
+	ran(0x12544,  2636, W::Wide),
+	ran(0x12ff3,    13, W::Wide),
+	ran(0x1342f,     1, W::Wide),
+	ran(0x13430,     9, W::Zero),
+	ran(0x13439,  4039, W::Wide),
+	ran(0x14647,  8633, W::Wide),
+	ran(0x16a39,     7, W::Wide),
+	ran(0x16a5f,     1, W::Wide),
+	ran(0x16a6a,     4, W::Wide),
+	ran(0x16abf,     1, W::Wide),
+	ran(0x16aca,     6, W::Wide),
+	ran(0x16aee,     2, W::Wide),
+	ran(0x16af0,     5, W::Zero),
+	ran(0x16af6,    10, W::Wide),
+	ran(0x16b30,     7, W::Zero),
+	ran(0x16b46,    10, W::Wide),
+	ran(0x16b5a,     1, W::Wide),
+	ran(0x16b62,     1, W::Wide),
+	ran(0x16b78,     5, W::Wide),
+	ran(0x16b90,   688, W::Wide),
+	ran(0x16e9b,   101, W::Wide),
+	ran(0x16f4b,     4, W::Wide),
+	ran(0x16f4f,     1, W::Zero),
+	ran(0x16f88,     7, W::Wide),
+	ran(0x16f8f,     4, W::Zero),
+	ran(0x16fa0,    68, W::Wide),
+	ran(0x16fe4,     1, W::Zero),
+	ran(0x16fe5, 19483, W::Wide),
+	ran(0x1bc6b,     5, W::Wide),
+	ran(0x1bc7d,     3, W::Wide),
+	ran(0x1bc89,     7, W::Wide),
+	ran(0x1bc9a,     2, W::Wide),
+	ran(0x1bc9d,     2, W::Zero),
+	ran(0x1bca0,     4, W::Zero),
+	ran(0x1bca4,  4700, W::Wide),
+	ran(0x1cf00,    46, W::Zero),
+	ran(0x1cf2e,     2, W::Wide),
+	ran(0x1cf30,    23, W::Zero),
+	ran(0x1cf47,     9, W::Wide),
+	ran(0x1cfc4,    60, W::Wide),
+	ran(0x1d0f6,    10, W::Wide),
+	ran(0x1d127,     2, W::Wide),
+	ran(0x1d167,     3, W::Zero),
+	ran(0x1d173,    16, W::Zero),
+	ran(0x1d185,     7, W::Zero),
+	ran(0x1d1aa,     4, W::Zero),
+	ran(0x1d1eb,    21, W::Wide),
+	ran(0x1d242,     3, W::Zero),
+	ran(0x1d246,   154, W::Wide),
+	ran(0x1d2f4,    12, W::Wide),
+	ran(0x1d357,     9, W::Wide),
+	ran(0x1d379,   135, W::Wide),
+	ran(0x1d455,     1, W::Wide),
+	ran(0x1d49d,     1, W::Wide),
+	ran(0x1d4a0,     2, W::Wide),
+	ran(0x1d4a3,     2, W::Wide),
+	ran(0x1d4a7,     2, W::Wide),
+	ran(0x1d4ad,     1, W::Wide),
+	ran(0x1d4ba,     1, W::Wide),
+	ran(0x1d4bc,     1, W::Wide),
+	ran(0x1d4c4,     1, W::Wide),
+	ran(0x1d506,     1, W::Wide),
+	ran(0x1d50b,     2, W::Wide),
+	ran(0x1d515,     1, W::Wide),
+	ran(0x1d51d,     1, W::Wide),
+	ran(0x1d53a,     1, W::Wide),
+	ran(0x1d53f,     1, W::Wide),
+	ran(0x1d545,     1, W::Wide),
+	ran(0x1d547,     3, W::Wide),
+	ran(0x1d551,     1, W::Wide),
+	ran(0x1d6a6,     2, W::Wide),
+	ran(0x1d7cc,     2, W::Wide),
+	ran(0x1da00,    55, W::Zero),
+	ran(0x1da3b,    50, W::Zero),
+	ran(0x1da75,     1, W::Zero),
+	ran(0x1da84,     1, W::Zero),
+	ran(0x1da8c,    15, W::Wide),
+	ran(0x1da9b,     5, W::Zero),
+	ran(0x1daa0,     1, W::Wide),
+	ran(0x1daa1,    15, W::Zero),
+	ran(0x1dab0,  1104, W::Wide),
+	ran(0x1df1f,   225, W::Wide),
+	ran(0x1e000,     7, W::Zero),
+	ran(0x1e007,     1, W::Wide),
+	ran(0x1e008,    17, W::Zero),
+	ran(0x1e019,     2, W::Wide),
+	ran(0x1e01b,     7, W::Zero),
+	ran(0x1e022,     1, W::Wide),
+	ran(0x1e023,     2, W::Zero),
+	ran(0x1e025,     1, W::Wide),
+	ran(0x1e026,     5, W::Zero),
+	ran(0x1e02b,   213, W::Wide),
+	ran(0x1e12d,     3, W::Wide),
+	ran(0x1e130,     7, W::Zero),
+	ran(0x1e13e,     2, W::Wide),
+	ran(0x1e14a,     4, W::Wide),
+	ran(0x1e150,   320, W::Wide),
+	ran(0x1e2ae,     1, W::Zero),
+	ran(0x1e2af,    17, W::Wide),
+	ran(0x1e2ec,     4, W::Zero),
+	ran(0x1e2fa,     5, W::Wide),
+	ran(0x1e300,  1248, W::Wide),
+	ran(0x1e7e7,     1, W::Wide),
+	ran(0x1e7ec,     1, W::Wide),
+	ran(0x1e7ef,     1, W::Wide),
+	ran(0x1e7ff,     1, W::Wide),
+	ran(0x1e8c5,     2, W::Wide),
+	ran(0x1e8d0,     7, W::Zero),
+	ran(0x1e8d7,    41, W::Wide),
+	ran(0x1e944,     7, W::Zero),
+	ran(0x1e94c,     4, W::Wide),
+	ran(0x1e95a,     4, W::Wide),
+	ran(0x1e960,   785, W::Wide),
+	ran(0x1ecb5,    76, W::Wide),
+	ran(0x1ed3e,   194, W::Wide),
+	ran(0x1ee04,     1, W::Wide),
+	ran(0x1ee20,     1, W::Wide),
+	ran(0x1ee23,     1, W::Wide),
+	ran(0x1ee25,     2, W::Wide),
+	ran(0x1ee28,     1, W::Wide),
+	ran(0x1ee33,     1, W::Wide),
+	ran(0x1ee38,     1, W::Wide),
+	ran(0x1ee3a,     1, W::Wide),
+	ran(0x1ee3c,     6, W::Wide),
+	ran(0x1ee43,     4, W::Wide),
+	ran(0x1ee48,     1, W::Wide),
+	ran(0x1ee4a,     1, W::Wide),
+	ran(0x1ee4c,     1, W::Wide),
+	ran(0x1ee50,     1, W::Wide),
+	ran(0x1ee53,     1, W::Wide),
+	ran(0x1ee55,     2, W::Wide),
+	ran(0x1ee58,     1, W::Wide),
+	ran(0x1ee5a,     1, W::Wide),
+	ran(0x1ee5c,     1, W::Wide),
+	ran(0x1ee5e,     1, W::Wide),
+	ran(0x1ee60,     1, W::Wide),
+	ran(0x1ee63,     1, W::Wide),
+	ran(0x1ee65,     2, W::Wide),
+	ran(0x1ee6b,     1, W::Wide),
+	ran(0x1ee73,     1, W::Wide),
+	ran(0x1ee78,     1, W::Wide),
+	ran(0x1ee7d,     1, W::Wide),
+	ran(0x1ee7f,     1, W::Wide),
+	ran(0x1ee8a,     1, W::Wide),
+	ran(0x1ee9c,     5, W::Wide),
+	ran(0x1eea4,     1, W::Wide),
+	ran(0x1eeaa,     1, W::Wide),
+	ran(0x1eebc,    52, W::Wide),
+	ran(0x1eef2,   270, W::Wide),
+	ran(0x1f004,     1, W::Wide),
+	ran(0x1f02c,     4, W::Wide),
+	ran(0x1f094,    12, W::Wide),
+	ran(0x1f0af,     2, W::Wide),
+	ran(0x1f0c0,     1, W::Wide),
+	ran(0x1f0cf,     2, W::Wide),
+	ran(0x1f0f6,    10, W::Wide),
+	ran(0x1f18e,     1, W::Wide),
+	ran(0x1f191,    10, W::Wide),
+	ran(0x1f1ae,    56, W::Wide),
+	ran(0x1f200,   289, W::Wide),
+	ran(0x1f32d,     9, W::Wide),
+	ran(0x1f337,    70, W::Wide),
+	ran(0x1f37e,    22, W::Wide),
+	ran(0x1f3a0,    43, W::Wide),
+	ran(0x1f3cf,     5, W::Wide),
+	ran(0x1f3e0,    17, W::Wide),
+	ran(0x1f3f4,     1, W::Wide),
+	ran(0x1f3f8,    71, W::Wide),
+	ran(0x1f440,     1, W::Wide),
+	ran(0x1f442,   187, W::Wide),
+	ran(0x1f4ff,    63, W::Wide),
+	ran(0x1f54b,     4, W::Wide),
+	ran(0x1f550,    24, W::Wide),
+	ran(0x1f57a,     1, W::Wide),
+	ran(0x1f595,     2, W::Wide),
+	ran(0x1f5a4,     1, W::Wide),
+	ran(0x1f5fb,    85, W::Wide),
+	ran(0x1f680,    70, W::Wide),
+	ran(0x1f6cc,     1, W::Wide),
+	ran(0x1f6d0,     3, W::Wide),
+	ran(0x1f6d5,    11, W::Wide),
+	ran(0x1f6eb,     5, W::Wide),
+	ran(0x1f6f4,    12, W::Wide),
+	ran(0x1f774,    12, W::Wide),
+	ran(0x1f7d9,    39, W::Wide),
+	ran(0x1f80c,     4, W::Wide),
+	ran(0x1f848,     8, W::Wide),
+	ran(0x1f85a,     6, W::Wide),
+	ran(0x1f888,     8, W::Wide),
+	ran(0x1f8ae,     2, W::Wide),
+	ran(0x1f8b2,    78, W::Wide),
+	ran(0x1f90c,    47, W::Wide),
+	ran(0x1f93c,    10, W::Wide),
+	ran(0x1f947,   185, W::Wide),
+	ran(0x1fa54,    12, W::Wide),
+	ran(0x1fa6e,   146, W::Wide),
+	ran(0x1fb93,     1, W::Wide),
+	ran(0x1fbcb,    37, W::Wide),
+	ran(0x1fbfa, 65535, W::Wide),
+	ran(0x2fbf9, 65535, W::Wide),
+	ran(0x3fbf8, 65535, W::Wide),
+	ran(0x4fbf7, 65535, W::Wide),
+	ran(0x5fbf6, 65535, W::Wide),
+	ran(0x6fbf5, 65535, W::Wide),
+	ran(0x7fbf4, 65535, W::Wide),
+	ran(0x8fbf3, 65535, W::Wide),
+	ran(0x9fbf2, 65535, W::Wide),
+	ran(0xafbf1, 65535, W::Wide),
+	ran(0xbfbf0, 65535, W::Wide),
+	ran(0xcfbef, 65535, W::Wide),
+	ran(0xdfbee,  1042, W::Wide),
+	ran(0xe0000,  4096, W::Zero),
+	ran(0xe1000, 61440, W::Wide),
+	ran(0xffffe,     2, W::Wide),
+	ran(0x10fffe,     2, W::Wide),
+];
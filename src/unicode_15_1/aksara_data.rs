@@ -0,0 +1,201 @@
+use crate::lookup::IndicConjunctBreak as I;
+use crate::tailoring::{AksaraRange, ran};
+pub const AKSARA_RANGES: [AksaraRange; 197] = [
+	ran(0x00915,    37, I::Consonant),
+	ran(0x0094d,     1, I::Linker),
+	ran(0x00958,     8, I::Consonant),
+	ran(0x00978,     8, I::Consonant),
+	ran(0x00995,    20, I::Consonant),
+	ran(0x009aa,     7, I::Consonant),
+	ran(0x009b2,     1, I::Consonant),
+	ran(0x009b6,     4, I::Consonant),
+	ran(0x009cd,     1, I::Linker),
+	ran(0x009dc,     2, I::Consonant),
+	ran(0x009df,     1, I::Consonant),
+	ran(0x009f0,     2, I::Consonant),
+	ran(0x00a15,    20, I::Consonant),
+	ran(0x00a2a,     7, I::Consonant),
+	ran(0x00a32,     2, I::Consonant),
+	ran(0x00a35,     2, I::Consonant),
+	ran(0x00a38,     2, I::Consonant),
+	ran(0x00a4d,     1, I::Linker),
+	ran(0x00a59,     4, I::Consonant),
+	ran(0x00a5e,     1, I::Consonant),
+	ran(0x00a95,    20, I::Consonant),
+	ran(0x00aaa,     7, I::Consonant),
+	ran(0x00ab2,     2, I::Consonant),
+	ran(0x00ab5,     5, I::Consonant),
+	ran(0x00acd,     1, I::Linker),
+	ran(0x00af9,     1, I::Consonant),
+	ran(0x00b15,    20, I::Consonant),
+	ran(0x00b2a,     7, I::Consonant),
+	ran(0x00b32,     2, I::Consonant),
+	ran(0x00b35,     5, I::Consonant),
+	ran(0x00b4d,     1, I::Linker),
+	ran(0x00b5c,     2, I::Consonant),
+	ran(0x00b5f,     1, I::Consonant),
+	ran(0x00b71,     1, I::Consonant),
+	ran(0x00b95,     1, I::Consonant),
+	ran(0x00b99,     2, I::Consonant),
+	ran(0x00b9c,     1, I::Consonant),
+	ran(0x00b9e,     2, I::Consonant),
+	ran(0x00ba3,     2, I::Consonant),
+	ran(0x00ba8,     3, I::Consonant),
+	ran(0x00bae,    12, I::Consonant),
+	ran(0x00bcd,     1, I::Linker),
+	ran(0x00c15,    20, I::Consonant),
+	ran(0x00c2a,    16, I::Consonant),
+	ran(0x00c4d,     1, I::Linker),
+	ran(0x00c58,     3, I::Consonant),
+	ran(0x00c95,    20, I::Consonant),
+	ran(0x00caa,    10, I::Consonant),
+	ran(0x00cb5,     5, I::Consonant),
+	ran(0x00ccd,     1, I::Linker),
+	ran(0x00cde,     1, I::Consonant),
+	ran(0x00d15,    38, I::Consonant),
+	ran(0x00d4d,     1, I::Linker),
+	ran(0x00d9a,    24, I::Consonant),
+	ran(0x00db3,     9, I::Consonant),
+	ran(0x00dbd,     1, I::Consonant),
+	ran(0x00dc0,     7, I::Consonant),
+	ran(0x00dca,     1, I::Linker),
+	ran(0x00e01,    46, I::Consonant),
+	ran(0x00e81,     2, I::Consonant),
+	ran(0x00e84,     1, I::Consonant),
+	ran(0x00e86,     5, I::Consonant),
+	ran(0x00e8c,    24, I::Consonant),
+	ran(0x00ea5,     1, I::Consonant),
+	ran(0x00ea7,     8, I::Consonant),
+	ran(0x00edc,     4, I::Consonant),
+	ran(0x00f40,     8, I::Consonant),
+	ran(0x00f49,    36, I::Consonant),
+	ran(0x01000,    33, I::Consonant),
+	ran(0x01039,     1, I::Linker),
+	ran(0x0103f,     1, I::Consonant),
+	ran(0x01050,     2, I::Consonant),
+	ran(0x0105a,     4, I::Consonant),
+	ran(0x01061,     1, I::Consonant),
+	ran(0x01065,     2, I::Consonant),
+	ran(0x0106e,     3, I::Consonant),
+	ran(0x01075,    13, I::Consonant),
+	ran(0x0108e,     1, I::Consonant),
+	ran(0x01703,    15, I::Consonant),
+	ran(0x0171f,     1, I::Consonant),
+	ran(0x01723,    15, I::Consonant),
+	ran(0x01743,    15, I::Consonant),
+	ran(0x01763,    10, I::Consonant),
+	ran(0x0176e,     3, I::Consonant),
+	ran(0x01780,    35, I::Consonant),
+	ran(0x017d2,     1, I::Linker),
+	ran(0x01901,    30, I::Consonant),
+	ran(0x01950,    19, I::Consonant),
+	ran(0x01980,    44, I::Consonant),
+	ran(0x01a00,    23, I::Consonant),
+	ran(0x01a20,    45, I::Consonant),
+	ran(0x01a53,     2, I::Consonant),
+	ran(0x01a60,     1, I::Linker),
+	ran(0x01b13,    33, I::Consonant),
+	ran(0x01b44,     1, I::Linker),
+	ran(0x01b45,     8, I::Consonant),
+	ran(0x01b8a,    23, I::Consonant),
+	ran(0x01bab,     1, I::Linker),
+	ran(0x01bae,     2, I::Consonant),
+	ran(0x01bbb,     3, I::Consonant),
+	ran(0x01bc0,    36, I::Consonant),
+	ran(0x01c00,    36, I::Consonant),
+	ran(0x01c4d,     3, I::Consonant),
+	ran(0x0a806,     1, I::Linker),
+	ran(0x0a807,     4, I::Consonant),
+	ran(0x0a80c,    23, I::Consonant),
+	ran(0x0a840,    30, I::Consonant),
+	ran(0x0a862,     4, I::Consonant),
+	ran(0x0a869,     8, I::Consonant),
+	ran(0x0a872,     1, I::Consonant),
+	ran(0x0a892,    34, I::Consonant),
+	ran(0x0a8c4,     1, I::Linker),
+	ran(0x0a90a,    24, I::Consonant),
+	ran(0x0a930,    23, I::Consonant),
+	ran(0x0a989,     3, I::Consonant),
+	ran(0x0a98f,    36, I::Consonant),
+	ran(0x0a9c0,     1, I::Linker),
+	ran(0x0a9e0,     5, I::Consonant),
+	ran(0x0a9e7,     9, I::Consonant),
+	ran(0x0a9fa,     5, I::Consonant),
+	ran(0x0aa06,    35, I::Consonant),
+	ran(0x0aa60,    16, I::Consonant),
+	ran(0x0aa71,     3, I::Consonant),
+	ran(0x0aa7a,     1, I::Consonant),
+	ran(0x0aa7e,    50, I::Consonant),
+	ran(0x0aae2,     9, I::Consonant),
+	ran(0x0aaf6,     1, I::Linker),
+	ran(0x0abc0,    14, I::Consonant),
+	ran(0x0abd0,     1, I::Consonant),
+	ran(0x0abd2,     9, I::Consonant),
+	ran(0x10a00,     1, I::Consonant),
+	ran(0x10a10,     4, I::Consonant),
+	ran(0x10a15,     3, I::Consonant),
+	ran(0x10a19,    29, I::Consonant),
+	ran(0x10a3f,     1, I::Linker),
+	ran(0x11013,    37, I::Consonant),
+	ran(0x11046,     1, I::Linker),
+	ran(0x11075,     1, I::Consonant),
+	ran(0x1108d,    35, I::Consonant),
+	ran(0x110b9,     1, I::Linker),
+	ran(0x11107,    32, I::Consonant),
+	ran(0x11133,     1, I::Linker),
+	ran(0x11144,     1, I::Consonant),
+	ran(0x11147,     1, I::Consonant),
+	ran(0x11155,    30, I::Consonant),
+	ran(0x11191,    34, I::Consonant),
+	ran(0x111c0,     1, I::Linker),
+	ran(0x11208,    10, I::Consonant),
+	ran(0x11213,    25, I::Consonant),
+	ran(0x11235,     1, I::Linker),
+	ran(0x1123f,     1, I::Consonant),
+	ran(0x11284,     3, I::Consonant),
+	ran(0x11288,     1, I::Consonant),
+	ran(0x1128a,     4, I::Consonant),
+	ran(0x1128f,    15, I::Consonant),
+	ran(0x1129f,    10, I::Consonant),
+	ran(0x112ba,    37, I::Consonant),
+	ran(0x11315,    20, I::Consonant),
+	ran(0x1132a,     7, I::Consonant),
+	ran(0x11332,     2, I::Consonant),
+	ran(0x11335,     5, I::Consonant),
+	ran(0x1134d,     1, I::Linker),
+	ran(0x1140e,    39, I::Consonant),
+	ran(0x11442,     1, I::Linker),
+	ran(0x1148f,    33, I::Consonant),
+	ran(0x114c2,     1, I::Linker),
+	ran(0x1158e,    33, I::Consonant),
+	ran(0x115bf,     1, I::Linker),
+	ran(0x1160e,    34, I::Consonant),
+	ran(0x1163f,     1, I::Linker),
+	ran(0x1168a,    33, I::Consonant),
+	ran(0x116b6,     1, I::Linker),
+	ran(0x116b8,     1, I::Consonant),
+	ran(0x11700,    27, I::Consonant),
+	ran(0x11740,     7, I::Consonant),
+	ran(0x1180a,    34, I::Consonant),
+	ran(0x11839,     1, I::Linker),
+	ran(0x1190c,     8, I::Consonant),
+	ran(0x11915,     2, I::Consonant),
+	ran(0x11918,    24, I::Consonant),
+	ran(0x1193e,     1, I::Linker),
+	ran(0x119ae,    35, I::Consonant),
+	ran(0x119e0,     1, I::Linker),
+	ran(0x11a0b,    40, I::Consonant),
+	ran(0x11a47,     1, I::Linker),
+	ran(0x11a5c,    40, I::Consonant),
+	ran(0x11a99,     1, I::Linker),
+	ran(0x11c0e,    33, I::Consonant),
+	ran(0x11c3f,     1, I::Linker),
+	ran(0x11c72,    30, I::Consonant),
+	ran(0x11d0c,    37, I::Consonant),
+	ran(0x11d45,     1, I::Linker),
+	ran(0x11d6c,    30, I::Consonant),
+	ran(0x11d97,     1, I::Linker),
+	ran(0x11ee0,    18, I::Consonant),
+	ran(0x11f12,    34, I::Consonant),
+	ran(0x11f42,     1, I::Linker),
+];
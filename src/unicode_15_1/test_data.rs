@@ -0,0 +1,1190 @@
+use crate::TestCase;
+pub static TEST_CASES: [TestCase; 1187] = [
+	TestCase::new(25, "\x20\x20", &[1]),
+	TestCase::new(26, "\x20\u{0308}\x20", &[3]),
+	TestCase::new(27, "\x20\x0d", &[1]),
+	TestCase::new(28, "\x20\u{0308}\x0d", &[3]),
+	TestCase::new(29, "\x20\x0a", &[1]),
+	TestCase::new(30, "\x20\u{0308}\x0a", &[3]),
+	TestCase::new(31, "\x20\x01", &[1]),
+	TestCase::new(32, "\x20\u{0308}\x01", &[3]),
+	TestCase::new(33, "\x20\u{034f}", &[]),
+	TestCase::new(34, "\x20\u{0308}\u{034f}", &[]),
+	TestCase::new(35, "\x20\u{1f1e6}", &[1]),
+	TestCase::new(36, "\x20\u{0308}\u{1f1e6}", &[3]),
+	TestCase::new(37, "\x20\u{0600}", &[1]),
+	TestCase::new(38, "\x20\u{0308}\u{0600}", &[3]),
+	TestCase::new(39, "\x20\u{0a03}", &[]),
+	TestCase::new(40, "\x20\u{0308}\u{0a03}", &[]),
+	TestCase::new(41, "\x20\u{1100}", &[1]),
+	TestCase::new(42, "\x20\u{0308}\u{1100}", &[3]),
+	TestCase::new(43, "\x20\u{1160}", &[1]),
+	TestCase::new(44, "\x20\u{0308}\u{1160}", &[3]),
+	TestCase::new(45, "\x20\u{11a8}", &[1]),
+	TestCase::new(46, "\x20\u{0308}\u{11a8}", &[3]),
+	TestCase::new(47, "\x20\u{ac00}", &[1]),
+	TestCase::new(48, "\x20\u{0308}\u{ac00}", &[3]),
+	TestCase::new(49, "\x20\u{ac01}", &[1]),
+	TestCase::new(50, "\x20\u{0308}\u{ac01}", &[3]),
+	TestCase::new(51, "\x20\u{0900}", &[]),
+	TestCase::new(52, "\x20\u{0308}\u{0900}", &[]),
+	TestCase::new(53, "\x20\u{0903}", &[]),
+	TestCase::new(54, "\x20\u{0308}\u{0903}", &[]),
+	TestCase::new(55, "\x20\u{0904}", &[1]),
+	TestCase::new(56, "\x20\u{0308}\u{0904}", &[3]),
+	TestCase::new(57, "\x20\u{0d4e}", &[1]),
+	TestCase::new(58, "\x20\u{0308}\u{0d4e}", &[3]),
+	TestCase::new(59, "\x20\u{0915}", &[1]),
+	TestCase::new(60, "\x20\u{0308}\u{0915}", &[3]),
+	TestCase::new(61, "\x20\u{231a}", &[1]),
+	TestCase::new(62, "\x20\u{0308}\u{231a}", &[3]),
+	TestCase::new(63, "\x20\u{0300}", &[]),
+	TestCase::new(64, "\x20\u{0308}\u{0300}", &[]),
+	TestCase::new(65, "\x20\u{093c}", &[]),
+	TestCase::new(66, "\x20\u{0308}\u{093c}", &[]),
+	TestCase::new(67, "\x20\u{094d}", &[]),
+	TestCase::new(68, "\x20\u{0308}\u{094d}", &[]),
+	TestCase::new(69, "\x20\u{200d}", &[]),
+	TestCase::new(70, "\x20\u{0308}\u{200d}", &[]),
+	TestCase::new(71, "\x20\u{0378}", &[1]),
+	TestCase::new(72, "\x20\u{0308}\u{0378}", &[3]),
+	TestCase::new(73, "\x0d\x20", &[1]),
+	TestCase::new(74, "\x0d\u{0308}\x20", &[1,3]),
+	TestCase::new(75, "\x0d\x0d", &[1]),
+	TestCase::new(76, "\x0d\u{0308}\x0d", &[1,3]),
+	TestCase::new(77, "\x0d\x0a", &[]),
+	TestCase::new(78, "\x0d\u{0308}\x0a", &[1,3]),
+	TestCase::new(79, "\x0d\x01", &[1]),
+	TestCase::new(80, "\x0d\u{0308}\x01", &[1,3]),
+	TestCase::new(81, "\x0d\u{034f}", &[1]),
+	TestCase::new(82, "\x0d\u{0308}\u{034f}", &[1]),
+	TestCase::new(83, "\x0d\u{1f1e6}", &[1]),
+	TestCase::new(84, "\x0d\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(85, "\x0d\u{0600}", &[1]),
+	TestCase::new(86, "\x0d\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(87, "\x0d\u{0a03}", &[1]),
+	TestCase::new(88, "\x0d\u{0308}\u{0a03}", &[1]),
+	TestCase::new(89, "\x0d\u{1100}", &[1]),
+	TestCase::new(90, "\x0d\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(91, "\x0d\u{1160}", &[1]),
+	TestCase::new(92, "\x0d\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(93, "\x0d\u{11a8}", &[1]),
+	TestCase::new(94, "\x0d\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(95, "\x0d\u{ac00}", &[1]),
+	TestCase::new(96, "\x0d\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(97, "\x0d\u{ac01}", &[1]),
+	TestCase::new(98, "\x0d\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(99, "\x0d\u{0900}", &[1]),
+	TestCase::new(100, "\x0d\u{0308}\u{0900}", &[1]),
+	TestCase::new(101, "\x0d\u{0903}", &[1]),
+	TestCase::new(102, "\x0d\u{0308}\u{0903}", &[1]),
+	TestCase::new(103, "\x0d\u{0904}", &[1]),
+	TestCase::new(104, "\x0d\u{0308}\u{0904}", &[1,3]),
+	TestCase::new(105, "\x0d\u{0d4e}", &[1]),
+	TestCase::new(106, "\x0d\u{0308}\u{0d4e}", &[1,3]),
+	TestCase::new(107, "\x0d\u{0915}", &[1]),
+	TestCase::new(108, "\x0d\u{0308}\u{0915}", &[1,3]),
+	TestCase::new(109, "\x0d\u{231a}", &[1]),
+	TestCase::new(110, "\x0d\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(111, "\x0d\u{0300}", &[1]),
+	TestCase::new(112, "\x0d\u{0308}\u{0300}", &[1]),
+	TestCase::new(113, "\x0d\u{093c}", &[1]),
+	TestCase::new(114, "\x0d\u{0308}\u{093c}", &[1]),
+	TestCase::new(115, "\x0d\u{094d}", &[1]),
+	TestCase::new(116, "\x0d\u{0308}\u{094d}", &[1]),
+	TestCase::new(117, "\x0d\u{200d}", &[1]),
+	TestCase::new(118, "\x0d\u{0308}\u{200d}", &[1]),
+	TestCase::new(119, "\x0d\u{0378}", &[1]),
+	TestCase::new(120, "\x0d\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(121, "\x0a\x20", &[1]),
+	TestCase::new(122, "\x0a\u{0308}\x20", &[1,3]),
+	TestCase::new(123, "\x0a\x0d", &[1]),
+	TestCase::new(124, "\x0a\u{0308}\x0d", &[1,3]),
+	TestCase::new(125, "\x0a\x0a", &[1]),
+	TestCase::new(126, "\x0a\u{0308}\x0a", &[1,3]),
+	TestCase::new(127, "\x0a\x01", &[1]),
+	TestCase::new(128, "\x0a\u{0308}\x01", &[1,3]),
+	TestCase::new(129, "\x0a\u{034f}", &[1]),
+	TestCase::new(130, "\x0a\u{0308}\u{034f}", &[1]),
+	TestCase::new(131, "\x0a\u{1f1e6}", &[1]),
+	TestCase::new(132, "\x0a\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(133, "\x0a\u{0600}", &[1]),
+	TestCase::new(134, "\x0a\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(135, "\x0a\u{0a03}", &[1]),
+	TestCase::new(136, "\x0a\u{0308}\u{0a03}", &[1]),
+	TestCase::new(137, "\x0a\u{1100}", &[1]),
+	TestCase::new(138, "\x0a\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(139, "\x0a\u{1160}", &[1]),
+	TestCase::new(140, "\x0a\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(141, "\x0a\u{11a8}", &[1]),
+	TestCase::new(142, "\x0a\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(143, "\x0a\u{ac00}", &[1]),
+	TestCase::new(144, "\x0a\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(145, "\x0a\u{ac01}", &[1]),
+	TestCase::new(146, "\x0a\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(147, "\x0a\u{0900}", &[1]),
+	TestCase::new(148, "\x0a\u{0308}\u{0900}", &[1]),
+	TestCase::new(149, "\x0a\u{0903}", &[1]),
+	TestCase::new(150, "\x0a\u{0308}\u{0903}", &[1]),
+	TestCase::new(151, "\x0a\u{0904}", &[1]),
+	TestCase::new(152, "\x0a\u{0308}\u{0904}", &[1,3]),
+	TestCase::new(153, "\x0a\u{0d4e}", &[1]),
+	TestCase::new(154, "\x0a\u{0308}\u{0d4e}", &[1,3]),
+	TestCase::new(155, "\x0a\u{0915}", &[1]),
+	TestCase::new(156, "\x0a\u{0308}\u{0915}", &[1,3]),
+	TestCase::new(157, "\x0a\u{231a}", &[1]),
+	TestCase::new(158, "\x0a\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(159, "\x0a\u{0300}", &[1]),
+	TestCase::new(160, "\x0a\u{0308}\u{0300}", &[1]),
+	TestCase::new(161, "\x0a\u{093c}", &[1]),
+	TestCase::new(162, "\x0a\u{0308}\u{093c}", &[1]),
+	TestCase::new(163, "\x0a\u{094d}", &[1]),
+	TestCase::new(164, "\x0a\u{0308}\u{094d}", &[1]),
+	TestCase::new(165, "\x0a\u{200d}", &[1]),
+	TestCase::new(166, "\x0a\u{0308}\u{200d}", &[1]),
+	TestCase::new(167, "\x0a\u{0378}", &[1]),
+	TestCase::new(168, "\x0a\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(169, "\x01\x20", &[1]),
+	TestCase::new(170, "\x01\u{0308}\x20", &[1,3]),
+	TestCase::new(171, "\x01\x0d", &[1]),
+	TestCase::new(172, "\x01\u{0308}\x0d", &[1,3]),
+	TestCase::new(173, "\x01\x0a", &[1]),
+	TestCase::new(174, "\x01\u{0308}\x0a", &[1,3]),
+	TestCase::new(175, "\x01\x01", &[1]),
+	TestCase::new(176, "\x01\u{0308}\x01", &[1,3]),
+	TestCase::new(177, "\x01\u{034f}", &[1]),
+	TestCase::new(178, "\x01\u{0308}\u{034f}", &[1]),
+	TestCase::new(179, "\x01\u{1f1e6}", &[1]),
+	TestCase::new(180, "\x01\u{0308}\u{1f1e6}", &[1,3]),
+	TestCase::new(181, "\x01\u{0600}", &[1]),
+	TestCase::new(182, "\x01\u{0308}\u{0600}", &[1,3]),
+	TestCase::new(183, "\x01\u{0a03}", &[1]),
+	TestCase::new(184, "\x01\u{0308}\u{0a03}", &[1]),
+	TestCase::new(185, "\x01\u{1100}", &[1]),
+	TestCase::new(186, "\x01\u{0308}\u{1100}", &[1,3]),
+	TestCase::new(187, "\x01\u{1160}", &[1]),
+	TestCase::new(188, "\x01\u{0308}\u{1160}", &[1,3]),
+	TestCase::new(189, "\x01\u{11a8}", &[1]),
+	TestCase::new(190, "\x01\u{0308}\u{11a8}", &[1,3]),
+	TestCase::new(191, "\x01\u{ac00}", &[1]),
+	TestCase::new(192, "\x01\u{0308}\u{ac00}", &[1,3]),
+	TestCase::new(193, "\x01\u{ac01}", &[1]),
+	TestCase::new(194, "\x01\u{0308}\u{ac01}", &[1,3]),
+	TestCase::new(195, "\x01\u{0900}", &[1]),
+	TestCase::new(196, "\x01\u{0308}\u{0900}", &[1]),
+	TestCase::new(197, "\x01\u{0903}", &[1]),
+	TestCase::new(198, "\x01\u{0308}\u{0903}", &[1]),
+	TestCase::new(199, "\x01\u{0904}", &[1]),
+	TestCase::new(200, "\x01\u{0308}\u{0904}", &[1,3]),
+	TestCase::new(201, "\x01\u{0d4e}", &[1]),
+	TestCase::new(202, "\x01\u{0308}\u{0d4e}", &[1,3]),
+	TestCase::new(203, "\x01\u{0915}", &[1]),
+	TestCase::new(204, "\x01\u{0308}\u{0915}", &[1,3]),
+	TestCase::new(205, "\x01\u{231a}", &[1]),
+	TestCase::new(206, "\x01\u{0308}\u{231a}", &[1,3]),
+	TestCase::new(207, "\x01\u{0300}", &[1]),
+	TestCase::new(208, "\x01\u{0308}\u{0300}", &[1]),
+	TestCase::new(209, "\x01\u{093c}", &[1]),
+	TestCase::new(210, "\x01\u{0308}\u{093c}", &[1]),
+	TestCase::new(211, "\x01\u{094d}", &[1]),
+	TestCase::new(212, "\x01\u{0308}\u{094d}", &[1]),
+	TestCase::new(213, "\x01\u{200d}", &[1]),
+	TestCase::new(214, "\x01\u{0308}\u{200d}", &[1]),
+	TestCase::new(215, "\x01\u{0378}", &[1]),
+	TestCase::new(216, "\x01\u{0308}\u{0378}", &[1,3]),
+	TestCase::new(217, "\u{034f}\x20", &[2]),
+	TestCase::new(218, "\u{034f}\u{0308}\x20", &[4]),
+	TestCase::new(219, "\u{034f}\x0d", &[2]),
+	TestCase::new(220, "\u{034f}\u{0308}\x0d", &[4]),
+	TestCase::new(221, "\u{034f}\x0a", &[2]),
+	TestCase::new(222, "\u{034f}\u{0308}\x0a", &[4]),
+	TestCase::new(223, "\u{034f}\x01", &[2]),
+	TestCase::new(224, "\u{034f}\u{0308}\x01", &[4]),
+	TestCase::new(225, "\u{034f}\u{034f}", &[]),
+	TestCase::new(226, "\u{034f}\u{0308}\u{034f}", &[]),
+	TestCase::new(227, "\u{034f}\u{1f1e6}", &[2]),
+	TestCase::new(228, "\u{034f}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(229, "\u{034f}\u{0600}", &[2]),
+	TestCase::new(230, "\u{034f}\u{0308}\u{0600}", &[4]),
+	TestCase::new(231, "\u{034f}\u{0a03}", &[]),
+	TestCase::new(232, "\u{034f}\u{0308}\u{0a03}", &[]),
+	TestCase::new(233, "\u{034f}\u{1100}", &[2]),
+	TestCase::new(234, "\u{034f}\u{0308}\u{1100}", &[4]),
+	TestCase::new(235, "\u{034f}\u{1160}", &[2]),
+	TestCase::new(236, "\u{034f}\u{0308}\u{1160}", &[4]),
+	TestCase::new(237, "\u{034f}\u{11a8}", &[2]),
+	TestCase::new(238, "\u{034f}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(239, "\u{034f}\u{ac00}", &[2]),
+	TestCase::new(240, "\u{034f}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(241, "\u{034f}\u{ac01}", &[2]),
+	TestCase::new(242, "\u{034f}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(243, "\u{034f}\u{0900}", &[]),
+	TestCase::new(244, "\u{034f}\u{0308}\u{0900}", &[]),
+	TestCase::new(245, "\u{034f}\u{0903}", &[]),
+	TestCase::new(246, "\u{034f}\u{0308}\u{0903}", &[]),
+	TestCase::new(247, "\u{034f}\u{0904}", &[2]),
+	TestCase::new(248, "\u{034f}\u{0308}\u{0904}", &[4]),
+	TestCase::new(249, "\u{034f}\u{0d4e}", &[2]),
+	TestCase::new(250, "\u{034f}\u{0308}\u{0d4e}", &[4]),
+	TestCase::new(251, "\u{034f}\u{0915}", &[2]),
+	TestCase::new(252, "\u{034f}\u{0308}\u{0915}", &[4]),
+	TestCase::new(253, "\u{034f}\u{231a}", &[2]),
+	TestCase::new(254, "\u{034f}\u{0308}\u{231a}", &[4]),
+	TestCase::new(255, "\u{034f}\u{0300}", &[]),
+	TestCase::new(256, "\u{034f}\u{0308}\u{0300}", &[]),
+	TestCase::new(257, "\u{034f}\u{093c}", &[]),
+	TestCase::new(258, "\u{034f}\u{0308}\u{093c}", &[]),
+	TestCase::new(259, "\u{034f}\u{094d}", &[]),
+	TestCase::new(260, "\u{034f}\u{0308}\u{094d}", &[]),
+	TestCase::new(261, "\u{034f}\u{200d}", &[]),
+	TestCase::new(262, "\u{034f}\u{0308}\u{200d}", &[]),
+	TestCase::new(263, "\u{034f}\u{0378}", &[2]),
+	TestCase::new(264, "\u{034f}\u{0308}\u{0378}", &[4]),
+	TestCase::new(265, "\u{1f1e6}\x20", &[4]),
+	TestCase::new(266, "\u{1f1e6}\u{0308}\x20", &[6]),
+	TestCase::new(267, "\u{1f1e6}\x0d", &[4]),
+	TestCase::new(268, "\u{1f1e6}\u{0308}\x0d", &[6]),
+	TestCase::new(269, "\u{1f1e6}\x0a", &[4]),
+	TestCase::new(270, "\u{1f1e6}\u{0308}\x0a", &[6]),
+	TestCase::new(271, "\u{1f1e6}\x01", &[4]),
+	TestCase::new(272, "\u{1f1e6}\u{0308}\x01", &[6]),
+	TestCase::new(273, "\u{1f1e6}\u{034f}", &[]),
+	TestCase::new(274, "\u{1f1e6}\u{0308}\u{034f}", &[]),
+	TestCase::new(275, "\u{1f1e6}\u{1f1e6}", &[]),
+	TestCase::new(276, "\u{1f1e6}\u{0308}\u{1f1e6}", &[6]),
+	TestCase::new(277, "\u{1f1e6}\u{0600}", &[4]),
+	TestCase::new(278, "\u{1f1e6}\u{0308}\u{0600}", &[6]),
+	TestCase::new(279, "\u{1f1e6}\u{0a03}", &[]),
+	TestCase::new(280, "\u{1f1e6}\u{0308}\u{0a03}", &[]),
+	TestCase::new(281, "\u{1f1e6}\u{1100}", &[4]),
+	TestCase::new(282, "\u{1f1e6}\u{0308}\u{1100}", &[6]),
+	TestCase::new(283, "\u{1f1e6}\u{1160}", &[4]),
+	TestCase::new(284, "\u{1f1e6}\u{0308}\u{1160}", &[6]),
+	TestCase::new(285, "\u{1f1e6}\u{11a8}", &[4]),
+	TestCase::new(286, "\u{1f1e6}\u{0308}\u{11a8}", &[6]),
+	TestCase::new(287, "\u{1f1e6}\u{ac00}", &[4]),
+	TestCase::new(288, "\u{1f1e6}\u{0308}\u{ac00}", &[6]),
+	TestCase::new(289, "\u{1f1e6}\u{ac01}", &[4]),
+	TestCase::new(290, "\u{1f1e6}\u{0308}\u{ac01}", &[6]),
+	TestCase::new(291, "\u{1f1e6}\u{0900}", &[]),
+	TestCase::new(292, "\u{1f1e6}\u{0308}\u{0900}", &[]),
+	TestCase::new(293, "\u{1f1e6}\u{0903}", &[]),
+	TestCase::new(294, "\u{1f1e6}\u{0308}\u{0903}", &[]),
+	TestCase::new(295, "\u{1f1e6}\u{0904}", &[4]),
+	TestCase::new(296, "\u{1f1e6}\u{0308}\u{0904}", &[6]),
+	TestCase::new(297, "\u{1f1e6}\u{0d4e}", &[4]),
+	TestCase::new(298, "\u{1f1e6}\u{0308}\u{0d4e}", &[6]),
+	TestCase::new(299, "\u{1f1e6}\u{0915}", &[4]),
+	TestCase::new(300, "\u{1f1e6}\u{0308}\u{0915}", &[6]),
+	TestCase::new(301, "\u{1f1e6}\u{231a}", &[4]),
+	TestCase::new(302, "\u{1f1e6}\u{0308}\u{231a}", &[6]),
+	TestCase::new(303, "\u{1f1e6}\u{0300}", &[]),
+	TestCase::new(304, "\u{1f1e6}\u{0308}\u{0300}", &[]),
+	TestCase::new(305, "\u{1f1e6}\u{093c}", &[]),
+	TestCase::new(306, "\u{1f1e6}\u{0308}\u{093c}", &[]),
+	TestCase::new(307, "\u{1f1e6}\u{094d}", &[]),
+	TestCase::new(308, "\u{1f1e6}\u{0308}\u{094d}", &[]),
+	TestCase::new(309, "\u{1f1e6}\u{200d}", &[]),
+	TestCase::new(310, "\u{1f1e6}\u{0308}\u{200d}", &[]),
+	TestCase::new(311, "\u{1f1e6}\u{0378}", &[4]),
+	TestCase::new(312, "\u{1f1e6}\u{0308}\u{0378}", &[6]),
+	TestCase::new(313, "\u{0600}\x20", &[]),
+	TestCase::new(314, "\u{0600}\u{0308}\x20", &[4]),
+	TestCase::new(315, "\u{0600}\x0d", &[2]),
+	TestCase::new(316, "\u{0600}\u{0308}\x0d", &[4]),
+	TestCase::new(317, "\u{0600}\x0a", &[2]),
+	TestCase::new(318, "\u{0600}\u{0308}\x0a", &[4]),
+	TestCase::new(319, "\u{0600}\x01", &[2]),
+	TestCase::new(320, "\u{0600}\u{0308}\x01", &[4]),
+	TestCase::new(321, "\u{0600}\u{034f}", &[]),
+	TestCase::new(322, "\u{0600}\u{0308}\u{034f}", &[]),
+	TestCase::new(323, "\u{0600}\u{1f1e6}", &[]),
+	TestCase::new(324, "\u{0600}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(325, "\u{0600}\u{0600}", &[]),
+	TestCase::new(326, "\u{0600}\u{0308}\u{0600}", &[4]),
+	TestCase::new(327, "\u{0600}\u{0a03}", &[]),
+	TestCase::new(328, "\u{0600}\u{0308}\u{0a03}", &[]),
+	TestCase::new(329, "\u{0600}\u{1100}", &[]),
+	TestCase::new(330, "\u{0600}\u{0308}\u{1100}", &[4]),
+	TestCase::new(331, "\u{0600}\u{1160}", &[]),
+	TestCase::new(332, "\u{0600}\u{0308}\u{1160}", &[4]),
+	TestCase::new(333, "\u{0600}\u{11a8}", &[]),
+	TestCase::new(334, "\u{0600}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(335, "\u{0600}\u{ac00}", &[]),
+	TestCase::new(336, "\u{0600}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(337, "\u{0600}\u{ac01}", &[]),
+	TestCase::new(338, "\u{0600}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(339, "\u{0600}\u{0900}", &[]),
+	TestCase::new(340, "\u{0600}\u{0308}\u{0900}", &[]),
+	TestCase::new(341, "\u{0600}\u{0903}", &[]),
+	TestCase::new(342, "\u{0600}\u{0308}\u{0903}", &[]),
+	TestCase::new(343, "\u{0600}\u{0904}", &[]),
+	TestCase::new(344, "\u{0600}\u{0308}\u{0904}", &[4]),
+	TestCase::new(345, "\u{0600}\u{0d4e}", &[]),
+	TestCase::new(346, "\u{0600}\u{0308}\u{0d4e}", &[4]),
+	TestCase::new(347, "\u{0600}\u{0915}", &[]),
+	TestCase::new(348, "\u{0600}\u{0308}\u{0915}", &[4]),
+	TestCase::new(349, "\u{0600}\u{231a}", &[]),
+	TestCase::new(350, "\u{0600}\u{0308}\u{231a}", &[4]),
+	TestCase::new(351, "\u{0600}\u{0300}", &[]),
+	TestCase::new(352, "\u{0600}\u{0308}\u{0300}", &[]),
+	TestCase::new(353, "\u{0600}\u{093c}", &[]),
+	TestCase::new(354, "\u{0600}\u{0308}\u{093c}", &[]),
+	TestCase::new(355, "\u{0600}\u{094d}", &[]),
+	TestCase::new(356, "\u{0600}\u{0308}\u{094d}", &[]),
+	TestCase::new(357, "\u{0600}\u{200d}", &[]),
+	TestCase::new(358, "\u{0600}\u{0308}\u{200d}", &[]),
+	TestCase::new(359, "\u{0600}\u{0378}", &[]),
+	TestCase::new(360, "\u{0600}\u{0308}\u{0378}", &[4]),
+	TestCase::new(361, "\u{0a03}\x20", &[3]),
+	TestCase::new(362, "\u{0a03}\u{0308}\x20", &[5]),
+	TestCase::new(363, "\u{0a03}\x0d", &[3]),
+	TestCase::new(364, "\u{0a03}\u{0308}\x0d", &[5]),
+	TestCase::new(365, "\u{0a03}\x0a", &[3]),
+	TestCase::new(366, "\u{0a03}\u{0308}\x0a", &[5]),
+	TestCase::new(367, "\u{0a03}\x01", &[3]),
+	TestCase::new(368, "\u{0a03}\u{0308}\x01", &[5]),
+	TestCase::new(369, "\u{0a03}\u{034f}", &[]),
+	TestCase::new(370, "\u{0a03}\u{0308}\u{034f}", &[]),
+	TestCase::new(371, "\u{0a03}\u{1f1e6}", &[3]),
+	TestCase::new(372, "\u{0a03}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(373, "\u{0a03}\u{0600}", &[3]),
+	TestCase::new(374, "\u{0a03}\u{0308}\u{0600}", &[5]),
+	TestCase::new(375, "\u{0a03}\u{0a03}", &[]),
+	TestCase::new(376, "\u{0a03}\u{0308}\u{0a03}", &[]),
+	TestCase::new(377, "\u{0a03}\u{1100}", &[3]),
+	TestCase::new(378, "\u{0a03}\u{0308}\u{1100}", &[5]),
+	TestCase::new(379, "\u{0a03}\u{1160}", &[3]),
+	TestCase::new(380, "\u{0a03}\u{0308}\u{1160}", &[5]),
+	TestCase::new(381, "\u{0a03}\u{11a8}", &[3]),
+	TestCase::new(382, "\u{0a03}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(383, "\u{0a03}\u{ac00}", &[3]),
+	TestCase::new(384, "\u{0a03}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(385, "\u{0a03}\u{ac01}", &[3]),
+	TestCase::new(386, "\u{0a03}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(387, "\u{0a03}\u{0900}", &[]),
+	TestCase::new(388, "\u{0a03}\u{0308}\u{0900}", &[]),
+	TestCase::new(389, "\u{0a03}\u{0903}", &[]),
+	TestCase::new(390, "\u{0a03}\u{0308}\u{0903}", &[]),
+	TestCase::new(391, "\u{0a03}\u{0904}", &[3]),
+	TestCase::new(392, "\u{0a03}\u{0308}\u{0904}", &[5]),
+	TestCase::new(393, "\u{0a03}\u{0d4e}", &[3]),
+	TestCase::new(394, "\u{0a03}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(395, "\u{0a03}\u{0915}", &[3]),
+	TestCase::new(396, "\u{0a03}\u{0308}\u{0915}", &[5]),
+	TestCase::new(397, "\u{0a03}\u{231a}", &[3]),
+	TestCase::new(398, "\u{0a03}\u{0308}\u{231a}", &[5]),
+	TestCase::new(399, "\u{0a03}\u{0300}", &[]),
+	TestCase::new(400, "\u{0a03}\u{0308}\u{0300}", &[]),
+	TestCase::new(401, "\u{0a03}\u{093c}", &[]),
+	TestCase::new(402, "\u{0a03}\u{0308}\u{093c}", &[]),
+	TestCase::new(403, "\u{0a03}\u{094d}", &[]),
+	TestCase::new(404, "\u{0a03}\u{0308}\u{094d}", &[]),
+	TestCase::new(405, "\u{0a03}\u{200d}", &[]),
+	TestCase::new(406, "\u{0a03}\u{0308}\u{200d}", &[]),
+	TestCase::new(407, "\u{0a03}\u{0378}", &[3]),
+	TestCase::new(408, "\u{0a03}\u{0308}\u{0378}", &[5]),
+	TestCase::new(409, "\u{1100}\x20", &[3]),
+	TestCase::new(410, "\u{1100}\u{0308}\x20", &[5]),
+	TestCase::new(411, "\u{1100}\x0d", &[3]),
+	TestCase::new(412, "\u{1100}\u{0308}\x0d", &[5]),
+	TestCase::new(413, "\u{1100}\x0a", &[3]),
+	TestCase::new(414, "\u{1100}\u{0308}\x0a", &[5]),
+	TestCase::new(415, "\u{1100}\x01", &[3]),
+	TestCase::new(416, "\u{1100}\u{0308}\x01", &[5]),
+	TestCase::new(417, "\u{1100}\u{034f}", &[]),
+	TestCase::new(418, "\u{1100}\u{0308}\u{034f}", &[]),
+	TestCase::new(419, "\u{1100}\u{1f1e6}", &[3]),
+	TestCase::new(420, "\u{1100}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(421, "\u{1100}\u{0600}", &[3]),
+	TestCase::new(422, "\u{1100}\u{0308}\u{0600}", &[5]),
+	TestCase::new(423, "\u{1100}\u{0a03}", &[]),
+	TestCase::new(424, "\u{1100}\u{0308}\u{0a03}", &[]),
+	TestCase::new(425, "\u{1100}\u{1100}", &[]),
+	TestCase::new(426, "\u{1100}\u{0308}\u{1100}", &[5]),
+	TestCase::new(427, "\u{1100}\u{1160}", &[]),
+	TestCase::new(428, "\u{1100}\u{0308}\u{1160}", &[5]),
+	TestCase::new(429, "\u{1100}\u{11a8}", &[3]),
+	TestCase::new(430, "\u{1100}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(431, "\u{1100}\u{ac00}", &[]),
+	TestCase::new(432, "\u{1100}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(433, "\u{1100}\u{ac01}", &[]),
+	TestCase::new(434, "\u{1100}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(435, "\u{1100}\u{0900}", &[]),
+	TestCase::new(436, "\u{1100}\u{0308}\u{0900}", &[]),
+	TestCase::new(437, "\u{1100}\u{0903}", &[]),
+	TestCase::new(438, "\u{1100}\u{0308}\u{0903}", &[]),
+	TestCase::new(439, "\u{1100}\u{0904}", &[3]),
+	TestCase::new(440, "\u{1100}\u{0308}\u{0904}", &[5]),
+	TestCase::new(441, "\u{1100}\u{0d4e}", &[3]),
+	TestCase::new(442, "\u{1100}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(443, "\u{1100}\u{0915}", &[3]),
+	TestCase::new(444, "\u{1100}\u{0308}\u{0915}", &[5]),
+	TestCase::new(445, "\u{1100}\u{231a}", &[3]),
+	TestCase::new(446, "\u{1100}\u{0308}\u{231a}", &[5]),
+	TestCase::new(447, "\u{1100}\u{0300}", &[]),
+	TestCase::new(448, "\u{1100}\u{0308}\u{0300}", &[]),
+	TestCase::new(449, "\u{1100}\u{093c}", &[]),
+	TestCase::new(450, "\u{1100}\u{0308}\u{093c}", &[]),
+	TestCase::new(451, "\u{1100}\u{094d}", &[]),
+	TestCase::new(452, "\u{1100}\u{0308}\u{094d}", &[]),
+	TestCase::new(453, "\u{1100}\u{200d}", &[]),
+	TestCase::new(454, "\u{1100}\u{0308}\u{200d}", &[]),
+	TestCase::new(455, "\u{1100}\u{0378}", &[3]),
+	TestCase::new(456, "\u{1100}\u{0308}\u{0378}", &[5]),
+	TestCase::new(457, "\u{1160}\x20", &[3]),
+	TestCase::new(458, "\u{1160}\u{0308}\x20", &[5]),
+	TestCase::new(459, "\u{1160}\x0d", &[3]),
+	TestCase::new(460, "\u{1160}\u{0308}\x0d", &[5]),
+	TestCase::new(461, "\u{1160}\x0a", &[3]),
+	TestCase::new(462, "\u{1160}\u{0308}\x0a", &[5]),
+	TestCase::new(463, "\u{1160}\x01", &[3]),
+	TestCase::new(464, "\u{1160}\u{0308}\x01", &[5]),
+	TestCase::new(465, "\u{1160}\u{034f}", &[]),
+	TestCase::new(466, "\u{1160}\u{0308}\u{034f}", &[]),
+	TestCase::new(467, "\u{1160}\u{1f1e6}", &[3]),
+	TestCase::new(468, "\u{1160}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(469, "\u{1160}\u{0600}", &[3]),
+	TestCase::new(470, "\u{1160}\u{0308}\u{0600}", &[5]),
+	TestCase::new(471, "\u{1160}\u{0a03}", &[]),
+	TestCase::new(472, "\u{1160}\u{0308}\u{0a03}", &[]),
+	TestCase::new(473, "\u{1160}\u{1100}", &[3]),
+	TestCase::new(474, "\u{1160}\u{0308}\u{1100}", &[5]),
+	TestCase::new(475, "\u{1160}\u{1160}", &[]),
+	TestCase::new(476, "\u{1160}\u{0308}\u{1160}", &[5]),
+	TestCase::new(477, "\u{1160}\u{11a8}", &[]),
+	TestCase::new(478, "\u{1160}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(479, "\u{1160}\u{ac00}", &[3]),
+	TestCase::new(480, "\u{1160}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(481, "\u{1160}\u{ac01}", &[3]),
+	TestCase::new(482, "\u{1160}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(483, "\u{1160}\u{0900}", &[]),
+	TestCase::new(484, "\u{1160}\u{0308}\u{0900}", &[]),
+	TestCase::new(485, "\u{1160}\u{0903}", &[]),
+	TestCase::new(486, "\u{1160}\u{0308}\u{0903}", &[]),
+	TestCase::new(487, "\u{1160}\u{0904}", &[3]),
+	TestCase::new(488, "\u{1160}\u{0308}\u{0904}", &[5]),
+	TestCase::new(489, "\u{1160}\u{0d4e}", &[3]),
+	TestCase::new(490, "\u{1160}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(491, "\u{1160}\u{0915}", &[3]),
+	TestCase::new(492, "\u{1160}\u{0308}\u{0915}", &[5]),
+	TestCase::new(493, "\u{1160}\u{231a}", &[3]),
+	TestCase::new(494, "\u{1160}\u{0308}\u{231a}", &[5]),
+	TestCase::new(495, "\u{1160}\u{0300}", &[]),
+	TestCase::new(496, "\u{1160}\u{0308}\u{0300}", &[]),
+	TestCase::new(497, "\u{1160}\u{093c}", &[]),
+	TestCase::new(498, "\u{1160}\u{0308}\u{093c}", &[]),
+	TestCase::new(499, "\u{1160}\u{094d}", &[]),
+	TestCase::new(500, "\u{1160}\u{0308}\u{094d}", &[]),
+	TestCase::new(501, "\u{1160}\u{200d}", &[]),
+	TestCase::new(502, "\u{1160}\u{0308}\u{200d}", &[]),
+	TestCase::new(503, "\u{1160}\u{0378}", &[3]),
+	TestCase::new(504, "\u{1160}\u{0308}\u{0378}", &[5]),
+	TestCase::new(505, "\u{11a8}\x20", &[3]),
+	TestCase::new(506, "\u{11a8}\u{0308}\x20", &[5]),
+	TestCase::new(507, "\u{11a8}\x0d", &[3]),
+	TestCase::new(508, "\u{11a8}\u{0308}\x0d", &[5]),
+	TestCase::new(509, "\u{11a8}\x0a", &[3]),
+	TestCase::new(510, "\u{11a8}\u{0308}\x0a", &[5]),
+	TestCase::new(511, "\u{11a8}\x01", &[3]),
+	TestCase::new(512, "\u{11a8}\u{0308}\x01", &[5]),
+	TestCase::new(513, "\u{11a8}\u{034f}", &[]),
+	TestCase::new(514, "\u{11a8}\u{0308}\u{034f}", &[]),
+	TestCase::new(515, "\u{11a8}\u{1f1e6}", &[3]),
+	TestCase::new(516, "\u{11a8}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(517, "\u{11a8}\u{0600}", &[3]),
+	TestCase::new(518, "\u{11a8}\u{0308}\u{0600}", &[5]),
+	TestCase::new(519, "\u{11a8}\u{0a03}", &[]),
+	TestCase::new(520, "\u{11a8}\u{0308}\u{0a03}", &[]),
+	TestCase::new(521, "\u{11a8}\u{1100}", &[3]),
+	TestCase::new(522, "\u{11a8}\u{0308}\u{1100}", &[5]),
+	TestCase::new(523, "\u{11a8}\u{1160}", &[3]),
+	TestCase::new(524, "\u{11a8}\u{0308}\u{1160}", &[5]),
+	TestCase::new(525, "\u{11a8}\u{11a8}", &[]),
+	TestCase::new(526, "\u{11a8}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(527, "\u{11a8}\u{ac00}", &[3]),
+	TestCase::new(528, "\u{11a8}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(529, "\u{11a8}\u{ac01}", &[3]),
+	TestCase::new(530, "\u{11a8}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(531, "\u{11a8}\u{0900}", &[]),
+	TestCase::new(532, "\u{11a8}\u{0308}\u{0900}", &[]),
+	TestCase::new(533, "\u{11a8}\u{0903}", &[]),
+	TestCase::new(534, "\u{11a8}\u{0308}\u{0903}", &[]),
+	TestCase::new(535, "\u{11a8}\u{0904}", &[3]),
+	TestCase::new(536, "\u{11a8}\u{0308}\u{0904}", &[5]),
+	TestCase::new(537, "\u{11a8}\u{0d4e}", &[3]),
+	TestCase::new(538, "\u{11a8}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(539, "\u{11a8}\u{0915}", &[3]),
+	TestCase::new(540, "\u{11a8}\u{0308}\u{0915}", &[5]),
+	TestCase::new(541, "\u{11a8}\u{231a}", &[3]),
+	TestCase::new(542, "\u{11a8}\u{0308}\u{231a}", &[5]),
+	TestCase::new(543, "\u{11a8}\u{0300}", &[]),
+	TestCase::new(544, "\u{11a8}\u{0308}\u{0300}", &[]),
+	TestCase::new(545, "\u{11a8}\u{093c}", &[]),
+	TestCase::new(546, "\u{11a8}\u{0308}\u{093c}", &[]),
+	TestCase::new(547, "\u{11a8}\u{094d}", &[]),
+	TestCase::new(548, "\u{11a8}\u{0308}\u{094d}", &[]),
+	TestCase::new(549, "\u{11a8}\u{200d}", &[]),
+	TestCase::new(550, "\u{11a8}\u{0308}\u{200d}", &[]),
+	TestCase::new(551, "\u{11a8}\u{0378}", &[3]),
+	TestCase::new(552, "\u{11a8}\u{0308}\u{0378}", &[5]),
+	TestCase::new(553, "\u{ac00}\x20", &[3]),
+	TestCase::new(554, "\u{ac00}\u{0308}\x20", &[5]),
+	TestCase::new(555, "\u{ac00}\x0d", &[3]),
+	TestCase::new(556, "\u{ac00}\u{0308}\x0d", &[5]),
+	TestCase::new(557, "\u{ac00}\x0a", &[3]),
+	TestCase::new(558, "\u{ac00}\u{0308}\x0a", &[5]),
+	TestCase::new(559, "\u{ac00}\x01", &[3]),
+	TestCase::new(560, "\u{ac00}\u{0308}\x01", &[5]),
+	TestCase::new(561, "\u{ac00}\u{034f}", &[]),
+	TestCase::new(562, "\u{ac00}\u{0308}\u{034f}", &[]),
+	TestCase::new(563, "\u{ac00}\u{1f1e6}", &[3]),
+	TestCase::new(564, "\u{ac00}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(565, "\u{ac00}\u{0600}", &[3]),
+	TestCase::new(566, "\u{ac00}\u{0308}\u{0600}", &[5]),
+	TestCase::new(567, "\u{ac00}\u{0a03}", &[]),
+	TestCase::new(568, "\u{ac00}\u{0308}\u{0a03}", &[]),
+	TestCase::new(569, "\u{ac00}\u{1100}", &[3]),
+	TestCase::new(570, "\u{ac00}\u{0308}\u{1100}", &[5]),
+	TestCase::new(571, "\u{ac00}\u{1160}", &[]),
+	TestCase::new(572, "\u{ac00}\u{0308}\u{1160}", &[5]),
+	TestCase::new(573, "\u{ac00}\u{11a8}", &[]),
+	TestCase::new(574, "\u{ac00}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(575, "\u{ac00}\u{ac00}", &[3]),
+	TestCase::new(576, "\u{ac00}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(577, "\u{ac00}\u{ac01}", &[3]),
+	TestCase::new(578, "\u{ac00}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(579, "\u{ac00}\u{0900}", &[]),
+	TestCase::new(580, "\u{ac00}\u{0308}\u{0900}", &[]),
+	TestCase::new(581, "\u{ac00}\u{0903}", &[]),
+	TestCase::new(582, "\u{ac00}\u{0308}\u{0903}", &[]),
+	TestCase::new(583, "\u{ac00}\u{0904}", &[3]),
+	TestCase::new(584, "\u{ac00}\u{0308}\u{0904}", &[5]),
+	TestCase::new(585, "\u{ac00}\u{0d4e}", &[3]),
+	TestCase::new(586, "\u{ac00}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(587, "\u{ac00}\u{0915}", &[3]),
+	TestCase::new(588, "\u{ac00}\u{0308}\u{0915}", &[5]),
+	TestCase::new(589, "\u{ac00}\u{231a}", &[3]),
+	TestCase::new(590, "\u{ac00}\u{0308}\u{231a}", &[5]),
+	TestCase::new(591, "\u{ac00}\u{0300}", &[]),
+	TestCase::new(592, "\u{ac00}\u{0308}\u{0300}", &[]),
+	TestCase::new(593, "\u{ac00}\u{093c}", &[]),
+	TestCase::new(594, "\u{ac00}\u{0308}\u{093c}", &[]),
+	TestCase::new(595, "\u{ac00}\u{094d}", &[]),
+	TestCase::new(596, "\u{ac00}\u{0308}\u{094d}", &[]),
+	TestCase::new(597, "\u{ac00}\u{200d}", &[]),
+	TestCase::new(598, "\u{ac00}\u{0308}\u{200d}", &[]),
+	TestCase::new(599, "\u{ac00}\u{0378}", &[3]),
+	TestCase::new(600, "\u{ac00}\u{0308}\u{0378}", &[5]),
+	TestCase::new(601, "\u{ac01}\x20", &[3]),
+	TestCase::new(602, "\u{ac01}\u{0308}\x20", &[5]),
+	TestCase::new(603, "\u{ac01}\x0d", &[3]),
+	TestCase::new(604, "\u{ac01}\u{0308}\x0d", &[5]),
+	TestCase::new(605, "\u{ac01}\x0a", &[3]),
+	TestCase::new(606, "\u{ac01}\u{0308}\x0a", &[5]),
+	TestCase::new(607, "\u{ac01}\x01", &[3]),
+	TestCase::new(608, "\u{ac01}\u{0308}\x01", &[5]),
+	TestCase::new(609, "\u{ac01}\u{034f}", &[]),
+	TestCase::new(610, "\u{ac01}\u{0308}\u{034f}", &[]),
+	TestCase::new(611, "\u{ac01}\u{1f1e6}", &[3]),
+	TestCase::new(612, "\u{ac01}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(613, "\u{ac01}\u{0600}", &[3]),
+	TestCase::new(614, "\u{ac01}\u{0308}\u{0600}", &[5]),
+	TestCase::new(615, "\u{ac01}\u{0a03}", &[]),
+	TestCase::new(616, "\u{ac01}\u{0308}\u{0a03}", &[]),
+	TestCase::new(617, "\u{ac01}\u{1100}", &[3]),
+	TestCase::new(618, "\u{ac01}\u{0308}\u{1100}", &[5]),
+	TestCase::new(619, "\u{ac01}\u{1160}", &[3]),
+	TestCase::new(620, "\u{ac01}\u{0308}\u{1160}", &[5]),
+	TestCase::new(621, "\u{ac01}\u{11a8}", &[]),
+	TestCase::new(622, "\u{ac01}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(623, "\u{ac01}\u{ac00}", &[3]),
+	TestCase::new(624, "\u{ac01}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(625, "\u{ac01}\u{ac01}", &[3]),
+	TestCase::new(626, "\u{ac01}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(627, "\u{ac01}\u{0900}", &[]),
+	TestCase::new(628, "\u{ac01}\u{0308}\u{0900}", &[]),
+	TestCase::new(629, "\u{ac01}\u{0903}", &[]),
+	TestCase::new(630, "\u{ac01}\u{0308}\u{0903}", &[]),
+	TestCase::new(631, "\u{ac01}\u{0904}", &[3]),
+	TestCase::new(632, "\u{ac01}\u{0308}\u{0904}", &[5]),
+	TestCase::new(633, "\u{ac01}\u{0d4e}", &[3]),
+	TestCase::new(634, "\u{ac01}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(635, "\u{ac01}\u{0915}", &[3]),
+	TestCase::new(636, "\u{ac01}\u{0308}\u{0915}", &[5]),
+	TestCase::new(637, "\u{ac01}\u{231a}", &[3]),
+	TestCase::new(638, "\u{ac01}\u{0308}\u{231a}", &[5]),
+	TestCase::new(639, "\u{ac01}\u{0300}", &[]),
+	TestCase::new(640, "\u{ac01}\u{0308}\u{0300}", &[]),
+	TestCase::new(641, "\u{ac01}\u{093c}", &[]),
+	TestCase::new(642, "\u{ac01}\u{0308}\u{093c}", &[]),
+	TestCase::new(643, "\u{ac01}\u{094d}", &[]),
+	TestCase::new(644, "\u{ac01}\u{0308}\u{094d}", &[]),
+	TestCase::new(645, "\u{ac01}\u{200d}", &[]),
+	TestCase::new(646, "\u{ac01}\u{0308}\u{200d}", &[]),
+	TestCase::new(647, "\u{ac01}\u{0378}", &[3]),
+	TestCase::new(648, "\u{ac01}\u{0308}\u{0378}", &[5]),
+	TestCase::new(649, "\u{0900}\x20", &[3]),
+	TestCase::new(650, "\u{0900}\u{0308}\x20", &[5]),
+	TestCase::new(651, "\u{0900}\x0d", &[3]),
+	TestCase::new(652, "\u{0900}\u{0308}\x0d", &[5]),
+	TestCase::new(653, "\u{0900}\x0a", &[3]),
+	TestCase::new(654, "\u{0900}\u{0308}\x0a", &[5]),
+	TestCase::new(655, "\u{0900}\x01", &[3]),
+	TestCase::new(656, "\u{0900}\u{0308}\x01", &[5]),
+	TestCase::new(657, "\u{0900}\u{034f}", &[]),
+	TestCase::new(658, "\u{0900}\u{0308}\u{034f}", &[]),
+	TestCase::new(659, "\u{0900}\u{1f1e6}", &[3]),
+	TestCase::new(660, "\u{0900}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(661, "\u{0900}\u{0600}", &[3]),
+	TestCase::new(662, "\u{0900}\u{0308}\u{0600}", &[5]),
+	TestCase::new(663, "\u{0900}\u{0a03}", &[]),
+	TestCase::new(664, "\u{0900}\u{0308}\u{0a03}", &[]),
+	TestCase::new(665, "\u{0900}\u{1100}", &[3]),
+	TestCase::new(666, "\u{0900}\u{0308}\u{1100}", &[5]),
+	TestCase::new(667, "\u{0900}\u{1160}", &[3]),
+	TestCase::new(668, "\u{0900}\u{0308}\u{1160}", &[5]),
+	TestCase::new(669, "\u{0900}\u{11a8}", &[3]),
+	TestCase::new(670, "\u{0900}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(671, "\u{0900}\u{ac00}", &[3]),
+	TestCase::new(672, "\u{0900}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(673, "\u{0900}\u{ac01}", &[3]),
+	TestCase::new(674, "\u{0900}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(675, "\u{0900}\u{0900}", &[]),
+	TestCase::new(676, "\u{0900}\u{0308}\u{0900}", &[]),
+	TestCase::new(677, "\u{0900}\u{0903}", &[]),
+	TestCase::new(678, "\u{0900}\u{0308}\u{0903}", &[]),
+	TestCase::new(679, "\u{0900}\u{0904}", &[3]),
+	TestCase::new(680, "\u{0900}\u{0308}\u{0904}", &[5]),
+	TestCase::new(681, "\u{0900}\u{0d4e}", &[3]),
+	TestCase::new(682, "\u{0900}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(683, "\u{0900}\u{0915}", &[3]),
+	TestCase::new(684, "\u{0900}\u{0308}\u{0915}", &[5]),
+	TestCase::new(685, "\u{0900}\u{231a}", &[3]),
+	TestCase::new(686, "\u{0900}\u{0308}\u{231a}", &[5]),
+	TestCase::new(687, "\u{0900}\u{0300}", &[]),
+	TestCase::new(688, "\u{0900}\u{0308}\u{0300}", &[]),
+	TestCase::new(689, "\u{0900}\u{093c}", &[]),
+	TestCase::new(690, "\u{0900}\u{0308}\u{093c}", &[]),
+	TestCase::new(691, "\u{0900}\u{094d}", &[]),
+	TestCase::new(692, "\u{0900}\u{0308}\u{094d}", &[]),
+	TestCase::new(693, "\u{0900}\u{200d}", &[]),
+	TestCase::new(694, "\u{0900}\u{0308}\u{200d}", &[]),
+	TestCase::new(695, "\u{0900}\u{0378}", &[3]),
+	TestCase::new(696, "\u{0900}\u{0308}\u{0378}", &[5]),
+	TestCase::new(697, "\u{0903}\x20", &[3]),
+	TestCase::new(698, "\u{0903}\u{0308}\x20", &[5]),
+	TestCase::new(699, "\u{0903}\x0d", &[3]),
+	TestCase::new(700, "\u{0903}\u{0308}\x0d", &[5]),
+	TestCase::new(701, "\u{0903}\x0a", &[3]),
+	TestCase::new(702, "\u{0903}\u{0308}\x0a", &[5]),
+	TestCase::new(703, "\u{0903}\x01", &[3]),
+	TestCase::new(704, "\u{0903}\u{0308}\x01", &[5]),
+	TestCase::new(705, "\u{0903}\u{034f}", &[]),
+	TestCase::new(706, "\u{0903}\u{0308}\u{034f}", &[]),
+	TestCase::new(707, "\u{0903}\u{1f1e6}", &[3]),
+	TestCase::new(708, "\u{0903}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(709, "\u{0903}\u{0600}", &[3]),
+	TestCase::new(710, "\u{0903}\u{0308}\u{0600}", &[5]),
+	TestCase::new(711, "\u{0903}\u{0a03}", &[]),
+	TestCase::new(712, "\u{0903}\u{0308}\u{0a03}", &[]),
+	TestCase::new(713, "\u{0903}\u{1100}", &[3]),
+	TestCase::new(714, "\u{0903}\u{0308}\u{1100}", &[5]),
+	TestCase::new(715, "\u{0903}\u{1160}", &[3]),
+	TestCase::new(716, "\u{0903}\u{0308}\u{1160}", &[5]),
+	TestCase::new(717, "\u{0903}\u{11a8}", &[3]),
+	TestCase::new(718, "\u{0903}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(719, "\u{0903}\u{ac00}", &[3]),
+	TestCase::new(720, "\u{0903}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(721, "\u{0903}\u{ac01}", &[3]),
+	TestCase::new(722, "\u{0903}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(723, "\u{0903}\u{0900}", &[]),
+	TestCase::new(724, "\u{0903}\u{0308}\u{0900}", &[]),
+	TestCase::new(725, "\u{0903}\u{0903}", &[]),
+	TestCase::new(726, "\u{0903}\u{0308}\u{0903}", &[]),
+	TestCase::new(727, "\u{0903}\u{0904}", &[3]),
+	TestCase::new(728, "\u{0903}\u{0308}\u{0904}", &[5]),
+	TestCase::new(729, "\u{0903}\u{0d4e}", &[3]),
+	TestCase::new(730, "\u{0903}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(731, "\u{0903}\u{0915}", &[3]),
+	TestCase::new(732, "\u{0903}\u{0308}\u{0915}", &[5]),
+	TestCase::new(733, "\u{0903}\u{231a}", &[3]),
+	TestCase::new(734, "\u{0903}\u{0308}\u{231a}", &[5]),
+	TestCase::new(735, "\u{0903}\u{0300}", &[]),
+	TestCase::new(736, "\u{0903}\u{0308}\u{0300}", &[]),
+	TestCase::new(737, "\u{0903}\u{093c}", &[]),
+	TestCase::new(738, "\u{0903}\u{0308}\u{093c}", &[]),
+	TestCase::new(739, "\u{0903}\u{094d}", &[]),
+	TestCase::new(740, "\u{0903}\u{0308}\u{094d}", &[]),
+	TestCase::new(741, "\u{0903}\u{200d}", &[]),
+	TestCase::new(742, "\u{0903}\u{0308}\u{200d}", &[]),
+	TestCase::new(743, "\u{0903}\u{0378}", &[3]),
+	TestCase::new(744, "\u{0903}\u{0308}\u{0378}", &[5]),
+	TestCase::new(745, "\u{0904}\x20", &[3]),
+	TestCase::new(746, "\u{0904}\u{0308}\x20", &[5]),
+	TestCase::new(747, "\u{0904}\x0d", &[3]),
+	TestCase::new(748, "\u{0904}\u{0308}\x0d", &[5]),
+	TestCase::new(749, "\u{0904}\x0a", &[3]),
+	TestCase::new(750, "\u{0904}\u{0308}\x0a", &[5]),
+	TestCase::new(751, "\u{0904}\x01", &[3]),
+	TestCase::new(752, "\u{0904}\u{0308}\x01", &[5]),
+	TestCase::new(753, "\u{0904}\u{034f}", &[]),
+	TestCase::new(754, "\u{0904}\u{0308}\u{034f}", &[]),
+	TestCase::new(755, "\u{0904}\u{1f1e6}", &[3]),
+	TestCase::new(756, "\u{0904}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(757, "\u{0904}\u{0600}", &[3]),
+	TestCase::new(758, "\u{0904}\u{0308}\u{0600}", &[5]),
+	TestCase::new(759, "\u{0904}\u{0a03}", &[]),
+	TestCase::new(760, "\u{0904}\u{0308}\u{0a03}", &[]),
+	TestCase::new(761, "\u{0904}\u{1100}", &[3]),
+	TestCase::new(762, "\u{0904}\u{0308}\u{1100}", &[5]),
+	TestCase::new(763, "\u{0904}\u{1160}", &[3]),
+	TestCase::new(764, "\u{0904}\u{0308}\u{1160}", &[5]),
+	TestCase::new(765, "\u{0904}\u{11a8}", &[3]),
+	TestCase::new(766, "\u{0904}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(767, "\u{0904}\u{ac00}", &[3]),
+	TestCase::new(768, "\u{0904}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(769, "\u{0904}\u{ac01}", &[3]),
+	TestCase::new(770, "\u{0904}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(771, "\u{0904}\u{0900}", &[]),
+	TestCase::new(772, "\u{0904}\u{0308}\u{0900}", &[]),
+	TestCase::new(773, "\u{0904}\u{0903}", &[]),
+	TestCase::new(774, "\u{0904}\u{0308}\u{0903}", &[]),
+	TestCase::new(775, "\u{0904}\u{0904}", &[3]),
+	TestCase::new(776, "\u{0904}\u{0308}\u{0904}", &[5]),
+	TestCase::new(777, "\u{0904}\u{0d4e}", &[3]),
+	TestCase::new(778, "\u{0904}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(779, "\u{0904}\u{0915}", &[3]),
+	TestCase::new(780, "\u{0904}\u{0308}\u{0915}", &[5]),
+	TestCase::new(781, "\u{0904}\u{231a}", &[3]),
+	TestCase::new(782, "\u{0904}\u{0308}\u{231a}", &[5]),
+	TestCase::new(783, "\u{0904}\u{0300}", &[]),
+	TestCase::new(784, "\u{0904}\u{0308}\u{0300}", &[]),
+	TestCase::new(785, "\u{0904}\u{093c}", &[]),
+	TestCase::new(786, "\u{0904}\u{0308}\u{093c}", &[]),
+	TestCase::new(787, "\u{0904}\u{094d}", &[]),
+	TestCase::new(788, "\u{0904}\u{0308}\u{094d}", &[]),
+	TestCase::new(789, "\u{0904}\u{200d}", &[]),
+	TestCase::new(790, "\u{0904}\u{0308}\u{200d}", &[]),
+	TestCase::new(791, "\u{0904}\u{0378}", &[3]),
+	TestCase::new(792, "\u{0904}\u{0308}\u{0378}", &[5]),
+	TestCase::new(793, "\u{0d4e}\x20", &[]),
+	TestCase::new(794, "\u{0d4e}\u{0308}\x20", &[5]),
+	TestCase::new(795, "\u{0d4e}\x0d", &[3]),
+	TestCase::new(796, "\u{0d4e}\u{0308}\x0d", &[5]),
+	TestCase::new(797, "\u{0d4e}\x0a", &[3]),
+	TestCase::new(798, "\u{0d4e}\u{0308}\x0a", &[5]),
+	TestCase::new(799, "\u{0d4e}\x01", &[3]),
+	TestCase::new(800, "\u{0d4e}\u{0308}\x01", &[5]),
+	TestCase::new(801, "\u{0d4e}\u{034f}", &[]),
+	TestCase::new(802, "\u{0d4e}\u{0308}\u{034f}", &[]),
+	TestCase::new(803, "\u{0d4e}\u{1f1e6}", &[]),
+	TestCase::new(804, "\u{0d4e}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(805, "\u{0d4e}\u{0600}", &[]),
+	TestCase::new(806, "\u{0d4e}\u{0308}\u{0600}", &[5]),
+	TestCase::new(807, "\u{0d4e}\u{0a03}", &[]),
+	TestCase::new(808, "\u{0d4e}\u{0308}\u{0a03}", &[]),
+	TestCase::new(809, "\u{0d4e}\u{1100}", &[]),
+	TestCase::new(810, "\u{0d4e}\u{0308}\u{1100}", &[5]),
+	TestCase::new(811, "\u{0d4e}\u{1160}", &[]),
+	TestCase::new(812, "\u{0d4e}\u{0308}\u{1160}", &[5]),
+	TestCase::new(813, "\u{0d4e}\u{11a8}", &[]),
+	TestCase::new(814, "\u{0d4e}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(815, "\u{0d4e}\u{ac00}", &[]),
+	TestCase::new(816, "\u{0d4e}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(817, "\u{0d4e}\u{ac01}", &[]),
+	TestCase::new(818, "\u{0d4e}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(819, "\u{0d4e}\u{0900}", &[]),
+	TestCase::new(820, "\u{0d4e}\u{0308}\u{0900}", &[]),
+	TestCase::new(821, "\u{0d4e}\u{0903}", &[]),
+	TestCase::new(822, "\u{0d4e}\u{0308}\u{0903}", &[]),
+	TestCase::new(823, "\u{0d4e}\u{0904}", &[]),
+	TestCase::new(824, "\u{0d4e}\u{0308}\u{0904}", &[5]),
+	TestCase::new(825, "\u{0d4e}\u{0d4e}", &[]),
+	TestCase::new(826, "\u{0d4e}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(827, "\u{0d4e}\u{0915}", &[]),
+	TestCase::new(828, "\u{0d4e}\u{0308}\u{0915}", &[5]),
+	TestCase::new(829, "\u{0d4e}\u{231a}", &[]),
+	TestCase::new(830, "\u{0d4e}\u{0308}\u{231a}", &[5]),
+	TestCase::new(831, "\u{0d4e}\u{0300}", &[]),
+	TestCase::new(832, "\u{0d4e}\u{0308}\u{0300}", &[]),
+	TestCase::new(833, "\u{0d4e}\u{093c}", &[]),
+	TestCase::new(834, "\u{0d4e}\u{0308}\u{093c}", &[]),
+	TestCase::new(835, "\u{0d4e}\u{094d}", &[]),
+	TestCase::new(836, "\u{0d4e}\u{0308}\u{094d}", &[]),
+	TestCase::new(837, "\u{0d4e}\u{200d}", &[]),
+	TestCase::new(838, "\u{0d4e}\u{0308}\u{200d}", &[]),
+	TestCase::new(839, "\u{0d4e}\u{0378}", &[]),
+	TestCase::new(840, "\u{0d4e}\u{0308}\u{0378}", &[5]),
+	TestCase::new(841, "\u{0915}\x20", &[3]),
+	TestCase::new(842, "\u{0915}\u{0308}\x20", &[5]),
+	TestCase::new(843, "\u{0915}\x0d", &[3]),
+	TestCase::new(844, "\u{0915}\u{0308}\x0d", &[5]),
+	TestCase::new(845, "\u{0915}\x0a", &[3]),
+	TestCase::new(846, "\u{0915}\u{0308}\x0a", &[5]),
+	TestCase::new(847, "\u{0915}\x01", &[3]),
+	TestCase::new(848, "\u{0915}\u{0308}\x01", &[5]),
+	TestCase::new(849, "\u{0915}\u{034f}", &[]),
+	TestCase::new(850, "\u{0915}\u{0308}\u{034f}", &[]),
+	TestCase::new(851, "\u{0915}\u{1f1e6}", &[3]),
+	TestCase::new(852, "\u{0915}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(853, "\u{0915}\u{0600}", &[3]),
+	TestCase::new(854, "\u{0915}\u{0308}\u{0600}", &[5]),
+	TestCase::new(855, "\u{0915}\u{0a03}", &[]),
+	TestCase::new(856, "\u{0915}\u{0308}\u{0a03}", &[]),
+	TestCase::new(857, "\u{0915}\u{1100}", &[3]),
+	TestCase::new(858, "\u{0915}\u{0308}\u{1100}", &[5]),
+	TestCase::new(859, "\u{0915}\u{1160}", &[3]),
+	TestCase::new(860, "\u{0915}\u{0308}\u{1160}", &[5]),
+	TestCase::new(861, "\u{0915}\u{11a8}", &[3]),
+	TestCase::new(862, "\u{0915}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(863, "\u{0915}\u{ac00}", &[3]),
+	TestCase::new(864, "\u{0915}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(865, "\u{0915}\u{ac01}", &[3]),
+	TestCase::new(866, "\u{0915}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(867, "\u{0915}\u{0900}", &[]),
+	TestCase::new(868, "\u{0915}\u{0308}\u{0900}", &[]),
+	TestCase::new(869, "\u{0915}\u{0903}", &[]),
+	TestCase::new(870, "\u{0915}\u{0308}\u{0903}", &[]),
+	TestCase::new(871, "\u{0915}\u{0904}", &[3]),
+	TestCase::new(872, "\u{0915}\u{0308}\u{0904}", &[5]),
+	TestCase::new(873, "\u{0915}\u{0d4e}", &[3]),
+	TestCase::new(874, "\u{0915}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(875, "\u{0915}\u{0915}", &[3]),
+	TestCase::new(876, "\u{0915}\u{0308}\u{0915}", &[5]),
+	TestCase::new(877, "\u{0915}\u{231a}", &[3]),
+	TestCase::new(878, "\u{0915}\u{0308}\u{231a}", &[5]),
+	TestCase::new(879, "\u{0915}\u{0300}", &[]),
+	TestCase::new(880, "\u{0915}\u{0308}\u{0300}", &[]),
+	TestCase::new(881, "\u{0915}\u{093c}", &[]),
+	TestCase::new(882, "\u{0915}\u{0308}\u{093c}", &[]),
+	TestCase::new(883, "\u{0915}\u{094d}", &[]),
+	TestCase::new(884, "\u{0915}\u{0308}\u{094d}", &[]),
+	TestCase::new(885, "\u{0915}\u{200d}", &[]),
+	TestCase::new(886, "\u{0915}\u{0308}\u{200d}", &[]),
+	TestCase::new(887, "\u{0915}\u{0378}", &[3]),
+	TestCase::new(888, "\u{0915}\u{0308}\u{0378}", &[5]),
+	TestCase::new(889, "\u{231a}\x20", &[3]),
+	TestCase::new(890, "\u{231a}\u{0308}\x20", &[5]),
+	TestCase::new(891, "\u{231a}\x0d", &[3]),
+	TestCase::new(892, "\u{231a}\u{0308}\x0d", &[5]),
+	TestCase::new(893, "\u{231a}\x0a", &[3]),
+	TestCase::new(894, "\u{231a}\u{0308}\x0a", &[5]),
+	TestCase::new(895, "\u{231a}\x01", &[3]),
+	TestCase::new(896, "\u{231a}\u{0308}\x01", &[5]),
+	TestCase::new(897, "\u{231a}\u{034f}", &[]),
+	TestCase::new(898, "\u{231a}\u{0308}\u{034f}", &[]),
+	TestCase::new(899, "\u{231a}\u{1f1e6}", &[3]),
+	TestCase::new(900, "\u{231a}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(901, "\u{231a}\u{0600}", &[3]),
+	TestCase::new(902, "\u{231a}\u{0308}\u{0600}", &[5]),
+	TestCase::new(903, "\u{231a}\u{0a03}", &[]),
+	TestCase::new(904, "\u{231a}\u{0308}\u{0a03}", &[]),
+	TestCase::new(905, "\u{231a}\u{1100}", &[3]),
+	TestCase::new(906, "\u{231a}\u{0308}\u{1100}", &[5]),
+	TestCase::new(907, "\u{231a}\u{1160}", &[3]),
+	TestCase::new(908, "\u{231a}\u{0308}\u{1160}", &[5]),
+	TestCase::new(909, "\u{231a}\u{11a8}", &[3]),
+	TestCase::new(910, "\u{231a}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(911, "\u{231a}\u{ac00}", &[3]),
+	TestCase::new(912, "\u{231a}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(913, "\u{231a}\u{ac01}", &[3]),
+	TestCase::new(914, "\u{231a}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(915, "\u{231a}\u{0900}", &[]),
+	TestCase::new(916, "\u{231a}\u{0308}\u{0900}", &[]),
+	TestCase::new(917, "\u{231a}\u{0903}", &[]),
+	TestCase::new(918, "\u{231a}\u{0308}\u{0903}", &[]),
+	TestCase::new(919, "\u{231a}\u{0904}", &[3]),
+	TestCase::new(920, "\u{231a}\u{0308}\u{0904}", &[5]),
+	TestCase::new(921, "\u{231a}\u{0d4e}", &[3]),
+	TestCase::new(922, "\u{231a}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(923, "\u{231a}\u{0915}", &[3]),
+	TestCase::new(924, "\u{231a}\u{0308}\u{0915}", &[5]),
+	TestCase::new(925, "\u{231a}\u{231a}", &[3]),
+	TestCase::new(926, "\u{231a}\u{0308}\u{231a}", &[5]),
+	TestCase::new(927, "\u{231a}\u{0300}", &[]),
+	TestCase::new(928, "\u{231a}\u{0308}\u{0300}", &[]),
+	TestCase::new(929, "\u{231a}\u{093c}", &[]),
+	TestCase::new(930, "\u{231a}\u{0308}\u{093c}", &[]),
+	TestCase::new(931, "\u{231a}\u{094d}", &[]),
+	TestCase::new(932, "\u{231a}\u{0308}\u{094d}", &[]),
+	TestCase::new(933, "\u{231a}\u{200d}", &[]),
+	TestCase::new(934, "\u{231a}\u{0308}\u{200d}", &[]),
+	TestCase::new(935, "\u{231a}\u{0378}", &[3]),
+	TestCase::new(936, "\u{231a}\u{0308}\u{0378}", &[5]),
+	TestCase::new(937, "\u{0300}\x20", &[2]),
+	TestCase::new(938, "\u{0300}\u{0308}\x20", &[4]),
+	TestCase::new(939, "\u{0300}\x0d", &[2]),
+	TestCase::new(940, "\u{0300}\u{0308}\x0d", &[4]),
+	TestCase::new(941, "\u{0300}\x0a", &[2]),
+	TestCase::new(942, "\u{0300}\u{0308}\x0a", &[4]),
+	TestCase::new(943, "\u{0300}\x01", &[2]),
+	TestCase::new(944, "\u{0300}\u{0308}\x01", &[4]),
+	TestCase::new(945, "\u{0300}\u{034f}", &[]),
+	TestCase::new(946, "\u{0300}\u{0308}\u{034f}", &[]),
+	TestCase::new(947, "\u{0300}\u{1f1e6}", &[2]),
+	TestCase::new(948, "\u{0300}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(949, "\u{0300}\u{0600}", &[2]),
+	TestCase::new(950, "\u{0300}\u{0308}\u{0600}", &[4]),
+	TestCase::new(951, "\u{0300}\u{0a03}", &[]),
+	TestCase::new(952, "\u{0300}\u{0308}\u{0a03}", &[]),
+	TestCase::new(953, "\u{0300}\u{1100}", &[2]),
+	TestCase::new(954, "\u{0300}\u{0308}\u{1100}", &[4]),
+	TestCase::new(955, "\u{0300}\u{1160}", &[2]),
+	TestCase::new(956, "\u{0300}\u{0308}\u{1160}", &[4]),
+	TestCase::new(957, "\u{0300}\u{11a8}", &[2]),
+	TestCase::new(958, "\u{0300}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(959, "\u{0300}\u{ac00}", &[2]),
+	TestCase::new(960, "\u{0300}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(961, "\u{0300}\u{ac01}", &[2]),
+	TestCase::new(962, "\u{0300}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(963, "\u{0300}\u{0900}", &[]),
+	TestCase::new(964, "\u{0300}\u{0308}\u{0900}", &[]),
+	TestCase::new(965, "\u{0300}\u{0903}", &[]),
+	TestCase::new(966, "\u{0300}\u{0308}\u{0903}", &[]),
+	TestCase::new(967, "\u{0300}\u{0904}", &[2]),
+	TestCase::new(968, "\u{0300}\u{0308}\u{0904}", &[4]),
+	TestCase::new(969, "\u{0300}\u{0d4e}", &[2]),
+	TestCase::new(970, "\u{0300}\u{0308}\u{0d4e}", &[4]),
+	TestCase::new(971, "\u{0300}\u{0915}", &[2]),
+	TestCase::new(972, "\u{0300}\u{0308}\u{0915}", &[4]),
+	TestCase::new(973, "\u{0300}\u{231a}", &[2]),
+	TestCase::new(974, "\u{0300}\u{0308}\u{231a}", &[4]),
+	TestCase::new(975, "\u{0300}\u{0300}", &[]),
+	TestCase::new(976, "\u{0300}\u{0308}\u{0300}", &[]),
+	TestCase::new(977, "\u{0300}\u{093c}", &[]),
+	TestCase::new(978, "\u{0300}\u{0308}\u{093c}", &[]),
+	TestCase::new(979, "\u{0300}\u{094d}", &[]),
+	TestCase::new(980, "\u{0300}\u{0308}\u{094d}", &[]),
+	TestCase::new(981, "\u{0300}\u{200d}", &[]),
+	TestCase::new(982, "\u{0300}\u{0308}\u{200d}", &[]),
+	TestCase::new(983, "\u{0300}\u{0378}", &[2]),
+	TestCase::new(984, "\u{0300}\u{0308}\u{0378}", &[4]),
+	TestCase::new(985, "\u{093c}\x20", &[3]),
+	TestCase::new(986, "\u{093c}\u{0308}\x20", &[5]),
+	TestCase::new(987, "\u{093c}\x0d", &[3]),
+	TestCase::new(988, "\u{093c}\u{0308}\x0d", &[5]),
+	TestCase::new(989, "\u{093c}\x0a", &[3]),
+	TestCase::new(990, "\u{093c}\u{0308}\x0a", &[5]),
+	TestCase::new(991, "\u{093c}\x01", &[3]),
+	TestCase::new(992, "\u{093c}\u{0308}\x01", &[5]),
+	TestCase::new(993, "\u{093c}\u{034f}", &[]),
+	TestCase::new(994, "\u{093c}\u{0308}\u{034f}", &[]),
+	TestCase::new(995, "\u{093c}\u{1f1e6}", &[3]),
+	TestCase::new(996, "\u{093c}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(997, "\u{093c}\u{0600}", &[3]),
+	TestCase::new(998, "\u{093c}\u{0308}\u{0600}", &[5]),
+	TestCase::new(999, "\u{093c}\u{0a03}", &[]),
+	TestCase::new(1000, "\u{093c}\u{0308}\u{0a03}", &[]),
+	TestCase::new(1001, "\u{093c}\u{1100}", &[3]),
+	TestCase::new(1002, "\u{093c}\u{0308}\u{1100}", &[5]),
+	TestCase::new(1003, "\u{093c}\u{1160}", &[3]),
+	TestCase::new(1004, "\u{093c}\u{0308}\u{1160}", &[5]),
+	TestCase::new(1005, "\u{093c}\u{11a8}", &[3]),
+	TestCase::new(1006, "\u{093c}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(1007, "\u{093c}\u{ac00}", &[3]),
+	TestCase::new(1008, "\u{093c}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(1009, "\u{093c}\u{ac01}", &[3]),
+	TestCase::new(1010, "\u{093c}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(1011, "\u{093c}\u{0900}", &[]),
+	TestCase::new(1012, "\u{093c}\u{0308}\u{0900}", &[]),
+	TestCase::new(1013, "\u{093c}\u{0903}", &[]),
+	TestCase::new(1014, "\u{093c}\u{0308}\u{0903}", &[]),
+	TestCase::new(1015, "\u{093c}\u{0904}", &[3]),
+	TestCase::new(1016, "\u{093c}\u{0308}\u{0904}", &[5]),
+	TestCase::new(1017, "\u{093c}\u{0d4e}", &[3]),
+	TestCase::new(1018, "\u{093c}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(1019, "\u{093c}\u{0915}", &[3]),
+	TestCase::new(1020, "\u{093c}\u{0308}\u{0915}", &[5]),
+	TestCase::new(1021, "\u{093c}\u{231a}", &[3]),
+	TestCase::new(1022, "\u{093c}\u{0308}\u{231a}", &[5]),
+	TestCase::new(1023, "\u{093c}\u{0300}", &[]),
+	TestCase::new(1024, "\u{093c}\u{0308}\u{0300}", &[]),
+	TestCase::new(1025, "\u{093c}\u{093c}", &[]),
+	TestCase::new(1026, "\u{093c}\u{0308}\u{093c}", &[]),
+	TestCase::new(1027, "\u{093c}\u{094d}", &[]),
+	TestCase::new(1028, "\u{093c}\u{0308}\u{094d}", &[]),
+	TestCase::new(1029, "\u{093c}\u{200d}", &[]),
+	TestCase::new(1030, "\u{093c}\u{0308}\u{200d}", &[]),
+	TestCase::new(1031, "\u{093c}\u{0378}", &[3]),
+	TestCase::new(1032, "\u{093c}\u{0308}\u{0378}", &[5]),
+	TestCase::new(1033, "\u{094d}\x20", &[3]),
+	TestCase::new(1034, "\u{094d}\u{0308}\x20", &[5]),
+	TestCase::new(1035, "\u{094d}\x0d", &[3]),
+	TestCase::new(1036, "\u{094d}\u{0308}\x0d", &[5]),
+	TestCase::new(1037, "\u{094d}\x0a", &[3]),
+	TestCase::new(1038, "\u{094d}\u{0308}\x0a", &[5]),
+	TestCase::new(1039, "\u{094d}\x01", &[3]),
+	TestCase::new(1040, "\u{094d}\u{0308}\x01", &[5]),
+	TestCase::new(1041, "\u{094d}\u{034f}", &[]),
+	TestCase::new(1042, "\u{094d}\u{0308}\u{034f}", &[]),
+	TestCase::new(1043, "\u{094d}\u{1f1e6}", &[3]),
+	TestCase::new(1044, "\u{094d}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(1045, "\u{094d}\u{0600}", &[3]),
+	TestCase::new(1046, "\u{094d}\u{0308}\u{0600}", &[5]),
+	TestCase::new(1047, "\u{094d}\u{0a03}", &[]),
+	TestCase::new(1048, "\u{094d}\u{0308}\u{0a03}", &[]),
+	TestCase::new(1049, "\u{094d}\u{1100}", &[3]),
+	TestCase::new(1050, "\u{094d}\u{0308}\u{1100}", &[5]),
+	TestCase::new(1051, "\u{094d}\u{1160}", &[3]),
+	TestCase::new(1052, "\u{094d}\u{0308}\u{1160}", &[5]),
+	TestCase::new(1053, "\u{094d}\u{11a8}", &[3]),
+	TestCase::new(1054, "\u{094d}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(1055, "\u{094d}\u{ac00}", &[3]),
+	TestCase::new(1056, "\u{094d}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(1057, "\u{094d}\u{ac01}", &[3]),
+	TestCase::new(1058, "\u{094d}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(1059, "\u{094d}\u{0900}", &[]),
+	TestCase::new(1060, "\u{094d}\u{0308}\u{0900}", &[]),
+	TestCase::new(1061, "\u{094d}\u{0903}", &[]),
+	TestCase::new(1062, "\u{094d}\u{0308}\u{0903}", &[]),
+	TestCase::new(1063, "\u{094d}\u{0904}", &[3]),
+	TestCase::new(1064, "\u{094d}\u{0308}\u{0904}", &[5]),
+	TestCase::new(1065, "\u{094d}\u{0d4e}", &[3]),
+	TestCase::new(1066, "\u{094d}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(1067, "\u{094d}\u{0915}", &[3]),
+	TestCase::new(1068, "\u{094d}\u{0308}\u{0915}", &[5]),
+	TestCase::new(1069, "\u{094d}\u{231a}", &[3]),
+	TestCase::new(1070, "\u{094d}\u{0308}\u{231a}", &[5]),
+	TestCase::new(1071, "\u{094d}\u{0300}", &[]),
+	TestCase::new(1072, "\u{094d}\u{0308}\u{0300}", &[]),
+	TestCase::new(1073, "\u{094d}\u{093c}", &[]),
+	TestCase::new(1074, "\u{094d}\u{0308}\u{093c}", &[]),
+	TestCase::new(1075, "\u{094d}\u{094d}", &[]),
+	TestCase::new(1076, "\u{094d}\u{0308}\u{094d}", &[]),
+	TestCase::new(1077, "\u{094d}\u{200d}", &[]),
+	TestCase::new(1078, "\u{094d}\u{0308}\u{200d}", &[]),
+	TestCase::new(1079, "\u{094d}\u{0378}", &[3]),
+	TestCase::new(1080, "\u{094d}\u{0308}\u{0378}", &[5]),
+	TestCase::new(1081, "\u{200d}\x20", &[3]),
+	TestCase::new(1082, "\u{200d}\u{0308}\x20", &[5]),
+	TestCase::new(1083, "\u{200d}\x0d", &[3]),
+	TestCase::new(1084, "\u{200d}\u{0308}\x0d", &[5]),
+	TestCase::new(1085, "\u{200d}\x0a", &[3]),
+	TestCase::new(1086, "\u{200d}\u{0308}\x0a", &[5]),
+	TestCase::new(1087, "\u{200d}\x01", &[3]),
+	TestCase::new(1088, "\u{200d}\u{0308}\x01", &[5]),
+	TestCase::new(1089, "\u{200d}\u{034f}", &[]),
+	TestCase::new(1090, "\u{200d}\u{0308}\u{034f}", &[]),
+	TestCase::new(1091, "\u{200d}\u{1f1e6}", &[3]),
+	TestCase::new(1092, "\u{200d}\u{0308}\u{1f1e6}", &[5]),
+	TestCase::new(1093, "\u{200d}\u{0600}", &[3]),
+	TestCase::new(1094, "\u{200d}\u{0308}\u{0600}", &[5]),
+	TestCase::new(1095, "\u{200d}\u{0a03}", &[]),
+	TestCase::new(1096, "\u{200d}\u{0308}\u{0a03}", &[]),
+	TestCase::new(1097, "\u{200d}\u{1100}", &[3]),
+	TestCase::new(1098, "\u{200d}\u{0308}\u{1100}", &[5]),
+	TestCase::new(1099, "\u{200d}\u{1160}", &[3]),
+	TestCase::new(1100, "\u{200d}\u{0308}\u{1160}", &[5]),
+	TestCase::new(1101, "\u{200d}\u{11a8}", &[3]),
+	TestCase::new(1102, "\u{200d}\u{0308}\u{11a8}", &[5]),
+	TestCase::new(1103, "\u{200d}\u{ac00}", &[3]),
+	TestCase::new(1104, "\u{200d}\u{0308}\u{ac00}", &[5]),
+	TestCase::new(1105, "\u{200d}\u{ac01}", &[3]),
+	TestCase::new(1106, "\u{200d}\u{0308}\u{ac01}", &[5]),
+	TestCase::new(1107, "\u{200d}\u{0900}", &[]),
+	TestCase::new(1108, "\u{200d}\u{0308}\u{0900}", &[]),
+	TestCase::new(1109, "\u{200d}\u{0903}", &[]),
+	TestCase::new(1110, "\u{200d}\u{0308}\u{0903}", &[]),
+	TestCase::new(1111, "\u{200d}\u{0904}", &[3]),
+	TestCase::new(1112, "\u{200d}\u{0308}\u{0904}", &[5]),
+	TestCase::new(1113, "\u{200d}\u{0d4e}", &[3]),
+	TestCase::new(1114, "\u{200d}\u{0308}\u{0d4e}", &[5]),
+	TestCase::new(1115, "\u{200d}\u{0915}", &[3]),
+	TestCase::new(1116, "\u{200d}\u{0308}\u{0915}", &[5]),
+	TestCase::new(1117, "\u{200d}\u{231a}", &[3]),
+	TestCase::new(1118, "\u{200d}\u{0308}\u{231a}", &[5]),
+	TestCase::new(1119, "\u{200d}\u{0300}", &[]),
+	TestCase::new(1120, "\u{200d}\u{0308}\u{0300}", &[]),
+	TestCase::new(1121, "\u{200d}\u{093c}", &[]),
+	TestCase::new(1122, "\u{200d}\u{0308}\u{093c}", &[]),
+	TestCase::new(1123, "\u{200d}\u{094d}", &[]),
+	TestCase::new(1124, "\u{200d}\u{0308}\u{094d}", &[]),
+	TestCase::new(1125, "\u{200d}\u{200d}", &[]),
+	TestCase::new(1126, "\u{200d}\u{0308}\u{200d}", &[]),
+	TestCase::new(1127, "\u{200d}\u{0378}", &[3]),
+	TestCase::new(1128, "\u{200d}\u{0308}\u{0378}", &[5]),
+	TestCase::new(1129, "\u{0378}\x20", &[2]),
+	TestCase::new(1130, "\u{0378}\u{0308}\x20", &[4]),
+	TestCase::new(1131, "\u{0378}\x0d", &[2]),
+	TestCase::new(1132, "\u{0378}\u{0308}\x0d", &[4]),
+	TestCase::new(1133, "\u{0378}\x0a", &[2]),
+	TestCase::new(1134, "\u{0378}\u{0308}\x0a", &[4]),
+	TestCase::new(1135, "\u{0378}\x01", &[2]),
+	TestCase::new(1136, "\u{0378}\u{0308}\x01", &[4]),
+	TestCase::new(1137, "\u{0378}\u{034f}", &[]),
+	TestCase::new(1138, "\u{0378}\u{0308}\u{034f}", &[]),
+	TestCase::new(1139, "\u{0378}\u{1f1e6}", &[2]),
+	TestCase::new(1140, "\u{0378}\u{0308}\u{1f1e6}", &[4]),
+	TestCase::new(1141, "\u{0378}\u{0600}", &[2]),
+	TestCase::new(1142, "\u{0378}\u{0308}\u{0600}", &[4]),
+	TestCase::new(1143, "\u{0378}\u{0a03}", &[]),
+	TestCase::new(1144, "\u{0378}\u{0308}\u{0a03}", &[]),
+	TestCase::new(1145, "\u{0378}\u{1100}", &[2]),
+	TestCase::new(1146, "\u{0378}\u{0308}\u{1100}", &[4]),
+	TestCase::new(1147, "\u{0378}\u{1160}", &[2]),
+	TestCase::new(1148, "\u{0378}\u{0308}\u{1160}", &[4]),
+	TestCase::new(1149, "\u{0378}\u{11a8}", &[2]),
+	TestCase::new(1150, "\u{0378}\u{0308}\u{11a8}", &[4]),
+	TestCase::new(1151, "\u{0378}\u{ac00}", &[2]),
+	TestCase::new(1152, "\u{0378}\u{0308}\u{ac00}", &[4]),
+	TestCase::new(1153, "\u{0378}\u{ac01}", &[2]),
+	TestCase::new(1154, "\u{0378}\u{0308}\u{ac01}", &[4]),
+	TestCase::new(1155, "\u{0378}\u{0900}", &[]),
+	TestCase::new(1156, "\u{0378}\u{0308}\u{0900}", &[]),
+	TestCase::new(1157, "\u{0378}\u{0903}", &[]),
+	TestCase::new(1158, "\u{0378}\u{0308}\u{0903}", &[]),
+	TestCase::new(1159, "\u{0378}\u{0904}", &[2]),
+	TestCase::new(1160, "\u{0378}\u{0308}\u{0904}", &[4]),
+	TestCase::new(1161, "\u{0378}\u{0d4e}", &[2]),
+	TestCase::new(1162, "\u{0378}\u{0308}\u{0d4e}", &[4]),
+	TestCase::new(1163, "\u{0378}\u{0915}", &[2]),
+	TestCase::new(1164, "\u{0378}\u{0308}\u{0915}", &[4]),
+	TestCase::new(1165, "\u{0378}\u{231a}", &[2]),
+	TestCase::new(1166, "\u{0378}\u{0308}\u{231a}", &[4]),
+	TestCase::new(1167, "\u{0378}\u{0300}", &[]),
+	TestCase::new(1168, "\u{0378}\u{0308}\u{0300}", &[]),
+	TestCase::new(1169, "\u{0378}\u{093c}", &[]),
+	TestCase::new(1170, "\u{0378}\u{0308}\u{093c}", &[]),
+	TestCase::new(1171, "\u{0378}\u{094d}", &[]),
+	TestCase::new(1172, "\u{0378}\u{0308}\u{094d}", &[]),
+	TestCase::new(1173, "\u{0378}\u{200d}", &[]),
+	TestCase::new(1174, "\u{0378}\u{0308}\u{200d}", &[]),
+	TestCase::new(1175, "\u{0378}\u{0378}", &[2]),
+	TestCase::new(1176, "\u{0378}\u{0308}\u{0378}", &[4]),
+	TestCase::new(1177, "\x0d\x0a\x61\x0a\u{0308}", &[2,3,4]),
+	TestCase::new(1178, "\x61\u{0308}", &[]),
+	TestCase::new(1179, "\x20\u{200d}\u{0646}", &[4]),
+	TestCase::new(1180, "\u{0646}\u{200d}\x20", &[5]),
+	TestCase::new(1181, "\u{1100}\u{1100}", &[]),
+	TestCase::new(1182, "\u{ac00}\u{11a8}\u{1100}", &[6]),
+	TestCase::new(1183, "\u{ac01}\u{11a8}\u{1100}", &[6]),
+	TestCase::new(1184, "\u{1f1e6}\u{1f1e7}\u{1f1e8}\x62", &[8,12]),
+	TestCase::new(1185, "\x61\u{1f1e6}\u{1f1e7}\u{1f1e8}\x62", &[1,9,13]),
+	TestCase::new(1186, "\x61\u{1f1e6}\u{1f1e7}\u{200d}\u{1f1e8}\x62", &[1,12,16]),
+	TestCase::new(1187, "\x61\u{1f1e6}\u{200d}\u{1f1e7}\u{1f1e8}\x62", &[1,8,16]),
+	TestCase::new(1188, "\x61\u{1f1e6}\u{1f1e7}\u{1f1e8}\u{1f1e9}\x62", &[1,9,17]),
+	TestCase::new(1189, "\x61\u{200d}", &[]),
+	TestCase::new(1190, "\x61\u{0308}\x62", &[3]),
+	TestCase::new(1191, "\x61\u{0903}\x62", &[4]),
+	TestCase::new(1192, "\x61\u{0600}\x62", &[1]),
+	TestCase::new(1193, "\u{1f476}\u{1f3ff}\u{1f476}", &[8]),
+	TestCase::new(1194, "\x61\u{1f3ff}\u{1f476}", &[5]),
+	TestCase::new(1195, "\x61\u{1f3ff}\u{1f476}\u{200d}\u{1f6d1}", &[5]),
+	TestCase::new(1196, "\u{1f476}\u{1f3ff}\u{0308}\u{200d}\u{1f476}\u{1f3ff}", &[]),
+	TestCase::new(1197, "\u{1f6d1}\u{200d}\u{1f6d1}", &[]),
+	TestCase::new(1198, "\x61\u{200d}\u{1f6d1}", &[4]),
+	TestCase::new(1199, "\u{2701}\u{200d}\u{2701}", &[]),
+	TestCase::new(1200, "\x61\u{200d}\u{2701}", &[4]),
+	TestCase::new(1201, "\u{0915}\u{0924}", &[3]),
+	TestCase::new(1202, "\u{0915}\u{094d}\u{0924}", &[]),
+	TestCase::new(1203, "\u{0915}\u{094d}\u{094d}\u{0924}", &[]),
+	TestCase::new(1204, "\u{0915}\u{094d}\u{200d}\u{0924}", &[]),
+	TestCase::new(1205, "\u{0915}\u{093c}\u{200d}\u{094d}\u{0924}", &[]),
+	TestCase::new(1206, "\u{0915}\u{093c}\u{094d}\u{200d}\u{0924}", &[]),
+	TestCase::new(1207, "\u{0915}\u{094d}\u{0924}\u{094d}\u{092f}", &[]),
+	TestCase::new(1208, "\u{0915}\u{094d}\x61", &[6]),
+	TestCase::new(1209, "\x61\u{094d}\u{0924}", &[4]),
+	TestCase::new(1210, "\x3f\u{094d}\u{0924}", &[4]),
+	TestCase::new(1211, "\u{0915}\u{094d}\u{094d}\u{0924}", &[]),
+];
@@ -0,0 +1,1048 @@
+use crate::ident::{XidClass as X, XidRange, ran};
+pub const XID_RANGES: [XidRange; 1045] = [
+	ran(0x00030,    10, X::Continue),
+	ran(0x00041,    26, X::Start),
+	ran(0x0005f,     1, X::Continue),
+	ran(0x00061,    26, X::Start),
+	ran(0x000aa,     1, X::Start),
+	ran(0x000b5,     1, X::Start),
+	ran(0x000b7,     1, X::Continue),
+	ran(0x000ba,     1, X::Start),
+	ran(0x000c0,    23, X::Start),
+	ran(0x000d8,    31, X::Start),
+	ran(0x000f8,   458, X::Start),
+	ran(0x002c6,    12, X::Start),
+	ran(0x002e0,     5, X::Start),
+	ran(0x002ec,     1, X::Start),
+	ran(0x002ee,     1, X::Start),
+	ran(0x00300,   112, X::Continue),
+	ran(0x00370,     5, X::Start),
+	ran(0x00376,     2, X::Start),
+	ran(0x0037b,     3, X::Start),
+	ran(0x0037f,     1, X::Start),
+	ran(0x00386,     1, X::Start),
+	ran(0x00387,     1, X::Continue),
+	ran(0x00388,     3, X::Start),
+	ran(0x0038c,     1, X::Start),
+	ran(0x0038e,    20, X::Start),
+	ran(0x003a3,    83, X::Start),
+	ran(0x003f7,   139, X::Start),
+	ran(0x00483,     5, X::Continue),
+	ran(0x0048a,   166, X::Start),
+	ran(0x00531,    38, X::Start),
+	ran(0x00559,     1, X::Start),
+	ran(0x00560,    41, X::Start),
+	ran(0x00591,    45, X::Continue),
+	ran(0x005bf,     1, X::Continue),
+	ran(0x005c1,     2, X::Continue),
+	ran(0x005c4,     2, X::Continue),
+	ran(0x005c7,     1, X::Continue),
+	ran(0x005d0,    27, X::Start),
+	ran(0x005ef,     4, X::Start),
+	ran(0x00610,    11, X::Continue),
+	ran(0x00620,    43, X::Start),
+	ran(0x0064b,    31, X::Continue),
+	ran(0x0066e,     2, X::Start),
+	ran(0x00670,     1, X::Continue),
+	ran(0x00671,    99, X::Start),
+	ran(0x006d5,     1, X::Start),
+	ran(0x006d6,     7, X::Continue),
+	ran(0x006df,     6, X::Continue),
+	ran(0x006e5,     2, X::Start),
+	ran(0x006e7,     2, X::Continue),
+	ran(0x006ea,     4, X::Continue),
+	ran(0x006ee,     2, X::Start),
+	ran(0x006f0,    10, X::Continue),
+	ran(0x006fa,     3, X::Start),
+	ran(0x006ff,     1, X::Start),
+	ran(0x00710,     1, X::Start),
+	ran(0x00711,     1, X::Continue),
+	ran(0x00712,    30, X::Start),
+	ran(0x00730,    27, X::Continue),
+	ran(0x0074d,    89, X::Start),
+	ran(0x007a6,    11, X::Continue),
+	ran(0x007b1,     1, X::Start),
+	ran(0x007c0,    10, X::Continue),
+	ran(0x007ca,    33, X::Start),
+	ran(0x007eb,     9, X::Continue),
+	ran(0x007f4,     2, X::Start),
+	ran(0x007fa,     1, X::Start),
+	ran(0x007fd,     1, X::Continue),
+	ran(0x00800,    22, X::Start),
+	ran(0x00816,     4, X::Continue),
+	ran(0x0081a,     1, X::Start),
+	ran(0x0081b,     9, X::Continue),
+	ran(0x00824,     1, X::Start),
+	ran(0x00825,     3, X::Continue),
+	ran(0x00828,     1, X::Start),
+	ran(0x00829,     5, X::Continue),
+	ran(0x00840,    25, X::Start),
+	ran(0x00859,     3, X::Continue),
+	ran(0x00860,    11, X::Start),
+	ran(0x00870,    24, X::Start),
+	ran(0x00889,     6, X::Start),
+	ran(0x00898,     8, X::Continue),
+	ran(0x008a0,    42, X::Start),
+	ran(0x008ca,    24, X::Continue),
+	ran(0x008e3,    33, X::Continue),
+	ran(0x00904,    54, X::Start),
+	ran(0x0093a,     3, X::Continue),
+	ran(0x0093d,     1, X::Start),
+	ran(0x0093e,    18, X::Continue),
+	ran(0x00950,     1, X::Start),
+	ran(0x00951,     7, X::Continue),
+	ran(0x00958,    10, X::Start),
+	ran(0x00962,     2, X::Continue),
+	ran(0x00966,    10, X::Continue),
+	ran(0x00971,    16, X::Start),
+	ran(0x00981,     3, X::Continue),
+	ran(0x00985,     8, X::Start),
+	ran(0x0098f,     2, X::Start),
+	ran(0x00993,    22, X::Start),
+	ran(0x009aa,     7, X::Start),
+	ran(0x009b2,     1, X::Start),
+	ran(0x009b6,     4, X::Start),
+	ran(0x009bc,     1, X::Continue),
+	ran(0x009bd,     1, X::Start),
+	ran(0x009be,     7, X::Continue),
+	ran(0x009c7,     2, X::Continue),
+	ran(0x009cb,     3, X::Continue),
+	ran(0x009ce,     1, X::Start),
+	ran(0x009d7,     1, X::Continue),
+	ran(0x009dc,     2, X::Start),
+	ran(0x009df,     3, X::Start),
+	ran(0x009e2,     2, X::Continue),
+	ran(0x009e6,    10, X::Continue),
+	ran(0x009f0,     2, X::Start),
+	ran(0x009fc,     1, X::Start),
+	ran(0x009fe,     1, X::Continue),
+	ran(0x00a01,     3, X::Continue),
+	ran(0x00a05,     6, X::Start),
+	ran(0x00a0f,     2, X::Start),
+	ran(0x00a13,    22, X::Start),
+	ran(0x00a2a,     7, X::Start),
+	ran(0x00a32,     2, X::Start),
+	ran(0x00a35,     2, X::Start),
+	ran(0x00a38,     2, X::Start),
+	ran(0x00a3c,     1, X::Continue),
+	ran(0x00a3e,     5, X::Continue),
+	ran(0x00a47,     2, X::Continue),
+	ran(0x00a4b,     3, X::Continue),
+	ran(0x00a51,     1, X::Continue),
+	ran(0x00a59,     4, X::Start),
+	ran(0x00a5e,     1, X::Start),
+	ran(0x00a66,    12, X::Continue),
+	ran(0x00a72,     3, X::Start),
+	ran(0x00a75,     1, X::Continue),
+	ran(0x00a81,     3, X::Continue),
+	ran(0x00a85,     9, X::Start),
+	ran(0x00a8f,     3, X::Start),
+	ran(0x00a93,    22, X::Start),
+	ran(0x00aaa,     7, X::Start),
+	ran(0x00ab2,     2, X::Start),
+	ran(0x00ab5,     5, X::Start),
+	ran(0x00abc,     1, X::Continue),
+	ran(0x00abd,     1, X::Start),
+	ran(0x00abe,     8, X::Continue),
+	ran(0x00ac7,     3, X::Continue),
+	ran(0x00acb,     3, X::Continue),
+	ran(0x00ad0,     1, X::Start),
+	ran(0x00ae0,     2, X::Start),
+	ran(0x00ae2,     2, X::Continue),
+	ran(0x00ae6,    10, X::Continue),
+	ran(0x00af9,     1, X::Start),
+	ran(0x00afa,     6, X::Continue),
+	ran(0x00b01,     3, X::Continue),
+	ran(0x00b05,     8, X::Start),
+	ran(0x00b0f,     2, X::Start),
+	ran(0x00b13,    22, X::Start),
+	ran(0x00b2a,     7, X::Start),
+	ran(0x00b32,     2, X::Start),
+	ran(0x00b35,     5, X::Start),
+	ran(0x00b3c,     1, X::Continue),
+	ran(0x00b3d,     1, X::Start),
+	ran(0x00b3e,     7, X::Continue),
+	ran(0x00b47,     2, X::Continue),
+	ran(0x00b4b,     3, X::Continue),
+	ran(0x00b55,     3, X::Continue),
+	ran(0x00b5c,     2, X::Start),
+	ran(0x00b5f,     3, X::Start),
+	ran(0x00b62,     2, X::Continue),
+	ran(0x00b66,    10, X::Continue),
+	ran(0x00b71,     1, X::Start),
+	ran(0x00b82,     1, X::Continue),
+	ran(0x00b83,     1, X::Start),
+	ran(0x00b85,     6, X::Start),
+	ran(0x00b8e,     3, X::Start),
+	ran(0x00b92,     4, X::Start),
+	ran(0x00b99,     2, X::Start),
+	ran(0x00b9c,     1, X::Start),
+	ran(0x00b9e,     2, X::Start),
+	ran(0x00ba3,     2, X::Start),
+	ran(0x00ba8,     3, X::Start),
+	ran(0x00bae,    12, X::Start),
+	ran(0x00bbe,     5, X::Continue),
+	ran(0x00bc6,     3, X::Continue),
+	ran(0x00bca,     4, X::Continue),
+	ran(0x00bd0,     1, X::Start),
+	ran(0x00bd7,     1, X::Continue),
+	ran(0x00be6,    10, X::Continue),
+	ran(0x00c00,     5, X::Continue),
+	ran(0x00c05,     8, X::Start),
+	ran(0x00c0e,     3, X::Start),
+	ran(0x00c12,    23, X::Start),
+	ran(0x00c2a,    16, X::Start),
+	ran(0x00c3c,     1, X::Continue),
+	ran(0x00c3d,     1, X::Start),
+	ran(0x00c3e,     7, X::Continue),
+	ran(0x00c46,     3, X::Continue),
+	ran(0x00c4a,     4, X::Continue),
+	ran(0x00c55,     2, X::Continue),
+	ran(0x00c58,     3, X::Start),
+	ran(0x00c5d,     1, X::Start),
+	ran(0x00c60,     2, X::Start),
+	ran(0x00c62,     2, X::Continue),
+	ran(0x00c66,    10, X::Continue),
+	ran(0x00c80,     1, X::Start),
+	ran(0x00c81,     3, X::Continue),
+	ran(0x00c85,     8, X::Start),
+	ran(0x00c8e,     3, X::Start),
+	ran(0x00c92,    23, X::Start),
+	ran(0x00caa,    10, X::Start),
+	ran(0x00cb5,     5, X::Start),
+	ran(0x00cbc,     1, X::Continue),
+	ran(0x00cbd,     1, X::Start),
+	ran(0x00cbe,     7, X::Continue),
+	ran(0x00cc6,     3, X::Continue),
+	ran(0x00cca,     4, X::Continue),
+	ran(0x00cd5,     2, X::Continue),
+	ran(0x00cdd,     2, X::Start),
+	ran(0x00ce0,     2, X::Start),
+	ran(0x00ce2,     2, X::Continue),
+	ran(0x00ce6,    10, X::Continue),
+	ran(0x00cf1,     2, X::Start),
+	ran(0x00cf3,     1, X::Continue),
+	ran(0x00d00,     4, X::Continue),
+	ran(0x00d04,     9, X::Start),
+	ran(0x00d0e,     3, X::Start),
+	ran(0x00d12,    41, X::Start),
+	ran(0x00d3b,     2, X::Continue),
+	ran(0x00d3d,     1, X::Start),
+	ran(0x00d3e,     7, X::Continue),
+	ran(0x00d46,     3, X::Continue),
+	ran(0x00d4a,     4, X::Continue),
+	ran(0x00d4e,     1, X::Start),
+	ran(0x00d54,     3, X::Start),
+	ran(0x00d57,     1, X::Continue),
+	ran(0x00d5f,     3, X::Start),
+	ran(0x00d62,     2, X::Continue),
+	ran(0x00d66,    10, X::Continue),
+	ran(0x00d7a,     6, X::Start),
+	ran(0x00d81,     3, X::Continue),
+	ran(0x00d85,    18, X::Start),
+	ran(0x00d9a,    24, X::Start),
+	ran(0x00db3,     9, X::Start),
+	ran(0x00dbd,     1, X::Start),
+	ran(0x00dc0,     7, X::Start),
+	ran(0x00dca,     1, X::Continue),
+	ran(0x00dcf,     6, X::Continue),
+	ran(0x00dd6,     1, X::Continue),
+	ran(0x00dd8,     8, X::Continue),
+	ran(0x00de6,    10, X::Continue),
+	ran(0x00df2,     2, X::Continue),
+	ran(0x00e01,    48, X::Start),
+	ran(0x00e31,     1, X::Continue),
+	ran(0x00e32,     1, X::Start),
+	ran(0x00e33,     8, X::Continue),
+	ran(0x00e40,     7, X::Start),
+	ran(0x00e47,     8, X::Continue),
+	ran(0x00e50,    10, X::Continue),
+	ran(0x00e81,     2, X::Start),
+	ran(0x00e84,     1, X::Start),
+	ran(0x00e86,     5, X::Start),
+	ran(0x00e8c,    24, X::Start),
+	ran(0x00ea5,     1, X::Start),
+	ran(0x00ea7,    10, X::Start),
+	ran(0x00eb1,     1, X::Continue),
+	ran(0x00eb2,     1, X::Start),
+	ran(0x00eb3,    10, X::Continue),
+	ran(0x00ebd,     1, X::Start),
+	ran(0x00ec0,     5, X::Start),
+	ran(0x00ec6,     1, X::Start),
+	ran(0x00ec8,     7, X::Continue),
+	ran(0x00ed0,    10, X::Continue),
+	ran(0x00edc,     4, X::Start),
+	ran(0x00f00,     1, X::Start),
+	ran(0x00f18,     2, X::Continue),
+	ran(0x00f20,    10, X::Continue),
+	ran(0x00f35,     1, X::Continue),
+	ran(0x00f37,     1, X::Continue),
+	ran(0x00f39,     1, X::Continue),
+	ran(0x00f3e,     2, X::Continue),
+	ran(0x00f40,     8, X::Start),
+	ran(0x00f49,    36, X::Start),
+	ran(0x00f71,    20, X::Continue),
+	ran(0x00f86,     2, X::Continue),
+	ran(0x00f88,     5, X::Start),
+	ran(0x00f8d,    11, X::Continue),
+	ran(0x00f99,    36, X::Continue),
+	ran(0x00fc6,     1, X::Continue),
+	ran(0x01000,    43, X::Start),
+	ran(0x0102b,    20, X::Continue),
+	ran(0x0103f,     1, X::Start),
+	ran(0x01040,    10, X::Continue),
+	ran(0x01050,     6, X::Start),
+	ran(0x01056,     4, X::Continue),
+	ran(0x0105a,     4, X::Start),
+	ran(0x0105e,     3, X::Continue),
+	ran(0x01061,     1, X::Start),
+	ran(0x01062,     3, X::Continue),
+	ran(0x01065,     2, X::Start),
+	ran(0x01067,     7, X::Continue),
+	ran(0x0106e,     3, X::Start),
+	ran(0x01071,     4, X::Continue),
+	ran(0x01075,    13, X::Start),
+	ran(0x01082,    12, X::Continue),
+	ran(0x0108e,     1, X::Start),
+	ran(0x0108f,    15, X::Continue),
+	ran(0x010a0,    38, X::Start),
+	ran(0x010c7,     1, X::Start),
+	ran(0x010cd,     1, X::Start),
+	ran(0x010d0,    43, X::Start),
+	ran(0x010fc,   333, X::Start),
+	ran(0x0124a,     4, X::Start),
+	ran(0x01250,     7, X::Start),
+	ran(0x01258,     1, X::Start),
+	ran(0x0125a,     4, X::Start),
+	ran(0x01260,    41, X::Start),
+	ran(0x0128a,     4, X::Start),
+	ran(0x01290,    33, X::Start),
+	ran(0x012b2,     4, X::Start),
+	ran(0x012b8,     7, X::Start),
+	ran(0x012c0,     1, X::Start),
+	ran(0x012c2,     4, X::Start),
+	ran(0x012c8,    15, X::Start),
+	ran(0x012d8,    57, X::Start),
+	ran(0x01312,     4, X::Start),
+	ran(0x01318,    67, X::Start),
+	ran(0x0135d,     3, X::Continue),
+	ran(0x01369,     9, X::Continue),
+	ran(0x01380,    16, X::Start),
+	ran(0x013a0,    86, X::Start),
+	ran(0x013f8,     6, X::Start),
+	ran(0x01401,   620, X::Start),
+	ran(0x0166f,    17, X::Start),
+	ran(0x01681,    26, X::Start),
+	ran(0x016a0,    75, X::Start),
+	ran(0x016ee,    11, X::Start),
+	ran(0x01700,    18, X::Start),
+	ran(0x01712,     4, X::Continue),
+	ran(0x0171f,    19, X::Start),
+	ran(0x01732,     3, X::Continue),
+	ran(0x01740,    18, X::Start),
+	ran(0x01752,     2, X::Continue),
+	ran(0x01760,    13, X::Start),
+	ran(0x0176e,     3, X::Start),
+	ran(0x01772,     2, X::Continue),
+	ran(0x01780,    52, X::Start),
+	ran(0x017b4,    32, X::Continue),
+	ran(0x017d7,     1, X::Start),
+	ran(0x017dc,     1, X::Start),
+	ran(0x017dd,     1, X::Continue),
+	ran(0x017e0,    10, X::Continue),
+	ran(0x0180b,     3, X::Continue),
+	ran(0x0180f,    11, X::Continue),
+	ran(0x01820,    89, X::Start),
+	ran(0x01880,    41, X::Start),
+	ran(0x018a9,     1, X::Continue),
+	ran(0x018aa,     1, X::Start),
+	ran(0x018b0,    70, X::Start),
+	ran(0x01900,    31, X::Start),
+	ran(0x01920,    12, X::Continue),
+	ran(0x01930,    12, X::Continue),
+	ran(0x01946,    10, X::Continue),
+	ran(0x01950,    30, X::Start),
+	ran(0x01970,     5, X::Start),
+	ran(0x01980,    44, X::Start),
+	ran(0x019b0,    26, X::Start),
+	ran(0x019d0,    11, X::Continue),
+	ran(0x01a00,    23, X::Start),
+	ran(0x01a17,     5, X::Continue),
+	ran(0x01a20,    53, X::Start),
+	ran(0x01a55,    10, X::Continue),
+	ran(0x01a60,    29, X::Continue),
+	ran(0x01a7f,    11, X::Continue),
+	ran(0x01a90,    10, X::Continue),
+	ran(0x01aa7,     1, X::Start),
+	ran(0x01ab0,    14, X::Continue),
+	ran(0x01abf,    16, X::Continue),
+	ran(0x01b00,     5, X::Continue),
+	ran(0x01b05,    47, X::Start),
+	ran(0x01b34,    17, X::Continue),
+	ran(0x01b45,     8, X::Start),
+	ran(0x01b50,    10, X::Continue),
+	ran(0x01b6b,     9, X::Continue),
+	ran(0x01b80,     3, X::Continue),
+	ran(0x01b83,    30, X::Start),
+	ran(0x01ba1,    13, X::Continue),
+	ran(0x01bae,     2, X::Start),
+	ran(0x01bb0,    10, X::Continue),
+	ran(0x01bba,    44, X::Start),
+	ran(0x01be6,    14, X::Continue),
+	ran(0x01c00,    36, X::Start),
+	ran(0x01c24,    20, X::Continue),
+	ran(0x01c40,    10, X::Continue),
+	ran(0x01c4d,     3, X::Start),
+	ran(0x01c50,    10, X::Continue),
+	ran(0x01c5a,    36, X::Start),
+	ran(0x01c80,     9, X::Start),
+	ran(0x01c90,    43, X::Start),
+	ran(0x01cbd,     3, X::Start),
+	ran(0x01cd0,     3, X::Continue),
+	ran(0x01cd4,    21, X::Continue),
+	ran(0x01ce9,     4, X::Start),
+	ran(0x01ced,     1, X::Continue),
+	ran(0x01cee,     6, X::Start),
+	ran(0x01cf4,     1, X::Continue),
+	ran(0x01cf5,     2, X::Start),
+	ran(0x01cf7,     3, X::Continue),
+	ran(0x01cfa,     1, X::Start),
+	ran(0x01d00,   192, X::Start),
+	ran(0x01dc0,    64, X::Continue),
+	ran(0x01e00,   278, X::Start),
+	ran(0x01f18,     6, X::Start),
+	ran(0x01f20,    38, X::Start),
+	ran(0x01f48,     6, X::Start),
+	ran(0x01f50,     8, X::Start),
+	ran(0x01f59,     1, X::Start),
+	ran(0x01f5b,     1, X::Start),
+	ran(0x01f5d,     1, X::Start),
+	ran(0x01f5f,    31, X::Start),
+	ran(0x01f80,    53, X::Start),
+	ran(0x01fb6,     7, X::Start),
+	ran(0x01fbe,     1, X::Start),
+	ran(0x01fc2,     3, X::Start),
+	ran(0x01fc6,     7, X::Start),
+	ran(0x01fd0,     4, X::Start),
+	ran(0x01fd6,     6, X::Start),
+	ran(0x01fe0,    13, X::Start),
+	ran(0x01ff2,     3, X::Start),
+	ran(0x01ff6,     7, X::Start),
+	ran(0x0200c,     2, X::Continue),
+	ran(0x0203f,     2, X::Continue),
+	ran(0x02054,     1, X::Continue),
+	ran(0x02071,     1, X::Start),
+	ran(0x0207f,     1, X::Start),
+	ran(0x02090,    13, X::Start),
+	ran(0x020d0,    13, X::Continue),
+	ran(0x020e1,     1, X::Continue),
+	ran(0x020e5,    12, X::Continue),
+	ran(0x02102,     1, X::Start),
+	ran(0x02107,     1, X::Start),
+	ran(0x0210a,    10, X::Start),
+	ran(0x02115,     1, X::Start),
+	ran(0x02118,     6, X::Start),
+	ran(0x02124,     1, X::Start),
+	ran(0x02126,     1, X::Start),
+	ran(0x02128,     1, X::Start),
+	ran(0x0212a,    16, X::Start),
+	ran(0x0213c,     4, X::Start),
+	ran(0x02145,     5, X::Start),
+	ran(0x0214e,     1, X::Start),
+	ran(0x02160,    41, X::Start),
+	ran(0x02c00,   229, X::Start),
+	ran(0x02ceb,     4, X::Start),
+	ran(0x02cef,     3, X::Continue),
+	ran(0x02cf2,     2, X::Start),
+	ran(0x02d00,    38, X::Start),
+	ran(0x02d27,     1, X::Start),
+	ran(0x02d2d,     1, X::Start),
+	ran(0x02d30,    56, X::Start),
+	ran(0x02d6f,     1, X::Start),
+	ran(0x02d7f,     1, X::Continue),
+	ran(0x02d80,    23, X::Start),
+	ran(0x02da0,     7, X::Start),
+	ran(0x02da8,     7, X::Start),
+	ran(0x02db0,     7, X::Start),
+	ran(0x02db8,     7, X::Start),
+	ran(0x02dc0,     7, X::Start),
+	ran(0x02dc8,     7, X::Start),
+	ran(0x02dd0,     7, X::Start),
+	ran(0x02dd8,     7, X::Start),
+	ran(0x02de0,    32, X::Continue),
+	ran(0x03005,     3, X::Start),
+	ran(0x03021,     9, X::Start),
+	ran(0x0302a,     6, X::Continue),
+	ran(0x03031,     5, X::Start),
+	ran(0x03038,     5, X::Start),
+	ran(0x03041,    86, X::Start),
+	ran(0x03099,     2, X::Continue),
+	ran(0x0309d,     3, X::Start),
+	ran(0x030a1,    90, X::Start),
+	ran(0x030fb,     1, X::Continue),
+	ran(0x030fc,     4, X::Start),
+	ran(0x03105,    43, X::Start),
+	ran(0x03131,    94, X::Start),
+	ran(0x031a0,    32, X::Start),
+	ran(0x031f0,    16, X::Start),
+	ran(0x03400,  6592, X::Start),
+	ran(0x04e00, 22157, X::Start),
+	ran(0x0a4d0,    46, X::Start),
+	ran(0x0a500,   269, X::Start),
+	ran(0x0a610,    16, X::Start),
+	ran(0x0a620,    10, X::Continue),
+	ran(0x0a62a,     2, X::Start),
+	ran(0x0a640,    47, X::Start),
+	ran(0x0a66f,     1, X::Continue),
+	ran(0x0a674,    10, X::Continue),
+	ran(0x0a67f,    31, X::Start),
+	ran(0x0a69e,     2, X::Continue),
+	ran(0x0a6a0,    80, X::Start),
+	ran(0x0a6f0,     2, X::Continue),
+	ran(0x0a717,     9, X::Start),
+	ran(0x0a722,   103, X::Start),
+	ran(0x0a78b,    64, X::Start),
+	ran(0x0a7d0,     2, X::Start),
+	ran(0x0a7d3,     1, X::Start),
+	ran(0x0a7d5,     5, X::Start),
+	ran(0x0a7f2,    16, X::Start),
+	ran(0x0a802,     1, X::Continue),
+	ran(0x0a803,     3, X::Start),
+	ran(0x0a806,     1, X::Continue),
+	ran(0x0a807,     4, X::Start),
+	ran(0x0a80b,     1, X::Continue),
+	ran(0x0a80c,    23, X::Start),
+	ran(0x0a823,     5, X::Continue),
+	ran(0x0a82c,     1, X::Continue),
+	ran(0x0a840,    52, X::Start),
+	ran(0x0a880,     2, X::Continue),
+	ran(0x0a882,    50, X::Start),
+	ran(0x0a8b4,    18, X::Continue),
+	ran(0x0a8d0,    10, X::Continue),
+	ran(0x0a8e0,    18, X::Continue),
+	ran(0x0a8f2,     6, X::Start),
+	ran(0x0a8fb,     1, X::Start),
+	ran(0x0a8fd,     2, X::Start),
+	ran(0x0a8ff,    11, X::Continue),
+	ran(0x0a90a,    28, X::Start),
+	ran(0x0a926,     8, X::Continue),
+	ran(0x0a930,    23, X::Start),
+	ran(0x0a947,    13, X::Continue),
+	ran(0x0a960,    29, X::Start),
+	ran(0x0a980,     4, X::Continue),
+	ran(0x0a984,    47, X::Start),
+	ran(0x0a9b3,    14, X::Continue),
+	ran(0x0a9cf,     1, X::Start),
+	ran(0x0a9d0,    10, X::Continue),
+	ran(0x0a9e0,     5, X::Start),
+	ran(0x0a9e5,     1, X::Continue),
+	ran(0x0a9e6,    10, X::Start),
+	ran(0x0a9f0,    10, X::Continue),
+	ran(0x0a9fa,     5, X::Start),
+	ran(0x0aa00,    41, X::Start),
+	ran(0x0aa29,    14, X::Continue),
+	ran(0x0aa40,     3, X::Start),
+	ran(0x0aa43,     1, X::Continue),
+	ran(0x0aa44,     8, X::Start),
+	ran(0x0aa4c,     2, X::Continue),
+	ran(0x0aa50,    10, X::Continue),
+	ran(0x0aa60,    23, X::Start),
+	ran(0x0aa7a,     1, X::Start),
+	ran(0x0aa7b,     3, X::Continue),
+	ran(0x0aa7e,    50, X::Start),
+	ran(0x0aab0,     1, X::Continue),
+	ran(0x0aab1,     1, X::Start),
+	ran(0x0aab2,     3, X::Continue),
+	ran(0x0aab5,     2, X::Start),
+	ran(0x0aab7,     2, X::Continue),
+	ran(0x0aab9,     5, X::Start),
+	ran(0x0aabe,     2, X::Continue),
+	ran(0x0aac0,     1, X::Start),
+	ran(0x0aac1,     1, X::Continue),
+	ran(0x0aac2,     1, X::Start),
+	ran(0x0aadb,     3, X::Start),
+	ran(0x0aae0,    11, X::Start),
+	ran(0x0aaeb,     5, X::Continue),
+	ran(0x0aaf2,     3, X::Start),
+	ran(0x0aaf5,     2, X::Continue),
+	ran(0x0ab01,     6, X::Start),
+	ran(0x0ab09,     6, X::Start),
+	ran(0x0ab11,     6, X::Start),
+	ran(0x0ab20,     7, X::Start),
+	ran(0x0ab28,     7, X::Start),
+	ran(0x0ab30,    43, X::Start),
+	ran(0x0ab5c,    14, X::Start),
+	ran(0x0ab70,   115, X::Start),
+	ran(0x0abe3,     8, X::Continue),
+	ran(0x0abec,     2, X::Continue),
+	ran(0x0abf0,    10, X::Continue),
+	ran(0x0ac00, 11172, X::Start),
+	ran(0x0d7b0,    23, X::Start),
+	ran(0x0d7cb,    49, X::Start),
+	ran(0x0f900,   366, X::Start),
+	ran(0x0fa70,   106, X::Start),
+	ran(0x0fb00,     7, X::Start),
+	ran(0x0fb13,     5, X::Start),
+	ran(0x0fb1d,     1, X::Start),
+	ran(0x0fb1e,     1, X::Continue),
+	ran(0x0fb1f,    10, X::Start),
+	ran(0x0fb2a,    13, X::Start),
+	ran(0x0fb38,     5, X::Start),
+	ran(0x0fb3e,     1, X::Start),
+	ran(0x0fb40,     2, X::Start),
+	ran(0x0fb43,     2, X::Start),
+	ran(0x0fb46,   108, X::Start),
+	ran(0x0fbd3,   139, X::Start),
+	ran(0x0fc64,   218, X::Start),
+	ran(0x0fd50,    64, X::Start),
+	ran(0x0fd92,    54, X::Start),
+	ran(0x0fdf0,    10, X::Start),
+	ran(0x0fe00,    16, X::Continue),
+	ran(0x0fe20,    16, X::Continue),
+	ran(0x0fe33,     2, X::Continue),
+	ran(0x0fe4d,     3, X::Continue),
+	ran(0x0fe71,     1, X::Start),
+	ran(0x0fe73,     1, X::Start),
+	ran(0x0fe77,     1, X::Start),
+	ran(0x0fe79,     1, X::Start),
+	ran(0x0fe7b,     1, X::Start),
+	ran(0x0fe7d,     1, X::Start),
+	ran(0x0fe7f,   126, X::Start),
+	ran(0x0ff10,    10, X::Continue),
+	ran(0x0ff21,    26, X::Start),
+	ran(0x0ff3f,     1, X::Continue),
+	ran(0x0ff41,    26, X::Start),
+	ran(0x0ff65,     1, X::Continue),
+	ran(0x0ff66,    56, X::Start),
+	ran(0x0ff9e,     2, X::Continue),
+	ran(0x0ffa0,    31, X::Start),
+	ran(0x0ffc2,     6, X::Start),
+	ran(0x0ffca,     6, X::Start),
+	ran(0x0ffd2,     6, X::Start),
+	ran(0x0ffda,     3, X::Start),
+	ran(0x10000,    12, X::Start),
+	ran(0x1000d,    26, X::Start),
+	ran(0x10028,    19, X::Start),
+	ran(0x1003c,     2, X::Start),
+	ran(0x1003f,    15, X::Start),
+	ran(0x10050,    14, X::Start),
+	ran(0x10080,   123, X::Start),
+	ran(0x10140,    53, X::Start),
+	ran(0x101fd,     1, X::Continue),
+	ran(0x10280,    29, X::Start),
+	ran(0x102a0,    49, X::Start),
+	ran(0x102e0,     1, X::Continue),
+	ran(0x10300,    32, X::Start),
+	ran(0x1032d,    30, X::Start),
+	ran(0x10350,    38, X::Start),
+	ran(0x10376,     5, X::Continue),
+	ran(0x10380,    30, X::Start),
+	ran(0x103a0,    36, X::Start),
+	ran(0x103c8,     8, X::Start),
+	ran(0x103d1,     5, X::Start),
+	ran(0x10400,   158, X::Start),
+	ran(0x104a0,    10, X::Continue),
+	ran(0x104b0,    36, X::Start),
+	ran(0x104d8,    36, X::Start),
+	ran(0x10500,    40, X::Start),
+	ran(0x10530,    52, X::Start),
+	ran(0x10570,    11, X::Start),
+	ran(0x1057c,    15, X::Start),
+	ran(0x1058c,     7, X::Start),
+	ran(0x10594,     2, X::Start),
+	ran(0x10597,    11, X::Start),
+	ran(0x105a3,    15, X::Start),
+	ran(0x105b3,     7, X::Start),
+	ran(0x105bb,     2, X::Start),
+	ran(0x10600,   311, X::Start),
+	ran(0x10740,    22, X::Start),
+	ran(0x10760,     8, X::Start),
+	ran(0x10780,     6, X::Start),
+	ran(0x10787,    42, X::Start),
+	ran(0x107b2,     9, X::Start),
+	ran(0x10800,     6, X::Start),
+	ran(0x10808,     1, X::Start),
+	ran(0x1080a,    44, X::Start),
+	ran(0x10837,     2, X::Start),
+	ran(0x1083c,     1, X::Start),
+	ran(0x1083f,    23, X::Start),
+	ran(0x10860,    23, X::Start),
+	ran(0x10880,    31, X::Start),
+	ran(0x108e0,    19, X::Start),
+	ran(0x108f4,     2, X::Start),
+	ran(0x10900,    22, X::Start),
+	ran(0x10920,    26, X::Start),
+	ran(0x10980,    56, X::Start),
+	ran(0x109be,     2, X::Start),
+	ran(0x10a00,     1, X::Start),
+	ran(0x10a01,     3, X::Continue),
+	ran(0x10a05,     2, X::Continue),
+	ran(0x10a0c,     4, X::Continue),
+	ran(0x10a10,     4, X::Start),
+	ran(0x10a15,     3, X::Start),
+	ran(0x10a19,    29, X::Start),
+	ran(0x10a38,     3, X::Continue),
+	ran(0x10a3f,     1, X::Continue),
+	ran(0x10a60,    29, X::Start),
+	ran(0x10a80,    29, X::Start),
+	ran(0x10ac0,     8, X::Start),
+	ran(0x10ac9,    28, X::Start),
+	ran(0x10ae5,     2, X::Continue),
+	ran(0x10b00,    54, X::Start),
+	ran(0x10b40,    22, X::Start),
+	ran(0x10b60,    19, X::Start),
+	ran(0x10b80,    18, X::Start),
+	ran(0x10c00,    73, X::Start),
+	ran(0x10c80,    51, X::Start),
+	ran(0x10cc0,    51, X::Start),
+	ran(0x10d00,    36, X::Start),
+	ran(0x10d24,     4, X::Continue),
+	ran(0x10d30,    10, X::Continue),
+	ran(0x10e80,    42, X::Start),
+	ran(0x10eab,     2, X::Continue),
+	ran(0x10eb0,     2, X::Start),
+	ran(0x10efd,     3, X::Continue),
+	ran(0x10f00,    29, X::Start),
+	ran(0x10f27,     1, X::Start),
+	ran(0x10f30,    22, X::Start),
+	ran(0x10f46,    11, X::Continue),
+	ran(0x10f70,    18, X::Start),
+	ran(0x10f82,     4, X::Continue),
+	ran(0x10fb0,    21, X::Start),
+	ran(0x10fe0,    23, X::Start),
+	ran(0x11000,     3, X::Continue),
+	ran(0x11003,    53, X::Start),
+	ran(0x11038,    15, X::Continue),
+	ran(0x11066,    11, X::Continue),
+	ran(0x11071,     2, X::Start),
+	ran(0x11073,     2, X::Continue),
+	ran(0x11075,     1, X::Start),
+	ran(0x1107f,     4, X::Continue),
+	ran(0x11083,    45, X::Start),
+	ran(0x110b0,    11, X::Continue),
+	ran(0x110c2,     1, X::Continue),
+	ran(0x110d0,    25, X::Start),
+	ran(0x110f0,    10, X::Continue),
+	ran(0x11100,     3, X::Continue),
+	ran(0x11103,    36, X::Start),
+	ran(0x11127,    14, X::Continue),
+	ran(0x11136,    10, X::Continue),
+	ran(0x11144,     1, X::Start),
+	ran(0x11145,     2, X::Continue),
+	ran(0x11147,     1, X::Start),
+	ran(0x11150,    35, X::Start),
+	ran(0x11173,     1, X::Continue),
+	ran(0x11176,     1, X::Start),
+	ran(0x11180,     3, X::Continue),
+	ran(0x11183,    48, X::Start),
+	ran(0x111b3,    14, X::Continue),
+	ran(0x111c1,     4, X::Start),
+	ran(0x111c9,     4, X::Continue),
+	ran(0x111ce,    12, X::Continue),
+	ran(0x111da,     1, X::Start),
+	ran(0x111dc,     1, X::Start),
+	ran(0x11200,    18, X::Start),
+	ran(0x11213,    25, X::Start),
+	ran(0x1122c,    12, X::Continue),
+	ran(0x1123e,     1, X::Continue),
+	ran(0x1123f,     2, X::Start),
+	ran(0x11241,     1, X::Continue),
+	ran(0x11280,     7, X::Start),
+	ran(0x11288,     1, X::Start),
+	ran(0x1128a,     4, X::Start),
+	ran(0x1128f,    15, X::Start),
+	ran(0x1129f,    10, X::Start),
+	ran(0x112b0,    47, X::Start),
+	ran(0x112df,    12, X::Continue),
+	ran(0x112f0,    10, X::Continue),
+	ran(0x11300,     4, X::Continue),
+	ran(0x11305,     8, X::Start),
+	ran(0x1130f,     2, X::Start),
+	ran(0x11313,    22, X::Start),
+	ran(0x1132a,     7, X::Start),
+	ran(0x11332,     2, X::Start),
+	ran(0x11335,     5, X::Start),
+	ran(0x1133b,     2, X::Continue),
+	ran(0x1133d,     1, X::Start),
+	ran(0x1133e,     7, X::Continue),
+	ran(0x11347,     2, X::Continue),
+	ran(0x1134b,     3, X::Continue),
+	ran(0x11350,     1, X::Start),
+	ran(0x11357,     1, X::Continue),
+	ran(0x1135d,     5, X::Start),
+	ran(0x11362,     2, X::Continue),
+	ran(0x11366,     7, X::Continue),
+	ran(0x11370,     5, X::Continue),
+	ran(0x11400,    53, X::Start),
+	ran(0x11435,    18, X::Continue),
+	ran(0x11447,     4, X::Start),
+	ran(0x11450,    10, X::Continue),
+	ran(0x1145e,     1, X::Continue),
+	ran(0x1145f,     3, X::Start),
+	ran(0x11480,    48, X::Start),
+	ran(0x114b0,    20, X::Continue),
+	ran(0x114c4,     2, X::Start),
+	ran(0x114c7,     1, X::Start),
+	ran(0x114d0,    10, X::Continue),
+	ran(0x11580,    47, X::Start),
+	ran(0x115af,     7, X::Continue),
+	ran(0x115b8,     9, X::Continue),
+	ran(0x115d8,     4, X::Start),
+	ran(0x115dc,     2, X::Continue),
+	ran(0x11600,    48, X::Start),
+	ran(0x11630,    17, X::Continue),
+	ran(0x11644,     1, X::Start),
+	ran(0x11650,    10, X::Continue),
+	ran(0x11680,    43, X::Start),
+	ran(0x116ab,    13, X::Continue),
+	ran(0x116b8,     1, X::Start),
+	ran(0x116c0,    10, X::Continue),
+	ran(0x11700,    27, X::Start),
+	ran(0x1171d,    15, X::Continue),
+	ran(0x11730,    10, X::Continue),
+	ran(0x11740,     7, X::Start),
+	ran(0x11800,    44, X::Start),
+	ran(0x1182c,    15, X::Continue),
+	ran(0x118a0,    64, X::Start),
+	ran(0x118e0,    10, X::Continue),
+	ran(0x118ff,     8, X::Start),
+	ran(0x11909,     1, X::Start),
+	ran(0x1190c,     8, X::Start),
+	ran(0x11915,     2, X::Start),
+	ran(0x11918,    24, X::Start),
+	ran(0x11930,     6, X::Continue),
+	ran(0x11937,     2, X::Continue),
+	ran(0x1193b,     4, X::Continue),
+	ran(0x1193f,     1, X::Start),
+	ran(0x11940,     1, X::Continue),
+	ran(0x11941,     1, X::Start),
+	ran(0x11942,     2, X::Continue),
+	ran(0x11950,    10, X::Continue),
+	ran(0x119a0,     8, X::Start),
+	ran(0x119aa,    39, X::Start),
+	ran(0x119d1,     7, X::Continue),
+	ran(0x119da,     7, X::Continue),
+	ran(0x119e1,     1, X::Start),
+	ran(0x119e3,     1, X::Start),
+	ran(0x119e4,     1, X::Continue),
+	ran(0x11a00,     1, X::Start),
+	ran(0x11a01,    10, X::Continue),
+	ran(0x11a0b,    40, X::Start),
+	ran(0x11a33,     7, X::Continue),
+	ran(0x11a3a,     1, X::Start),
+	ran(0x11a3b,     4, X::Continue),
+	ran(0x11a47,     1, X::Continue),
+	ran(0x11a50,     1, X::Start),
+	ran(0x11a51,    11, X::Continue),
+	ran(0x11a5c,    46, X::Start),
+	ran(0x11a8a,    16, X::Continue),
+	ran(0x11a9d,     1, X::Start),
+	ran(0x11ab0,    73, X::Start),
+	ran(0x11c00,     9, X::Start),
+	ran(0x11c0a,    37, X::Start),
+	ran(0x11c2f,     8, X::Continue),
+	ran(0x11c38,     8, X::Continue),
+	ran(0x11c40,     1, X::Start),
+	ran(0x11c50,    10, X::Continue),
+	ran(0x11c72,    30, X::Start),
+	ran(0x11c92,    22, X::Continue),
+	ran(0x11ca9,    14, X::Continue),
+	ran(0x11d00,     7, X::Start),
+	ran(0x11d08,     2, X::Start),
+	ran(0x11d0b,    38, X::Start),
+	ran(0x11d31,     6, X::Continue),
+	ran(0x11d3a,     1, X::Continue),
+	ran(0x11d3c,     2, X::Continue),
+	ran(0x11d3f,     7, X::Continue),
+	ran(0x11d46,     1, X::Start),
+	ran(0x11d47,     1, X::Continue),
+	ran(0x11d50,    10, X::Continue),
+	ran(0x11d60,     6, X::Start),
+	ran(0x11d67,     2, X::Start),
+	ran(0x11d6a,    32, X::Start),
+	ran(0x11d8a,     5, X::Continue),
+	ran(0x11d90,     2, X::Continue),
+	ran(0x11d93,     5, X::Continue),
+	ran(0x11d98,     1, X::Start),
+	ran(0x11da0,    10, X::Continue),
+	ran(0x11ee0,    19, X::Start),
+	ran(0x11ef3,     4, X::Continue),
+	ran(0x11f00,     2, X::Continue),
+	ran(0x11f02,     1, X::Start),
+	ran(0x11f03,     1, X::Continue),
+	ran(0x11f04,    13, X::Start),
+	ran(0x11f12,    34, X::Start),
+	ran(0x11f34,     7, X::Continue),
+	ran(0x11f3e,     5, X::Continue),
+	ran(0x11f50,    10, X::Continue),
+	ran(0x11fb0,     1, X::Start),
+	ran(0x12000,   922, X::Start),
+	ran(0x12400,   111, X::Start),
+	ran(0x12480,   196, X::Start),
+	ran(0x12f90,    97, X::Start),
+	ran(0x13000,  1072, X::Start),
+	ran(0x13440,     1, X::Continue),
+	ran(0x13441,     6, X::Start),
+	ran(0x13447,    15, X::Continue),
+	ran(0x14400,   583, X::Start),
+	ran(0x16800,   569, X::Start),
+	ran(0x16a40,    31, X::Start),
+	ran(0x16a60,    10, X::Continue),
+	ran(0x16a70,    79, X::Start),
+	ran(0x16ac0,    10, X::Continue),
+	ran(0x16ad0,    30, X::Start),
+	ran(0x16af0,     5, X::Continue),
+	ran(0x16b00,    48, X::Start),
+	ran(0x16b30,     7, X::Continue),
+	ran(0x16b40,     4, X::Start),
+	ran(0x16b50,    10, X::Continue),
+	ran(0x16b63,    21, X::Start),
+	ran(0x16b7d,    19, X::Start),
+	ran(0x16e40,    64, X::Start),
+	ran(0x16f00,    75, X::Start),
+	ran(0x16f4f,     1, X::Continue),
+	ran(0x16f50,     1, X::Start),
+	ran(0x16f51,    55, X::Continue),
+	ran(0x16f8f,     4, X::Continue),
+	ran(0x16f93,    13, X::Start),
+	ran(0x16fe0,     2, X::Start),
+	ran(0x16fe3,     1, X::Start),
+	ran(0x16fe4,     1, X::Continue),
+	ran(0x16ff0,     2, X::Continue),
+	ran(0x17000,  6136, X::Start),
+	ran(0x18800,  1238, X::Start),
+	ran(0x18d00,     9, X::Start),
+	ran(0x1aff0,     4, X::Start),
+	ran(0x1aff5,     7, X::Start),
+	ran(0x1affd,     2, X::Start),
+	ran(0x1b000,   291, X::Start),
+	ran(0x1b132,     1, X::Start),
+	ran(0x1b150,     3, X::Start),
+	ran(0x1b155,     1, X::Start),
+	ran(0x1b164,     4, X::Start),
+	ran(0x1b170,   396, X::Start),
+	ran(0x1bc00,   107, X::Start),
+	ran(0x1bc70,    13, X::Start),
+	ran(0x1bc80,     9, X::Start),
+	ran(0x1bc90,    10, X::Start),
+	ran(0x1bc9d,     2, X::Continue),
+	ran(0x1cf00,    46, X::Continue),
+	ran(0x1cf30,    23, X::Continue),
+	ran(0x1d165,     5, X::Continue),
+	ran(0x1d16d,     6, X::Continue),
+	ran(0x1d17b,     8, X::Continue),
+	ran(0x1d185,     7, X::Continue),
+	ran(0x1d1aa,     4, X::Continue),
+	ran(0x1d242,     3, X::Continue),
+	ran(0x1d400,    85, X::Start),
+	ran(0x1d456,    71, X::Start),
+	ran(0x1d49e,     2, X::Start),
+	ran(0x1d4a2,     1, X::Start),
+	ran(0x1d4a5,     2, X::Start),
+	ran(0x1d4a9,     4, X::Start),
+	ran(0x1d4ae,    12, X::Start),
+	ran(0x1d4bb,     1, X::Start),
+	ran(0x1d4bd,     7, X::Start),
+	ran(0x1d4c5,    65, X::Start),
+	ran(0x1d507,     4, X::Start),
+	ran(0x1d50d,     8, X::Start),
+	ran(0x1d516,     7, X::Start),
+	ran(0x1d51e,    28, X::Start),
+	ran(0x1d53b,     4, X::Start),
+	ran(0x1d540,     5, X::Start),
+	ran(0x1d546,     1, X::Start),
+	ran(0x1d54a,     7, X::Start),
+	ran(0x1d552,   340, X::Start),
+	ran(0x1d6a8,    25, X::Start),
+	ran(0x1d6c2,    25, X::Start),
+	ran(0x1d6dc,    31, X::Start),
+	ran(0x1d6fc,    25, X::Start),
+	ran(0x1d716,    31, X::Start),
+	ran(0x1d736,    25, X::Start),
+	ran(0x1d750,    31, X::Start),
+	ran(0x1d770,    25, X::Start),
+	ran(0x1d78a,    31, X::Start),
+	ran(0x1d7aa,    25, X::Start),
+	ran(0x1d7c4,     8, X::Start),
+	ran(0x1d7ce,    50, X::Continue),
+	ran(0x1da00,    55, X::Continue),
+	ran(0x1da3b,    50, X::Continue),
+	ran(0x1da75,     1, X::Continue),
+	ran(0x1da84,     1, X::Continue),
+	ran(0x1da9b,     5, X::Continue),
+	ran(0x1daa1,    15, X::Continue),
+	ran(0x1df00,    31, X::Start),
+	ran(0x1df25,     6, X::Start),
+	ran(0x1e000,     7, X::Continue),
+	ran(0x1e008,    17, X::Continue),
+	ran(0x1e01b,     7, X::Continue),
+	ran(0x1e023,     2, X::Continue),
+	ran(0x1e026,     5, X::Continue),
+	ran(0x1e030,    62, X::Start),
+	ran(0x1e08f,     1, X::Continue),
+	ran(0x1e100,    45, X::Start),
+	ran(0x1e130,     7, X::Continue),
+	ran(0x1e137,     7, X::Start),
+	ran(0x1e140,    10, X::Continue),
+	ran(0x1e14e,     1, X::Start),
+	ran(0x1e290,    30, X::Start),
+	ran(0x1e2ae,     1, X::Continue),
+	ran(0x1e2c0,    44, X::Start),
+	ran(0x1e2ec,    14, X::Continue),
+	ran(0x1e4d0,    28, X::Start),
+	ran(0x1e4ec,    14, X::Continue),
+	ran(0x1e7e0,     7, X::Start),
+	ran(0x1e7e8,     4, X::Start),
+	ran(0x1e7ed,     2, X::Start),
+	ran(0x1e7f0,    15, X::Start),
+	ran(0x1e800,   197, X::Start),
+	ran(0x1e8d0,     7, X::Continue),
+	ran(0x1e900,    68, X::Start),
+	ran(0x1e944,     7, X::Continue),
+	ran(0x1e94b,     1, X::Start),
+	ran(0x1e950,    10, X::Continue),
+	ran(0x1ee00,     4, X::Start),
+	ran(0x1ee05,    27, X::Start),
+	ran(0x1ee21,     2, X::Start),
+	ran(0x1ee24,     1, X::Start),
+	ran(0x1ee27,     1, X::Start),
+	ran(0x1ee29,    10, X::Start),
+	ran(0x1ee34,     4, X::Start),
+	ran(0x1ee39,     1, X::Start),
+	ran(0x1ee3b,     1, X::Start),
+	ran(0x1ee42,     1, X::Start),
+	ran(0x1ee47,     1, X::Start),
+	ran(0x1ee49,     1, X::Start),
+	ran(0x1ee4b,     1, X::Start),
+	ran(0x1ee4d,     3, X::Start),
+	ran(0x1ee51,     2, X::Start),
+	ran(0x1ee54,     1, X::Start),
+	ran(0x1ee57,     1, X::Start),
+	ran(0x1ee59,     1, X::Start),
+	ran(0x1ee5b,     1, X::Start),
+	ran(0x1ee5d,     1, X::Start),
+	ran(0x1ee5f,     1, X::Start),
+	ran(0x1ee61,     2, X::Start),
+	ran(0x1ee64,     1, X::Start),
+	ran(0x1ee67,     4, X::Start),
+	ran(0x1ee6c,     7, X::Start),
+	ran(0x1ee74,     4, X::Start),
+	ran(0x1ee79,     4, X::Start),
+	ran(0x1ee7e,     1, X::Start),
+	ran(0x1ee80,    10, X::Start),
+	ran(0x1ee8b,    17, X::Start),
+	ran(0x1eea1,     3, X::Start),
+	ran(0x1eea5,     5, X::Start),
+	ran(0x1eeab,    17, X::Start),
+	ran(0x1fbf0,    10, X::Continue),
+	ran(0x20000, 42720, X::Start),
+	ran(0x2a700,  4154, X::Start),
+	ran(0x2b740,   222, X::Start),
+	ran(0x2b820,  5762, X::Start),
+	ran(0x2ceb0,  7473, X::Start),
+	ran(0x2ebf0,   622, X::Start),
+	ran(0x2f800,   542, X::Start),
+	ran(0x30000,  4939, X::Start),
+	ran(0x31350,  4192, X::Start),
+	ran(0xe0100,   240, X::Continue),
+];
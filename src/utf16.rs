@@ -0,0 +1,69 @@
+use core::iter::FusedIterator;
+
+use crate::logic::Segmenter;
+
+/// A forward iterator over the end indices of EGCs in UTF-16 text, counted in code units.
+/// 
+/// Obtained with [egc_indices_u16].
+#[derive(Debug, Clone, Copy)]
+pub struct U16EgcIndices<'a> {
+	units: &'a [u16],
+	offset: usize,
+}
+
+impl<'a> U16EgcIndices<'a> {
+	/// Returns the offset of the next EGC, in code units.
+	pub fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the part of the text which has not been iterated over yet.
+	pub fn as_slice(&self) -> &'a [u16] {
+		&self.units[self.offset..]
+	}
+}
+
+impl Iterator for U16EgcIndices<'_> {
+	type Item = usize;
+	fn next(&mut self) -> Option<usize> {
+		let rest = self.as_slice();
+		if rest.is_empty() {
+			return None;
+		}
+		let mut seg = Segmenter::new();
+		let mut len = 0;
+		for c in char::decode_utf16(rest.iter().copied()) {
+			match c {
+				Ok(c) => {
+					if seg.push_char(c).is_some() {
+						break;
+					}
+					len += c.len_utf16();
+				},
+				// An unpaired surrogate is a cluster of its own
+				Err(_) => {
+					if len == 0 {
+						len = 1;
+					}
+					break;
+				},
+			}
+		}
+		self.offset += len;
+		Some(self.offset)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.units.len() - self.offset;
+		(remaining.min(1), Some(remaining))
+	}
+}
+impl FusedIterator for U16EgcIndices<'_> {}
+
+/// Returns an iterator over the end indices of the EGCs in `units`, counted in code units,
+/// for UTF-16 text coming from Windows APIs or JavaScript engines.
+/// 
+/// Unpaired surrogates are tolerated, and each returned as a cluster of its own.
+pub fn egc_indices_u16(units: &[u16]) -> U16EgcIndices<'_> {
+	U16EgcIndices { units, offset: 0 }
+}
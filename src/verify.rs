@@ -0,0 +1,214 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::ident::{lookup_xid_class, XidRange, XID_RANGES};
+use crate::lookup::{is_default_ignorable, lookup_egc_props, lookup_packed_props, CharRange, EgcProps, DEFAULT_IGNORABLE, RANGES};
+#[cfg(feature = "width")]
+use crate::width::{lookup_char_width, CharWidth, WidthRange};
+
+/// A problem found in a data table by [verify_tables].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TableIssue {
+	/// The range at this index is empty.
+	EmptyRange(usize),
+	/// The range at this index extends past U+10FFFF.
+	OutOfBounds(usize),
+	/// The range at this index starts before the end of the previous one.
+	Unsorted(usize),
+	/// The lookup function returns a different value than the range at this index
+	/// for the given codepoint (eg. because of a fast path shadowing the range).
+	FastPathMismatch(usize, u32),
+	/// The lookup function returns a value other than the default one for this codepoint,
+	/// which is in no range.
+	GapMismatch(u32),
+	/// The lookup function returns properties which do not match the canonical
+	/// decomposition of this precomposed Hangul syllable.
+	HangulMismatch(u32),
+}
+
+impl fmt::Display for TableIssue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			TableIssue::EmptyRange(i) => write!(f, "range {} is empty", i),
+			TableIssue::OutOfBounds(i) => write!(f, "range {} extends past U+10FFFF", i),
+			TableIssue::Unsorted(i) => write!(f, "range {} starts before the end of the previous range", i),
+			TableIssue::FastPathMismatch(i, cp) => write!(f, "range {} disagrees with the lookup function at U+{:04X}", i, cp),
+			TableIssue::GapMismatch(cp) => write!(f, "U+{:04X} is in no range but has a value in the lookup function", cp),
+			TableIssue::HangulMismatch(cp) => write!(f, "U+{:04X} disagrees with its Hangul decomposition", cp),
+		}
+	}
+}
+
+/// Result of the verification of a data table.
+#[derive(Debug, Clone)]
+pub struct TableReport {
+	/// Name of the table
+	pub name: &'static str,
+	/// Number of ranges in the table
+	pub len: usize,
+	/// Problems found
+	pub issues: Vec<TableIssue>,
+}
+
+impl TableReport {
+	/// Returns true if no problems were found.
+	pub fn is_ok(&self) -> bool {
+		self.issues.is_empty()
+	}
+}
+
+impl fmt::Display for TableReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} ({} ranges): ", self.name, self.len)?;
+		if self.is_ok() {
+			return write!(f, "ok");
+		}
+		for (i, issue) in self.issues.iter().enumerate() {
+			if i > 0 {
+				write!(f, ", ")?;
+			}
+			write!(f, "{}", issue)?;
+		}
+		Ok(())
+	}
+}
+
+/// Checks that a table of `(start, count)` ranges is sorted and well-formed, and that
+/// `agrees(Some(index), c)` holds for every character of each range, and `agrees(None, c)`
+/// for every character in no range. Only the first disagreement in each range or gap is reported.
+fn verify_ranges(
+	name: &'static str,
+	ranges: impl ExactSizeIterator<Item = (u32, u32)>,
+	agrees: impl Fn(Option<usize>, char) -> bool,
+) -> TableReport {
+	let len = ranges.len();
+	let mut issues = vec![];
+	let first_mismatch = |cps: core::ops::Range<u32>, i: Option<usize>| {
+		cps.filter_map(char::from_u32).find(|&c| !agrees(i, c)).map(|c| c as u32)
+	};
+	let mut prev_end = 0;
+	for (i, (start, count)) in ranges.enumerate() {
+		if count == 0 {
+			issues.push(TableIssue::EmptyRange(i));
+			continue;
+		}
+		let end = start + count;
+		if end > 0x110000 {
+			issues.push(TableIssue::OutOfBounds(i));
+		}
+		if start < prev_end {
+			issues.push(TableIssue::Unsorted(i));
+		}
+		if let Some(cp) = first_mismatch(prev_end..start, None) {
+			issues.push(TableIssue::GapMismatch(cp));
+		}
+		if let Some(cp) = first_mismatch(start..end, Some(i)) {
+			issues.push(TableIssue::FastPathMismatch(i, cp));
+		}
+		prev_end = prev_end.max(end);
+	}
+	if let Some(cp) = first_mismatch(prev_end..0x110000, None) {
+		issues.push(TableIssue::GapMismatch(cp));
+	}
+	TableReport { name, len, issues }
+}
+
+/// Checks a table of EGC properties, such as [RANGES], against [lookup_egc_props],
+/// for every codepoint.
+/// 
+/// Precomposed Hangul syllables, which are not in [RANGES] as their properties are computed,
+/// are checked against the table if it contains them (eg. if it was generated from the UCD
+/// files without leaving them out), and otherwise against their canonical decomposition
+/// (Unicode Standard, section 3.12) into jamos of the table: LV syllables are made of L and
+/// V jamos, and LVT syllables have an additional T jamo.
+pub fn verify_egc_ranges(ranges: &[CharRange]) -> TableReport {
+	let mut report = verify_ranges(
+		"RANGES",
+		ranges.iter().map(|r| (r.start, r.count as u32)),
+		|i, c| match i {
+			Some(i) => lookup_packed_props(c) == ranges[i].packed,
+			None => is_hangul_syllable(c) || lookup_egc_props(c) == EgcProps::XX,
+		},
+	);
+	let in_table = |cp: u32| ranges.iter().find(|r| r.start <= cp && cp - r.start < r.count as u32).map(|r| r.kind);
+	let mismatch = (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT)
+		.filter(|&cp| in_table(cp).is_none())
+		.filter_map(char::from_u32)
+		.find(|&c| lookup_egc_props(c) != hangul_props(c, |cp| in_table(cp).unwrap_or(EgcProps::XX)));
+	if let Some(c) = mismatch {
+		report.issues.push(TableIssue::HangulMismatch(c as u32));
+	}
+	report
+}
+
+const HANGUL_BASE: u32 = 0xac00;
+const HANGUL_COUNT: u32 = 11172;
+
+fn is_hangul_syllable(c: char) -> bool {
+	(HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&(c as u32))
+}
+
+/// Computes the properties of a precomposed Hangul syllable from the ones of the jamos
+/// of its canonical decomposition in the table, or XX if they are not the expected ones.
+fn hangul_props(c: char, props: impl Fn(u32) -> EgcProps) -> EgcProps {
+	let index = c as u32 - HANGUL_BASE;
+	let (l, v, t) = (0x1100 + index / 588, 0x1161 + index % 588 / 28, 0x11a7 + index % 28);
+	match (props(l), props(v)) {
+		(EgcProps::L, EgcProps::V) if t == 0x11a7 => EgcProps::LV,
+		(EgcProps::L, EgcProps::V) if props(t) == EgcProps::T => EgcProps::LVT,
+		_ => EgcProps::XX,
+	}
+}
+
+/// Checks a table of inclusive ranges of default-ignorable characters against
+/// [is_default_ignorable], for every codepoint.
+pub fn verify_default_ignorable(ranges: &[(u32, u32)]) -> TableReport {
+	verify_ranges(
+		"DEFAULT_IGNORABLE",
+		ranges.iter().map(|&(start, end)| (start, (end + 1).saturating_sub(start))),
+		|i, c| is_default_ignorable(c) == i.is_some(),
+	)
+}
+
+/// Checks a table of identifier classes, such as [XID_RANGES], against [lookup_xid_class],
+/// for every codepoint.
+pub fn verify_xid_ranges(ranges: &[XidRange]) -> TableReport {
+	verify_ranges(
+		"XID_RANGES",
+		ranges.iter().map(|r| (r.start, r.count as u32)),
+		|i, c| lookup_xid_class(c) == i.map(|i| ranges[i].kind),
+	)
+}
+
+/// Checks a table of width classes, such as [WIDTH_RANGES](crate::width::WIDTH_RANGES),
+/// against [lookup_char_width], for every codepoint.
+#[cfg(feature = "width")]
+pub fn verify_width_ranges(ranges: &[WidthRange]) -> TableReport {
+	verify_ranges(
+		"WIDTH_RANGES",
+		ranges.iter().map(|r| (r.start, r.count as u32)),
+		|i, c| lookup_char_width(c) == i.map_or(CharWidth::Narrow, |i| ranges[i].kind),
+	)
+}
+
+/// Checks the internal consistency of the data tables used by this crate,
+/// and returns a report for each table.
+/// 
+/// This checks that the ranges in the tables are non-empty, sorted and non-overlapping,
+/// and that the lookup functions, including their fast paths (for ASCII, CJK, or precomposed
+/// Hangul), return the values of the tables for every codepoint. The functions checking
+/// each table can also be called with tables generated from other copies of the UCD files.
+/// 
+/// Only available in debug builds, or with the `verify` feature, and requires the `alloc` feature.
+pub fn verify_tables() -> Vec<TableReport> {
+	#[allow(unused_mut)]
+	let mut reports = vec![
+		verify_egc_ranges(&RANGES),
+		verify_default_ignorable(&DEFAULT_IGNORABLE),
+		verify_xid_ranges(&XID_RANGES),
+	];
+	#[cfg(feature = "width")]
+	reports.push(verify_width_ranges(&crate::width::WIDTH_RANGES));
+	reports
+}
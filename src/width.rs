@@ -0,0 +1,290 @@
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use crate::iter::{EgcIndices, EgcSlices};
+use crate::lookup::{lookup_egc_props, EgcProps};
+use crate::Egc;
+
+/// Width class of a character when displayed on a monospace grid (eg. a terminal).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum CharWidth {
+	/// Takes up no column on its own (combining marks, format characters, default-ignorables)
+	Zero,
+	/// Takes up one column
+	Narrow,
+	/// Takes up two columns (East_Asian_Width=Wide or Fullwidth)
+	Wide,
+}
+
+/// A range of Unicode codepoints, and the associated width class.
+#[derive(Clone, Copy)]
+pub struct WidthRange {
+	pub start: u32,
+	pub count: u16,
+	pub kind: CharWidth,
+}
+pub(crate) const fn ran(start: u32, count: u16, kind: CharWidth) -> WidthRange {
+	WidthRange { start, count, kind }
+}
+
+/// The width data table. Characters not listed are [Narrow](CharWidth::Narrow).
+pub use crate::width_data::WIDTH_RANGES;
+
+/// Looks up the width class of `c`, without considering the EGC it is part of.
+pub fn lookup_char_width(c: char) -> CharWidth {
+	let cp = c as u32;
+	if (0x20..0x7f).contains(&cp) {
+		// fast path for printable ASCII
+		return CharWidth::Narrow;
+	}
+	let mut start = 0usize;
+	let mut end = WIDTH_RANGES.len();
+	while end > start {
+		let pivot_idx = (start + end) / 2;
+		let pivot = WIDTH_RANGES[pivot_idx];
+		if cp < pivot.start {
+			end = pivot_idx;
+		} else if cp >= pivot.start + pivot.count as u32 {
+			start = pivot_idx + 1;
+		} else {
+			return pivot.kind;
+		}
+	}
+	CharWidth::Narrow
+}
+
+/// Computes the number of columns taken up by a single EGC on a monospace grid.
+/// 
+/// The width of an EGC is the width of its first non-zero-width character, with
+/// some adjustments for emoji: variation selector 16 (emoji presentation) makes
+/// pictographs and keycaps wide, variation selector 15 (text presentation) makes
+/// pictographs narrow, and flags (pairs of regional indicators) are wide.
+/// Control characters have a width of 0.
+/// 
+/// The result is unspecified if `egc` is not a single EGC.
+pub fn egc_width(egc: &str) -> usize {
+	let mut chars = egc.chars();
+	let Some(first) = chars.next() else {
+		return 0;
+	};
+	let first_props = lookup_egc_props(first);
+	if first_props.is_control() {
+		return 0;
+	}
+	if first_props == EgcProps::RI {
+		return if chars.next().map(lookup_egc_props) == Some(EgcProps::RI) { 2 } else { 1 };
+	}
+
+	let mut base = None;
+	for c in egc.chars() {
+		match lookup_char_width(c) {
+			CharWidth::Zero => (),
+			width => {
+				base = Some((c, width));
+				break;
+			}
+		}
+	}
+	let Some((base, width)) = base else {
+		return 0;
+	};
+	let is_pictograph = lookup_egc_props(base) == EgcProps::EP;
+	if egc.contains('\u{fe0f}') && (is_pictograph || matches!(base, '0'..='9' | '#' | '*')) {
+		return 2;
+	}
+	if egc.contains('\u{fe0e}') && is_pictograph {
+		return 1;
+	}
+	match width {
+		CharWidth::Wide => 2,
+		_ => 1,
+	}
+}
+
+/// Computes the number of columns taken up by a string on a monospace grid,
+/// by summing the [width](egc_width) of each of its EGCs.
+pub fn str_width(s: &str) -> usize {
+	s.egcs().map(egc_width).sum()
+}
+
+/// Iterator returned by [egcs_with_width].
+#[derive(Debug, Clone)]
+pub struct EgcsWithWidth<'a> {
+	egcs: EgcSlices<'a>,
+}
+
+impl<'a> Iterator for EgcsWithWidth<'a> {
+	type Item = (&'a str, usize);
+	fn next(&mut self) -> Option<(&'a str, usize)> {
+		self.egcs.next().map(|egc| (egc, egc_width(egc)))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.egcs.size_hint()
+	}
+}
+impl FusedIterator for EgcsWithWidth<'_> {}
+
+/// Returns an iterator over the EGCs of `s`, returned as sub-slices along with
+/// the number of columns they take up, as computed by [egc_width].
+/// 
+/// Unlike [visual_cols], tabs and line terminators are not handled specially.
+pub fn egcs_with_width(s: &str) -> EgcsWithWidth<'_> {
+	EgcsWithWidth { egcs: s.egcs() }
+}
+
+/// Checks whether an EGC is rendered without any advance, ie. if it only contains
+/// zero-width characters: combining marks without a base, joiners, variation selectors,
+/// and other default-ignorable characters.
+/// 
+/// Control characters are not considered zero-width clusters, even though [egc_width]
+/// returns 0 for them, since they are not meant to be rendered at all.
+pub fn is_zero_width_cluster(egc: &str) -> bool {
+	!egc.is_empty() && egc.chars().all(|c| lookup_char_width(c) == CharWidth::Zero)
+}
+
+/// Iterator returned by [visual_cols].
+#[derive(Debug, Clone)]
+pub struct VisualCols<'a> {
+	str: &'a str,
+	indices: EgcIndices<'a>,
+	start: usize,
+	col: usize,
+	tab_width: usize,
+}
+
+impl Iterator for VisualCols<'_> {
+	type Item = (Range<usize>, usize, usize);
+	fn next(&mut self) -> Option<Self::Item> {
+		let end = self.indices.next()?;
+		let range = self.start..end;
+		self.start = end;
+		let start_col = self.col;
+		let egc = &self.str[range.clone()];
+		let width = match egc {
+			"\t" if self.tab_width > 0 => self.tab_width - start_col % self.tab_width,
+			"\n" | "\r" | "\r\n" => {
+				self.col = 0;
+				return Some((range, start_col, 0));
+			},
+			_ => egc_width(egc),
+		};
+		self.col += width;
+		Some((range, start_col, width))
+	}
+}
+
+/// Returns an iterator over the EGCs of `s`, yielding their byte range, the column
+/// at which they start, and the number of columns they take up, as needed to render
+/// the text and a cursor on a monospace grid.
+/// 
+/// Widths are computed by [egc_width], so zero-width clusters take up no column,
+/// and wide characters and emoji two. Tabs extend to the next multiple of
+/// `tab_width` (or have a width of 0 if `tab_width` is 0). Line terminators
+/// have a width of 0, and the following EGC starts back at column 0.
+pub fn visual_cols(s: &str, tab_width: usize) -> VisualCols<'_> {
+	VisualCols { str: s, indices: s.egc_indices(), start: 0, col: 0, tab_width }
+}
+
+/// How [slice_by_columns] snapped a column range to EGCs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ColumnSnap {
+	/// Number of columns at the start of the range covered by an EGC which starts
+	/// before the range (eg. the right half of a wide character), left out of the slice
+	pub left_partial: usize,
+	/// Number of columns at the end of the range covered by an EGC which ends
+	/// after the range (eg. the left half of a wide character), left out of the slice
+	pub right_partial: usize,
+}
+
+/// Returns the slice of `s` made of the EGCs which are entirely within the columns
+/// `cols`, as measured by [egc_width], along with the number of columns at each edge
+/// of the range taken up by EGCs which are only partially within it.
+/// 
+/// Zero-width clusters are considered part of the cell before them, so they are
+/// included if it is. If the string is shorter than the range, the slice simply
+/// ends with the string.
+pub fn slice_by_columns(s: &str, cols: Range<usize>) -> (&str, ColumnSnap) {
+	let (range, snap) = column_range(s, cols);
+	(&s[range], snap)
+}
+
+/// Like [slice_by_columns], but returns the byte range of the slice.
+fn column_range(s: &str, cols: Range<usize>) -> (Range<usize>, ColumnSnap) {
+	// A reversed range is empty
+	let cols = cols.start..cols.end.max(cols.start);
+	let mut snap = ColumnSnap::default();
+	let mut col = 0;
+	let mut start = 0;
+	let mut end = 0;
+	for egc in s.egcs() {
+		let width = egc_width(egc);
+		if col < cols.start || (col == cols.start && width == 0 && col > 0) {
+			if col + width > cols.start {
+				snap.left_partial = (col + width).min(cols.end) - cols.start;
+			}
+			start += egc.len();
+			end = start;
+		} else if col + width > cols.end {
+			if col < cols.end {
+				snap.right_partial = cols.end - col;
+			}
+			break;
+		} else {
+			end += egc.len();
+		}
+		col += width;
+	}
+	(start..end, snap)
+}
+
+/// The part of a line visible in a horizontally scrolled viewport, returned by [hscroll_window].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HScrollWindow<'a> {
+	/// The EGCs entirely within the window
+	pub text: &'a str,
+	/// Number of placeholder cells to draw before `text`, in place of an EGC cut by the left edge
+	pub left_placeholders: usize,
+	/// Number of placeholder cells to draw after `text`, in place of an EGC cut by the right edge
+	pub right_placeholders: usize,
+	/// Whether part of the line is hidden to the left of the window
+	pub truncated_left: bool,
+	/// Whether part of the line is hidden to the right of the window
+	pub truncated_right: bool,
+}
+
+impl HScrollWindow<'_> {
+	/// Writes the visible text, with `placeholder` (which should be narrow) substituted
+	/// for each cell of the EGCs cut by the edges of the window.
+	pub fn write_to(&self, out: &mut impl fmt::Write, placeholder: char) -> fmt::Result {
+		for _ in 0..self.left_placeholders {
+			out.write_char(placeholder)?;
+		}
+		out.write_str(self.text)?;
+		for _ in 0..self.right_placeholders {
+			out.write_char(placeholder)?;
+		}
+		Ok(())
+	}
+}
+
+/// Returns the part of the line `s` visible in a viewport `width` columns wide,
+/// scrolled horizontally to start at column `first_col`.
+/// 
+/// Wide EGCs straddling an edge of the window are replaced with placeholder cells,
+/// so that the rendered text takes up exactly `width` columns (unless the line ends
+/// before the window does). The truncation flags can be used to draw indicators
+/// that the line continues beyond the window. See [slice_by_columns].
+pub fn hscroll_window(s: &str, first_col: usize, width: usize) -> HScrollWindow<'_> {
+	let (range, snap) = column_range(s, first_col..first_col + width);
+	HScrollWindow {
+		text: &s[range.clone()],
+		left_placeholders: snap.left_partial,
+		right_placeholders: snap.right_partial,
+		truncated_left: range.start > 0,
+		truncated_right: range.end < s.len(),
+	}
+}
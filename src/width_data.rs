@@ -0,0 +1,494 @@
+use crate::width::{CharWidth as W, WidthRange, ran};
+pub const WIDTH_RANGES: [WidthRange; 491] = [
+	ran(0x00300,   112, W::Zero),
+	ran(0x00483,     7, W::Zero),
+	ran(0x00591,    45, W::Zero),
+	ran(0x005bf,     1, W::Zero),
+	ran(0x005c1,     2, W::Zero),
+	ran(0x005c4,     2, W::Zero),
+	ran(0x005c7,     1, W::Zero),
+	ran(0x00600,     6, W::Zero),
+	ran(0x00610,    11, W::Zero),
+	ran(0x0061c,     1, W::Zero),
+	ran(0x0064b,    21, W::Zero),
+	ran(0x00670,     1, W::Zero),
+	ran(0x006d6,     8, W::Zero),
+	ran(0x006df,     6, W::Zero),
+	ran(0x006e7,     2, W::Zero),
+	ran(0x006ea,     4, W::Zero),
+	ran(0x0070f,     1, W::Zero),
+	ran(0x00711,     1, W::Zero),
+	ran(0x00730,    27, W::Zero),
+	ran(0x007a6,    11, W::Zero),
+	ran(0x007eb,     9, W::Zero),
+	ran(0x007fd,     1, W::Zero),
+	ran(0x00816,     4, W::Zero),
+	ran(0x0081b,     9, W::Zero),
+	ran(0x00825,     3, W::Zero),
+	ran(0x00829,     5, W::Zero),
+	ran(0x00859,     3, W::Zero),
+	ran(0x00890,     2, W::Zero),
+	ran(0x00897,     9, W::Zero),
+	ran(0x008ca,    57, W::Zero),
+	ran(0x0093a,     1, W::Zero),
+	ran(0x0093c,     1, W::Zero),
+	ran(0x00941,     8, W::Zero),
+	ran(0x0094d,     1, W::Zero),
+	ran(0x00951,     7, W::Zero),
+	ran(0x00962,     2, W::Zero),
+	ran(0x00981,     1, W::Zero),
+	ran(0x009bc,     1, W::Zero),
+	ran(0x009c1,     4, W::Zero),
+	ran(0x009cd,     1, W::Zero),
+	ran(0x009e2,     2, W::Zero),
+	ran(0x009fe,     1, W::Zero),
+	ran(0x00a01,     2, W::Zero),
+	ran(0x00a3c,     1, W::Zero),
+	ran(0x00a41,     2, W::Zero),
+	ran(0x00a47,     2, W::Zero),
+	ran(0x00a4b,     3, W::Zero),
+	ran(0x00a51,     1, W::Zero),
+	ran(0x00a70,     2, W::Zero),
+	ran(0x00a75,     1, W::Zero),
+	ran(0x00a81,     2, W::Zero),
+	ran(0x00abc,     1, W::Zero),
+	ran(0x00ac1,     5, W::Zero),
+	ran(0x00ac7,     2, W::Zero),
+	ran(0x00acd,     1, W::Zero),
+	ran(0x00ae2,     2, W::Zero),
+	ran(0x00afa,     6, W::Zero),
+	ran(0x00b01,     1, W::Zero),
+	ran(0x00b3c,     1, W::Zero),
+	ran(0x00b3f,     1, W::Zero),
+	ran(0x00b41,     4, W::Zero),
+	ran(0x00b4d,     1, W::Zero),
+	ran(0x00b55,     2, W::Zero),
+	ran(0x00b62,     2, W::Zero),
+	ran(0x00b82,     1, W::Zero),
+	ran(0x00bc0,     1, W::Zero),
+	ran(0x00bcd,     1, W::Zero),
+	ran(0x00c00,     1, W::Zero),
+	ran(0x00c04,     1, W::Zero),
+	ran(0x00c3c,     1, W::Zero),
+	ran(0x00c3e,     3, W::Zero),
+	ran(0x00c46,     3, W::Zero),
+	ran(0x00c4a,     4, W::Zero),
+	ran(0x00c55,     2, W::Zero),
+	ran(0x00c62,     2, W::Zero),
+	ran(0x00c81,     1, W::Zero),
+	ran(0x00cbc,     1, W::Zero),
+	ran(0x00cbf,     1, W::Zero),
+	ran(0x00cc6,     1, W::Zero),
+	ran(0x00ccc,     2, W::Zero),
+	ran(0x00ce2,     2, W::Zero),
+	ran(0x00d00,     2, W::Zero),
+	ran(0x00d3b,     2, W::Zero),
+	ran(0x00d41,     4, W::Zero),
+	ran(0x00d4d,     1, W::Zero),
+	ran(0x00d62,     2, W::Zero),
+	ran(0x00d81,     1, W::Zero),
+	ran(0x00dca,     1, W::Zero),
+	ran(0x00dd2,     3, W::Zero),
+	ran(0x00dd6,     1, W::Zero),
+	ran(0x00e31,     1, W::Zero),
+	ran(0x00e34,     7, W::Zero),
+	ran(0x00e47,     8, W::Zero),
+	ran(0x00eb1,     1, W::Zero),
+	ran(0x00eb4,     9, W::Zero),
+	ran(0x00ec8,     7, W::Zero),
+	ran(0x00f18,     2, W::Zero),
+	ran(0x00f35,     1, W::Zero),
+	ran(0x00f37,     1, W::Zero),
+	ran(0x00f39,     1, W::Zero),
+	ran(0x00f71,    14, W::Zero),
+	ran(0x00f80,     5, W::Zero),
+	ran(0x00f86,     2, W::Zero),
+	ran(0x00f8d,    11, W::Zero),
+	ran(0x00f99,    36, W::Zero),
+	ran(0x00fc6,     1, W::Zero),
+	ran(0x0102d,     4, W::Zero),
+	ran(0x01032,     6, W::Zero),
+	ran(0x01039,     2, W::Zero),
+	ran(0x0103d,     2, W::Zero),
+	ran(0x01058,     2, W::Zero),
+	ran(0x0105e,     3, W::Zero),
+	ran(0x01071,     4, W::Zero),
+	ran(0x01082,     1, W::Zero),
+	ran(0x01085,     2, W::Zero),
+	ran(0x0108d,     1, W::Zero),
+	ran(0x0109d,     1, W::Zero),
+	ran(0x01100,    95, W::Wide),
+	ran(0x0115f,   161, W::Zero),
+	ran(0x0135d,     3, W::Zero),
+	ran(0x01712,     3, W::Zero),
+	ran(0x01732,     2, W::Zero),
+	ran(0x01752,     2, W::Zero),
+	ran(0x01772,     2, W::Zero),
+	ran(0x017b4,     2, W::Zero),
+	ran(0x017b7,     7, W::Zero),
+	ran(0x017c6,     1, W::Zero),
+	ran(0x017c9,    11, W::Zero),
+	ran(0x017dd,     1, W::Zero),
+	ran(0x0180b,     5, W::Zero),
+	ran(0x01885,     2, W::Zero),
+	ran(0x018a9,     1, W::Zero),
+	ran(0x01920,     3, W::Zero),
+	ran(0x01927,     2, W::Zero),
+	ran(0x01932,     1, W::Zero),
+	ran(0x01939,     3, W::Zero),
+	ran(0x01a17,     2, W::Zero),
+	ran(0x01a1b,     1, W::Zero),
+	ran(0x01a56,     1, W::Zero),
+	ran(0x01a58,     7, W::Zero),
+	ran(0x01a60,     1, W::Zero),
+	ran(0x01a62,     1, W::Zero),
+	ran(0x01a65,     8, W::Zero),
+	ran(0x01a73,    10, W::Zero),
+	ran(0x01a7f,     1, W::Zero),
+	ran(0x01ab0,    31, W::Zero),
+	ran(0x01b00,     4, W::Zero),
+	ran(0x01b34,     1, W::Zero),
+	ran(0x01b36,     5, W::Zero),
+	ran(0x01b3c,     1, W::Zero),
+	ran(0x01b42,     1, W::Zero),
+	ran(0x01b6b,     9, W::Zero),
+	ran(0x01b80,     2, W::Zero),
+	ran(0x01ba2,     4, W::Zero),
+	ran(0x01ba8,     2, W::Zero),
+	ran(0x01bab,     3, W::Zero),
+	ran(0x01be6,     1, W::Zero),
+	ran(0x01be8,     2, W::Zero),
+	ran(0x01bed,     1, W::Zero),
+	ran(0x01bef,     3, W::Zero),
+	ran(0x01c2c,     8, W::Zero),
+	ran(0x01c36,     2, W::Zero),
+	ran(0x01cd0,     3, W::Zero),
+	ran(0x01cd4,    13, W::Zero),
+	ran(0x01ce2,     7, W::Zero),
+	ran(0x01ced,     1, W::Zero),
+	ran(0x01cf4,     1, W::Zero),
+	ran(0x01cf8,     2, W::Zero),
+	ran(0x01dc0,    64, W::Zero),
+	ran(0x0200b,     5, W::Zero),
+	ran(0x0202a,     5, W::Zero),
+	ran(0x02060,    16, W::Zero),
+	ran(0x020d0,    33, W::Zero),
+	ran(0x0231a,     2, W::Wide),
+	ran(0x02329,     2, W::Wide),
+	ran(0x023e9,     4, W::Wide),
+	ran(0x023f0,     1, W::Wide),
+	ran(0x023f3,     1, W::Wide),
+	ran(0x025fd,     2, W::Wide),
+	ran(0x02614,     2, W::Wide),
+	ran(0x02630,     8, W::Wide),
+	ran(0x02648,    12, W::Wide),
+	ran(0x0267f,     1, W::Wide),
+	ran(0x0268a,     6, W::Wide),
+	ran(0x02693,     1, W::Wide),
+	ran(0x026a1,     1, W::Wide),
+	ran(0x026aa,     2, W::Wide),
+	ran(0x026bd,     2, W::Wide),
+	ran(0x026c4,     2, W::Wide),
+	ran(0x026ce,     1, W::Wide),
+	ran(0x026d4,     1, W::Wide),
+	ran(0x026ea,     1, W::Wide),
+	ran(0x026f2,     2, W::Wide),
+	ran(0x026f5,     1, W::Wide),
+	ran(0x026fa,     1, W::Wide),
+	ran(0x026fd,     1, W::Wide),
+	ran(0x02705,     1, W::Wide),
+	ran(0x0270a,     2, W::Wide),
+	ran(0x02728,     1, W::Wide),
+	ran(0x0274c,     1, W::Wide),
+	ran(0x0274e,     1, W::Wide),
+	ran(0x02753,     3, W::Wide),
+	ran(0x02757,     1, W::Wide),
+	ran(0x02795,     3, W::Wide),
+	ran(0x027b0,     1, W::Wide),
+	ran(0x027bf,     1, W::Wide),
+	ran(0x02b1b,     2, W::Wide),
+	ran(0x02b50,     1, W::Wide),
+	ran(0x02b55,     1, W::Wide),
+	ran(0x02cef,     3, W::Zero),
+	ran(0x02d7f,     1, W::Zero),
+	ran(0x02de0,    32, W::Zero),
+	ran(0x02e80,    26, W::Wide),
+	ran(0x02e9b,    89, W::Wide),
+	ran(0x02f00,   214, W::Wide),
+	ran(0x02ff0,    58, W::Wide),
+	ran(0x0302a,     4, W::Zero),
+	ran(0x0302e,    17, W::Wide),
+	ran(0x03041,    86, W::Wide),
+	ran(0x03099,     2, W::Zero),
+	ran(0x0309b,   101, W::Wide),
+	ran(0x03105,    43, W::Wide),
+	ran(0x03131,    51, W::Wide),
+	ran(0x03164,     1, W::Zero),
+	ran(0x03165,    42, W::Wide),
+	ran(0x03190,    86, W::Wide),
+	ran(0x031ef,    48, W::Wide),
+	ran(0x03220,    40, W::Wide),
+	ran(0x03250, 29245, W::Wide),
+	ran(0x0a490,    55, W::Wide),
+	ran(0x0a66f,     4, W::Zero),
+	ran(0x0a674,    10, W::Zero),
+	ran(0x0a69e,     2, W::Zero),
+	ran(0x0a6f0,     2, W::Zero),
+	ran(0x0a802,     1, W::Zero),
+	ran(0x0a806,     1, W::Zero),
+	ran(0x0a80b,     1, W::Zero),
+	ran(0x0a825,     2, W::Zero),
+	ran(0x0a82c,     1, W::Zero),
+	ran(0x0a8c4,     2, W::Zero),
+	ran(0x0a8e0,    18, W::Zero),
+	ran(0x0a8ff,     1, W::Zero),
+	ran(0x0a926,     8, W::Zero),
+	ran(0x0a947,    11, W::Zero),
+	ran(0x0a960,    29, W::Wide),
+	ran(0x0a980,     3, W::Zero),
+	ran(0x0a9b3,     1, W::Zero),
+	ran(0x0a9b6,     4, W::Zero),
+	ran(0x0a9bc,     2, W::Zero),
+	ran(0x0a9e5,     1, W::Zero),
+	ran(0x0aa29,     6, W::Zero),
+	ran(0x0aa31,     2, W::Zero),
+	ran(0x0aa35,     2, W::Zero),
+	ran(0x0aa43,     1, W::Zero),
+	ran(0x0aa4c,     1, W::Zero),
+	ran(0x0aa7c,     1, W::Zero),
+	ran(0x0aab0,     1, W::Zero),
+	ran(0x0aab2,     3, W::Zero),
+	ran(0x0aab7,     2, W::Zero),
+	ran(0x0aabe,     2, W::Zero),
+	ran(0x0aac1,     1, W::Zero),
+	ran(0x0aaec,     2, W::Zero),
+	ran(0x0aaf6,     1, W::Zero),
+	ran(0x0abe5,     1, W::Zero),
+	ran(0x0abe8,     1, W::Zero),
+	ran(0x0abed,     1, W::Zero),
+	ran(0x0ac00, 11172, W::Wide),
+	ran(0x0f900,   512, W::Wide),
+	ran(0x0fb1e,     1, W::Zero),
+	ran(0x0fe00,    16, W::Zero),
+	ran(0x0fe10,    10, W::Wide),
+	ran(0x0fe20,    16, W::Zero),
+	ran(0x0fe30,    35, W::Wide),
+	ran(0x0fe54,    19, W::Wide),
+	ran(0x0fe68,     4, W::Wide),
+	ran(0x0feff,     1, W::Zero),
+	ran(0x0ff01,    96, W::Wide),
+	ran(0x0ffa0,     1, W::Zero),
+	ran(0x0ffe0,     7, W::Wide),
+	ran(0x0fff0,    12, W::Zero),
+	ran(0x101fd,     1, W::Zero),
+	ran(0x102e0,     1, W::Zero),
+	ran(0x10376,     5, W::Zero),
+	ran(0x10a01,     3, W::Zero),
+	ran(0x10a05,     2, W::Zero),
+	ran(0x10a0c,     4, W::Zero),
+	ran(0x10a38,     3, W::Zero),
+	ran(0x10a3f,     1, W::Zero),
+	ran(0x10ae5,     2, W::Zero),
+	ran(0x10d24,     4, W::Zero),
+	ran(0x10d69,     5, W::Zero),
+	ran(0x10eab,     2, W::Zero),
+	ran(0x10efc,     4, W::Zero),
+	ran(0x10f46,    11, W::Zero),
+	ran(0x10f82,     4, W::Zero),
+	ran(0x11001,     1, W::Zero),
+	ran(0x11038,    15, W::Zero),
+	ran(0x11070,     1, W::Zero),
+	ran(0x11073,     2, W::Zero),
+	ran(0x1107f,     3, W::Zero),
+	ran(0x110b3,     4, W::Zero),
+	ran(0x110b9,     2, W::Zero),
+	ran(0x110bd,     1, W::Zero),
+	ran(0x110c2,     1, W::Zero),
+	ran(0x110cd,     1, W::Zero),
+	ran(0x11100,     3, W::Zero),
+	ran(0x11127,     5, W::Zero),
+	ran(0x1112d,     8, W::Zero),
+	ran(0x11173,     1, W::Zero),
+	ran(0x11180,     2, W::Zero),
+	ran(0x111b6,     9, W::Zero),
+	ran(0x111c9,     4, W::Zero),
+	ran(0x111cf,     1, W::Zero),
+	ran(0x1122f,     3, W::Zero),
+	ran(0x11234,     1, W::Zero),
+	ran(0x11236,     2, W::Zero),
+	ran(0x1123e,     1, W::Zero),
+	ran(0x11241,     1, W::Zero),
+	ran(0x112df,     1, W::Zero),
+	ran(0x112e3,     8, W::Zero),
+	ran(0x11300,     2, W::Zero),
+	ran(0x1133b,     2, W::Zero),
+	ran(0x11340,     1, W::Zero),
+	ran(0x11366,     7, W::Zero),
+	ran(0x11370,     5, W::Zero),
+	ran(0x113bb,     6, W::Zero),
+	ran(0x113ce,     1, W::Zero),
+	ran(0x113d0,     1, W::Zero),
+	ran(0x113d2,     1, W::Zero),
+	ran(0x113e1,     2, W::Zero),
+	ran(0x11438,     8, W::Zero),
+	ran(0x11442,     3, W::Zero),
+	ran(0x11446,     1, W::Zero),
+	ran(0x1145e,     1, W::Zero),
+	ran(0x114b3,     6, W::Zero),
+	ran(0x114ba,     1, W::Zero),
+	ran(0x114bf,     2, W::Zero),
+	ran(0x114c2,     2, W::Zero),
+	ran(0x115b2,     4, W::Zero),
+	ran(0x115bc,     2, W::Zero),
+	ran(0x115bf,     2, W::Zero),
+	ran(0x115dc,     2, W::Zero),
+	ran(0x11633,     8, W::Zero),
+	ran(0x1163d,     1, W::Zero),
+	ran(0x1163f,     2, W::Zero),
+	ran(0x116ab,     1, W::Zero),
+	ran(0x116ad,     1, W::Zero),
+	ran(0x116b0,     6, W::Zero),
+	ran(0x116b7,     1, W::Zero),
+	ran(0x1171d,     1, W::Zero),
+	ran(0x1171f,     1, W::Zero),
+	ran(0x11722,     4, W::Zero),
+	ran(0x11727,     5, W::Zero),
+	ran(0x1182f,     9, W::Zero),
+	ran(0x11839,     2, W::Zero),
+	ran(0x1193b,     2, W::Zero),
+	ran(0x1193e,     1, W::Zero),
+	ran(0x11943,     1, W::Zero),
+	ran(0x119d4,     4, W::Zero),
+	ran(0x119da,     2, W::Zero),
+	ran(0x119e0,     1, W::Zero),
+	ran(0x11a01,    10, W::Zero),
+	ran(0x11a33,     6, W::Zero),
+	ran(0x11a3b,     4, W::Zero),
+	ran(0x11a47,     1, W::Zero),
+	ran(0x11a51,     6, W::Zero),
+	ran(0x11a59,     3, W::Zero),
+	ran(0x11a8a,    13, W::Zero),
+	ran(0x11a98,     2, W::Zero),
+	ran(0x11c30,     7, W::Zero),
+	ran(0x11c38,     6, W::Zero),
+	ran(0x11c3f,     1, W::Zero),
+	ran(0x11c92,    22, W::Zero),
+	ran(0x11caa,     7, W::Zero),
+	ran(0x11cb2,     2, W::Zero),
+	ran(0x11cb5,     2, W::Zero),
+	ran(0x11d31,     6, W::Zero),
+	ran(0x11d3a,     1, W::Zero),
+	ran(0x11d3c,     2, W::Zero),
+	ran(0x11d3f,     7, W::Zero),
+	ran(0x11d47,     1, W::Zero),
+	ran(0x11d90,     2, W::Zero),
+	ran(0x11d95,     1, W::Zero),
+	ran(0x11d97,     1, W::Zero),
+	ran(0x11ef3,     2, W::Zero),
+	ran(0x11f00,     2, W::Zero),
+	ran(0x11f36,     5, W::Zero),
+	ran(0x11f40,     1, W::Zero),
+	ran(0x11f42,     1, W::Zero),
+	ran(0x11f5a,     1, W::Zero),
+	ran(0x13430,    17, W::Zero),
+	ran(0x13447,    15, W::Zero),
+	ran(0x1611e,    12, W::Zero),
+	ran(0x1612d,     3, W::Zero),
+	ran(0x16af0,     5, W::Zero),
+	ran(0x16b30,     7, W::Zero),
+	ran(0x16f4f,     1, W::Zero),
+	ran(0x16f8f,     4, W::Zero),
+	ran(0x16fe0,     4, W::Wide),
+	ran(0x16fe4,     1, W::Zero),
+	ran(0x16ff0,     2, W::Wide),
+	ran(0x17000,  6136, W::Wide),
+	ran(0x18800,  1238, W::Wide),
+	ran(0x18cff,    10, W::Wide),
+	ran(0x1aff0,     4, W::Wide),
+	ran(0x1aff5,     7, W::Wide),
+	ran(0x1affd,     2, W::Wide),
+	ran(0x1b000,   291, W::Wide),
+	ran(0x1b132,     1, W::Wide),
+	ran(0x1b150,     3, W::Wide),
+	ran(0x1b155,     1, W::Wide),
+	ran(0x1b164,     4, W::Wide),
+	ran(0x1b170,   396, W::Wide),
+	ran(0x1bc9d,     2, W::Zero),
+	ran(0x1bca0,     4, W::Zero),
+	ran(0x1cf00,    46, W::Zero),
+	ran(0x1cf30,    23, W::Zero),
+	ran(0x1d167,     3, W::Zero),
+	ran(0x1d173,    16, W::Zero),
+	ran(0x1d185,     7, W::Zero),
+	ran(0x1d1aa,     4, W::Zero),
+	ran(0x1d242,     3, W::Zero),
+	ran(0x1d300,    87, W::Wide),
+	ran(0x1d360,    23, W::Wide),
+	ran(0x1da00,    55, W::Zero),
+	ran(0x1da3b,    50, W::Zero),
+	ran(0x1da75,     1, W::Zero),
+	ran(0x1da84,     1, W::Zero),
+	ran(0x1da9b,     5, W::Zero),
+	ran(0x1daa1,    15, W::Zero),
+	ran(0x1e000,     7, W::Zero),
+	ran(0x1e008,    17, W::Zero),
+	ran(0x1e01b,     7, W::Zero),
+	ran(0x1e023,     2, W::Zero),
+	ran(0x1e026,     5, W::Zero),
+	ran(0x1e08f,     1, W::Zero),
+	ran(0x1e130,     7, W::Zero),
+	ran(0x1e2ae,     1, W::Zero),
+	ran(0x1e2ec,     4, W::Zero),
+	ran(0x1e4ec,     4, W::Zero),
+	ran(0x1e5ee,     2, W::Zero),
+	ran(0x1e8d0,     7, W::Zero),
+	ran(0x1e944,     7, W::Zero),
+	ran(0x1f004,     1, W::Wide),
+	ran(0x1f0cf,     1, W::Wide),
+	ran(0x1f18e,     1, W::Wide),
+	ran(0x1f191,    10, W::Wide),
+	ran(0x1f200,     3, W::Wide),
+	ran(0x1f210,    44, W::Wide),
+	ran(0x1f240,     9, W::Wide),
+	ran(0x1f250,     2, W::Wide),
+	ran(0x1f260,     6, W::Wide),
+	ran(0x1f300,    33, W::Wide),
+	ran(0x1f32d,     9, W::Wide),
+	ran(0x1f337,    70, W::Wide),
+	ran(0x1f37e,    22, W::Wide),
+	ran(0x1f3a0,    43, W::Wide),
+	ran(0x1f3cf,     5, W::Wide),
+	ran(0x1f3e0,    17, W::Wide),
+	ran(0x1f3f4,     1, W::Wide),
+	ran(0x1f3f8,    71, W::Wide),
+	ran(0x1f440,     1, W::Wide),
+	ran(0x1f442,   187, W::Wide),
+	ran(0x1f4ff,    63, W::Wide),
+	ran(0x1f54b,     4, W::Wide),
+	ran(0x1f550,    24, W::Wide),
+	ran(0x1f57a,     1, W::Wide),
+	ran(0x1f595,     2, W::Wide),
+	ran(0x1f5a4,     1, W::Wide),
+	ran(0x1f5fb,    85, W::Wide),
+	ran(0x1f680,    70, W::Wide),
+	ran(0x1f6cc,     1, W::Wide),
+	ran(0x1f6d0,     3, W::Wide),
+	ran(0x1f6d5,     3, W::Wide),
+	ran(0x1f6dc,     4, W::Wide),
+	ran(0x1f6eb,     2, W::Wide),
+	ran(0x1f6f4,     9, W::Wide),
+	ran(0x1f7e0,    12, W::Wide),
+	ran(0x1f7f0,     1, W::Wide),
+	ran(0x1f90c,    47, W::Wide),
+	ran(0x1f93c,    10, W::Wide),
+	ran(0x1f947,   185, W::Wide),
+	ran(0x1fa70,    13, W::Wide),
+	ran(0x1fa80,    10, W::Wide),
+	ran(0x1fa8f,    56, W::Wide),
+	ran(0x1face,    15, W::Wide),
+	ran(0x1fadf,    11, W::Wide),
+	ran(0x1faf0,     9, W::Wide),
+	ran(0x20000, 65534, W::Wide),
+	ran(0x30000, 65534, W::Wide),
+	ran(0xe0000,  4096, W::Zero),
+];
@@ -0,0 +1,124 @@
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::width::egc_width;
+use crate::Egc;
+
+/// Where lines can be broken by [wrap_with_options].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BreakPolicy {
+	/// Lines can be broken between any two EGCs.
+	Anywhere,
+	/// Lines are broken at spaces, which are then left out of the lines.
+	/// Words which do not fit on a line by themselves are broken anywhere.
+	AtSpaces,
+}
+
+/// Options for [wrap_with_options].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WrapOptions {
+	/// Maximum width of the lines in columns, including indentation
+	pub width: usize,
+	/// Indentation of the first line of each paragraph, in columns
+	pub first_indent: usize,
+	/// Indentation of the following lines of each paragraph, in columns
+	pub hanging_indent: usize,
+	/// Where lines can be broken
+	pub policy: BreakPolicy,
+}
+
+impl WrapOptions {
+	/// Creates options for wrapping at spaces to the given width, without indentation.
+	pub fn new(width: usize) -> Self {
+		WrapOptions { width, first_indent: 0, hanging_indent: 0, policy: BreakPolicy::AtSpaces }
+	}
+}
+
+/// A line produced by [wrap_with_options].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedLine {
+	/// Byte range of the line in the wrapped text, excluding line terminators
+	/// and the spaces at which the line was broken
+	pub range: Range<usize>,
+	/// Indentation of the line, in columns
+	pub indent: usize,
+	/// Width of the line in columns, excluding indentation
+	pub width: usize,
+}
+
+/// A possible line break at a run of spaces.
+#[derive(Clone, Copy)]
+struct SpaceBreak {
+	/// End of the line if broken here
+	end: usize,
+	/// Width of the line if broken here
+	width: usize,
+	/// Start of the next line if broken here
+	next_start: usize,
+	/// Width of the current line up to `next_start`
+	next_width: usize,
+}
+
+/// Wraps text to fit in a given width, as measured by [egc_width], calling `on_line`
+/// with each line produced, in order.
+/// 
+/// EGCs are never split. Line terminators (LF, CR, CR LF) start a new paragraph.
+/// EGCs wider than the available width are put on a line of their own.
+pub fn wrap_with_options(s: &str, options: &WrapOptions, mut on_line: impl FnMut(WrappedLine)) {
+	let mut line_start = 0;
+	let mut line_width = 0;
+	let mut indent = options.first_indent;
+	let mut space_break: Option<SpaceBreak> = None;
+	let mut prev_space = false;
+	let mut start = 0;
+	for egc in s.egcs() {
+		let end = start + egc.len();
+		if matches!(egc, "\n" | "\r" | "\r\n") {
+			on_line(WrappedLine { range: line_start..start, indent, width: line_width });
+			line_start = end;
+			line_width = 0;
+			indent = options.first_indent;
+			space_break = None;
+			prev_space = false;
+			start = end;
+			continue;
+		}
+
+		let width = egc_width(egc);
+		let is_space = options.policy == BreakPolicy::AtSpaces && egc == " ";
+		if is_space {
+			let brk = space_break.filter(|_| prev_space);
+			space_break = Some(SpaceBreak {
+				end: brk.map_or(start, |brk| brk.end),
+				width: brk.map_or(line_width, |brk| brk.width),
+				next_start: end,
+				next_width: line_width + width,
+			});
+		} else if line_width + width > options.width.saturating_sub(indent) && line_start < start {
+			let brk = space_break.filter(|brk| brk.end > line_start).unwrap_or(SpaceBreak { end: start, width: line_width, next_start: start, next_width: line_width });
+			on_line(WrappedLine { range: line_start..brk.end, indent, width: brk.width });
+			line_start = brk.next_start;
+			line_width -= brk.next_width;
+			indent = options.hanging_indent;
+			space_break = None;
+		}
+		line_width += width;
+		prev_space = is_space;
+		start = end;
+	}
+	on_line(WrappedLine { range: line_start..s.len(), indent, width: line_width });
+}
+
+/// Wraps text at spaces to fit in `width` columns, and returns the byte ranges
+/// of the lines.
+/// 
+/// See [wrap_with_options] for more control. Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn wrap(s: &str, width: usize) -> Vec<Range<usize>> {
+	let mut lines = vec![];
+	wrap_with_options(s, &WrapOptions::new(width), |line| lines.push(line.range));
+	lines
+}